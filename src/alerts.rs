@@ -0,0 +1,104 @@
+//! Session log of the alerts raised for the open miner: chip drops, watched chip
+//! temperature crossings, throttling, CRC bursts, fan curve and input voltage alerts,
+//! and the maintenance windows that silence their desktop notifications
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Alerts kept in the log; the oldest are dropped beyond this
+pub const ALERT_HISTORY_MAX: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AlertSeverity {
+    /// A condition that cleared, e.g. an input voltage back above the critical level
+    Info,
+    Warning,
+    Critical,
+}
+
+impl AlertSeverity {
+    /// Name used in the CSV log
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertEvent {
+    pub timestamp: DateTime<Local>,
+    pub severity: AlertSeverity,
+    pub miner_ip: String,
+    pub slot_id: i32,
+    /// Chip id for chip alerts, None for board alerts
+    pub chip_id: Option<i32>,
+    pub message: String,
+}
+
+/// Planned downtime during which alerts up to `suppress_level` are only logged,
+/// without a desktop notification
+///
+/// A window whose end is before its start runs past midnight into the next day;
+/// `days` are the days it starts on. Equal start and end times cover the whole day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertWindow {
+    pub days: Vec<Weekday>,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub suppress_level: AlertSeverity,
+}
+
+impl Default for AlertWindow {
+    /// Weeknights 22:00-06:00, every severity
+    fn default() -> Self {
+        Self {
+            days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            start_time: NaiveTime::from_hms_opt(22, 0, 0).unwrap_or_default(),
+            end_time: NaiveTime::from_hms_opt(6, 0, 0).unwrap_or_default(),
+            suppress_level: AlertSeverity::Critical,
+        }
+    }
+}
+
+/// Append an alert, dropping the oldest beyond ALERT_HISTORY_MAX
+pub fn push_alert(history: &mut VecDeque<AlertEvent>, event: AlertEvent) {
+    if history.len() == ALERT_HISTORY_MAX {
+        history.pop_front();
+    }
+    history.push_back(event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_alert_keeps_the_newest() {
+        let event = |slot_id| AlertEvent {
+            timestamp: Local::now(),
+            severity: AlertSeverity::Warning,
+            miner_ip: "10.0.0.5".into(),
+            slot_id,
+            chip_id: None,
+            message: String::new(),
+        };
+        let mut history = VecDeque::new();
+        for slot_id in 0..=i32::try_from(ALERT_HISTORY_MAX).unwrap() {
+            push_alert(&mut history, event(slot_id));
+        }
+        assert_eq!(history.len(), ALERT_HISTORY_MAX);
+        assert_eq!(history.front().map(|e| e.slot_id), Some(1));
+        assert!(AlertSeverity::Critical > AlertSeverity::Info);
+    }
+}
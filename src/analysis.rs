@@ -3,28 +3,272 @@
 //! Provides:
 //! - Gradient: Local hotspot detection (chips hotter than neighbors)
 //! - Outliers: Cross-slot comparison (chips hotter than same position on other boards)
+//! - Health: Composite 0-100 score combining the above with nonce deficit and temperature
+//! - Nonce histogram: distribution of nonce counts across a slot
+//! - Error rate: errors per hour between two polls
+//! - Temperature baseline: learned per-position mean and spread over many polls
+//! - Section stats: top vs bottom half of the snake layout
+//! - CRC clustering: Moran's I of CRC errors over the chip grid
+//! - CRC segments: where in a domain's chain CRC errors start, pointing at a bus fault
+//! - Config match: reported chip count vs the model's spec
+//! - Miner diff: per-chip temperature change between two snapshots
+//! - Board fingerprint: cheap change detection between two fetches of a slot
+
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::MinerConfig;
+use crate::layout::ChipLayout;
+use crate::models::{Chip, MinerData, Slot};
+
+// Health score penalty weights (sum = 100) and the value at which each penalty maxes out
+// (the nonce and outlier penalties max out at the `AnalysisConfig` thresholds)
+const HEALTH_NONCE_WEIGHT: f32 = 50.0;
+const HEALTH_OUTLIER_WEIGHT: f32 = 20.0;
+const HEALTH_GRADIENT_WEIGHT: f32 = 15.0;
+const HEALTH_GRADIENT_FULL: f32 = 15.0; // °C above upstream neighbors
+const HEALTH_TEMP_WEIGHT: f32 = 15.0;
+const HEALTH_TEMP_RANGE: (f32, f32) = (80.0, 100.0); // °C: no penalty below, full penalty above
+
+// Bimodal detection: each peak must hold this share of chips (and at least 2), and the valley between
+// them must drop below this fraction of the smaller peak
+const BIMODAL_MIN_PEAK_SHARE: f32 = 0.15;
+const BIMODAL_MAX_VALLEY_RATIO: f32 = 0.5;
+
+/// Most upstream neighbors a chip's gradient can be measured against
+pub const MAX_GRADIENT_NEIGHBORS: usize = 3;
+
+/// Tunable thresholds of the chip analysis, set in the settings panel
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnalysisConfig {
+    /// Gradient below which a hot spot is treated as sensor noise (°C)
+    pub gradient_noise_floor: f32,
+    /// Cross-slot z-score at which a chip counts as a full outlier (std devs)
+    pub outlier_sigma_threshold: f32,
+    /// Nonce deficit at which a chip counts as critical (% below slot average)
+    pub nonce_deficit_critical_pct: f32,
+    /// Chips with fewer upstream neighbors than this get no gradient
+    pub min_neighbors_for_gradient: usize,
+    /// Gaussian kernel radius of the dead chip density map (grid cells, 1-5)
+    pub dead_density_kernel: usize,
+}
+
+impl Default for AnalysisConfig {
+    fn default() -> Self {
+        Self {
+            gradient_noise_floor: 2.0,
+            outlier_sigma_threshold: 3.0,
+            nonce_deficit_critical_pct: 50.0,
+            min_neighbors_for_gradient: 1,
+            dead_density_kernel: 2,
+        }
+    }
+}
 
-use crate::models::Slot;
+// Section stats: a chip counts as a gradient outlier above this many °C over its upstream neighbors
+const SECTION_GRADIENT_OUTLIER: f32 = 5.0;
+/// Mean temperature difference (°C) between board sections that suggests blocked airflow
+pub const SECTION_TEMP_IMBALANCE: f32 = 5.0;
+
+/// Bins in a chip's domain nonce histogram
+pub const NONCE_HIST_BINS: usize = 5;
+
+/// Chips below this health score count toward failing-chip clusters
+pub const CLUSTER_HEALTH_THRESHOLD: f32 = 50.0;
+/// Fewer adjacent failing chips than this are treated as individual failures
+pub const MIN_CLUSTER_SIZE: usize = 3;
+
+/// Moran's I of CRC errors above this means errors cluster on neighboring chips,
+/// which points at signal integrity rather than individual chips
+pub const CRC_CLUSTERING_HIGH: f32 = 0.3;
+
+/// Time segments of a chip's nonce timeline
+pub const NONCE_TIMELINE_SEGMENTS: usize = 12;
+/// Window covered by the nonce timeline in the chip tooltip (s)
+pub const NONCE_TIMELINE_WINDOW_SECS: u32 = 60;
+
+/// Coefficient of variation (%) at which the thermal uniformity score reaches 0
+const UNIFORMITY_ZERO_CV: f32 = 25.0;
+/// Thermal uniformity scores below this (%) are flagged in the slot header
+pub const UNIFORMITY_WARN_PCT: f32 = 70.0;
+
+/// Polls a position needs before its temperature baseline counts as established
+pub const TEMP_BASELINE_POLLS: u32 = 10;
+/// Deviation from the learned baseline (in std devs) treated as anomalous
+pub const TEMP_ANOMALY_SIGMA: f32 = 3.0;
+// Chips reporting the same temperature every poll would otherwise have zero spread
+const TEMP_BASELINE_MIN_STD: f32 = 0.5;
+
+// A domain's CRC errors are attributed to a bus segment when the later half of its
+// chain has at least this many and this many times the earlier half's mean
+const CRC_SEGMENT_MIN_ERRORS: i32 = 10;
+const CRC_SEGMENT_RATIO: f32 = 3.0;
+
+/// Boards with a smaller share of unchanged chips than this changed since the last fetch
+pub const BOARD_SIMILARITY_MIN: f32 = 0.9;
+// Temperature swing between fetches still treated as the same chip state (°C)
+const BOARD_CHANGE_TEMP: i32 = 3;
+// Multiplier of the Fx hash (rustc's FxHasher)
+const FX_SEED: u64 = 0x517c_c1b7_2722_0a95;
+
+/// Slots whose nonce rate is further than this share from the mean are imbalanced
+pub const SLOT_BALANCE_TOLERANCE: f32 = 0.2;
+
+/// Chips whose readings haven't changed for more polls than this may be reporting
+/// cached data (a firmware bug in some versions)
+pub const STALE_DATA_POLLS: u32 = 5;
+
+/// Polls of (nonce, freq) kept per chip for the efficiency trend
+pub const EFFICIENCY_TREND_POLLS: usize = 10;
+// Fewer polls than this give no trend
+const EFFICIENCY_TREND_MIN_POLLS: usize = 3;
+/// Efficiency trend slopes (nonce/MHz per poll): below this is a steady decline
+pub const EFFICIENCY_STEEP_DECLINE: f32 = -0.5;
+/// Slopes within ± this are stable
+pub const EFFICIENCY_STABLE: f32 = 0.1;
+
+/// Polls of active chip counts kept per slot for the header sparkline
+pub const ACTIVE_CHIP_HISTORY_POLLS: usize = 20;
+/// Chips disabled between two polls that raise a chip drop alert
+pub const CHIP_DROP_ALERT: usize = 5;
+/// New CRC errors on one slot between two polls that count as a CRC burst
+pub const CRC_BURST_ALERT: i32 = 100;
+
+/// Most voltage groups `cluster_chips_by_voltage` splits a board into
+pub const VOLTAGE_GROUPS_MAX: usize = 4;
+// Voltage steps (chip `vol` units) narrower than this stay within one group
+const VOLTAGE_GROUP_MIN_GAP: i32 = 5;
+
+/// Polls of throttle status kept for the throttle history chart (an hour at 5 s)
+const THROTTLE_HISTORY_POLLS: usize = 720;
+/// Slot clock drop below its session peak (%) that counts as throttling
+const THROTTLE_FREQ_DROP_PCT: f32 = 5.0;
+/// Share of throttled polls (%) above which a slot is chronically overheating
+pub const THROTTLE_RATE_WARN_PCT: f32 = 20.0;
+
+/// Chips within this many MHz of the slot's programmed frequency are compliant
+pub const FREQ_COMPLIANCE_TOLERANCE_MHZ: i32 = 5;
+/// Slots with a smaller share of compliant chips get an amber warning
+pub const FREQ_COMPLIANCE_WARN: f32 = 0.9;
+
+/// Chips below this (°C) have cooled down; warming back past it is one heat cycle
+pub const CHIP_TEMP_COOL: i32 = 60;
+
+/// Chip clocks below this (MHz) are a firmware sleep frequency, not a throttle
+const SLEEP_FREQ_MAX_MHZ: i32 = 100;
+/// Chips below this share of the target frequency count as throttled
+const THROTTLED_FREQ_RATIO: f32 = 0.9;
+
+// Chip count mismatches above this share of the expected count are shown as errors, not warnings
+const CONFIG_MISMATCH_SEVERE_RATIO: f32 = 0.05;
 
 /// Analysis results for a single chip
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ChipAnalysis {
     /// Local gradient: positive = hotter than neighbors (the bad case)
     /// Zero or negative values indicate chip is same or cooler than surroundings
+    /// Values below the noise floor are clipped to zero
     pub gradient: f32,
+    /// Gradient scaled by the square of the neighbor count (more neighbors = more reliable)
+    pub gradient_significance: f32,
     /// Cross-slot z-score: how many std devs hotter than same position on other slots
     /// Positive = hotter than other boards at this position
     pub cross_slot_zscore: f32,
+    /// Like `cross_slot_zscore`, against the same position on every board of every
+    /// loaded fleet miner (0 with fewer than two miners)
+    pub cross_miner_zscore: f32,
     /// Nonce deficit: percentage below slot average (0 = average, 100 = zero nonces)
     /// Higher = worse performance
     pub nonce_deficit: f32,
+    /// Composite health: 100 = healthy, 0 = dead chip
+    pub health_score: f32,
+    /// Z-score of nonce/MHz vs slot average: positive = efficient silicon, negative = inefficient
+    pub nonce_per_mhz_zscore: f32,
+    /// Errors per hour since the previous poll (0 until two polls are available)
+    pub error_rate_per_hour: f32,
+    /// Mean CRC errors of the adjacent chips relative to the slot's worst chip (0-1)
+    pub crc_neighbor_correlation: f32,
+    /// Std devs from the position's learned temperature baseline: positive = hotter than usual
+    /// (0 until the baseline is established)
+    pub temp_anomaly_score: f32,
+    /// Chip frequency is off the slot's programmed frequency by more than the tolerance
+    pub freq_noncompliant: bool,
+    /// Polls in a row with every reading identical to the previous one
+    pub data_staleness_polls: u32,
+    /// Temperature read off an aligned IR photo at the chip's position (°C)
+    pub ir_temp: Option<f32>,
+    /// Slope of nonce/MHz over the recent polls: positive = improving, negative = declining
+    /// (0 until EFFICIENCY_TREND_MIN_POLLS polls are available)
+    pub efficiency_trend: f32,
+    /// Voltage group on the board, lowest voltages first (see `cluster_chips_by_voltage`)
+    pub voltage_group: u8,
+    /// Gaussian-weighted share of dead chips around this one (see `compute_dead_density_map`)
+    pub dead_density: f32,
+    /// Power state from the chip clock against the slot's programmed frequency
+    pub power_state: ChipPowerState,
+    /// Times the chip warmed past CHIP_TEMP_COOL this session (see `ChipMetadata`)
+    pub heat_cycles: u32,
+}
+
+/// Running mean and variance of one chip position's temperature (Welford's method)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunningMeanVar {
+    pub count: u32,
+    pub mean: f32,
+    /// Sum of squared deviations from the mean
+    m2: f32,
+}
+
+impl RunningMeanVar {
+    /// Rebuild from exported count, mean and (population) variance
+    #[allow(clippy::cast_precision_loss)] // poll counts fit in f32
+    pub fn from_parts(count: u32, mean: f32, variance: f32) -> Self {
+        Self {
+            count,
+            mean,
+            m2: variance * count as f32,
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)] // poll counts fit in f32
+    pub fn push(&mut self, value: f32) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f32;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Population variance of the values seen so far
+    #[allow(clippy::cast_precision_loss)] // poll counts fit in f32
+    pub fn variance(&self) -> f32 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.m2 / self.count as f32
+        }
+    }
+
+    pub fn std_dev(&self) -> f32 {
+        self.variance().sqrt()
+    }
 }
 
+/// Learned temperature per (slot id, chip id)
+pub type TempBaseline = HashMap<(i32, i32), RunningMeanVar>;
+
 /// Analyze all slots together for cross-slot comparison
 ///
 /// Returns a Vec of analysis results per slot, parallel to input slots.
 /// Each inner Vec is parallel to that slot's chips.
-pub fn analyze_all_slots(slots: &[Slot], chips_per_domain: usize) -> Vec<Vec<ChipAnalysis>> {
+pub fn analyze_all_slots(
+    slots: &[Slot],
+    chips_per_domain: usize,
+    config: &AnalysisConfig,
+) -> Vec<Vec<ChipAnalysis>> {
     if slots.is_empty() {
         return vec![];
     }
@@ -37,7 +281,9 @@ pub fn analyze_all_slots(slots: &[Slot], chips_per_domain: usize) -> Vec<Vec<Chi
         .map(|chip_idx| {
             slots
                 .iter()
-                .filter_map(|slot| slot.chips.get(chip_idx).map(|c| c.temp))
+                .filter_map(|slot| slot.chips.get(chip_idx))
+                .filter(|c| !c.placeholder)
+                .map(|c| c.temp)
                 .collect()
         })
         .collect();
@@ -51,15 +297,87 @@ pub fn analyze_all_slots(slots: &[Slot], chips_per_domain: usize) -> Vec<Vec<Chi
     // Analyze each slot
     slots
         .iter()
-        .map(|slot| analyze_single_slot(slot, chips_per_domain, &cross_slot_stats))
+        .map(|slot| analyze_single_slot(slot, chips_per_domain, &cross_slot_stats, config))
         .collect()
 }
 
+/// A chip compared with the same position across miners
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CrossMinerAnalysis {
+    /// How many std devs hotter than the same chip position on all boards of all
+    /// miners; only positive deviations count, as with `cross_slot_zscore`
+    pub cross_miner_temp_zscore: f32,
+}
+
+/// Cross-slot comparison extended to several miners: each chip against the same
+/// chip index on every board of every miner
+///
+/// Result is per miner, per slot, per chip, parallel to `all_miners`. Miners
+/// should share a board layout (`chips_per_domain`); with fewer than two miners
+/// every score is 0.
+pub fn analyze_cross_miner(
+    all_miners: &[&MinerData],
+    chips_per_domain: usize,
+) -> Vec<Vec<Vec<CrossMinerAnalysis>>> {
+    let boards = || all_miners.iter().flat_map(|m| &m.slots);
+    let max_chips = boards().map(|s| s.chips.len()).max().unwrap_or(0);
+    let stats: Vec<(f32, f32)> = if all_miners.len() < 2 || chips_per_domain == 0 {
+        Vec::new()
+    } else {
+        (0..max_chips)
+            .map(|chip_idx| {
+                let temps: Vec<i32> = boards()
+                    .filter_map(|slot| slot.chips.get(chip_idx))
+                    .filter(|c| !c.placeholder)
+                    .map(|c| c.temp)
+                    .collect();
+                compute_mean_std(&temps)
+            })
+            .collect()
+    };
+    all_miners
+        .iter()
+        .map(|miner| {
+            miner
+                .slots
+                .iter()
+                .map(|slot| {
+                    slot.chips
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, chip)| CrossMinerAnalysis {
+                            cross_miner_temp_zscore: stats
+                                .get(idx)
+                                .filter(|_| !chip.placeholder)
+                                .map_or(0.0, |&(mean, std)| {
+                                    compute_hot_zscore(chip.temp, mean, std)
+                                }),
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Copy one miner's `analyze_cross_miner` results into its chip analysis
+pub fn apply_cross_miner(
+    all_analysis: &mut [Vec<ChipAnalysis>],
+    cross_miner: &[Vec<CrossMinerAnalysis>],
+) {
+    for (analysis, cross) in all_analysis.iter_mut().zip(cross_miner) {
+        for (a, c) in analysis.iter_mut().zip(cross) {
+            a.cross_miner_zscore = c.cross_miner_temp_zscore;
+        }
+    }
+}
+
 /// Analyze a single slot with pre-computed cross-slot statistics
 fn analyze_single_slot(
     slot: &Slot,
     chips_per_domain: usize,
     cross_slot_stats: &[(f32, f32)],
+    config: &AnalysisConfig,
 ) -> Vec<ChipAnalysis> {
     let chips = &slot.chips;
 
@@ -67,24 +385,38 @@ fn analyze_single_slot(
         return vec![ChipAnalysis::default(); chips.len()];
     }
 
-    let num_domains = chips.len().div_ceil(chips_per_domain);
-
-    // Snake pattern section split (must match ui.rs logic)
-    let remaining = num_domains.saturating_sub(1);
-    let bottom_domains = 1 + remaining / 2;
+    let layout = ChipLayout::new(chips.len(), chips_per_domain);
+    let num_domains = layout.num_domains;
 
     // Compute slot average nonce for performance comparison
     let slot_avg_nonce = compute_slot_avg_nonce(chips);
 
+    // Nonce/MHz efficiency stats for the slot, over the chips the firmware reported
+    let nonce_per_mhz: Vec<f32> = chips.iter().map(compute_nonce_per_mhz).collect();
+    let reported: Vec<f32> = chips
+        .iter()
+        .zip(&nonce_per_mhz)
+        .filter(|(c, _)| !c.placeholder)
+        .map(|(_, &npm)| npm)
+        .collect();
+    let (npm_mean, npm_std) = compute_mean_std_f32(&reported);
+
+    let max_crc = chips.iter().map(|c| c.crc).max().unwrap_or(0);
+    let voltage_groups = cluster_chips_by_voltage(chips);
+    let dead_density = compute_dead_density_map(slot, chips_per_domain, config.dead_density_kernel);
+
     chips
         .iter()
         .enumerate()
         .map(|(idx, chip)| {
+            // Nothing to score on a chip the firmware didn't report
+            if chip.placeholder {
+                return ChipAnalysis::default();
+            }
             let domain = idx / chips_per_domain;
             let row = idx % chips_per_domain;
 
-            // Determine if chip is in top or bottom section
-            let is_top_section = domain >= bottom_domains;
+            let is_top_section = layout.is_top_section(domain);
 
             // Local gradient (only positive = hotter than upstream neighbors)
             let neighbors = get_upstream_neighbor_temps(
@@ -95,7 +427,15 @@ fn analyze_single_slot(
                 row,
                 is_top_section,
             );
-            let gradient = compute_hot_gradient(chip.temp, &neighbors);
+            let gradient = if neighbors.len() < config.min_neighbors_for_gradient {
+                0.0
+            } else {
+                clip_noise(
+                    compute_hot_gradient(chip.temp, &neighbors),
+                    config.gradient_noise_floor,
+                )
+            };
+            let gradient_significance = gradient * neighbors.len().pow(2) as f32;
 
             // Cross-slot comparison
             let cross_slot_zscore = if let Some(&(mean, std)) = cross_slot_stats.get(idx) {
@@ -107,15 +447,1289 @@ fn analyze_single_slot(
             // Nonce performance deficit
             let nonce_deficit = compute_nonce_deficit(chip.nonce, slot_avg_nonce);
 
+            let health_score =
+                compute_health_score(chip, gradient, cross_slot_zscore, nonce_deficit, config);
+
+            let nonce_per_mhz_zscore = if npm_std > f32::EPSILON {
+                (nonce_per_mhz[idx] - npm_mean) / npm_std
+            } else {
+                0.0
+            };
+
             ChipAnalysis {
                 gradient,
+                gradient_significance,
                 cross_slot_zscore,
                 nonce_deficit,
+                health_score,
+                nonce_per_mhz_zscore,
+                crc_neighbor_correlation: compute_crc_neighbor_mean(
+                    chips,
+                    chips_per_domain,
+                    idx,
+                    max_crc,
+                ),
+                freq_noncompliant: freq_compliance(
+                    chip.freq,
+                    slot.freq,
+                    FREQ_COMPLIANCE_TOLERANCE_MHZ,
+                ) != Ordering::Equal,
+                voltage_group: voltage_groups[idx],
+                dead_density: dead_density[idx],
+                power_state: compute_chip_power_state(chip, slot.freq),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+/// Group a board's chips by supply voltage, as a proxy for mixed chip batches
+///
+/// Threshold clustering: the sorted voltages are split at their widest gaps of at
+/// least VOLTAGE_GROUP_MIN_GAP, at most VOLTAGE_GROUPS_MAX - 1 times, giving 1-4
+/// groups numbered from the lowest voltage up. Chips without a voltage are group 0.
+pub fn cluster_chips_by_voltage(chips: &[Chip]) -> Vec<u8> {
+    let mut vols: Vec<i32> = chips.iter().map(|c| c.vol).filter(|&v| v > 0).collect();
+    vols.sort_unstable();
+    vols.dedup();
+    let mut gaps: Vec<(i32, i32)> = vols
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0], pair[1]))
+        .filter(|&(gap, _)| gap >= VOLTAGE_GROUP_MIN_GAP)
+        .collect();
+    // Widest gaps first; each kept gap starts a new group at its upper voltage
+    gaps.sort_by_key(|&(gap, _)| std::cmp::Reverse(gap));
+    let mut splits: Vec<i32> = gaps
+        .iter()
+        .take(VOLTAGE_GROUPS_MAX - 1)
+        .map(|&(_, upper)| upper)
+        .collect();
+    splits.sort_unstable();
+    chips
+        .iter()
+        .map(|chip| {
+            let group = splits.iter().filter(|&&split| chip.vol >= split).count();
+            if chip.vol > 0 {
+                u8::try_from(group).unwrap_or(u8::MAX)
+            } else {
+                0
+            }
+        })
+        .collect()
+}
+
+/// Errors per hour between two polls
+/// A drop in the counter (miner restarted) or a non-positive interval yields 0
+#[allow(clippy::cast_precision_loss)] // error deltas fit in f32
+pub fn compute_error_rate(current: i32, previous: i32, interval_h: f32) -> f32 {
+    if interval_h <= 0.0 || current < previous {
+        return 0.0;
+    }
+    (current - previous) as f32 / interval_h
+}
+
+/// Fill in `error_rate_per_hour` from the previous poll's chip state
+///
+/// Chips are matched by slot id and chip id; chips missing from the previous poll keep 0.
+pub fn apply_error_rates(
+    all_analysis: &mut [Vec<ChipAnalysis>],
+    slots: &[Slot],
+    previous: &[Slot],
+    interval_h: f32,
+) {
+    for (slot, analysis) in slots.iter().zip(all_analysis.iter_mut()) {
+        let Some(prev_slot) = previous.iter().find(|s| s.id == slot.id) else {
+            continue;
+        };
+        for (chip, a) in slot.chips.iter().zip(analysis.iter_mut()) {
+            if let Some(prev_chip) = prev_slot.chips.iter().find(|c| c.id == chip.id) {
+                a.error_rate_per_hour =
+                    compute_error_rate(chip.errors, prev_chip.errors, interval_h);
+            }
+        }
+    }
+}
+
+/// Add one poll's chip temperatures to the baseline; placeholders are skipped
+#[allow(clippy::cast_precision_loss)] // temps fit in f32
+pub fn update_temp_baseline(baseline: &mut TempBaseline, slots: &[Slot]) {
+    for slot in slots {
+        for chip in slot.chips.iter().filter(|c| !c.placeholder) {
+            baseline
+                .entry((slot.id, chip.id))
+                .or_default()
+                .push(chip.temp as f32);
+        }
+    }
+}
+
+/// Fill in `temp_anomaly_score` for positions with at least `min_polls` baseline samples
+///
+/// Chips are matched by slot id and chip id, like `apply_error_rates`.
+#[allow(clippy::cast_precision_loss)] // temps fit in f32
+pub fn apply_temp_baseline(
+    all_analysis: &mut [Vec<ChipAnalysis>],
+    slots: &[Slot],
+    baseline: &TempBaseline,
+    min_polls: u32,
+) {
+    for (slot, analysis) in slots.iter().zip(all_analysis.iter_mut()) {
+        for (chip, a) in slot.chips.iter().zip(analysis.iter_mut()) {
+            a.temp_anomaly_score = baseline
+                .get(&(slot.id, chip.id))
+                .filter(|b| b.count >= min_polls.max(1))
+                .map_or(0.0, |b| {
+                    (chip.temp as f32 - b.mean) / b.std_dev().max(TEMP_BASELINE_MIN_STD)
+                });
+        }
+    }
+}
+
+/// Polls seen by the best-covered position, for "4/10 polls" progress
+pub fn temp_baseline_polls(baseline: &TempBaseline) -> u32 {
+    baseline.values().map(|b| b.count).max().unwrap_or(0)
+}
+
+/// Recent (nonce, freq) readings per (slot id, chip id), oldest first
+pub type EfficiencyHistory = HashMap<(i32, i32), Vec<(i64, i32)>>;
+
+/// Direction of a chip's nonce/MHz over the recent polls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EfficiencyTrend {
+    /// Slope below EFFICIENCY_STEEP_DECLINE
+    SteadyDecline,
+    SlightDecline,
+    /// Slope within ±EFFICIENCY_STABLE
+    Stable,
+    Improving,
+}
+
+impl EfficiencyTrend {
+    pub fn from_slope(slope: f32) -> Self {
+        if slope < EFFICIENCY_STEEP_DECLINE {
+            Self::SteadyDecline
+        } else if slope < -EFFICIENCY_STABLE {
+            Self::SlightDecline
+        } else if slope <= EFFICIENCY_STABLE {
+            Self::Stable
+        } else {
+            Self::Improving
+        }
+    }
+}
+
+/// Least-squares slope of nonce/freq against poll number, for (nonce, freq) readings
+/// oldest first; 0 with fewer than EFFICIENCY_TREND_MIN_POLLS readings
+#[allow(clippy::cast_precision_loss)] // poll counts and nonce/MHz fit in f32
+pub fn compute_efficiency_trend(history: &[(i64, i32)]) -> f32 {
+    if history.len() < EFFICIENCY_TREND_MIN_POLLS {
+        return 0.0;
+    }
+    let n = history.len() as f32;
+    let mean_x = (n - 1.0) / 2.0;
+    let ys: Vec<f32> = history
+        .iter()
+        .map(|&(nonce, freq)| nonce as f32 / freq.max(1) as f32)
+        .collect();
+    let mean_y = ys.iter().sum::<f32>() / n;
+    let (cov, var) = ys
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(cov, var), (x, y)| {
+            let dx = x as f32 - mean_x;
+            (cov + dx * (y - mean_y), var + dx * dx)
+        });
+    cov / var
+}
+
+/// Append each chip's (nonce, freq) to its history, keeping the last EFFICIENCY_TREND_POLLS;
+/// placeholders are skipped
+pub fn update_efficiency_history(history: &mut EfficiencyHistory, slots: &[Slot]) {
+    for slot in slots {
+        for chip in slot.chips.iter().filter(|c| !c.placeholder) {
+            let polls = history.entry((slot.id, chip.id)).or_default();
+            if polls.len() == EFFICIENCY_TREND_POLLS {
+                polls.remove(0);
+            }
+            polls.push((chip.nonce, chip.freq));
+        }
+    }
+}
+
+/// Fill in `efficiency_trend`, matching chips by slot id and chip id
+pub fn apply_efficiency_trends(
+    all_analysis: &mut [Vec<ChipAnalysis>],
+    slots: &[Slot],
+    history: &EfficiencyHistory,
+) {
+    for (slot, analysis) in slots.iter().zip(all_analysis.iter_mut()) {
+        for (chip, a) in slot.chips.iter().zip(analysis.iter_mut()) {
+            a.efficiency_trend = history
+                .get(&(slot.id, chip.id))
+                .map_or(0.0, |polls| compute_efficiency_trend(polls));
+        }
+    }
+}
+
+/// What the session has learned about one chip across polls
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChipMetadata {
+    /// Temperature at the previous poll
+    pub last_temp: i32,
+    /// Estimated heat cycles: warm-ups past CHIP_TEMP_COOL from below
+    pub heat_cycles: u32,
+}
+
+/// Chip metadata per (slot id, chip id), like `ChipTracker`
+pub type ChipMetadataMap = HashMap<(i32, i32), ChipMetadata>;
+
+/// Times `history` rises from below `threshold` to at or above it
+pub fn count_temp_threshold_crossings(history: &[i32], threshold: i32) -> u32 {
+    let crossings = history
+        .windows(2)
+        .filter(|pair| pair[0] < threshold && pair[1] >= threshold)
+        .count();
+    u32::try_from(crossings).unwrap_or(u32::MAX)
+}
+
+/// Add a poll's temperatures, counting a heat cycle for every chip that warmed
+/// past CHIP_TEMP_COOL since the previous poll; placeholders are skipped
+pub fn update_chip_metadata(metadata: &mut ChipMetadataMap, slots: &[Slot]) {
+    for slot in slots {
+        for chip in slot.chips.iter().filter(|c| !c.placeholder) {
+            match metadata.get_mut(&(slot.id, chip.id)) {
+                Some(meta) => {
+                    meta.heat_cycles += count_temp_threshold_crossings(
+                        &[meta.last_temp, chip.temp],
+                        CHIP_TEMP_COOL,
+                    );
+                    meta.last_temp = chip.temp;
+                }
+                None => {
+                    metadata.insert(
+                        (slot.id, chip.id),
+                        ChipMetadata {
+                            last_temp: chip.temp,
+                            heat_cycles: 0,
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Fill in `heat_cycles`, matching chips by slot id and chip id
+pub fn apply_heat_cycles(
+    all_analysis: &mut [Vec<ChipAnalysis>],
+    slots: &[Slot],
+    metadata: &ChipMetadataMap,
+) {
+    for (slot, analysis) in slots.iter().zip(all_analysis.iter_mut()) {
+        for (chip, a) in slot.chips.iter().zip(analysis.iter_mut()) {
+            a.heat_cycles = metadata
+                .get(&(slot.id, chip.id))
+                .map_or(0, |m| m.heat_cycles);
+        }
+    }
+}
+
+/// Active chip count per poll, by slot id, oldest first
+pub type ActiveChipHistory = HashMap<i32, VecDeque<usize>>;
+
+/// Chips the firmware has enabled (non-zero frequency)
+pub fn active_chip_count(slot: &Slot) -> usize {
+    slot.chips.iter().filter(|c| c.freq > 0).count()
+}
+
+/// Add a poll's active chip counts; returns (slot id, chips lost) for slots that
+/// dropped at least CHIP_DROP_ALERT chips since the previous poll
+pub fn record_active_chips(history: &mut ActiveChipHistory, slots: &[Slot]) -> Vec<(i32, usize)> {
+    let mut drops = Vec::new();
+    for slot in slots {
+        let counts = history.entry(slot.id).or_default();
+        let active = active_chip_count(slot);
+        if let Some(&previous) = counts.back()
+            && previous >= active + CHIP_DROP_ALERT
+        {
+            drops.push((slot.id, previous - active));
+        }
+        if counts.len() == ACTIVE_CHIP_HISTORY_POLLS {
+            counts.pop_front();
+        }
+        counts.push_back(active);
+    }
+    drops
+}
+
+/// (slot id, new CRC errors) for slots whose chips logged at least CRC_BURST_ALERT
+/// CRC errors since `previous`, matched by slot id; counters that went down (a
+/// reboot) are skipped
+pub fn find_crc_bursts(previous: &[Slot], current: &[Slot]) -> Vec<(i32, i32)> {
+    let crc = |slot: &Slot| slot.chips.iter().map(|c| c.crc).sum::<i32>();
+    current
+        .iter()
+        .filter_map(|slot| {
+            let before = previous.iter().find(|s| s.id == slot.id)?;
+            let new_errors = crc(slot) - crc(before);
+            (new_errors >= CRC_BURST_ALERT).then_some((slot.id, new_errors))
+        })
+        .collect()
+}
+
+/// Fewer chips active now than at the start of the kept history
+pub fn is_active_count_declining(counts: &VecDeque<usize>) -> bool {
+    matches!((counts.front(), counts.back()), (Some(first), Some(last)) if last < first)
+}
+
+/// A slot's clock at one poll compared with the highest clock it ran at this session
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThrottleStatus {
+    pub slot_id: i32,
+    pub freq: i32,
+    /// How far `freq` is below the session peak (%); 0 when not throttled
+    pub severity_pct: f32,
+}
+
+impl ThrottleStatus {
+    pub fn is_throttled(self) -> bool {
+        self.severity_pct > 0.0
+    }
+}
+
+/// Throttle status of every slot per poll, oldest first
+pub type ThrottleHistory = VecDeque<(Instant, Vec<ThrottleStatus>)>;
+
+/// Whether each slot is throttled at this poll
+///
+/// The firmware pulls a slot's frequency down when the board runs too hot, so a clock
+/// more than THROTTLE_FREQ_DROP_PCT below the highest one in `history` counts as
+/// throttled. A manual underclock during the session reads the same way.
+#[allow(clippy::cast_precision_loss)] // MHz fit in f32
+pub fn compute_throttle_status(slots: &[Slot], history: &ThrottleHistory) -> Vec<ThrottleStatus> {
+    slots
+        .iter()
+        .map(|slot| {
+            let peak = history
+                .iter()
+                .flat_map(|(_, statuses)| statuses)
+                .filter(|s| s.slot_id == slot.id)
+                .map(|s| s.freq)
+                .fold(slot.freq, i32::max);
+            let drop_pct = if peak > 0 {
+                (peak - slot.freq) as f32 / peak as f32 * 100.0
+            } else {
+                0.0
+            };
+            ThrottleStatus {
+                slot_id: slot.id,
+                freq: slot.freq,
+                severity_pct: if drop_pct > THROTTLE_FREQ_DROP_PCT {
+                    drop_pct
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect()
+}
+
+/// Add a poll's throttle status, dropping the oldest beyond THROTTLE_HISTORY_POLLS
+pub fn record_throttle_status(
+    history: &mut ThrottleHistory,
+    at: Instant,
+    statuses: Vec<ThrottleStatus>,
+) {
+    if history.len() == THROTTLE_HISTORY_POLLS {
+        history.pop_front();
+    }
+    history.push_back((at, statuses));
+}
+
+/// Throttled polls / recorded polls of a slot (%); None before its first recorded poll
+#[allow(clippy::cast_precision_loss)] // poll counts fit in f32
+pub fn throttle_percentage(history: &ThrottleHistory, slot_id: i32) -> Option<f32> {
+    let (throttled, total) = history
+        .iter()
+        .flat_map(|(_, statuses)| statuses)
+        .filter(|s| s.slot_id == slot_id)
+        .fold((0usize, 0usize), |(throttled, total), s| {
+            (throttled + usize::from(s.is_throttled()), total + 1)
+        });
+    (total > 0).then(|| throttled as f32 / total as f32 * 100.0)
+}
+
+/// A chip's readings at the last poll and when they last changed
+#[derive(Debug, Clone)]
+pub struct TrackedChip {
+    pub chip: Chip,
+    /// Poll at which any reading last differed from the one before
+    pub last_changed: Instant,
+    /// Polls since `last_changed`
+    pub unchanged_polls: u32,
+}
+
+/// Last readings per (slot id, chip id), like `TempBaseline`
+pub type ChipTracker = HashMap<(i32, i32), TrackedChip>;
+
+/// Compare a poll's chips with the tracked readings; chips with any differing
+/// field (or seen for the first time) are marked changed at `now`
+pub fn track_chip_changes(tracker: &mut ChipTracker, slots: &[Slot], now: Instant) {
+    for slot in slots {
+        for chip in &slot.chips {
+            match tracker.get_mut(&(slot.id, chip.id)) {
+                Some(tracked) if tracked.chip == *chip => tracked.unchanged_polls += 1,
+                Some(tracked) => {
+                    tracked.chip.clone_from(chip);
+                    tracked.last_changed = now;
+                    tracked.unchanged_polls = 0;
+                }
+                None => {
+                    tracker.insert(
+                        (slot.id, chip.id),
+                        TrackedChip {
+                            chip: chip.clone(),
+                            last_changed: now,
+                            unchanged_polls: 0,
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Fill in `data_staleness_polls`, matching chips by slot id and chip id
+pub fn apply_data_staleness(
+    all_analysis: &mut [Vec<ChipAnalysis>],
+    slots: &[Slot],
+    tracker: &ChipTracker,
+) {
+    for (slot, analysis) in slots.iter().zip(all_analysis.iter_mut()) {
+        for (chip, a) in slot.chips.iter().zip(analysis.iter_mut()) {
+            a.data_staleness_polls = tracker
+                .get(&(slot.id, chip.id))
+                .map_or(0, |t| t.unchanged_polls);
+        }
+    }
+}
+
+/// Aggregate stats for one physical board section
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SectionStats {
+    pub chip_count: usize,
+    pub mean_temp: f32,
+    pub mean_nonce: f64,
+    pub dead_chips: usize,
+    /// Chips more than SECTION_GRADIENT_OUTLIER °C hotter than their upstream neighbors
+    pub gradient_outliers: usize,
+}
+
+/// Chips of one domain (the last domain may be short)
+fn domain_chips(chips: &[Chip], chips_per_domain: usize, domain: usize) -> &[Chip] {
+    let start = (domain * chips_per_domain).min(chips.len());
+    let end = (start + chips_per_domain).min(chips.len());
+    &chips[start..end]
+}
+
+/// Bin of `nonce` after normalizing to the domain's min..max nonce range
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+// nonce counts fit in f32; the bin index is clamped to 0..NONCE_HIST_BINS
+fn nonce_bin(nonce: i64, min: i64, max: i64) -> usize {
+    if max <= min {
+        return NONCE_HIST_BINS / 2;
+    }
+    let t = (nonce - min) as f32 / (max - min) as f32;
+    ((t * NONCE_HIST_BINS as f32) as usize).min(NONCE_HIST_BINS - 1)
+}
+
+/// Lowest and highest nonce among the reported chips
+fn reported_nonce_range(chips: &[Chip]) -> Option<(i64, i64)> {
+    let nonces = || chips.iter().filter(|c| !c.placeholder).map(|c| c.nonce);
+    nonces().min().zip(nonces().max())
+}
+
+/// Chip count per nonce bin in one domain; bins split the domain's min..max nonce range
+/// evenly, lowest first. Placeholders aren't counted.
+pub fn compute_domain_nonce_histogram(
+    chips: &[Chip],
+    chips_per_domain: usize,
+    domain: usize,
+) -> [u8; NONCE_HIST_BINS] {
+    let chips = domain_chips(chips, chips_per_domain, domain);
+    let mut bins = [0u8; NONCE_HIST_BINS];
+    let Some((min, max)) = reported_nonce_range(chips) else {
+        return bins;
+    };
+    for chip in chips.iter().filter(|c| !c.placeholder) {
+        let bin = &mut bins[nonce_bin(chip.nonce, min, max)];
+        *bin = bin.saturating_add(1);
+    }
+    bins
+}
+
+/// Bin of chip `idx` in its domain's nonce histogram
+pub fn domain_nonce_bin(chips: &[Chip], chips_per_domain: usize, idx: usize) -> usize {
+    let domain = idx / chips_per_domain.max(1);
+    let (min, max) =
+        reported_nonce_range(domain_chips(chips, chips_per_domain, domain)).unwrap_or_default();
+    chips.get(idx).map_or(0, |c| nonce_bin(c.nonce, min, max))
+}
+
+/// Suspected break in each domain's daisy chain, as the slot index of the first chip past it
+///
+/// Chips in a domain relay work down a chain, so a broken link silences every chip after
+/// it. The break is placed after chip n when n still hashes with no bus errors (x == 0)
+/// and every later chip in the domain has zero nonces. One entry per domain.
+pub fn find_signal_break_domain(chips: &[Chip], chips_per_domain: usize) -> Vec<Option<usize>> {
+    let cpd = chips_per_domain.max(1);
+    (0..chips.len().div_ceil(cpd))
+        .map(|domain| {
+            let start = domain * cpd;
+            let domain_chips = domain_chips(chips, cpd, domain);
+            (0..domain_chips.len().saturating_sub(1))
+                .find(|&n| {
+                    let chip = &domain_chips[n];
+                    chip.x == 0
+                        && !chip.is_dead()
+                        && domain_chips[n + 1..].iter().all(Chip::is_dead)
+                })
+                .map(|n| start + n + 1)
+        })
+        .collect()
+}
+
+/// Fx hash of the slot's (chip id, temp, freq, nonce) tuples in chip id order
+///
+/// Equal fingerprints mean nothing changed; any reading that moved changes it,
+/// so use `board_similarity` to judge how much.
+pub fn compute_board_fingerprint(slot: &Slot) -> u64 {
+    let mut chips: Vec<_> = slot
+        .chips
+        .iter()
+        .map(|c| (c.id, c.temp, c.freq, c.nonce))
+        .collect();
+    chips.sort_unstable();
+    chips
+        .into_iter()
+        .flat_map(|(id, temp, freq, nonce)| {
+            [i64::from(id), i64::from(temp), i64::from(freq), nonce]
+        })
+        .fold(0, |hash: u64, value| {
+            let word = u64::from_le_bytes(value.to_le_bytes());
+            (hash.rotate_left(5) ^ word).wrapping_mul(FX_SEED)
+        })
+}
+
+/// Share of chips (matched by id) with the same frequency, liveness and a
+/// temperature within BOARD_CHANGE_TEMP; chips on only one side count as changed
+#[allow(clippy::cast_precision_loss)] // chip counts fit in f32
+pub fn board_similarity(previous: &Slot, current: &Slot) -> f32 {
+    let total = previous.chips.len().max(current.chips.len());
+    if total == 0 {
+        return 1.0;
+    }
+    let unchanged = current
+        .chips
+        .iter()
+        .filter(|chip| {
+            previous
+                .chips
+                .iter()
+                .find(|p| p.id == chip.id)
+                .is_some_and(|p| {
+                    p.freq == chip.freq
+                        && p.is_dead() == chip.is_dead()
+                        && (p.temp - chip.temp).abs() <= BOARD_CHANGE_TEMP
+                })
+        })
+        .count();
+    unchanged as f32 / total as f32
+}
+
+/// CRC attribution for one domain's bus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentHealth {
+    pub domain: usize,
+    /// Slot index of the last chip before the suspected bad link
+    pub break_likely_after: Option<usize>,
+}
+
+/// Suspected bus segment fault in each domain, from where its CRC errors start
+///
+/// A bad link corrupts data for every chip after it, so CRC errors that pile up in
+/// the later half of a domain's chain point at the bus rather than at single chips.
+/// When they do, the break is placed where the mean CRC steps up the most; CRC
+/// concentrated in the earlier half is left to per-chip analysis. One entry per domain.
+#[allow(clippy::cast_precision_loss)] // chip counts and CRC sums fit in f32
+pub fn attribute_crc_to_segments(chips: &[Chip], cpd: usize) -> Vec<SegmentHealth> {
+    let cpd = cpd.max(1);
+    let mean_crc = |chips: &[Chip]| {
+        chips.iter().map(|c| c.crc).sum::<i32>() as f32 / chips.len().max(1) as f32
+    };
+    (0..chips.len().div_ceil(cpd))
+        .map(|domain| {
+            let domain_chips = domain_chips(chips, cpd, domain);
+            let (earlier, later) = domain_chips.split_at(domain_chips.len() / 2);
+            let later_sum: i32 = later.iter().map(|c| c.crc).sum();
+            let attributed = !earlier.is_empty()
+                && later_sum >= CRC_SEGMENT_MIN_ERRORS
+                && mean_crc(later) >= CRC_SEGMENT_RATIO * mean_crc(earlier);
+            let break_likely_after = attributed
+                .then(|| {
+                    (1..domain_chips.len()).max_by(|&a, &b| {
+                        let step =
+                            |k: usize| mean_crc(&domain_chips[k..]) - mean_crc(&domain_chips[..k]);
+                        step(a).total_cmp(&step(b))
+                    })
+                })
+                .flatten()
+                .map(|k| domain * cpd + k - 1);
+            SegmentHealth {
+                domain,
+                break_likely_after,
+            }
+        })
+        .collect()
+}
+
+/// Adjacent unhealthy chips, e.g. over a thermal paste void or a damaged area
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChipCluster {
+    /// Chip indices into the slot, ascending
+    pub chips: Vec<usize>,
+}
+
+/// Groups of at least `min_cluster_size` chips with health below `health_threshold`
+/// that touch on the (domain, row) grid, found by flood fill over the four
+/// direct neighbors. Placeholders (unreported ids) are never part of a cluster.
+pub fn find_chip_clusters(
+    chips: &[Chip],
+    slot_analysis: &[ChipAnalysis],
+    chips_per_domain: usize,
+    health_threshold: f32,
+    min_cluster_size: usize,
+) -> Vec<ChipCluster> {
+    let cpd = chips_per_domain.max(1);
+    let unhealthy = |idx: usize| {
+        chips.get(idx).is_some_and(|c| !c.placeholder)
+            && slot_analysis
+                .get(idx)
+                .is_some_and(|a| a.health_score < health_threshold)
+    };
+    let mut visited = vec![false; slot_analysis.len()];
+    let mut clusters = Vec::new();
+
+    for start in 0..slot_analysis.len() {
+        if visited[start] || !unhealthy(start) {
+            continue;
+        }
+        visited[start] = true;
+        let mut chips = Vec::new();
+        let mut stack = vec![start];
+        while let Some(idx) = stack.pop() {
+            chips.push(idx);
+            for next in grid_neighbors(idx, slot_analysis.len(), cpd) {
+                if !visited[next] && unhealthy(next) {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        if chips.len() >= min_cluster_size {
+            chips.sort_unstable();
+            clusters.push(ChipCluster { chips });
+        }
+    }
+    clusters
+}
+
+/// Direct neighbors of chip `idx` on the (domain, row) grid: adjacent rows of the
+/// same domain and the same row of the adjacent domains
+fn grid_neighbors(idx: usize, chip_count: usize, cpd: usize) -> impl Iterator<Item = usize> {
+    let (domain, row) = (idx / cpd, idx % cpd);
+    [
+        (domain > 0).then(|| idx - cpd),
+        Some(idx + cpd),
+        (row > 0).then(|| idx - 1),
+        (row + 1 < cpd).then_some(idx + 1),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(move |&next| next < chip_count)
+}
+
+/// Fewest dead chips that count as a line or diagonal
+const DEAD_PATTERN_MIN_CHIPS: usize = 3;
+
+/// Shape formed by a slot's dead chips on the (domain, row) grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadChipPattern {
+    /// No shape: individual chip failures
+    Scattered,
+    /// Several chips of one domain: its bus
+    VerticalLine { domain: usize },
+    /// The same row across domains: row power
+    HorizontalLine { row: usize },
+    /// Every chip of one domain
+    FullDomain { domain: usize },
+    /// Every chip of consecutive domains, inclusive
+    DomainRange { start: usize, end: usize },
+    /// One chip per consecutive domain, stepping a row each time: a firmware scan artifact
+    Diagonal,
+}
+
+/// (domain, row) of every dead chip of a slot
+pub fn dead_chip_positions(chips: &[Chip], chips_per_domain: usize) -> Vec<(usize, usize)> {
+    let cpd = chips_per_domain.max(1);
+    chips
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.is_dead())
+        .map(|(idx, _)| (idx / cpd, idx % cpd))
+        .collect()
+}
+
+/// Classify dead chip (domain, row) positions; whole domains win over lines, and
+/// fewer than DEAD_PATTERN_MIN_CHIPS chips are always scattered
+pub fn classify_dead_chip_pattern(
+    dead_chip_positions: &[(usize, usize)],
+    cpd: usize,
+) -> DeadChipPattern {
+    let dead: BTreeSet<(usize, usize)> = dead_chip_positions.iter().copied().collect();
+    if dead.is_empty() {
+        return DeadChipPattern::Scattered;
+    }
+    let domains: BTreeSet<usize> = dead.iter().map(|&(domain, _)| domain).collect();
+    let rows: BTreeSet<usize> = dead.iter().map(|&(_, row)| row).collect();
+    let (first, last) = (domains.first().copied(), domains.last().copied());
+    let consecutive = |first: usize, last: usize| last - first + 1 == domains.len();
+
+    if let (Some(first), Some(last)) = (first, last)
+        && cpd > 0
+        && dead.len() == domains.len() * cpd
+        && consecutive(first, last)
+    {
+        return if first == last {
+            DeadChipPattern::FullDomain { domain: first }
+        } else {
+            DeadChipPattern::DomainRange {
+                start: first,
+                end: last,
+            }
+        };
+    }
+    if dead.len() < DEAD_PATTERN_MIN_CHIPS {
+        return DeadChipPattern::Scattered;
+    }
+    if let (Some(domain), 1) = (first, domains.len()) {
+        return DeadChipPattern::VerticalLine { domain };
+    }
+    if let (Some(&row), 1) = (rows.first(), rows.len()) {
+        return DeadChipPattern::HorizontalLine { row };
+    }
+
+    // One chip per domain, each a row above or below the previous domain's
+    let one_per_domain = domains.len() == dead.len();
+    let steps: Vec<isize> = dead
+        .iter()
+        .zip(dead.iter().skip(1))
+        .map(|(&(_, a), &(_, b))| b.cast_signed() - a.cast_signed())
+        .collect();
+    if let (Some(first), Some(last)) = (first, last)
+        && one_per_domain
+        && consecutive(first, last)
+        && (steps.iter().all(|&s| s == 1) || steps.iter().all(|&s| s == -1))
+    {
+        return DeadChipPattern::Diagonal;
+    }
+    DeadChipPattern::Scattered
+}
+
+/// Kernel radii (grid cells) the dead chip density map accepts
+pub const DEAD_DENSITY_KERNEL_MIN: usize = 1;
+pub const DEAD_DENSITY_KERNEL_MAX: usize = 5;
+
+/// Share of dead chips around each chip on the board grid as drawn, smoothed with a
+/// Gaussian kernel: 0 = no dead neighbors, 1 = surrounded by dead chips
+///
+/// `kernel_size` is the kernel radius in cells (clamped to 1-5), with a sigma of half
+/// the radius plus half a cell. The chip itself isn't counted, and the weights are
+/// normalized over the neighbors that exist, so board edges and placeholder cells
+/// don't dilute the density. Parallel to `slot.chips`.
+#[allow(clippy::cast_precision_loss)] // grid offsets fit in f32
+pub fn compute_dead_density_map(slot: &Slot, cpd: usize, kernel_size: usize) -> Vec<f32> {
+    let chips = &slot.chips;
+    if cpd == 0 {
+        return vec![0.0; chips.len()];
+    }
+    let layout = ChipLayout::new(chips.len(), cpd);
+    let (columns, rows) = (layout.columns(), layout.rows());
+    // Dead flag per cell; None where there's no chip
+    let mut grid = vec![None; columns * rows];
+    for (idx, chip) in chips.iter().enumerate() {
+        let (col, row) = layout.position(idx);
+        if !chip.placeholder {
+            grid[row * columns + col] = Some(chip.is_dead());
+        }
+    }
+
+    let radius = kernel_size.clamp(DEAD_DENSITY_KERNEL_MIN, DEAD_DENSITY_KERNEL_MAX);
+    let sigma = radius as f32 / 2.0 + 0.5;
+    let weight = |dx: usize, dy: usize| {
+        let d2 = (dx * dx + dy * dy) as f32;
+        (-d2 / (2.0 * sigma * sigma)).exp()
+    };
+    (0..chips.len())
+        .map(|idx| {
+            let (col, row) = layout.position(idx);
+            let (mut dead, mut total) = (0.0, 0.0);
+            for r in row.saturating_sub(radius)..=(row + radius).min(rows - 1) {
+                for c in col.saturating_sub(radius)..=(col + radius).min(columns - 1) {
+                    if (c, r) == (col, row) {
+                        continue;
+                    }
+                    if let Some(is_dead) = grid[r * columns + c] {
+                        let w = weight(c.abs_diff(col), r.abs_diff(row));
+                        total += w;
+                        if is_dead {
+                            dead += w;
+                        }
+                    }
+                }
+            }
+            if total > 0.0 { dead / total } else { 0.0 }
+        })
+        .collect()
+}
+
+/// Power state of a chip, read off its clock
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChipPowerState {
+    /// Running at (or near) the target frequency
+    #[default]
+    Active,
+    /// Clocked down below 90% of the target frequency
+    Throttled,
+    /// Parked at the firmware's sleep frequency (e.g. 25 or 50 MHz)
+    Sleep,
+    /// Not clocked at all
+    Dead,
+}
+
+impl ChipPowerState {
+    /// Stable lowercase name for exports
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Throttled => "throttled",
+            Self::Sleep => "sleep",
+            Self::Dead => "dead",
+        }
+    }
+}
+
+/// Classify a chip by its frequency: 0 MHz is dead, below 100 MHz is the firmware's
+/// sleep clock, below 90% of `target_freq` is throttled, anything else is active
+#[allow(clippy::cast_precision_loss)] // MHz values fit in f32
+pub fn compute_chip_power_state(chip: &Chip, target_freq: i32) -> ChipPowerState {
+    if chip.freq == 0 {
+        ChipPowerState::Dead
+    } else if chip.freq < SLEEP_FREQ_MAX_MHZ {
+        ChipPowerState::Sleep
+    } else if (chip.freq as f32) < target_freq as f32 * THROTTLED_FREQ_RATIO {
+        ChipPowerState::Throttled
+    } else {
+        ChipPowerState::Active
+    }
+}
+
+/// Mean CRC errors of a chip's grid neighbors, divided by the slot's highest CRC count
+#[allow(clippy::cast_precision_loss)] // CRC counts fit in f32
+fn compute_crc_neighbor_mean(chips: &[Chip], cpd: usize, idx: usize, max_crc: i32) -> f32 {
+    if max_crc <= 0 {
+        return 0.0;
+    }
+    let crcs: Vec<f32> = grid_neighbors(idx, chips.len(), cpd.max(1))
+        .map(|n| chips[n].crc as f32)
+        .collect();
+    if crcs.is_empty() {
+        return 0.0;
+    }
+    crcs.iter().sum::<f32>() / crcs.len() as f32 / max_crc as f32
+}
+
+/// Moran's I of the slot's CRC error counts over the (domain, row) grid, with
+/// equal weights for direct neighbors
+///
+/// Around +1 when errors cluster on neighboring chips, around 0 when they are
+/// scattered at random, negative when they alternate. 0 if every chip has the
+/// same count.
+#[allow(clippy::cast_precision_loss)] // chip and CRC counts fit in f32
+pub fn compute_crc_spatial_correlation(slot: &Slot, cpd: usize) -> f32 {
+    let n = slot.chips.len();
+    if n < 2 || cpd == 0 {
+        return 0.0;
+    }
+    let values: Vec<f32> = slot.chips.iter().map(|c| c.crc as f32).collect();
+    let mean = values.iter().sum::<f32>() / n as f32;
+    let variance: f32 = values.iter().map(|v| (v - mean).powi(2)).sum();
+    if variance <= f32::EPSILON {
+        return 0.0;
+    }
+
+    let (mut weight_sum, mut cross) = (0.0_f32, 0.0_f32);
+    for (idx, value) in values.iter().enumerate() {
+        for neighbor in grid_neighbors(idx, n, cpd) {
+            weight_sum += 1.0;
+            cross += (value - mean) * (values[neighbor] - mean);
+        }
+    }
+    if weight_sum == 0.0 {
+        return 0.0;
+    }
+    n as f32 / weight_sum * cross / variance
+}
+
+/// Stats for the bottom and top sections of a slot, split like the snake layout
+pub fn compute_section_stats(
+    slot: &Slot,
+    chips_per_domain: usize,
+    config: &AnalysisConfig,
+) -> (SectionStats, SectionStats) {
+    if chips_per_domain == 0 {
+        return Default::default();
+    }
+
+    let layout = ChipLayout::new(slot.chips.len(), chips_per_domain);
+    let split = (layout.bottom_domains() * chips_per_domain).min(slot.chips.len());
+
+    // Section stats only use the local gradient, so no cross-slot stats are needed
+    let analysis = analyze_single_slot(slot, chips_per_domain, &[], config);
+    let (bottom_chips, top_chips) = slot.chips.split_at(split);
+    let (bottom_analysis, top_analysis) = analysis.split_at(split);
+
+    (
+        section_stats(bottom_chips, bottom_analysis),
+        section_stats(top_chips, top_analysis),
+    )
+}
+
+#[allow(clippy::cast_precision_loss)] // temps and chip counts fit in f32
+fn section_stats(chips: &[Chip], analysis: &[ChipAnalysis]) -> SectionStats {
+    let temps: Vec<i32> = chips
+        .iter()
+        .filter(|c| !c.placeholder)
+        .map(|c| c.temp)
+        .collect();
+    if temps.is_empty() {
+        return SectionStats::default();
+    }
+    SectionStats {
+        chip_count: temps.len(),
+        mean_temp: compute_mean_std(&temps).0,
+        mean_nonce: compute_slot_avg_nonce(chips),
+        dead_chips: chips.iter().filter(|c| c.is_dead()).count(),
+        gradient_outliers: analysis
+            .iter()
+            .filter(|a| a.gradient > SECTION_GRADIENT_OUTLIER)
+            .count(),
+    }
+}
+
+/// Chip temperature spread across a slot; airflow blockage or uneven TIM shows up
+/// as a wide spread or a hot side
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ThermalUniformity {
+    /// Hottest minus coolest chip (°C)
+    pub temperature_range: i32,
+    /// Std dev of chip temps as a percentage of their mean
+    pub coefficient_of_variation: f32,
+    /// Mean temp of the exhaust-side (left) half of the columns minus the intake-side (right) half
+    pub hot_side_bias: f32,
+    /// Mean temp of the top half of the grid rows minus the bottom half
+    pub vertical_gradient: f32,
+}
+
+impl ThermalUniformity {
+    /// 0-100%, 100% when every chip runs at the same temperature
+    pub fn score(&self) -> f32 {
+        (100.0 * (1.0 - self.coefficient_of_variation / UNIFORMITY_ZERO_CV)).clamp(0.0, 100.0)
+    }
+}
+
+/// How evenly heat is spread over a slot's chips in the snake layout
+///
+/// Intake is on the right of the grid for both sections, so the bias compares
+/// the left and right halves of the columns; a middle column of an odd count is
+/// left out, as is a middle row.
+#[allow(clippy::cast_precision_loss)] // chip counts and temps fit in f32
+pub fn compute_thermal_uniformity(slot: &Slot, chips_per_domain: usize) -> ThermalUniformity {
+    let chips = &slot.chips;
+    if chips.is_empty() || chips_per_domain == 0 {
+        return ThermalUniformity::default();
+    }
+    let layout = ChipLayout::new(chips.len(), chips_per_domain);
+    let (columns, rows) = (layout.columns(), layout.rows());
+    let cell = |idx: usize| layout.position(idx);
+    let mean_where = |keep: &dyn Fn((usize, usize)) -> bool| {
+        let temps: Vec<i32> = chips
+            .iter()
+            .enumerate()
+            .filter(|&(idx, c)| !c.placeholder && keep(cell(idx)))
+            .map(|(_, c)| c.temp)
+            .collect();
+        (!temps.is_empty()).then(|| compute_mean_std(&temps).0)
+    };
+    let difference = |a: Option<f32>, b: Option<f32>| a.zip(b).map_or(0.0, |(a, b)| a - b);
+
+    let temps: Vec<i32> = chips
+        .iter()
+        .filter(|c| !c.placeholder)
+        .map(|c| c.temp)
+        .collect();
+    let (mean, std) = compute_mean_std(&temps);
+    let max = temps.iter().max().copied().unwrap_or(0);
+    let min = temps.iter().min().copied().unwrap_or(0);
+    ThermalUniformity {
+        temperature_range: max - min,
+        coefficient_of_variation: if mean.abs() > f32::EPSILON {
+            std / mean * 100.0
+        } else {
+            0.0
+        },
+        hot_side_bias: difference(
+            mean_where(&|(col, _)| col < columns / 2),
+            mean_where(&|(col, _)| col >= columns - columns / 2),
+        ),
+        vertical_gradient: difference(
+            mean_where(&|(_, row)| row < rows / 2),
+            mean_where(&|(_, row)| row >= rows - rows / 2),
+        ),
+    }
+}
+
+/// A slot whose chip count differs from the model spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigMismatch {
+    pub slot_id: i32,
+    pub expected: u16,
+    pub actual: usize,
+    /// actual - expected: negative = missing chips
+    pub delta: i16,
+}
+
+impl ConfigMismatch {
+    /// More than 5% of the expected chips missing or extra
+    #[allow(clippy::cast_precision_loss)] // chip counts fit in f32
+    pub fn is_severe(&self) -> bool {
+        f32::from(self.delta.unsigned_abs())
+            > f32::from(self.expected) * CONFIG_MISMATCH_SEVERE_RATIO
+    }
+}
+
+/// Compare each slot's chip count against the config's chips per board
+///
+/// Only reported chips count: placeholders filling skipped ids would pad the slot
+/// back toward the spec and hide the missing chips.
+pub fn compute_config_match(slots: &[Slot], config: &MinerConfig) -> Vec<ConfigMismatch> {
+    let expected = config.chips_per_board();
+    slots
+        .iter()
+        .map(|slot| (slot, slot.chips.iter().filter(|c| !c.placeholder).count()))
+        .filter(|&(_, actual)| actual != usize::from(expected))
+        .map(|(slot, actual)| {
+            let actual_i16 = i16::try_from(actual).unwrap_or(i16::MAX);
+            ConfigMismatch {
+                slot_id: slot.id,
+                expected,
+                actual,
+                delta: actual_i16.saturating_sub_unsigned(expected),
             }
         })
         .collect()
 }
 
+/// How many of a slot's chips run at its programmed frequency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreqComplianceReport {
+    pub compliant_count: usize,
+    /// chip.freq > slot.freq + tolerance_mhz
+    pub over_freq_count: usize,
+    /// chip.freq < slot.freq - tolerance_mhz
+    pub under_freq_count: usize,
+    pub tolerance_mhz: i32,
+}
+
+impl FreqComplianceReport {
+    /// Share of compliant chips, 0-1; a slot without chips is compliant
+    #[allow(clippy::cast_precision_loss)] // chip counts fit in f32
+    pub fn compliance(&self) -> f32 {
+        let total = self.compliant_count + self.over_freq_count + self.under_freq_count;
+        if total == 0 {
+            1.0
+        } else {
+            self.compliant_count as f32 / total as f32
+        }
+    }
+}
+
+/// Chip frequency against the slot target: Less = under, Greater = over,
+/// Equal = within `tolerance_mhz`
+pub fn freq_compliance(chip_freq: i32, slot_freq: i32, tolerance_mhz: i32) -> Ordering {
+    if chip_freq > slot_freq + tolerance_mhz {
+        Ordering::Greater
+    } else if chip_freq < slot_freq - tolerance_mhz {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Count the slot's chips running within FREQ_COMPLIANCE_TOLERANCE_MHZ of its `freq`;
+/// placeholders have no clock to judge and aren't counted
+pub fn check_freq_compliance(slot: &Slot) -> FreqComplianceReport {
+    let tolerance_mhz = FREQ_COMPLIANCE_TOLERANCE_MHZ;
+    let mut report = FreqComplianceReport {
+        compliant_count: 0,
+        over_freq_count: 0,
+        under_freq_count: 0,
+        tolerance_mhz,
+    };
+    for chip in slot.chips.iter().filter(|c| !c.placeholder) {
+        match freq_compliance(chip.freq, slot.freq, tolerance_mhz) {
+            Ordering::Less => report.under_freq_count += 1,
+            Ordering::Equal => report.compliant_count += 1,
+            Ordering::Greater => report.over_freq_count += 1,
+        }
+    }
+    report
+}
+
+/// Spread of nonce output across a miner's hashboards
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SlotBalance {
+    pub mean_nonce_rate: f32,
+    /// Population std dev of the slot nonce rates
+    pub std_nonce_rate: f32,
+    /// Slots more than SLOT_BALANCE_TOLERANCE from the mean
+    pub imbalanced_slots: Vec<i32>,
+    /// Slot furthest from the mean and its deviation (-0.35 = 35% below)
+    pub worst: Option<(i32, f32)>,
+}
+
+/// Compare slot nonce rates; an underpowered or overclocked board stands out
+/// from the others. Fewer than two slots are always balanced.
+#[allow(clippy::cast_precision_loss)] // nonce rates and slot counts fit in f32
+pub fn compute_slot_balance(slots: &[Slot]) -> SlotBalance {
+    if slots.len() < 2 {
+        return SlotBalance::default();
+    }
+    let rates: Vec<f32> = slots.iter().map(|s| s.nonce_rate as f32).collect();
+    let mean = rates.iter().sum::<f32>() / rates.len() as f32;
+    let variance = rates.iter().map(|r| (r - mean).powi(2)).sum::<f32>() / rates.len() as f32;
+    if mean <= 0.0 {
+        return SlotBalance::default();
+    }
+    let deviations: Vec<(i32, f32)> = slots
+        .iter()
+        .zip(&rates)
+        .map(|(slot, rate)| (slot.id, (rate - mean) / mean))
+        .collect();
+    SlotBalance {
+        mean_nonce_rate: mean,
+        std_nonce_rate: variance.sqrt(),
+        imbalanced_slots: deviations
+            .iter()
+            .filter(|(_, dev)| dev.abs() > SLOT_BALANCE_TOLERANCE)
+            .map(|&(id, _)| id)
+            .collect(),
+        worst: deviations
+            .into_iter()
+            .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs())),
+    }
+}
+
+/// One error counter of a chip next to the slot's average of it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErrorBar {
+    /// Field as named in the log: "err", "crc", "x" or "repeat"
+    pub field: &'static str,
+    pub value: i32,
+    pub slot_avg: f32,
+}
+
+/// A chip's err, crc, x and repeat counters against the slot averages, for the hover tooltip
+#[allow(clippy::cast_precision_loss)] // counters and chip counts fit in f32
+pub fn error_breakdown(chip: &Chip, slot: &Slot) -> [ErrorBar; 4] {
+    let counters = |c: &Chip| [c.errors, c.crc, c.x, c.repeat];
+    let mut sums = [0.0f32; 4];
+    for c in &slot.chips {
+        for (sum, value) in sums.iter_mut().zip(counters(c)) {
+            *sum += value as f32;
+        }
+    }
+    let count = slot.chips.len().max(1) as f32;
+    let values = counters(chip);
+    std::array::from_fn(|i| ErrorBar {
+        field: ["err", "crc", "x", "repeat"][i],
+        value: values[i],
+        slot_avg: sums[i] / count,
+    })
+}
+
+/// Notional nonce arrivals in each of NONCE_TIMELINE_SEGMENTS equal time segments
+/// of the last `window_secs`
+///
+/// The firmware only reports cumulative per-chip counts, so submission is assumed
+/// uniform: the chip's nonces, capped at what the whole slot submits in the window
+/// at `slot_nonce_rate` (nonces/s, no cap if unknown), are spread evenly over the
+/// segments. Empty for a zero-length window.
+pub fn compute_nonce_timeline(chip: &Chip, slot_nonce_rate: i32, window_secs: u32) -> Vec<u32> {
+    if window_secs == 0 {
+        return Vec::new();
+    }
+    let mut total = u64::try_from(chip.nonce).unwrap_or(0);
+    if let Ok(rate) = u64::try_from(slot_nonce_rate)
+        && rate > 0
+    {
+        total = total.min(rate * u64::from(window_secs));
+    }
+    let segments = NONCE_TIMELINE_SEGMENTS as u64;
+    (0..segments)
+        .map(|i| {
+            let arrivals = total * (i + 1) / segments - total * i / segments;
+            u32::try_from(arrivals).unwrap_or(u32::MAX)
+        })
+        .collect()
+}
+
+/// Per-chip temperature deltas (current - target), parallel to `current.slots`
+///
+/// Slots are matched by id (ordering can differ between firmware versions), chips by id.
+/// Chips without a counterpart in the target get 0.
+pub fn compute_miner_diff(current: &MinerData, target: &MinerData) -> Vec<Vec<i32>> {
+    current
+        .slots
+        .iter()
+        .map(|slot| {
+            let target_slot = target.slots.iter().find(|s| s.id == slot.id);
+            slot.chips
+                .iter()
+                .map(|chip| {
+                    target_slot
+                        .and_then(|s| s.chips.iter().find(|c| c.id == chip.id))
+                        .map_or(0, |t| chip.temp - t.temp)
+                })
+                .collect()
+        })
+        .collect()
+}
+
 /// Get temperature values of upstream neighbors (airflow-aware, snake-pattern-aware)
 ///
 /// Physical layout with snake pattern:
@@ -139,15 +1753,15 @@ fn get_upstream_neighbor_temps(
     row: usize,
     is_top_section: bool,
 ) -> Vec<i32> {
-    let mut neighbors = Vec::with_capacity(3);
+    let mut neighbors = Vec::with_capacity(MAX_GRADIENT_NEIGHBORS);
 
     if is_top_section {
         // TOP SECTION: D_max is at intake (right), D_bottom is at exhaust (left)
         // Upstream = higher domain number (toward intake)
         if domain + 1 < num_domains {
             let idx = (domain + 1) * cpd + row;
-            if idx < chips.len() {
-                neighbors.push(chips[idx].temp);
+            if let Some(chip) = chips.get(idx).filter(|c| !c.placeholder) {
+                neighbors.push(chip.temp);
             }
         }
         // NOTE: domain - 1 would be downstream (toward exhaust) - excluded
@@ -156,8 +1770,8 @@ fn get_upstream_neighbor_temps(
         // Upstream = lower domain number (toward intake)
         if domain > 0 {
             let idx = (domain - 1) * cpd + row;
-            if idx < chips.len() {
-                neighbors.push(chips[idx].temp);
+            if let Some(chip) = chips.get(idx).filter(|c| !c.placeholder) {
+                neighbors.push(chip.temp);
             }
         }
         // NOTE: domain + 1 would be downstream (toward exhaust) - excluded
@@ -169,15 +1783,15 @@ fn get_upstream_neighbor_temps(
     // Up/down neighbors (row - 1, row + 1) = same airflow position in either section
     if row > 0 {
         let idx = domain * cpd + (row - 1);
-        if idx < chips.len() {
-            neighbors.push(chips[idx].temp);
+        if let Some(chip) = chips.get(idx).filter(|c| !c.placeholder) {
+            neighbors.push(chip.temp);
         }
     }
 
     if row + 1 < cpd {
         let idx = domain * cpd + (row + 1);
-        if idx < chips.len() {
-            neighbors.push(chips[idx].temp);
+        if let Some(chip) = chips.get(idx).filter(|c| !c.placeholder) {
+            neighbors.push(chip.temp);
         }
     }
 
@@ -199,6 +1813,15 @@ fn compute_hot_gradient(center: i32, neighbors: &[i32]) -> f32 {
     (center_f - neighbor_avg).max(0.0)
 }
 
+/// Zero out gradients below the noise floor (sensor jitter, not real hot spots)
+fn clip_noise(gradient: f32, noise_floor: f32) -> f32 {
+    if gradient < noise_floor {
+        0.0
+    } else {
+        gradient
+    }
+}
+
 /// Compute mean and standard deviation
 fn compute_mean_std(temps: &[i32]) -> (f32, f32) {
     if temps.is_empty() {
@@ -220,6 +1843,24 @@ fn compute_mean_std(temps: &[i32]) -> (f32, f32) {
     (mean, variance.sqrt())
 }
 
+/// Compute mean and standard deviation of float values
+fn compute_mean_std_f32(values: &[f32]) -> (f32, f32) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+
+    let n = values.len() as f32;
+    let mean: f32 = values.iter().sum::<f32>() / n;
+    let variance: f32 = values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / n;
+    (mean, variance.sqrt())
+}
+
+/// Nonces per MHz: higher frequency should yield proportionally more nonces
+#[allow(clippy::cast_precision_loss)] // ratio only needs f32 precision
+fn compute_nonce_per_mhz(chip: &Chip) -> f32 {
+    chip.nonce as f32 / chip.freq.max(1) as f32
+}
+
 /// Compute z-score, but only for positive deviations (hotter than mean)
 /// Returns 0 if chip is at or below the cross-slot mean
 fn compute_hot_zscore(temp: i32, mean: f32, std: f32) -> f32 {
@@ -240,13 +1881,17 @@ fn compute_hot_zscore(temp: i32, mean: f32, std: f32) -> f32 {
     deviation / std
 }
 
-/// Compute average nonce count for a slot
-fn compute_slot_avg_nonce(chips: &[crate::models::Chip]) -> f64 {
-    if chips.is_empty() {
+/// Compute average nonce count for a slot, leaving out placeholder chips
+pub fn compute_slot_avg_nonce(chips: &[Chip]) -> f64 {
+    let nonces: Vec<i64> = chips
+        .iter()
+        .filter(|c| !c.placeholder)
+        .map(|c| c.nonce)
+        .collect();
+    if nonces.is_empty() {
         return 0.0;
     }
-    let total: i64 = chips.iter().map(|c| c.nonce).sum();
-    total as f64 / chips.len() as f64
+    nonces.iter().sum::<i64>() as f64 / nonces.len() as f64
 }
 
 /// Compute nonce deficit as percentage below slot average
@@ -268,6 +1913,110 @@ fn compute_nonce_deficit(chip_nonce: i64, slot_avg: f64) -> f32 {
     deficit as f32
 }
 
+/// Combine the per-chip metrics into a 0-100 health score
+/// Dead chips (zero nonces) always score 0
+#[allow(clippy::cast_precision_loss)] // temp values fit in f32
+fn compute_health_score(
+    chip: &crate::models::Chip,
+    gradient: f32,
+    cross_slot_zscore: f32,
+    nonce_deficit: f32,
+    config: &AnalysisConfig,
+) -> f32 {
+    if chip.is_dead() {
+        return 0.0;
+    }
+
+    let penalty = |value: f32, full: f32, weight: f32| (value / full).clamp(0.0, 1.0) * weight;
+    let temp_excess = chip.temp as f32 - HEALTH_TEMP_RANGE.0;
+    let temp_span = HEALTH_TEMP_RANGE.1 - HEALTH_TEMP_RANGE.0;
+
+    let total = penalty(
+        nonce_deficit,
+        config.nonce_deficit_critical_pct,
+        HEALTH_NONCE_WEIGHT,
+    ) + penalty(
+        cross_slot_zscore,
+        config.outlier_sigma_threshold,
+        HEALTH_OUTLIER_WEIGHT,
+    ) + penalty(gradient, HEALTH_GRADIENT_FULL, HEALTH_GRADIENT_WEIGHT)
+        + penalty(temp_excess, temp_span, HEALTH_TEMP_WEIGHT);
+
+    (100.0 - total).max(0.0)
+}
+
+/// Mean chip health across all slots, placeholders left out (None if no chip was reported)
+///
+/// `all_analysis` is parallel to `slots`, as returned by `analyze_all_slots`.
+#[allow(clippy::cast_precision_loss)] // chip counts fit in f32
+pub fn overall_health(slots: &[Slot], all_analysis: &[Vec<ChipAnalysis>]) -> Option<f32> {
+    let scores: Vec<f32> = slots
+        .iter()
+        .zip(all_analysis)
+        .flat_map(|(slot, analysis)| slot.chips.iter().zip(analysis))
+        .filter(|(chip, _)| !chip.placeholder)
+        .map(|(_, a)| a.health_score)
+        .collect();
+    (!scores.is_empty()).then(|| scores.iter().sum::<f32>() / scores.len() as f32)
+}
+
+/// Bucket chip nonce counts into `bins` equal-width bins; placeholders aren't counted
+///
+/// Returns (bin_min, bin_max, count) tuples; bin_max is exclusive except for the last bin.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+pub fn compute_nonce_histogram(chips: &[Chip], bins: usize) -> Vec<(i64, i64, usize)> {
+    let Some((min, max)) = reported_nonce_range(chips) else {
+        return vec![];
+    };
+    if bins == 0 {
+        return vec![];
+    }
+
+    let width = ((max - min) as f64 / bins as f64).max(1.0);
+    let mut counts = vec![0usize; bins];
+    for chip in chips.iter().filter(|c| !c.placeholder) {
+        let idx = ((chip.nonce - min) as f64 / width) as usize;
+        counts[idx.min(bins - 1)] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let lo = min + (i as f64 * width) as i64;
+            let hi = min + ((i + 1) as f64 * width) as i64;
+            (lo, hi, count)
+        })
+        .collect()
+}
+
+/// Detect two separated peaks in a histogram (e.g. a board built from two chip batches)
+#[allow(clippy::cast_precision_loss)] // chip counts fit in f32
+pub fn is_bimodal(histogram: &[(i64, i64, usize)]) -> bool {
+    let counts: Vec<usize> = histogram.iter().map(|&(_, _, c)| c).collect();
+    let total: usize = counts.iter().sum();
+    if total == 0 {
+        return false;
+    }
+    let min_peak = (total as f32 * BIMODAL_MIN_PEAK_SHARE).max(2.0);
+
+    // Local maxima (plateaus count once, at their first bin)
+    let peaks: Vec<usize> = (0..counts.len())
+        .filter(|&i| {
+            let left = if i > 0 { counts[i - 1] } else { 0 };
+            let right = counts.get(i + 1).copied().unwrap_or(0);
+            counts[i] as f32 >= min_peak && counts[i] > left && counts[i] >= right
+        })
+        .collect();
+
+    peaks.windows(2).any(|w| {
+        let (a, b) = (w[0], w[1]);
+        let valley = counts[a..=b].iter().copied().min().unwrap_or(0);
+        let smaller_peak = counts[a].min(counts[b]);
+        (valley as f32) < smaller_peak as f32 * BIMODAL_MAX_VALLEY_RATIO
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,7 +2067,7 @@ mod tests {
     fn test_uniform_temps_no_gradient() {
         // 3x3 grid, all same temp
         let slots = vec![make_slot(0, &[50; 9])];
-        let analysis = analyze_all_slots(&slots, 3);
+        let analysis = analyze_all_slots(&slots, 3, &AnalysisConfig::default());
 
         // All chips should have 0 gradient (no one is hotter)
         assert!(analysis[0].iter().all(|a| a.gradient < 0.1));
@@ -331,7 +2080,7 @@ mod tests {
         temps[4] = 80; // Center is 30 degrees hotter
 
         let slots = vec![make_slot(0, &temps)];
-        let analysis = analyze_all_slots(&slots, 3);
+        let analysis = analyze_all_slots(&slots, 3, &AnalysisConfig::default());
 
         // Center should have high gradient (local hotspot)
         assert!(analysis[0][4].gradient > 20.0);
@@ -346,7 +2095,7 @@ mod tests {
         temps[4] = 50; // Center is 30 degrees COOLER
 
         let slots = vec![make_slot(0, &temps)];
-        let analysis = analyze_all_slots(&slots, 3);
+        let analysis = analyze_all_slots(&slots, 3, &AnalysisConfig::default());
 
         // Center should have 0 gradient (we don't flag cold spots)
         assert!(analysis[0][4].gradient < 0.1);
@@ -360,7 +2109,7 @@ mod tests {
             make_slot(1, &[50, 50, 50]),
             make_slot(2, &[50, 50, 50]),
         ];
-        let analysis = analyze_all_slots(&slots, 3);
+        let analysis = analyze_all_slots(&slots, 3, &AnalysisConfig::default());
 
         // Chip 0 on slot 0 should be a cross-slot outlier
         assert!(analysis[0][0].cross_slot_zscore > 1.0);
@@ -377,7 +2126,7 @@ mod tests {
             make_slot(1, &[50, 50, 50]),
             make_slot(2, &[50, 50, 50]),
         ];
-        let analysis = analyze_all_slots(&slots, 3);
+        let analysis = analyze_all_slots(&slots, 3, &AnalysisConfig::default());
 
         // Chip 0 on slot 0 should NOT be flagged (it's cooler, not a problem)
         assert!(analysis[0][0].cross_slot_zscore < 0.1);
@@ -396,7 +2145,7 @@ mod tests {
         //
         // Temps: D0=50, D1=60, D2=70 (bottom, normal gradient toward exhaust)
         let slots = vec![make_slot(0, &[50, 60, 70, 50, 50, 50])];
-        let analysis = analyze_all_slots(&slots, 1);
+        let analysis = analyze_all_slots(&slots, 1, &AnalysisConfig::default());
 
         // Bottom section: upstream = lower domain (toward D0/intake)
         // D0: no upstream, gradient = 0
@@ -415,7 +2164,7 @@ mod tests {
         //
         // Temps: D3=80, D4=60, D5=50 (normal gradient: D3 hottest at exhaust)
         let slots = vec![make_slot(0, &[50, 50, 50, 80, 60, 50])];
-        let analysis = analyze_all_slots(&slots, 1);
+        let analysis = analyze_all_slots(&slots, 1, &AnalysisConfig::default());
 
         // D3: upstream is D4 (60°C), D3 (80) is 20°C hotter
         assert!(
@@ -444,7 +2193,7 @@ mod tests {
         //
         // Temps: all 50 except D2=90 and D3=90
         let slots = vec![make_slot(0, &[50, 50, 90, 90, 50, 50])];
-        let analysis = analyze_all_slots(&slots, 1);
+        let analysis = analyze_all_slots(&slots, 1, &AnalysisConfig::default());
 
         // D2 (bottom): upstream is D1 (50°C), D2 is 40°C hotter - flags!
         assert!(
@@ -465,7 +2214,7 @@ mod tests {
     fn test_nonce_uniform_no_deficit() {
         // All chips have same nonce count - no deficit
         let slots = vec![make_slot_with_nonces(0, &[1000, 1000, 1000])];
-        let analysis = analyze_all_slots(&slots, 1);
+        let analysis = analyze_all_slots(&slots, 1, &AnalysisConfig::default());
 
         for (i, a) in analysis[0].iter().enumerate() {
             assert!(
@@ -483,7 +2232,7 @@ mod tests {
         // Average = (1000 + 500 + 1000) / 3 = 833
         // Chip 1 deficit = (833 - 500) / 833 * 100 = 40%
         let slots = vec![make_slot_with_nonces(0, &[1000, 500, 1000])];
-        let analysis = analyze_all_slots(&slots, 1);
+        let analysis = analyze_all_slots(&slots, 1, &AnalysisConfig::default());
 
         // Chip 0 and 2 are above average - no deficit
         assert!(analysis[0][0].nonce_deficit < 1.0);
@@ -503,7 +2252,7 @@ mod tests {
         // Average = (1000 + 0 + 1000) / 3 = 666
         // Chip 1 deficit = (666 - 0) / 666 * 100 = 100%
         let slots = vec![make_slot_with_nonces(0, &[1000, 0, 1000])];
-        let analysis = analyze_all_slots(&slots, 1);
+        let analysis = analyze_all_slots(&slots, 1, &AnalysisConfig::default());
 
         // Chip 1 should have 100% deficit (or close to it)
         assert!(
@@ -517,7 +2266,7 @@ mod tests {
     fn test_nonce_overperformer_no_deficit() {
         // Chip 1 has MORE nonces than average - should not flag
         let slots = vec![make_slot_with_nonces(0, &[500, 1500, 500])];
-        let analysis = analyze_all_slots(&slots, 1);
+        let analysis = analyze_all_slots(&slots, 1, &AnalysisConfig::default());
 
         // Chip 1 is above average - no deficit
         assert!(
@@ -526,4 +2275,903 @@ mod tests {
             analysis[0][1].nonce_deficit
         );
     }
+
+    #[test]
+    fn test_health_healthy_board_scores_full() {
+        let slots = vec![make_slot_with_nonces(0, &[1000, 1000, 1000])];
+        let analysis = analyze_all_slots(&slots, 1, &AnalysisConfig::default());
+
+        assert!(analysis[0].iter().all(|a| a.health_score > 99.0));
+        assert!(overall_health(&slots, &analysis).unwrap() > 99.0);
+    }
+
+    #[test]
+    fn test_health_dead_chip_scores_zero() {
+        let slots = vec![make_slot_with_nonces(0, &[1000, 0, 1000])];
+        let analysis = analyze_all_slots(&slots, 1, &AnalysisConfig::default());
+
+        assert!(analysis[0][1].health_score < 0.1);
+        assert!(analysis[0][0].health_score > 99.0);
+    }
+
+    #[test]
+    fn test_health_overall_empty() {
+        assert!(overall_health(&[], &[]).is_none());
+    }
+
+    #[test]
+    fn test_nonce_histogram_counts_all_chips() {
+        let slot = make_slot_with_nonces(0, &[0, 100, 200, 300, 400, 500, 600, 700, 800, 900]);
+        let hist = compute_nonce_histogram(&slot.chips, 5);
+
+        assert_eq!(hist.len(), 5);
+        assert_eq!(hist.iter().map(|&(_, _, c)| c).sum::<usize>(), 10);
+        assert!(hist.iter().all(|&(_, _, c)| c == 2));
+        assert_eq!(hist[0].0, 0);
+        assert_eq!(hist[4].1, 900);
+    }
+
+    #[test]
+    fn test_nonce_histogram_uniform_and_empty() {
+        let slot = make_slot_with_nonces(0, &[500; 6]);
+        let hist = compute_nonce_histogram(&slot.chips, 4);
+        assert_eq!(hist[0].2, 6);
+
+        assert!(compute_nonce_histogram(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_bimodal_detection() {
+        // Two clusters around 100 and 900
+        let mut nonces = vec![100; 10];
+        nonces.extend([900; 10]);
+        let slot = make_slot_with_nonces(0, &nonces);
+        assert!(is_bimodal(&compute_nonce_histogram(&slot.chips, 8)));
+
+        // Single cluster with a tail
+        let slot = make_slot_with_nonces(0, &[480, 490, 500, 500, 500, 510, 520, 300]);
+        assert!(!is_bimodal(&compute_nonce_histogram(&slot.chips, 8)));
+    }
+
+    #[test]
+    fn test_nonce_per_mhz_inefficient_chip() {
+        // Same freq everywhere, chip 1 produces far fewer nonces per MHz
+        let mut slot = make_slot_with_nonces(0, &[1000, 400, 1000, 1000]);
+        for chip in &mut slot.chips {
+            chip.freq = 500;
+        }
+        // Chip 3 runs at half freq with full nonces: very efficient
+        slot.chips[3].freq = 250;
+        let analysis = analyze_all_slots(&[slot], 1, &AnalysisConfig::default());
+
+        assert!(analysis[0][1].nonce_per_mhz_zscore < -0.5);
+        assert!(analysis[0][3].nonce_per_mhz_zscore > 1.0);
+    }
+
+    #[test]
+    fn test_nonce_per_mhz_uniform_zero() {
+        let analysis = analyze_all_slots(
+            &[make_slot_with_nonces(0, &[800; 4])],
+            1,
+            &AnalysisConfig::default(),
+        );
+        assert!(analysis[0].iter().all(|a| a.nonce_per_mhz_zscore == 0.0));
+    }
+
+    #[test]
+    fn test_error_rate() {
+        assert!((compute_error_rate(110, 100, 0.5) - 20.0).abs() < 0.01);
+        // Counter reset after a reboot and zero interval
+        assert_eq!(compute_error_rate(5, 100, 1.0), 0.0);
+        assert_eq!(compute_error_rate(110, 100, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_apply_error_rates_matches_by_id() {
+        let mut prev = make_slot(1, &[70, 70]);
+        prev.chips[1].errors = 10;
+        let mut cur = prev.clone();
+        cur.chips[1].errors = 40;
+        let mut analysis =
+            analyze_all_slots(std::slice::from_ref(&cur), 2, &AnalysisConfig::default());
+        apply_error_rates(&mut analysis, &[cur], &[prev], 2.0);
+        assert_eq!(analysis[0][0].error_rate_per_hour, 0.0);
+        assert!((analysis[0][1].error_rate_per_hour - 15.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_temp_baseline() {
+        let mut stats = RunningMeanVar::default();
+        for t in [68.0, 70.0, 72.0] {
+            stats.push(t);
+        }
+        assert!((stats.mean - 70.0).abs() < 0.01);
+        assert!((stats.variance() - 8.0 / 3.0).abs() < 0.01);
+        assert_eq!(RunningMeanVar::from_parts(3, 70.0, stats.variance()), stats);
+
+        // Chip 0 alternates 68/72 (std 2), chip 1 never moves
+        let mut baseline = TempBaseline::new();
+        for poll in 0..10 {
+            let t = if poll % 2 == 0 { 68 } else { 72 };
+            update_temp_baseline(&mut baseline, &[make_slot(1, &[t, 75])]);
+        }
+        assert_eq!(temp_baseline_polls(&baseline), 10);
+
+        let cur = make_slot(1, &[78, 76]);
+        let mut analysis =
+            analyze_all_slots(std::slice::from_ref(&cur), 2, &AnalysisConfig::default());
+        // Not established yet with 11 polls required
+        apply_temp_baseline(&mut analysis, std::slice::from_ref(&cur), &baseline, 11);
+        assert_eq!(analysis[0][0].temp_anomaly_score, 0.0);
+
+        apply_temp_baseline(&mut analysis, &[cur], &baseline, 10);
+        assert!((analysis[0][0].temp_anomaly_score - 4.0).abs() < 0.01);
+        // Zero spread is floored, so 1°C reads as 2σ rather than infinity
+        assert!((analysis[0][1].temp_anomaly_score - 2.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_efficiency_trend() {
+        // nonce/MHz 2.0, 1.5, 1.0, 0.5: slope -0.5 per poll
+        let declining = [(1200, 600), (900, 600), (600, 600), (300, 600)];
+        assert!((compute_efficiency_trend(&declining) + 0.5).abs() < 1e-4);
+        assert_eq!(compute_efficiency_trend(&declining[..2]), 0.0);
+        assert_eq!(
+            compute_efficiency_trend(&[(1200, 600), (1000, 500), (1400, 700)]),
+            0.0
+        );
+        // A freq change alone doesn't count: nonce scales with it
+        let improving = [(600, 600), (700, 600), (800, 600)];
+        assert!((compute_efficiency_trend(&improving) - 1.0 / 6.0).abs() < 1e-4);
+
+        assert_eq!(
+            EfficiencyTrend::from_slope(-0.6),
+            EfficiencyTrend::SteadyDecline
+        );
+        assert_eq!(
+            EfficiencyTrend::from_slope(-0.5),
+            EfficiencyTrend::SlightDecline
+        );
+        assert_eq!(EfficiencyTrend::from_slope(0.05), EfficiencyTrend::Stable);
+        assert_eq!(EfficiencyTrend::from_slope(0.2), EfficiencyTrend::Improving);
+
+        let mut history = EfficiencyHistory::new();
+        for poll in 0..12 {
+            update_efficiency_history(
+                &mut history,
+                &[make_slot_with_nonces(3, &[1000 - poll * 60, 1000])],
+            );
+        }
+        assert_eq!(history[&(3, 0)].len(), EFFICIENCY_TREND_POLLS);
+        let slots = [make_slot_with_nonces(3, &[280, 1000])];
+        let mut analysis = analyze_all_slots(&slots, 2, &AnalysisConfig::default());
+        apply_efficiency_trends(&mut analysis, &slots, &history);
+        assert!(analysis[0][0].efficiency_trend < EFFICIENCY_STEEP_DECLINE);
+        assert_eq!(analysis[0][1].efficiency_trend, 0.0);
+    }
+
+    #[test]
+    fn test_cross_miner_zscore() {
+        // Chip 0 runs hot on every board of the first miner only, so cross-slot
+        // comparison within that miner can't see it
+        let hot = MinerData {
+            slots: vec![make_slot(0, &[90, 70]), make_slot(1, &[90, 70])],
+            ..Default::default()
+        };
+        let peer = || MinerData {
+            slots: vec![make_slot(0, &[70, 70]), make_slot(1, &[70, 70])],
+            ..Default::default()
+        };
+        let (a, b) = (peer(), peer());
+        let cross = analyze_cross_miner(&[&hot, &a, &b], 2);
+        assert_eq!(cross.len(), 3);
+        assert!(cross[0][0][0].cross_miner_temp_zscore > 1.0);
+        assert_eq!(cross[0][1][1].cross_miner_temp_zscore, 0.0);
+        assert_eq!(cross[1][0][0].cross_miner_temp_zscore, 0.0);
+
+        let within = analyze_all_slots(&hot.slots, 2, &AnalysisConfig::default());
+        assert_eq!(within[0][0].cross_slot_zscore, 0.0);
+        let mut analysis = within;
+        apply_cross_miner(&mut analysis, &cross[0]);
+        assert!(analysis[1][0].cross_miner_zscore > 1.0);
+
+        // A lone miner has nothing to compare against
+        let alone = analyze_cross_miner(&[&hot], 2);
+        assert_eq!(alone[0][0][0], CrossMinerAnalysis::default());
+    }
+
+    #[test]
+    fn test_active_chip_drops() {
+        let slot = |id, freqs: &[i32]| Slot {
+            id,
+            chips: freqs
+                .iter()
+                .map(|&freq| Chip {
+                    freq,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let mut history = ActiveChipHistory::new();
+        assert!(record_active_chips(&mut history, &[slot(1, &[600; 10])]).is_empty());
+        // Losing a few chips at a time is tracked but not alerted
+        let mut freqs = [600; 10];
+        freqs[..2].fill(0);
+        assert!(record_active_chips(&mut history, &[slot(1, &freqs)]).is_empty());
+        assert!(is_active_count_declining(&history[&1]));
+        freqs[..8].fill(0);
+        assert_eq!(
+            record_active_chips(&mut history, &[slot(1, &freqs)]),
+            vec![(1, 6)]
+        );
+
+        for _ in 0..ACTIVE_CHIP_HISTORY_POLLS {
+            record_active_chips(&mut history, &[slot(1, &freqs)]);
+        }
+        assert_eq!(history[&1].len(), ACTIVE_CHIP_HISTORY_POLLS);
+        assert!(!is_active_count_declining(&history[&1]));
+    }
+
+    #[test]
+    fn test_crc_bursts() {
+        let slot = |id, crcs: &[i32]| Slot {
+            id,
+            chips: crcs
+                .iter()
+                .map(|&crc| Chip {
+                    crc,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let before = [slot(1, &[10, 10]), slot(2, &[0, 0]), slot(3, &[500, 0])];
+        let after = [slot(1, &[10, 60]), slot(2, &[90, 40]), slot(3, &[0, 0])];
+        // Slot 1 logged 50 (below the threshold), slot 3's counters were reset
+        assert_eq!(find_crc_bursts(&before, &after), vec![(2, 130)]);
+        assert!(find_crc_bursts(&[], &after).is_empty());
+    }
+
+    #[test]
+    fn test_cluster_chips_by_voltage() {
+        let chips = |vols: &[i32]| -> Vec<Chip> {
+            vols.iter()
+                .map(|&vol| Chip {
+                    vol,
+                    ..Default::default()
+                })
+                .collect()
+        };
+        // Small steps stay in one group
+        assert_eq!(
+            cluster_chips_by_voltage(&chips(&[320, 321, 323])),
+            [0, 0, 0]
+        );
+        // Two batches, and a chip that doesn't report its voltage
+        assert_eq!(
+            cluster_chips_by_voltage(&chips(&[330, 300, 0, 302, 331])),
+            [1, 0, 0, 0, 1]
+        );
+        // Six levels: only the three widest steps split, so at most four groups
+        assert_eq!(
+            cluster_chips_by_voltage(&chips(&[300, 310, 340, 350, 390, 450])),
+            [0, 0, 1, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_throttle_history() {
+        let slot = |freq| Slot {
+            id: 1,
+            freq,
+            ..Default::default()
+        };
+        let mut history = ThrottleHistory::new();
+        assert_eq!(throttle_percentage(&history, 1), None);
+        let now = Instant::now();
+        for freq in [600, 590, 480, 600] {
+            let statuses = compute_throttle_status(&[slot(freq)], &history);
+            record_throttle_status(&mut history, now, statuses);
+        }
+        // 590 MHz is within the 5% tolerance of the 600 MHz peak; 480 MHz is 20% below it
+        let severities: Vec<f32> = history.iter().map(|(_, s)| s[0].severity_pct).collect();
+        assert_eq!(severities, [0.0, 0.0, 20.0, 0.0]);
+        assert_eq!(throttle_percentage(&history, 1), Some(25.0));
+        assert_eq!(throttle_percentage(&history, 2), None);
+
+        for _ in 0..THROTTLE_HISTORY_POLLS {
+            record_throttle_status(&mut history, now, Vec::new());
+        }
+        assert_eq!(history.len(), THROTTLE_HISTORY_POLLS);
+    }
+
+    #[test]
+    fn test_data_staleness() {
+        let start = Instant::now();
+        let mut tracker = ChipTracker::new();
+        // Chip 0 keeps hashing, chip 1 repeats the same readings
+        for poll in 0..7 {
+            let mut slot = make_slot(2, &[70, 71]);
+            slot.chips[0].nonce = 100 + poll;
+            let now = start + std::time::Duration::from_secs(poll as u64 * 30);
+            track_chip_changes(&mut tracker, std::slice::from_ref(&slot), now);
+        }
+        assert_eq!(tracker[&(2, 0)].unchanged_polls, 0);
+        assert_eq!(
+            tracker[&(2, 0)].last_changed,
+            start + std::time::Duration::from_secs(180)
+        );
+        assert_eq!(tracker[&(2, 1)].unchanged_polls, 6);
+        assert_eq!(tracker[&(2, 1)].last_changed, start);
+
+        let slots = [make_slot(2, &[70, 71, 72])];
+        let mut analysis = analyze_all_slots(&slots, 3, &AnalysisConfig::default());
+        apply_data_staleness(&mut analysis, &slots, &tracker);
+        assert_eq!(analysis[0][1].data_staleness_polls, 6);
+        assert!(analysis[0][1].data_staleness_polls > STALE_DATA_POLLS);
+        // Not tracked yet
+        assert_eq!(analysis[0][2].data_staleness_polls, 0);
+
+        // Any field moving resets the count
+        let mut slot = make_slot(2, &[70, 71]);
+        slot.chips[1].crc = 1;
+        track_chip_changes(&mut tracker, &[slot], start);
+        assert_eq!(tracker[&(2, 1)].unchanged_polls, 0);
+    }
+
+    #[test]
+    fn test_section_stats_split() {
+        // 4 domains of 2 chips: bottom = D0, D1 (1 + 3/2 = 2 domains), top = D2, D3
+        let mut slot = make_slot(0, &[80, 80, 80, 80, 90, 90, 90, 90]);
+        slot.chips[7].nonce = 0;
+        for chip in &mut slot.chips[..7] {
+            chip.nonce = 100;
+        }
+        let (bottom, top) = compute_section_stats(&slot, 2, &AnalysisConfig::default());
+        assert_eq!(bottom.chip_count, 4);
+        assert_eq!(top.chip_count, 4);
+        assert!((bottom.mean_temp - 80.0).abs() < 0.01);
+        assert!((top.mean_temp - 90.0).abs() < 0.01);
+        assert_eq!(bottom.dead_chips, 0);
+        assert_eq!(top.dead_chips, 1);
+        assert!((bottom.mean_nonce - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_nonce_timeline() {
+        let chip = make_chip_with_nonce(0, 70, 100);
+        let timeline = compute_nonce_timeline(&chip, 0, 60);
+        assert_eq!(timeline.len(), NONCE_TIMELINE_SEGMENTS);
+        assert_eq!(timeline.iter().sum::<u32>(), 100);
+        assert!(timeline.iter().all(|&n| n == 8 || n == 9));
+
+        // Capped at what the slot submits in the window: 1/s for 60 s
+        assert_eq!(
+            compute_nonce_timeline(&chip, 1, 60),
+            vec![5; NONCE_TIMELINE_SEGMENTS]
+        );
+        let dead = make_chip_with_nonce(1, 70, 0);
+        assert_eq!(
+            compute_nonce_timeline(&dead, 3000, 60),
+            vec![0; NONCE_TIMELINE_SEGMENTS]
+        );
+        assert!(compute_nonce_timeline(&chip, 3000, 0).is_empty());
+    }
+
+    #[test]
+    fn test_thermal_uniformity() {
+        // 4 domains of 2: columns are [D1 D0] at the bottom and [D2 D3] on top, intake on the right
+        let slot = make_slot(0, &[70, 70, 80, 80, 90, 90, 80, 80]);
+        let uniformity = compute_thermal_uniformity(&slot, 2);
+        assert_eq!(uniformity.temperature_range, 20);
+        assert!((uniformity.hot_side_bias - 10.0).abs() < 0.01);
+        assert!((uniformity.vertical_gradient - 10.0).abs() < 0.01);
+        assert!(uniformity.coefficient_of_variation > 0.0);
+        assert!(uniformity.score() < UNIFORMITY_WARN_PCT);
+
+        let even = compute_thermal_uniformity(&make_slot(1, &[75; 8]), 2);
+        assert_eq!(even.temperature_range, 0);
+        assert_eq!(even.hot_side_bias, 0.0);
+        assert_eq!(even.score(), 100.0);
+    }
+
+    #[test]
+    fn test_placeholders_left_out_of_aggregates() {
+        // Six healthy chips at 75°C with a skipped id in the middle
+        let mut slot = Slot {
+            id: 0,
+            chips: (0..6).map(|i| make_chip_with_nonce(i, 75, 1000)).collect(),
+            ..Default::default()
+        };
+        slot.chips[2] = Chip::placeholder(2);
+        let slots = [slot];
+        let analysis = analyze_all_slots(&slots, 3, &AnalysisConfig::default());
+
+        assert!(overall_health(&slots, &analysis).unwrap() > 99.0);
+        let uniformity = compute_thermal_uniformity(&slots[0], 3);
+        assert_eq!(uniformity.temperature_range, 0);
+        assert_eq!(uniformity.score(), 100.0);
+        let (bottom, top) = compute_section_stats(&slots[0], 3, &AnalysisConfig::default());
+        assert_eq!((bottom.chip_count, bottom.mean_temp), (2, 75.0));
+        assert_eq!((top.chip_count, top.dead_chips), (3, 0));
+
+        let histogram = compute_nonce_histogram(&slots[0].chips, 4);
+        assert_eq!(histogram.iter().map(|&(_, _, n)| n).sum::<usize>(), 5);
+        assert_eq!(
+            compute_domain_nonce_histogram(&slots[0].chips, 3, 0)
+                .iter()
+                .sum::<u8>(),
+            2
+        );
+
+        let mut baseline = TempBaseline::default();
+        update_temp_baseline(&mut baseline, &slots);
+        let mut history = EfficiencyHistory::default();
+        update_efficiency_history(&mut history, &slots);
+        assert!(!baseline.contains_key(&(0, 2)) && !history.contains_key(&(0, 2)));
+        assert_eq!(baseline.len(), 5);
+
+        // A reported chip at the placeholder's position on another miner isn't
+        // compared against its 0°C
+        let other = MinerData {
+            slots: vec![make_slot(0, &[75, 75, 80, 75, 75, 75])],
+            ..Default::default()
+        };
+        let own = MinerData {
+            slots: slots.to_vec(),
+            ..Default::default()
+        };
+        let cross = analyze_cross_miner(&[&own, &other], 3);
+        assert_eq!(cross[0][0][2].cross_miner_temp_zscore, 0.0);
+        assert_eq!(cross[1][0][2].cross_miner_temp_zscore, 0.0);
+    }
+
+    #[test]
+    fn test_gradient_noise_filtered() {
+        // Uniform 70°C grid with ±1°C sensor jitter
+        let temps = [70, 71, 70, 69, 71, 70, 71, 70, 69];
+        let analysis = analyze_all_slots(&[make_slot(0, &temps)], 3, &AnalysisConfig::default());
+        assert!(analysis[0].iter().all(|a| a.gradient == 0.0));
+        assert!(analysis[0].iter().all(|a| a.gradient_significance == 0.0));
+
+        // A zero floor keeps the jitter
+        let analysis = analyze_all_slots(
+            &[make_slot(0, &temps)],
+            3,
+            &AnalysisConfig {
+                gradient_noise_floor: 0.0,
+                ..AnalysisConfig::default()
+            },
+        );
+        assert!(analysis[0].iter().any(|a| a.gradient > 0.0));
+
+        // Requiring all three upstream neighbors leaves only the middle chip of domain 1
+        let analysis = analyze_all_slots(
+            &[make_slot(0, &temps)],
+            3,
+            &AnalysisConfig {
+                gradient_noise_floor: 0.0,
+                min_neighbors_for_gradient: MAX_GRADIENT_NEIGHBORS,
+                ..AnalysisConfig::default()
+            },
+        );
+        assert!(analysis[0][4].gradient > 0.0);
+        assert!(
+            analysis[0]
+                .iter()
+                .enumerate()
+                .all(|(idx, a)| idx == 4 || a.gradient == 0.0)
+        );
+    }
+
+    #[test]
+    fn test_domain_nonce_histogram() {
+        // Two domains of 4; the second has one chip far below the rest
+        let slot = make_slot_with_nonces(0, &[100, 100, 100, 100, 100, 200, 300, 500]);
+        assert_eq!(
+            compute_domain_nonce_histogram(&slot.chips, 4, 1),
+            [1, 1, 1, 0, 1]
+        );
+        assert_eq!(domain_nonce_bin(&slot.chips, 4, 4), 0);
+        assert_eq!(domain_nonce_bin(&slot.chips, 4, 7), NONCE_HIST_BINS - 1);
+        // Identical nonces all land in the middle bin
+        assert_eq!(
+            compute_domain_nonce_histogram(&slot.chips, 4, 0),
+            [0, 0, 4, 0, 0]
+        );
+        // Domain past the end is empty
+        assert_eq!(compute_domain_nonce_histogram(&slot.chips, 4, 5), [0; 5]);
+    }
+
+    #[test]
+    fn test_signal_break() {
+        // Domain 0: chips 2-3 silent after a clean chip 1; domain 1: healthy;
+        // domain 2: only the last chip dead, but its predecessor has bus errors
+        let mut slot = make_slot_with_nonces(0, &[9, 9, 0, 0, 9, 9, 9, 9, 9, 9, 9, 0]);
+        slot.chips[10].x = 3;
+        assert_eq!(
+            find_signal_break_domain(&slot.chips, 4),
+            [Some(2), None, None]
+        );
+        // Whole domain dead: no good chip to place the break after
+        let dead = make_slot_with_nonces(0, &[0, 0, 0]);
+        assert_eq!(find_signal_break_domain(&dead.chips, 3), [None]);
+    }
+
+    #[test]
+    fn test_board_fingerprint() {
+        let slot = make_slot_with_nonces(1, &[100, 200, 300, 400, 500, 600, 700, 800, 900, 1000]);
+        let mut shuffled = slot.clone();
+        shuffled.chips.reverse();
+        assert_eq!(
+            compute_board_fingerprint(&slot),
+            compute_board_fingerprint(&shuffled)
+        );
+
+        // Small drift: fingerprint moves, board still counts as the same
+        let mut drift = slot.clone();
+        drift.chips[0].temp += 2;
+        drift.chips[1].nonce += 50;
+        assert_ne!(
+            compute_board_fingerprint(&slot),
+            compute_board_fingerprint(&drift)
+        );
+        assert_eq!(board_similarity(&slot, &drift), 1.0);
+
+        // A frequency change and a chip dying change 2 of 10 chips
+        let mut changed = slot.clone();
+        changed.chips[2].freq += 25;
+        changed.chips[3].nonce = 0;
+        assert!((board_similarity(&slot, &changed) - 0.8).abs() < 0.01);
+        assert!(board_similarity(&slot, &changed) < BOARD_SIMILARITY_MIN);
+        // Missing chips count as changed
+        changed.chips.truncate(5);
+        assert!((board_similarity(&slot, &changed) - 0.3).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_crc_segments() {
+        let slot_with_crc = |crc: &[i32]| {
+            let mut slot = make_slot(0, &vec![70; crc.len()]);
+            for (chip, &crc) in slot.chips.iter_mut().zip(crc) {
+                chip.crc = crc;
+            }
+            slot
+        };
+        // Domain 0: errors from chip 3 on; domain 1: one bad chip early in the chain;
+        // domain 2: errors everywhere; domain 3: too few to attribute
+        let slot = slot_with_crc(&[
+            0, 0, 1, 12, 15, 20, //
+            30, 0, 0, 0, 0, 0, //
+            10, 10, 10, 10, 10, 10, //
+            0, 0, 0, 2, 2, 2,
+        ]);
+        let segments = attribute_crc_to_segments(&slot.chips, 6);
+        let breaks: Vec<_> = segments.iter().map(|s| s.break_likely_after).collect();
+        assert_eq!(breaks, [Some(2), None, None, None]);
+        assert_eq!(segments[3].domain, 3);
+        // A single-chip domain has no chain to split
+        assert_eq!(
+            attribute_crc_to_segments(&slot_with_crc(&[50]).chips, 1)[0].break_likely_after,
+            None
+        );
+    }
+
+    #[test]
+    fn test_freq_compliance() {
+        let slot = Slot {
+            freq: 600,
+            chips: [600, 605, 595, 606, 590, 640, 598, 600]
+                .into_iter()
+                .map(|freq| Chip {
+                    freq,
+                    nonce: 100,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let report = check_freq_compliance(&slot);
+        assert_eq!(
+            report,
+            FreqComplianceReport {
+                compliant_count: 5,
+                over_freq_count: 2,
+                under_freq_count: 1,
+                tolerance_mhz: 5,
+            }
+        );
+        assert!((report.compliance() - 0.625).abs() < 1e-6);
+        assert_eq!(check_freq_compliance(&Slot::default()).compliance(), 1.0);
+        // A skipped id (freq 0) isn't an under-frequency chip
+        let mut skipped = slot.clone();
+        skipped.chips.push(Chip::placeholder(8));
+        assert_eq!(check_freq_compliance(&skipped), report);
+
+        let analysis =
+            analyze_all_slots(std::slice::from_ref(&slot), 4, &AnalysisConfig::default());
+        let flagged: Vec<bool> = analysis[0].iter().map(|a| a.freq_noncompliant).collect();
+        assert_eq!(
+            flagged,
+            [false, false, false, true, true, true, false, false]
+        );
+    }
+
+    #[test]
+    fn test_dead_density_map() {
+        // 4 domains × 3 chips: a 2×6 grid
+        let alive = make_slot_with_nonces(0, &[100; 12]);
+        assert!(
+            compute_dead_density_map(&alive, 3, 2)
+                .iter()
+                .all(|&d| d == 0.0)
+        );
+
+        // Every chip dead but C0: C0 is surrounded, its neighbors only partly
+        let mut nonces = [0; 12];
+        nonces[0] = 100;
+        let slot = make_slot_with_nonces(0, &nonces);
+        let density = compute_dead_density_map(&slot, 3, 1);
+        assert!((density[0] - 1.0).abs() < 1e-6);
+        assert!(density[1] < 1.0 && density[1] > 0.5);
+        // Kernel sizes outside 1-5 are clamped
+        assert_eq!(compute_dead_density_map(&slot, 3, 0), density);
+        assert!(
+            compute_dead_density_map(&slot, 0, 2)
+                .iter()
+                .all(|&d| d == 0.0)
+        );
+    }
+
+    #[test]
+    fn test_heat_cycles() {
+        assert_eq!(count_temp_threshold_crossings(&[], 60), 0);
+        // Up at 60 and 75; staying hot, cooling down and sitting at the threshold don't count
+        assert_eq!(
+            count_temp_threshold_crossings(&[40, 60, 70, 55, 60, 60, 59, 75], 60),
+            3
+        );
+
+        let mut metadata = ChipMetadataMap::new();
+        for temps in [[50, 70], [75, 70], [50, 70], [80, 40], [85, 40]] {
+            update_chip_metadata(&mut metadata, &[make_slot(2, &temps)]);
+        }
+        assert_eq!(metadata[&(2, 0)].heat_cycles, 2);
+        assert_eq!(metadata[&(2, 1)].heat_cycles, 0);
+        assert_eq!(metadata[&(2, 1)].last_temp, 40);
+        let slots = [make_slot(2, &[85, 40])];
+        let mut analysis = analyze_all_slots(&slots, 2, &AnalysisConfig::default());
+        apply_heat_cycles(&mut analysis, &slots, &metadata);
+        assert_eq!(analysis[0][0].heat_cycles, 2);
+    }
+
+    #[test]
+    fn test_chip_power_state() {
+        let state = |freq| {
+            let chip = Chip {
+                freq,
+                ..Default::default()
+            };
+            compute_chip_power_state(&chip, 600)
+        };
+        assert_eq!(state(0), ChipPowerState::Dead);
+        assert_eq!(state(25), ChipPowerState::Sleep);
+        assert_eq!(state(50), ChipPowerState::Sleep);
+        assert_eq!(state(100), ChipPowerState::Throttled);
+        assert_eq!(state(539), ChipPowerState::Throttled);
+        assert_eq!(state(540), ChipPowerState::Active);
+        assert_eq!(state(650), ChipPowerState::Active);
+    }
+
+    #[test]
+    fn test_dead_chip_pattern() {
+        use DeadChipPattern::*;
+        let classify = |positions: &[(usize, usize)]| classify_dead_chip_pattern(positions, 4);
+
+        assert_eq!(classify(&[]), Scattered);
+        assert_eq!(classify(&[(0, 1), (5, 3)]), Scattered);
+        assert_eq!(
+            classify(&[(2, 0), (2, 1), (2, 3)]),
+            VerticalLine { domain: 2 }
+        );
+        assert_eq!(
+            classify(&[(2, 3), (2, 2), (2, 1), (2, 0)]),
+            FullDomain { domain: 2 }
+        );
+        let range: Vec<_> = (3..=5).flat_map(|d| (0..4).map(move |r| (d, r))).collect();
+        assert_eq!(classify(&range), DomainRange { start: 3, end: 5 });
+        assert_eq!(
+            classify(&[(1, 2), (4, 2), (7, 2)]),
+            HorizontalLine { row: 2 }
+        );
+        assert_eq!(classify(&[(4, 0), (5, 1), (6, 2), (7, 3)]), Diagonal);
+        assert_eq!(classify(&[(4, 3), (5, 2), (6, 1)]), Diagonal);
+        // A gap in the domains breaks the diagonal
+        assert_eq!(classify(&[(4, 0), (5, 1), (7, 2)]), Scattered);
+
+        let chips: Vec<Chip> = (0..8)
+            .map(|id| Chip {
+                id,
+                nonce: if id % 4 == 1 { 0 } else { 10 },
+                ..Default::default()
+            })
+            .collect();
+        assert_eq!(dead_chip_positions(&chips, 4), [(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_chip_clusters() {
+        // 3 domains × 3 rows: an L of failing chips across domains 0-1, a lone one in domain 2
+        let health = [100.0, 10.0, 10.0, 100.0, 100.0, 10.0, 20.0, 100.0, 100.0];
+        let slot_analysis: Vec<ChipAnalysis> = health
+            .iter()
+            .map(|&health_score| ChipAnalysis {
+                health_score,
+                ..Default::default()
+            })
+            .collect();
+        let mut chips: Vec<Chip> = (0..9).map(|id| make_chip(id, 70)).collect();
+        assert_eq!(
+            find_chip_clusters(&chips, &slot_analysis, 3, 50.0, 3),
+            [ChipCluster {
+                chips: vec![1, 2, 5]
+            }]
+        );
+        // Chip 6 is on row 0 of domain 2: not adjacent to chip 5 (row 2 of domain 1)
+        assert_eq!(
+            find_chip_clusters(&chips, &slot_analysis, 3, 50.0, 1).len(),
+            2
+        );
+        assert!(find_chip_clusters(&chips, &slot_analysis, 3, 5.0, 1).is_empty());
+
+        // Skipped ids score 0 but aren't failing chips
+        chips[2] = Chip::placeholder(2);
+        chips[5] = Chip::placeholder(5);
+        assert!(find_chip_clusters(&chips, &slot_analysis, 3, 50.0, 2).is_empty());
+    }
+
+    #[test]
+    fn test_crc_spatial_correlation() {
+        // 4 domains × 3 rows
+        let slot_with_crc = |crc: [i32; 12]| Slot {
+            chips: crc
+                .iter()
+                .map(|&crc| Chip {
+                    crc,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        // Errors on domains 0-1 only: strongly clustered
+        let clustered = slot_with_crc([9, 9, 9, 9, 9, 9, 0, 0, 0, 0, 0, 0]);
+        assert!(compute_crc_spatial_correlation(&clustered, 3) > CRC_CLUSTERING_HIGH);
+        // Checkerboard: every neighbor of an erroring chip is clean
+        let alternating = slot_with_crc([9, 0, 9, 0, 9, 0, 9, 0, 9, 0, 9, 0]);
+        assert!(compute_crc_spatial_correlation(&alternating, 3) < 0.0);
+        assert_eq!(
+            compute_crc_spatial_correlation(&slot_with_crc([2; 12]), 3),
+            0.0
+        );
+
+        // Chip 6 (domain 2 row 0) touches chip 3 (9 errors), chip 9 and chip 7 (clean)
+        let analysis = analyze_all_slots(&[clustered], 3, &AnalysisConfig::default());
+        assert!((analysis[0][6].crc_neighbor_correlation - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(analysis[0][11].crc_neighbor_correlation, 0.0);
+    }
+
+    fn rate_slots(rates: &[i32]) -> Vec<Slot> {
+        (0..)
+            .zip(rates)
+            .map(|(id, &nonce_rate)| Slot {
+                id,
+                nonce_rate,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_slot_balance() {
+        let balanced = compute_slot_balance(&rate_slots(&[3000, 3000, 3000]));
+        assert!((balanced.mean_nonce_rate - 3000.0).abs() < 1e-3);
+        assert_eq!(balanced.std_nonce_rate, 0.0);
+        assert!(balanced.imbalanced_slots.is_empty());
+
+        // 10% low is within tolerance but still the worst slot
+        let slight = compute_slot_balance(&rate_slots(&[3100, 3100, 2790]));
+        assert!(slight.imbalanced_slots.is_empty());
+        assert_eq!(slight.worst.map(|(id, _)| id), Some(2));
+
+        // Slot 1 at 35% below the mean
+        let severe = compute_slot_balance(&rate_slots(&[3550, 1950, 3500]));
+        assert_eq!(severe.imbalanced_slots, [1]);
+        let (id, dev) = severe.worst.unwrap();
+        assert_eq!(id, 1);
+        assert!((dev + 0.35).abs() < 1e-3);
+
+        assert_eq!(
+            compute_slot_balance(&rate_slots(&[3000])),
+            SlotBalance::default()
+        );
+        assert_eq!(
+            compute_slot_balance(&rate_slots(&[0, 0])),
+            SlotBalance::default()
+        );
+    }
+
+    #[test]
+    fn test_error_breakdown() {
+        let chips: Vec<Chip> = [(0, 2, 0, 0), (6, 4, 0, 1), (0, 0, 3, 2)]
+            .into_iter()
+            .map(|(errors, crc, x, repeat)| Chip {
+                errors,
+                crc,
+                x,
+                repeat,
+                ..Default::default()
+            })
+            .collect();
+        let slot = Slot {
+            chips,
+            ..Default::default()
+        };
+        let bars = error_breakdown(&slot.chips[1], &slot);
+        assert_eq!(bars.map(|b| b.field), ["err", "crc", "x", "repeat"]);
+        assert_eq!(bars.map(|b| b.value), [6, 4, 0, 1]);
+        assert_eq!(bars.map(|b| b.slot_avg), [2.0, 2.0, 1.0, 1.0]);
+
+        let empty = Slot::default();
+        assert_eq!(error_breakdown(&Chip::default(), &empty)[0].slot_avg, 0.0);
+    }
+
+    #[test]
+    fn test_config_match() {
+        let config = MinerConfig {
+            model: "TEST",
+            chip_num: 100,
+            chips_per_domain: 4,
+            board_num: 4,
+            slot_link: None,
+            rated_power_per_chip_w: 8.0,
+            rated_freq_mhz: 550,
+            rated_hashrate_ths: 100.0,
+        };
+        let slots = vec![
+            make_slot(0, &[70; 100]),
+            make_slot(1, &[70; 97]),
+            make_slot(2, &[70; 90]),
+        ];
+        let mismatches = compute_config_match(&slots, &config);
+        assert_eq!(mismatches.len(), 2);
+        assert_eq!(mismatches[0].slot_id, 1);
+        assert_eq!(mismatches[0].delta, -3);
+        assert!(!mismatches[0].is_severe());
+        assert_eq!(mismatches[1].delta, -10);
+        assert!(mismatches[1].is_severe());
+
+        // Placeholders filling skipped ids don't make up the missing chips
+        let mut padded = make_slot(1, &[70; 97]);
+        padded.chips.extend((97..100).map(Chip::placeholder));
+        let mismatches = compute_config_match(&[padded], &config);
+        assert_eq!((mismatches[0].actual, mismatches[0].delta), (97, -3));
+    }
+
+    #[test]
+    fn test_miner_diff_matches_slots_by_id() {
+        let current = MinerData {
+            slots: vec![make_slot(0, &[70, 72]), make_slot(1, &[80, 80])],
+            ..Default::default()
+        };
+        // Target lists slots in a different order and lacks one chip
+        let target = MinerData {
+            slots: vec![make_slot(1, &[75]), make_slot(0, &[70, 70])],
+            ..Default::default()
+        };
+        assert_eq!(
+            compute_miner_diff(&current, &target),
+            vec![vec![0, 2], vec![5, 0]]
+        );
+    }
 }
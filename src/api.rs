@@ -1,52 +1,856 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
+use hmac::{Hmac, Mac};
 use reqwest::Client;
+use serde::Deserialize;
+use sha2::Sha256;
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
 
-use crate::models::{Chip, MinerData, Slot, SystemInfo};
+use crate::config::MinerConfig;
+use crate::models::{Chip, ChipDataQuality, ChipField, HardwareInfo, MinerData, Slot, SystemInfo};
 
 const TIMEOUT_SECS: u64 = 30;
 
+/// Overview labels for the pool response time, by firmware generation
+const POOL_LATENCY_LABELS: [&str; 3] = ["Pool Latency", "Pool Response Time", "Pool Delay"];
+/// Overview labels for the active pool address
+const POOL_URL_LABELS: [&str; 3] = ["Pool URL", "Pool 1", "Pool"];
+/// Overview labels for fan speeds; intake and exhaust fans are listed separately
+const FAN_SPEED_LABELS: [&str; 3] = ["Fan Speed In", "Fan Speed Out", "Fan Speed"];
+/// Stratum's customary port, for pool addresses without one
+// Slot header voltages above this are millivolts
+const MAX_INPUT_VOLTAGE: f32 = 100.0;
+
+const STRATUM_DEFAULT_PORT: u16 = 3333;
+const POOL_PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Standard remote syslog port; binding it usually needs root
+pub const SYSLOG_PORT: u16 = 514;
+// A pushed log dump is complete once its sender has been quiet this long
+const SYSLOG_QUIET_MS: u64 = 1500;
+const SYSLOG_MAX_PACKET: usize = 8192;
+
+/// Environment variable holding the firmware changelog JSON URL
+/// The file maps firmware versions to changelog entries, newest first:
+/// `{"20230612.19.Rel": ["Fix fan PWM at low temps", ...]}`
+const CHANGELOG_URL_VAR: &str = "WHATSMINER_CHANGELOG_URL";
+
+/// Environment variable holding a URL to refresh the bundled firmware database from
+const FIRMWARE_DB_URL_VAR: &str = "WHATSMINER_FIRMWARE_DB_URL";
+
+/// Environment variable holding the Awesome Miner API key, sent in AWESOME_MINER_KEY_HEADER
+const AWESOME_MINER_KEY_VAR: &str = "AWESOME_MINER_API_KEY";
+const AWESOME_MINER_KEY_HEADER: &str = "X-Api-Key";
+
+/// Known firmware releases shipped with the app
+const BUNDLED_FIRMWARE_DB: &str = include_str!("../assets/firmware_db.json");
+
+/// Known firmware releases, `{"releases": [{"version", "released", "changes"}]}`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FirmwareDb {
+    pub releases: Vec<FirmwareRelease>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FirmwareRelease {
+    /// e.g. "20251016.14.Rel2"
+    pub version: String,
+    /// Release date, YYYY-MM-DD
+    pub released: String,
+    /// Changelog summary, one change per line
+    pub changes: String,
+}
+
+impl FirmwareDb {
+    /// The database bundled with the app
+    pub fn bundled() -> Self {
+        parse_firmware_db(BUNDLED_FIRMWARE_DB).unwrap_or_default()
+    }
+}
+
+/// Miner firmware compared with the newest known release
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FirmwareStatus {
+    UpToDate,
+    /// `changes` lists every newer release's changes, newest first
+    UpdateAvailable {
+        latest: String,
+        changes: String,
+    },
+    /// Unparseable version or empty database
+    Unknown,
+}
+
+/// How the miner's web UI is reached
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ConnectionMode {
+    #[default]
+    Direct,
+    /// Through an SSH jump host ("host" or "host:port"), with public key auth
+    SshTunnel {
+        host: String,
+        user: String,
+        key_path: PathBuf,
+    },
+}
+
+/// Where the open miner's chip data comes from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DataSource {
+    /// The miner's own web interface
+    #[default]
+    WhatsMinerNative,
+    /// An Awesome Miner server ("http://server:8080") that manages the miner
+    AwesomeMiner { url: String, miner_id: u32 },
+}
+
+/// Stage of a single-miner fetch, reported through `fetch_all_with_progress`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchStage {
+    Connecting,
+    Authenticating,
+    FetchingChipData,
+    FetchingOverview,
+    Parsing,
+}
+
+impl FetchStage {
+    pub const ALL: [Self; 5] = [
+        Self::Connecting,
+        Self::Authenticating,
+        Self::FetchingChipData,
+        Self::FetchingOverview,
+        Self::Parsing,
+    ];
+}
+
 /// Fetch all data with single auth, parallel page fetches
 pub async fn fetch_all(
     ip: &str,
     user: &str,
     pass: &str,
 ) -> Result<(MinerData, SystemInfo), String> {
-    let client = Arc::new(
-        Client::builder()
-            // SECURITY: Accept self-signed certs - required for miner's HTTPS interface.
-            // This is safe in this context as we're connecting to a known local device.
-            .danger_accept_invalid_certs(true)
-            .cookie_store(true)
+    fetch_all_with_progress(ip, user, pass, &|_| {}).await
+}
+
+/// `fetch_all_with_progress` over the given connection
+pub async fn fetch_all_with_mode(
+    mode: &ConnectionMode,
+    ip: &str,
+    user: &str,
+    pass: &str,
+    progress: &(dyn Fn(FetchStage) + Sync),
+) -> Result<(MinerData, SystemInfo), String> {
+    match mode {
+        ConnectionMode::Direct => fetch_all_with_progress(ip, user, pass, progress).await,
+        ConnectionMode::SshTunnel {
+            host,
+            user: jump_user,
+            key_path,
+        } => {
+            progress(FetchStage::Connecting);
+            let tunnel = open_ssh_tunnel(host, jump_user, key_path, ip).await?;
+            fetch_all_with_progress(&tunnel.local_addr.to_string(), user, pass, progress).await
+        }
+    }
+}
+
+/// `fetch_all` for a miner only reachable from an SSH jump host
+#[allow(dead_code)] // explicit entry point; the UI goes through `fetch_all_with_mode`
+pub async fn fetch_all_via_ssh(
+    jump_host: &str,
+    jump_user: &str,
+    key_path: &Path,
+    miner_ip: &str,
+    miner_user: &str,
+    miner_pass: &str,
+) -> Result<(MinerData, SystemInfo), String> {
+    let tunnel = open_ssh_tunnel(jump_host, jump_user, key_path, miner_ip).await?;
+    fetch_all(&tunnel.local_addr.to_string(), miner_user, miner_pass).await
+}
+
+/// `fetch_all`, calling `progress` as each stage starts
+///
+/// The chip data and overview pages are fetched in parallel; FetchingOverview
+/// is reported once the (larger) chip data page has arrived.
+pub async fn fetch_all_with_progress(
+    ip: &str,
+    user: &str,
+    pass: &str,
+    progress: &(dyn Fn(FetchStage) + Sync),
+) -> Result<(MinerData, SystemInfo), String> {
+    progress(FetchStage::Connecting);
+    let client = Arc::new(build_client()?);
+
+    // Authenticate once
+    login_at(&client, &format!("https://{ip}"), user, pass, progress).await?;
+
+    // Fetch both pages in parallel
+    progress(FetchStage::FetchingChipData);
+    let ip = ip.to_string();
+    let (miner_html, overview_html) = tokio::join!(
+        async {
+            let html = fetch_miner_api(client.clone(), &ip).await;
+            progress(FetchStage::FetchingOverview);
+            html
+        },
+        fetch_overview(client.clone(), &ip),
+    );
+    let (miner_html, overview_html) = (miner_html?, overview_html?);
+
+    progress(FetchStage::Parsing);
+    let data = parse_html(&miner_html)?;
+    let mut info = parse_overview_html(&overview_html);
+    if info.pool_latency_ms.is_none() && !info.pool_url.is_empty() {
+        info.pool_latency_ms = measure_pool_latency(&info.pool_url)
+            .await
+            .map(|d| u32::try_from(d.as_millis()).unwrap_or(u32::MAX));
+    }
+    Ok((data, info))
+}
+
+/// Fetch a miner's status from an Awesome Miner server's REST API
+///
+/// The API key, if the server requires one, is read from AWESOME_MINER_API_KEY.
+pub async fn fetch_from_awesome_miner(
+    server_url: &str,
+    miner_id: u32,
+) -> Result<(MinerData, SystemInfo), String> {
+    let api_key = std::env::var(AWESOME_MINER_KEY_VAR).ok();
+    fetch_awesome_miner_with_key(server_url, miner_id, api_key.as_deref()).await
+}
+
+async fn fetch_awesome_miner_with_key(
+    server_url: &str,
+    miner_id: u32,
+    api_key: Option<&str>,
+) -> Result<(MinerData, SystemInfo), String> {
+    let base = server_url.trim().trim_end_matches('/');
+    let base = if base.contains("://") {
+        base.to_string()
+    } else {
+        format!("http://{base}")
+    };
+    let mut request = Client::builder()
+        .timeout(Duration::from_secs(TIMEOUT_SECS))
+        .build()
+        .map_err(|e| e.to_string())?
+        .get(format!("{base}/api/miners/{miner_id}"));
+    if let Some(key) = api_key {
+        request = request.header(AWESOME_MINER_KEY_HEADER, key);
+    }
+    let resp = request.send().await.map_err(|e| e.to_string())?;
+    if !resp.status().is_success() {
+        return Err(format!("Awesome Miner failed: {}", resp.status()));
+    }
+    parse_awesome_miner(&resp.text().await.map_err(|e| e.to_string())?)
+}
+
+/// One miner from the Awesome Miner API
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct AwesomeMinerStatus {
+    model: String,
+    firmware_version: String,
+    pool_list: Vec<AwesomeMinerPool>,
+    /// Hashboards; Awesome Miner lists ASIC boards under its GPU list
+    #[serde(alias = "gpuList")]
+    asic_list: Vec<AwesomeMinerBoard>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct AwesomeMinerPool {
+    url: String,
+    is_active: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct AwesomeMinerBoard {
+    id: i32,
+    temperature: f64,
+    frequency: i32,
+    fan_speed: Option<i32>,
+    chip_list: Vec<AwesomeMinerChip>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct AwesomeMinerChip {
+    id: i32,
+    temperature: i32,
+    frequency: i32,
+    voltage: i32,
+    nonce: i64,
+    errors: i32,
+    crc: i32,
+}
+
+/// Map an Awesome Miner miner to the native types: each board becomes a slot
+/// and its chip list the slot's chips; slot nonce, error and CRC counts are the
+/// sums over its chips
+fn parse_awesome_miner(json: &str) -> Result<(MinerData, SystemInfo), String> {
+    let miner: AwesomeMinerStatus = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    if miner.asic_list.is_empty() {
+        return Err("No slots found".into());
+    }
+    let info = SystemInfo {
+        model: miner.model,
+        firmware_version: miner.firmware_version,
+        pool_url: miner
+            .pool_list
+            .iter()
+            .find(|p| p.is_active)
+            .or(miner.pool_list.first())
+            .map(|p| p.url.clone())
+            .unwrap_or_default(),
+        fan_speed_rpm: miner.asic_list.iter().filter_map(|b| b.fan_speed).max(),
+        ..SystemInfo::default()
+    };
+    let slots = miner
+        .asic_list
+        .into_iter()
+        .map(|board| {
+            let chips: Vec<Chip> = board
+                .chip_list
+                .into_iter()
+                .map(|c| Chip {
+                    id: c.id,
+                    freq: c.frequency,
+                    vol: c.voltage,
+                    temp: c.temperature,
+                    nonce: c.nonce,
+                    errors: c.errors,
+                    crc: c.crc,
+                    ..Chip::default()
+                })
+                .collect();
+            Slot {
+                id: board.id,
+                freq: board.frequency,
+                temp: board.temperature,
+                nonce_valid: chips.iter().map(|c| c.nonce).sum(),
+                errors: chips.iter().map(|c| c.errors).sum(),
+                crc: chips.iter().map(|c| c.crc).sum(),
+                chips,
+                ..Slot::default()
+            }
+        })
+        .collect();
+    Ok((
+        MinerData {
+            slots,
+            ..MinerData::default()
+        },
+        info,
+    ))
+}
+
+/// Time to open a TCP connection to the pool, from this machine rather than the
+/// miner; None if it can't be reached within 2 seconds
+pub async fn measure_pool_latency(pool_url: &str) -> Option<Duration> {
+    let (host, port) = pool_host_port(pool_url)?;
+    let start = std::time::Instant::now();
+    tokio::time::timeout(
+        POOL_PING_TIMEOUT,
+        tokio::net::TcpStream::connect((host, port)),
+    )
+    .await
+    .ok()?
+    .ok()?;
+    Some(start.elapsed())
+}
+
+/// Host and port of a pool address such as "stratum+tcp://btc.pool.example:3333"
+fn pool_host_port(pool_url: &str) -> Option<(&str, u16)> {
+    let addr = pool_url.trim();
+    let addr = addr.split_once("://").map_or(addr, |(_, rest)| rest);
+    let addr = addr.split('/').next()?;
+    let (host, port) = split_host_port(addr, STRATUM_DEFAULT_PORT);
+    (!host.is_empty()).then_some((host, port))
+}
+
+/// Milliseconds from a firmware latency value: "45ms", "45 ms", "45.6"
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // checked non-negative
+fn parse_latency_ms(value: &str) -> Option<u32> {
+    let number = value.trim().trim_end_matches("ms").trim();
+    let ms: f64 = number.parse().ok()?;
+    (ms.is_finite() && ms >= 0.0).then(|| ms.round() as u32)
+}
+
+/// RPM from a firmware fan speed value: "5520", "5520 RPM"
+fn parse_fan_rpm(value: &str) -> Option<i32> {
+    let number = value
+        .trim()
+        .trim_end_matches(['R', 'P', 'M', 'r', 'p', 'm'])
+        .trim();
+    number.parse().ok().filter(|&rpm| rpm >= 0)
+}
+
+/// Authenticate and send the reboot command to a single miner
+pub async fn send_reboot(ip: &str, user: &str, pass: &str) -> Result<(), String> {
+    let client = build_client()?;
+    login(&client, ip, user, pass).await?;
+
+    let resp = client
+        .post(format!("https://{ip}/cgi-bin/luci/admin/btminer/reboot"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !resp.status().is_success() && !resp.status().is_redirection() {
+        return Err(format!("Reboot failed: {}", resp.status()));
+    }
+    Ok(())
+}
+
+/// Reboot several miners in parallel, given (ip, user, pass) targets
+///
+/// `on_result` gets each miner's (ip, result) as soon as its reboot finishes.
+pub async fn reboot_all(
+    targets: Vec<(String, String, String)>,
+    on_result: impl FnMut(String, Result<(), String>),
+) {
+    let jobs = targets.into_iter().map(|(ip, user, pass)| {
+        (
+            ip.clone(),
+            async move { send_reboot(&ip, &user, &pass).await },
+        )
+    });
+    run_per_miner(jobs, on_result).await;
+}
+
+/// Run one task per miner, reporting each (ip, result) in completion order;
+/// a task that panics or is cancelled reports an error for its ip
+async fn run_per_miner<F>(
+    jobs: impl IntoIterator<Item = (String, F)>,
+    mut on_result: impl FnMut(String, Result<(), String>),
+) where
+    F: Future<Output = Result<(), String>> + Send + 'static,
+{
+    let mut set = JoinSet::new();
+    let mut ips = HashMap::new();
+    for (ip, job) in jobs {
+        let handle = set.spawn(job);
+        ips.insert(handle.id(), ip);
+    }
+
+    while let Some(joined) = set.join_next_with_id().await {
+        let (id, result) = match joined {
+            Ok((id, result)) => (id, result),
+            Err(e) => (e.id(), Err(e.to_string())),
+        };
+        if let Some(ip) = ips.remove(&id) {
+            on_result(ip, result);
+        }
+    }
+}
+
+/// Fetch changelog entries for a firmware version, one entry per line
+pub async fn fetch_firmware_changelog(version: &str) -> Result<String, String> {
+    let url = std::env::var(CHANGELOG_URL_VAR)
+        .map_err(|_| format!("No changelog source configured ({CHANGELOG_URL_VAR})"))?;
+
+    let resp = Client::builder()
+        .timeout(Duration::from_secs(TIMEOUT_SECS))
+        .build()
+        .map_err(|e| e.to_string())?
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !resp.status().is_success() {
+        return Err(format!("Changelog failed: {}", resp.status()));
+    }
+
+    let json = resp.text().await.map_err(|e| e.to_string())?;
+    parse_changelog(&json, version)
+}
+
+/// Refresh the firmware database from WHATSMINER_FIRMWARE_DB_URL
+/// Returns None if no URL is configured
+pub async fn fetch_firmware_db() -> Option<Result<FirmwareDb, String>> {
+    let url = std::env::var(FIRMWARE_DB_URL_VAR).ok()?;
+    let fetch = async {
+        let resp = Client::builder()
             .timeout(Duration::from_secs(TIMEOUT_SECS))
             .build()
-            .map_err(|e| e.to_string())?,
-    );
+            .map_err(|e| e.to_string())?
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        if !resp.status().is_success() {
+            return Err(format!("Firmware database failed: {}", resp.status()));
+        }
+        parse_firmware_db(&resp.text().await.map_err(|e| e.to_string())?)
+    };
+    Some(fetch.await)
+}
 
-    // Authenticate once
+fn parse_firmware_db(json: &str) -> Result<FirmwareDb, String> {
+    serde_json::from_str(json).map_err(|e| e.to_string())
+}
+
+/// Compare the miner's firmware with the newest release in `db`
+///
+/// Versions are ordered by their build date and build number ("20251016.14.Rel2"
+/// → (20251016, 14)); the release suffix is ignored. Firmware newer than every
+/// known release counts as up to date.
+pub fn check_firmware_version(current: &str, db: &FirmwareDb) -> FirmwareStatus {
+    let Some(current_key) = firmware_sort_key(current) else {
+        return FirmwareStatus::Unknown;
+    };
+    let mut newer: Vec<(_, &FirmwareRelease)> = db
+        .releases
+        .iter()
+        .filter_map(|r| Some((firmware_sort_key(&r.version)?, r)))
+        .filter(|(key, _)| *key > current_key)
+        .collect();
+    if newer.is_empty() {
+        let known = db
+            .releases
+            .iter()
+            .any(|r| firmware_sort_key(&r.version).is_some());
+        return if known {
+            FirmwareStatus::UpToDate
+        } else {
+            FirmwareStatus::Unknown
+        };
+    }
+
+    newer.sort_by_key(|(key, _)| std::cmp::Reverse(*key));
+    let changes = newer
+        .iter()
+        .map(|(_, r)| r.changes.trim())
+        .filter(|c| !c.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    FirmwareStatus::UpdateAvailable {
+        latest: newer[0].1.version.clone(),
+        changes,
+    }
+}
+
+/// (build date, build number) of a version like "20251016.14.Rel2"
+fn firmware_sort_key(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let date = parts.next()?.parse().ok()?;
+    let build = parts.next().and_then(|b| b.parse().ok()).unwrap_or(0);
+    Some((date, build))
+}
+
+/// Look up a version in the changelog JSON (exact key, else a key the version starts with)
+fn parse_changelog(json: &str, version: &str) -> Result<String, String> {
+    let entries: std::collections::HashMap<String, Vec<String>> =
+        serde_json::from_str(json).map_err(|e| e.to_string())?;
+
+    entries
+        .get(version)
+        .or_else(|| {
+            entries
+                .iter()
+                .filter(|(key, _)| version.starts_with(key.as_str()))
+                .max_by_key(|(key, _)| key.len())
+                .map(|(_, v)| v)
+        })
+        .map(|lines| lines.join("\n"))
+        .ok_or_else(|| format!("No changelog for {version}"))
+}
+
+fn build_client() -> Result<Client, String> {
+    Client::builder()
+        // SECURITY: Accept self-signed certs - required for miner's HTTPS interface.
+        // This is safe in this context as we're connecting to a known local device.
+        .danger_accept_invalid_certs(true)
+        .cookie_store(true)
+        .timeout(Duration::from_secs(TIMEOUT_SECS))
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Login scheme accepted by the miner's web interface
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMethod {
+    /// Plain luci_username/luci_password form POST (older firmware)
+    FormPost,
+    /// Challenge nonce signed with HMAC-SHA256 (newer firmware)
+    HmacSha256,
+}
+
+/// Log in with auto-detection: try HMAC first, fall back to form POST when the
+/// firmware hands out no nonce. The session cookie is kept in the client's cookie store.
+async fn login(client: &Client, ip: &str, user: &str, pass: &str) -> Result<AuthMethod, String> {
+    login_at(client, &format!("https://{ip}"), user, pass, &|_| {}).await
+}
+
+/// Login against `base`: `authenticate_hmac_at`, then the form POST if it found no nonce
+async fn login_at(
+    client: &Client,
+    base: &str,
+    user: &str,
+    pass: &str,
+    progress: &(dyn Fn(FetchStage) + Sync),
+) -> Result<AuthMethod, String> {
+    if authenticate_hmac_at(client, base, user, pass, progress).await? {
+        return Ok(AuthMethod::HmacSha256);
+    }
+    form_login_at(client, base, user, pass).await?;
+    Ok(AuthMethod::FormPost)
+}
+
+/// Authenticate with an HMAC-SHA256 signed challenge: fetch a nonce from
+/// `/cgi-bin/luci?nonce=1` and post `user:nonce:pass` signed with the password
+#[allow(dead_code)] // explicit entry point; `login` auto-detects via `authenticate_hmac_at`
+pub async fn authenticate_hmac(
+    client: &Client,
+    ip: &str,
+    user: &str,
+    pass: &str,
+) -> Result<(), String> {
+    if authenticate_hmac_at(client, &format!("https://{ip}"), user, pass, &|_| {}).await? {
+        Ok(())
+    } else {
+        Err("HMAC authentication not supported by this firmware".into())
+    }
+}
+
+/// `authenticate_hmac` against `base`; Ok(false) if the firmware hands out no nonce.
+/// Reports Authenticating once the miner answers the nonce probe.
+async fn authenticate_hmac_at(
+    client: &Client,
+    base: &str,
+    user: &str,
+    pass: &str,
+    progress: &(dyn Fn(FetchStage) + Sync),
+) -> Result<bool, String> {
+    let nonce = fetch_nonce(client, base).await?;
+    progress(FetchStage::Authenticating);
+    let Some(nonce) = nonce else {
+        return Ok(false);
+    };
+    submit_hmac_login(client, base, user, &nonce, pass).await?;
+    Ok(true)
+}
+
+/// Longest body accepted as a login nonce
+const MAX_NONCE_LEN: usize = 128;
+
+/// Login challenge nonce; None if the firmware doesn't hand one out
+///
+/// Stock LuCI ignores `?nonce=1` and answers 200 with the login page, and some
+/// firmware answers 401/403, so anything but a short alphanumeric body means
+/// the form POST is the way in. Only an unreachable miner is an error.
+async fn fetch_nonce(client: &Client, base: &str) -> Result<Option<String>, String> {
     let resp = client
-        .post(format!("https://{ip}/cgi-bin/luci"))
+        .get(format!("{base}/cgi-bin/luci?nonce=1"))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !resp.status().is_success() {
+        return Ok(None);
+    }
+    let body = resp.text().await.map_err(|e| e.to_string())?;
+    let nonce = body.trim();
+    let valid = (1..=MAX_NONCE_LEN).contains(&nonce.len())
+        && nonce.bytes().all(|b| b.is_ascii_alphanumeric());
+    Ok(valid.then(|| nonce.to_string()))
+}
+
+/// Check a login POST answer: LuCI sets its `sysauth` session cookie or
+/// redirects to the admin page on success and re-serves the form on failure
+fn check_login_response(resp: &reqwest::Response, url: &str) -> Result<(), String> {
+    let status = resp.status();
+    if !status.is_success() && !status.is_redirection() {
+        return Err(format!("Login failed: {status}"));
+    }
+    let session_cookie = resp
+        .headers()
+        .get_all(reqwest::header::SET_COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .any(|cookie| cookie.trim_start().starts_with("sysauth"));
+    // reqwest follows redirects, so a redirect shows up as a different final URL
+    let redirected = status.is_redirection() || resp.url().as_str() != url;
+    if session_cookie || redirected {
+        Ok(())
+    } else {
+        Err("Login failed: no session returned (wrong user or password?)".into())
+    }
+}
+
+async fn submit_hmac_login(
+    client: &Client,
+    base: &str,
+    user: &str,
+    nonce: &str,
+    pass: &str,
+) -> Result<(), String> {
+    let signature = sign_challenge(user, nonce, pass);
+    let url = format!("{base}/cgi-bin/luci");
+    let resp = client
+        .post(&url)
+        .form(&[
+            ("luci_username", user),
+            ("nonce", nonce),
+            ("signature", signature.as_str()),
+        ])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    check_login_response(&resp, &url)
+}
+
+async fn form_login_at(client: &Client, base: &str, user: &str, pass: &str) -> Result<(), String> {
+    let url = format!("{base}/cgi-bin/luci");
+    let resp = client
+        .post(&url)
         .form(&[("luci_username", user), ("luci_password", pass)])
         .send()
         .await
         .map_err(|e| e.to_string())?;
+    check_login_response(&resp, &url)
+}
 
-    if !resp.status().is_success() && !resp.status().is_redirection() {
-        return Err(format!("Login failed: {}", resp.status()));
+/// Lowercase hex HMAC-SHA256 of "user:nonce:pass", keyed with the password
+fn sign_challenge(user: &str, nonce: &str, pass: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(pass.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(format!("{user}:{nonce}:{pass}").as_bytes());
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Local end of an SSH port forward to a miner's HTTPS port; closed on drop
+///
+/// reqwest has no hook for a custom transport stream, so the forward listens on
+/// 127.0.0.1 and each accepted connection gets its own direct-tcpip channel.
+struct SshTunnel {
+    local_addr: SocketAddr,
+    listener: tokio::task::JoinHandle<()>,
+}
+
+impl Drop for SshTunnel {
+    fn drop(&mut self) {
+        self.listener.abort();
     }
+}
 
-    // Fetch both pages in parallel
-    let ip = ip.to_string();
-    let (miner_result, overview_result) = tokio::join!(
-        fetch_miner_api(client.clone(), &ip),
-        fetch_overview(client, &ip),
-    );
+/// Accepts the jump host only if its key matches ~/.ssh/known_hosts
+struct KnownHostsCheck {
+    host: String,
+    port: u16,
+}
+
+impl russh::client::Handler for KnownHostsCheck {
+    type Error = russh::Error;
 
-    Ok((miner_result?, overview_result?))
+    async fn check_server_key(
+        &mut self,
+        server_public_key: &russh::keys::PublicKey,
+    ) -> Result<bool, Self::Error> {
+        Ok(russh::keys::check_known_hosts(
+            &self.host,
+            self.port,
+            server_public_key,
+        )?)
+    }
 }
 
-async fn fetch_miner_api(client: Arc<Client>, ip: &str) -> Result<MinerData, String> {
+async fn open_ssh_tunnel(
+    jump_host: &str,
+    jump_user: &str,
+    key_path: &Path,
+    miner_ip: &str,
+) -> Result<SshTunnel, String> {
+    let (host, port) = split_host_port(jump_host, 22);
+    let (miner_host, miner_port) = split_host_port(miner_ip, 443);
+    let key = russh::keys::load_secret_key(key_path, None)
+        .map_err(|e| format!("{}: {e}", key_path.display()))?;
+
+    let ssh_err = |e: russh::Error| match e {
+        russh::Error::UnknownKey => format!("SSH {jump_host}: host key not in ~/.ssh/known_hosts"),
+        e => format!("SSH {jump_host}: {e}"),
+    };
+    let config = Arc::new(russh::client::Config::default());
+    let check = KnownHostsCheck {
+        host: host.to_string(),
+        port,
+    };
+    let mut session = russh::client::connect(config, (host, port), check)
+        .await
+        .map_err(ssh_err)?;
+    let hash_alg = session
+        .best_supported_rsa_hash()
+        .await
+        .map_err(ssh_err)?
+        .flatten();
+    let auth = session
+        .authenticate_publickey(
+            jump_user,
+            russh::keys::PrivateKeyWithHashAlg::new(Arc::new(key), hash_alg),
+        )
+        .await
+        .map_err(ssh_err)?;
+    if !auth.success() {
+        return Err(format!("SSH {jump_host}: key rejected for {jump_user}"));
+    }
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .map_err(|e| e.to_string())?;
+    let local_addr = listener.local_addr().map_err(|e| e.to_string())?;
+    let session = Arc::new(session);
+    let miner_host = miner_host.to_string();
+    let listener = tokio::spawn(async move {
+        while let Ok((mut local, origin)) = listener.accept().await {
+            let session = session.clone();
+            let miner_host = miner_host.clone();
+            tokio::spawn(async move {
+                let Ok(channel) = session
+                    .channel_open_direct_tcpip(
+                        miner_host,
+                        u32::from(miner_port),
+                        origin.ip().to_string(),
+                        u32::from(origin.port()),
+                    )
+                    .await
+                else {
+                    return;
+                };
+                let mut remote = channel.into_stream();
+                let _ = tokio::io::copy_bidirectional(&mut local, &mut remote).await;
+            });
+        }
+    });
+    Ok(SshTunnel {
+        local_addr,
+        listener,
+    })
+}
+
+/// Split "host:port", falling back to `default_port` when there's no valid port
+fn split_host_port(addr: &str, default_port: u16) -> (&str, u16) {
+    addr.rsplit_once(':')
+        .and_then(|(host, port)| Some((host, port.parse().ok()?)))
+        .unwrap_or((addr, default_port))
+}
+
+/// Raw HTML of the btminerapi page
+async fn fetch_miner_api(client: Arc<Client>, ip: &str) -> Result<String, String> {
     let resp = client
         .get(format!("https://{ip}/cgi-bin/luci/admin/status/btminerapi"))
         .send()
@@ -57,11 +861,11 @@ async fn fetch_miner_api(client: Arc<Client>, ip: &str) -> Result<MinerData, Str
         return Err(format!("API failed: {}", resp.status()));
     }
 
-    let html = resp.text().await.map_err(|e| e.to_string())?;
-    parse_html(&html)
+    resp.text().await.map_err(|e| e.to_string())
 }
 
-async fn fetch_overview(client: Arc<Client>, ip: &str) -> Result<SystemInfo, String> {
+/// Raw HTML of the status overview page
+async fn fetch_overview(client: Arc<Client>, ip: &str) -> Result<String, String> {
     let resp = client
         .get(format!("https://{ip}/cgi-bin/luci/admin/status/overview"))
         .send()
@@ -72,15 +876,44 @@ async fn fetch_overview(client: Arc<Client>, ip: &str) -> Result<SystemInfo, Str
         return Err(format!("Overview failed: {}", resp.status()));
     }
 
-    let html = resp.text().await.map_err(|e| e.to_string())?;
-    Ok(parse_overview_html(&html))
+    resp.text().await.map_err(|e| e.to_string())
 }
 
 fn parse_overview_html(html: &str) -> SystemInfo {
+    let hardware_info = extract_table_value(html, "Hardware Info").unwrap_or_default();
     SystemInfo {
         model: extract_table_value(html, "Model").unwrap_or_default(),
-        hardware_info: extract_table_value(html, "Hardware Info").unwrap_or_default(),
+        hardware: parse_hardware_info(&hardware_info),
+        hardware_info,
         firmware_version: extract_table_value(html, "Firmware Version").unwrap_or_default(),
+        pool_url: POOL_URL_LABELS
+            .iter()
+            .find_map(|label| extract_table_value(html, label))
+            .unwrap_or_default(),
+        pool_latency_ms: POOL_LATENCY_LABELS
+            .iter()
+            .find_map(|label| extract_table_value(html, label))
+            .and_then(|value| parse_latency_ms(&value)),
+        fan_speed_rpm: FAN_SPEED_LABELS
+            .iter()
+            .filter_map(|label| extract_table_value(html, label))
+            .filter_map(|value| parse_fan_rpm(&value))
+            .max(),
+    }
+}
+
+/// Split a hardware info string into model variant, control board and serial
+///
+/// Format: "{model}_{variant}.{control board}.{psu}-{serial}", e.g.
+/// "M50S++_VK40.H616-CB6V10.P222B-VE1-197806A". Older firmware omits the
+/// serial segment; missing segments are left empty.
+pub fn parse_hardware_info(s: &str) -> HardwareInfo {
+    let mut segments = s.trim().splitn(3, '.').map(str::trim);
+    let mut next = || segments.next().unwrap_or_default().to_string();
+    HardwareInfo {
+        model_variant: next(),
+        pcb_revision: next(),
+        serial_prefix: next(),
     }
 }
 
@@ -92,18 +925,190 @@ fn extract_table_value(html: &str, label: &str) -> Option<String> {
     Some(html[start..end].to_string())
 }
 
+/// Where a firmware generation puts the miner log on the log page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtmlApiFormat {
+    /// `<textarea id="syslog">`
+    SyslogTextarea,
+    /// `<textarea id="logbody">`, older firmware
+    LogbodyTextarea,
+    /// `<div class="miner-log">`, newer firmware
+    MinerLogDiv,
+    /// `{"log": "..."}` in a `<script type="application/json">` block
+    JsonEmbedded,
+}
+
+impl HtmlApiFormat {
+    /// Order `parse_html` falls back through
+    pub const ALL: [Self; 4] = [
+        Self::SyslogTextarea,
+        Self::LogbodyTextarea,
+        Self::MinerLogDiv,
+        Self::JsonEmbedded,
+    ];
+
+    /// Attribute that identifies the log element
+    fn marker(self) -> &'static str {
+        match self {
+            Self::SyslogTextarea => r#"id="syslog""#,
+            Self::LogbodyTextarea => r#"id="logbody""#,
+            Self::MinerLogDiv => r#"class="miner-log""#,
+            Self::JsonEmbedded => r#"type="application/json""#,
+        }
+    }
+}
+
+/// Guess the page format from its log element; pages without a known marker
+/// are treated as the current `id="syslog"` layout
+pub fn detect_api_format(html: &str) -> HtmlApiFormat {
+    HtmlApiFormat::ALL
+        .into_iter()
+        .find(|format| html.contains(format.marker()))
+        .unwrap_or(HtmlApiFormat::SyslogTextarea)
+}
+
+/// Miner log text of a page in `format`; JSON logs are unescaped, so they come back owned
+pub fn extract_log_content(html: &str, format: HtmlApiFormat) -> Option<Cow<'_, str>> {
+    match format {
+        HtmlApiFormat::SyslogTextarea | HtmlApiFormat::LogbodyTextarea => {
+            element_content(html, format.marker(), "</textarea>").map(Cow::Borrowed)
+        }
+        HtmlApiFormat::MinerLogDiv => {
+            element_content(html, format.marker(), "</div>").map(Cow::Borrowed)
+        }
+        HtmlApiFormat::JsonEmbedded => {
+            let json = element_content(html, format.marker(), "</script>")?;
+            let value: serde_json::Value = serde_json::from_str(json).ok()?;
+            Some(Cow::Owned(value.get("log")?.as_str()?.to_string()))
+        }
+    }
+}
+
+/// Text between the end of the tag carrying `marker` and `close_tag`
+fn element_content<'a>(html: &'a str, marker: &str, close_tag: &str) -> Option<&'a str> {
+    let tag = html.find(marker)?;
+    let start = tag + html[tag..].find('>')? + 1;
+    let end = start + html[start..].find(close_tag)?;
+    Some(&html[start..end])
+}
+
+/// Parse the log page, starting with the detected format and falling back
+/// through the others in order
 fn parse_html(html: &str) -> Result<MinerData, String> {
-    let start = html.find(r#"id="syslog">"#).ok_or("Missing textarea")? + 12;
-    let end = start
-        + html[start..]
-            .find("</textarea>")
-            .ok_or("Unclosed textarea")?;
-    parse_text(&html[start..end])
+    let detected = detect_api_format(html);
+    let mut error = String::from("Missing miner log");
+    for format in
+        std::iter::once(detected).chain(HtmlApiFormat::ALL.into_iter().filter(|&f| f != detected))
+    {
+        let Some(log) = extract_log_content(html, format) else {
+            continue;
+        };
+        match parse_text(&log) {
+            Ok(data) => return Ok(data),
+            Err(e) => error = e,
+        }
+    }
+    Err(error)
+}
+
+/// Bind UDP `port` on all interfaces and parse the miner log lines pushed to it
+///
+/// Lines are collected per sender; a dump is complete when a slot header repeats
+/// or the sender goes quiet, and each one is sent on the returned channel. The
+/// socket is closed once the receiver is dropped.
+pub async fn start_syslog_listener(port: u16) -> Result<mpsc::Receiver<MinerData>, String> {
+    let socket = UdpSocket::bind(("0.0.0.0", port))
+        .await
+        .map_err(|e| format!("UDP {port}: {e}"))?;
+    let (tx, rx) = mpsc::channel(4);
+    tokio::spawn(async move {
+        let mut buf = vec![0; SYSLOG_MAX_PACKET];
+        let mut dumps: HashMap<IpAddr, SyslogAssembler> = HashMap::new();
+        let quiet = Duration::from_millis(SYSLOG_QUIET_MS);
+        loop {
+            let complete = match tokio::time::timeout(quiet, socket.recv_from(&mut buf)).await {
+                Ok(Ok((len, from))) => dumps
+                    .entry(from.ip())
+                    .or_default()
+                    .push_packet(&String::from_utf8_lossy(&buf[..len])),
+                Ok(Err(_)) => None,
+                Err(_) => dumps.values_mut().find_map(SyslogAssembler::flush),
+            };
+            if let Some(data) = complete
+                && tx.send(data).await.is_err()
+            {
+                break;
+            }
+            if tx.is_closed() {
+                break;
+            }
+        }
+    });
+    Ok(rx)
+}
+
+/// Collects one sender's syslog lines into complete log dumps
+#[derive(Debug, Default)]
+struct SyslogAssembler {
+    lines: Vec<String>,
+    slots_seen: Vec<i32>,
+}
+
+impl SyslogAssembler {
+    /// Add a packet's lines; returns the previous dump when a slot header of it repeats
+    fn push_packet(&mut self, packet: &str) -> Option<MinerData> {
+        let mut complete = None;
+        for line in packet.lines().map(syslog_message) {
+            if line.starts_with("slot:") {
+                let slot_id = parse_slot_header(line).id;
+                if self.slots_seen.contains(&slot_id) {
+                    complete = self.flush().or(complete);
+                }
+                self.slots_seen.push(slot_id);
+            }
+            self.lines.push(line.to_string());
+        }
+        complete
+    }
+
+    /// Parse and clear the lines collected so far
+    fn flush(&mut self) -> Option<MinerData> {
+        self.slots_seen.clear();
+        let text = std::mem::take(&mut self.lines).join("\n");
+        parse_text(&text).ok()
+    }
+}
+
+/// Miner log text of a syslog line: drops the `<PRI>`, timestamp, host and tag
+fn syslog_message(line: &str) -> &str {
+    let line = line.trim();
+    let body = line
+        .strip_prefix('<')
+        .and_then(|rest| rest.split_once('>'))
+        .map_or(line, |(_, rest)| rest);
+    let start = ["slot:", "nonce valid:"]
+        .iter()
+        .filter_map(|marker| body.find(marker))
+        .min()
+        .or_else(|| chip_line_start(body));
+    start.map_or(body, |i| &body[i..])
+}
+
+/// Start of a `C12 freq:...` chip line within a syslog line
+fn chip_line_start(line: &str) -> Option<usize> {
+    line.match_indices(" C").map(|(i, _)| i + 1).find(|&i| {
+        let rest = &line[i + 1..];
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        digits > 0 && rest[digits..].starts_with(char::is_whitespace) && rest.contains("freq:")
+    })
 }
 
 fn parse_text(text: &str) -> Result<MinerData, String> {
     let mut slots = Vec::new();
     let mut current: Option<Slot> = None;
+    // Optional chip fields found on any chip line
+    let mut chip_lines = 0;
+    let mut seen_fields = Vec::new();
 
     for line in text.lines().map(str::trim) {
         if line.starts_with("slot:") {
@@ -118,9 +1123,11 @@ fn parse_text(text: &str) -> Result<MinerData, String> {
         } else if line.starts_with('C')
             && line.contains("freq:")
             && let Some(slot) = &mut current
-            && let Some(chip) = parse_chip_line(line)
+            && let Some((chip, fields)) = parse_chip_line(line)
         {
             slot.chips.push(chip);
+            chip_lines += 1;
+            seen_fields.extend(fields);
         }
     }
 
@@ -132,7 +1139,122 @@ fn parse_text(text: &str) -> Result<MinerData, String> {
         return Err("No slots found".into());
     }
 
-    Ok(MinerData { slots })
+    for slot in &mut slots {
+        remap_chip_ids(&mut slot.chips);
+    }
+
+    let missing_fields: Vec<ChipField> = ChipField::ALL
+        .into_iter()
+        .filter(|f| !seen_fields.contains(f))
+        .collect();
+    let data_quality = if chip_lines == 0 || missing_fields.is_empty() {
+        ChipDataQuality::Full
+    } else {
+        ChipDataQuality::Partial { missing_fields }
+    };
+    Ok(MinerData {
+        slots,
+        data_quality,
+    })
+}
+
+/// Put chips in id order and fill skipped ids with placeholders, so a chip's
+/// index (and grid cell) matches its id on firmware that leaves gaps (C2, C4)
+fn remap_chip_ids(chips: &mut Vec<Chip>) {
+    chips.sort_by_key(|c| c.id);
+    let (Some(first), Some(last)) = (chips.first(), chips.last()) else {
+        return;
+    };
+    let ids = first.id..=last.id;
+    let span = ids.clone().count();
+    // A garbled id far past the rest would bury the board in placeholders
+    if span == chips.len() || span > chips.len() * 2 {
+        return;
+    }
+    let mut parsed = std::mem::take(chips).into_iter().peekable();
+    for id in ids {
+        match parsed.next_if(|c| c.id == id) {
+            Some(chip) => {
+                chips.push(chip);
+                // A repeated id keeps every copy rather than dropping readings
+                while let Some(dup) = parsed.next_if(|c| c.id == id) {
+                    chips.push(dup);
+                }
+            }
+            None => chips.push(Chip::placeholder(id)),
+        }
+    }
+}
+
+/// A parsed value that is more likely a field the parser missed than a real reading
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationWarning {
+    /// Field as named in the log, e.g. "freq"
+    pub field: &'static str,
+    pub slot_id: i32,
+    /// Plausible values, e.g. "> 0" or "14–135"
+    pub expected_range: String,
+    pub actual_value: i64,
+}
+
+/// Sanity check a parsed fetch; unparseable fields default to 0, so zeros where the
+/// miner can't report zero point at a log format the parser doesn't know.
+/// The chip count check needs the model's config and is skipped without one.
+pub fn validate_miner_data(
+    data: &MinerData,
+    config: Option<&MinerConfig>,
+) -> Vec<ValidationWarning> {
+    let mut warnings = Vec::new();
+    for slot in &data.slots {
+        if slot.freq == 0 {
+            warnings.push(ValidationWarning {
+                field: "freq",
+                slot_id: slot.id,
+                expected_range: "> 0".into(),
+                actual_value: 0,
+            });
+        }
+
+        let unparsed = slot
+            .chips
+            .iter()
+            .filter(|c| c.freq == 0 && c.temp == 0 && !c.placeholder)
+            .count();
+        if unparsed > 0 {
+            warnings.push(ValidationWarning {
+                field: "chip freq+temp = 0",
+                slot_id: slot.id,
+                expected_range: "0".into(),
+                actual_value: unparsed as i64,
+            });
+        }
+
+        if let Some(cfg) = config {
+            let expected = cfg.chips_per_board();
+            let min = expected.div_ceil(10);
+            if slot.chips.len() < usize::from(min) {
+                warnings.push(ValidationWarning {
+                    field: "chips",
+                    slot_id: slot.id,
+                    expected_range: format!("{min}–{expected}"),
+                    actual_value: slot.chips.len() as i64,
+                });
+            }
+        }
+
+        // The slot's valid nonces are the sum of its chips' nonces
+        let chip_nonces: i64 = slot.chips.iter().map(|c| c.nonce).sum();
+        let min = chip_nonces / 10;
+        if slot.nonce_valid < min {
+            warnings.push(ValidationWarning {
+                field: "nonce valid",
+                slot_id: slot.id,
+                expected_range: format!("≥ {min}"),
+                actual_value: slot.nonce_valid,
+            });
+        }
+    }
+    warnings
 }
 
 fn parse_slot_header(line: &str) -> Slot {
@@ -145,6 +1267,7 @@ fn parse_slot_header(line: &str) -> Slot {
                 "freq" => slot.freq = val.parse().unwrap_or_default(),
                 "temp" => slot.temp = val.parse().unwrap_or_default(),
                 "step" => slot.step = val.parse().unwrap_or_default(),
+                "vol" | "input_vol" => slot.voltage_input = parse_input_voltage(val),
                 _ => {}
             }
         }
@@ -152,6 +1275,17 @@ fn parse_slot_header(line: &str) -> Slot {
     slot
 }
 
+/// Board input voltage in volts; some firmware logs millivolts ("vol:12050")
+fn parse_input_voltage(val: &str) -> Option<f32> {
+    let value: f32 = val.trim_end_matches(['V', 'v']).trim().parse().ok()?;
+    let volts = if value > MAX_INPUT_VOLTAGE {
+        value / 1000.0
+    } else {
+        value
+    };
+    (volts.is_finite() && volts > 0.0).then_some(volts)
+}
+
 fn parse_nonce_line(line: &str, slot: &mut Slot) {
     // Parse "nonce valid: 981367(3182/s), ..."
     if let Some(rest) = line.strip_prefix("nonce valid:")
@@ -174,7 +1308,8 @@ fn parse_nonce_line(line: &str, slot: &mut Slot) {
     }
 }
 
-fn parse_chip_line(line: &str) -> Option<Chip> {
+/// Parse a chip line, with the optional fields it had (see `ChipField`)
+fn parse_chip_line(line: &str) -> Option<(Chip, Vec<ChipField>)> {
     let id_end = line.find(char::is_whitespace)?;
     let id: i32 = line[1..id_end].parse().ok()?;
 
@@ -194,8 +1329,12 @@ fn parse_chip_line(line: &str) -> Option<Chip> {
         }
     }
 
+    let mut fields = Vec::new();
     for part in line.split_whitespace() {
         if let Some((key, val)) = part.split_once(':') {
+            if let Some(field) = ChipField::ALL.into_iter().find(|f| f.key() == key) {
+                fields.push(field);
+            }
             match key {
                 "freq" => chip.freq = val.parse().unwrap_or_default(),
                 "vol" => chip.vol = val.parse().unwrap_or_default(),
@@ -210,5 +1349,515 @@ fn parse_chip_line(line: &str) -> Option<Chip> {
         }
     }
 
-    Some(chip)
+    Some((chip, fields))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_string_contains, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_split_host_port() {
+        assert_eq!(
+            split_host_port("jump.example.com", 22),
+            ("jump.example.com", 22)
+        );
+        assert_eq!(split_host_port("10.0.0.5:2222", 22), ("10.0.0.5", 2222));
+        assert_eq!(split_host_port("192.168.1.10", 443), ("192.168.1.10", 443));
+    }
+
+    #[test]
+    fn test_parse_overview_pool() {
+        let html = "<tr><td width=\"33%\">Model</td><td>WhatsMiner M50S</td></tr>\
+                    <tr><td width=\"33%\">Pool 1</td><td>stratum+tcp://btc.pool.example:3333</td></tr>\
+                    <tr><td width=\"33%\">Pool Latency</td><td>45 ms</td></tr>";
+        let info = parse_overview_html(html);
+        assert_eq!(info.pool_url, "stratum+tcp://btc.pool.example:3333");
+        assert_eq!(info.pool_latency_ms, Some(45));
+        assert_eq!(info.fan_speed_rpm, None);
+
+        let info = parse_overview_html("<td>Pool Response Time</td><td>212.6ms</td>");
+        assert_eq!(info.pool_latency_ms, Some(213));
+        assert_eq!(info.pool_url, "");
+        assert_eq!(parse_latency_ms("n/a"), None);
+        assert_eq!(parse_latency_ms("-1"), None);
+
+        let info = parse_overview_html(
+            "<td>Fan Speed In</td><td>5400</td><td>Fan Speed Out</td><td>5520 RPM</td>",
+        );
+        assert_eq!(info.fan_speed_rpm, Some(5520));
+
+        assert_eq!(
+            pool_host_port("stratum+tcp://btc.pool.example:3333"),
+            Some(("btc.pool.example", 3333))
+        );
+        assert_eq!(
+            pool_host_port("stratum2+tcp://pool.example:443/abc"),
+            Some(("pool.example", 443))
+        );
+        assert_eq!(pool_host_port("pool.example"), Some(("pool.example", 3333)));
+        assert_eq!(pool_host_port(""), None);
+    }
+
+    #[test]
+    fn test_parse_slot_input_voltage() {
+        let slot = parse_slot_header("slot:1, freq:600, temp:70, step:1, vol:12.05");
+        assert_eq!((slot.id, slot.voltage_input), (1, Some(12.05)));
+        // Millivolts under the other key name
+        let slot = parse_slot_header("slot:2, freq:600, input_vol:11800");
+        assert_eq!(slot.voltage_input, Some(11.8));
+        assert_eq!(parse_slot_header("slot:0, freq:600").voltage_input, None);
+        assert_eq!(parse_slot_header("slot:0, vol:n/a").voltage_input, None);
+    }
+
+    #[test]
+    fn test_parse_text_fills_skipped_chip_ids() {
+        let text = "slot:0, freq:600, temp:70, step:1\n\
+            C4 freq:600 vol:300 temp:74 nonce:500\n\
+            C0 freq:600 vol:300 temp:70 nonce:500\n\
+            C1 freq:600 vol:300 temp:71 nonce:500\n\
+            C2 freq:600 vol:300 temp:72 nonce:500\n";
+        let chips = &parse_text(text).unwrap().slots[0].chips;
+        let ids: Vec<i32> = chips.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+        assert!(chips[3].placeholder && !chips[3].is_dead());
+        assert_eq!((chips[3].freq, chips[3].temp, chips[3].nonce), (0, 0, 0));
+        assert!(chips.iter().filter(|c| c.id != 3).all(|c| !c.placeholder));
+    }
+
+    #[test]
+    fn test_parse_text_detects_partial_chip_fields() {
+        let minimal = "slot:0, freq:600, temp:70, step:1\n\
+            C0 freq:600 temp:70 nonce:500\n\
+            C1 freq:600 temp:71 nonce:0 err:0\n";
+        let data = parse_text(minimal).unwrap();
+        assert_eq!(
+            data.data_quality,
+            ChipDataQuality::Partial {
+                missing_fields: vec![
+                    ChipField::Vol,
+                    ChipField::Crc,
+                    ChipField::X,
+                    ChipField::Repeat
+                ]
+            }
+        );
+        assert!(data.data_quality.supports(crate::models::ColorMode::Errors));
+        assert!(!data.data_quality.supports(crate::models::ColorMode::Crc));
+        assert!(
+            !data
+                .data_quality
+                .supports(crate::models::ColorMode::VoltageGroup)
+        );
+        assert!(
+            data.data_quality
+                .supports(crate::models::ColorMode::Temperature)
+        );
+
+        let full = "slot:0, freq:600, temp:70, step:1\n\
+            C0 freq:600 vol:300 temp:70 nonce:500 err:0 crc:0 x:0 repeat:0 pct:99.0%/98.0%\n";
+        assert_eq!(
+            parse_text(full).unwrap().data_quality,
+            ChipDataQuality::Full
+        );
+    }
+
+    #[test]
+    fn test_parse_hardware_info_m50() {
+        let hw = parse_hardware_info("M50S++_VK40.H616-CB6V10.P222B-VE1-197806A");
+        assert_eq!(hw.model_variant, "M50S++_VK40");
+        assert_eq!(hw.pcb_revision, "H616-CB6V10");
+        assert_eq!(hw.serial_prefix, "P222B-VE1-197806A");
+    }
+
+    #[test]
+    fn test_parse_hardware_info_other_families() {
+        let hw = parse_hardware_info("M30S+_VE40.H6OS-CB2V06.P221B-VD1-2133A");
+        assert_eq!(hw.model_variant, "M30S+_VE40");
+        assert_eq!(hw.pcb_revision, "H6OS-CB2V06");
+        assert_eq!(hw.serial_prefix, "P221B-VD1-2133A");
+
+        // Hydro models carry a longer variant code
+        let hw = parse_hardware_info("M63S_VL30.H616-CB6V10.P521A-VD2-249019B");
+        assert_eq!(hw.model_variant, "M63S_VL30");
+        assert_eq!(hw.serial_prefix, "P521A-VD2-249019B");
+
+        // Older firmware: no serial segment
+        let hw = parse_hardware_info("M20S_V10.H3-CB1V3");
+        assert_eq!(hw.model_variant, "M20S_V10");
+        assert_eq!(hw.pcb_revision, "H3-CB1V3");
+        assert_eq!(hw.serial_prefix, "");
+
+        assert_eq!(parse_hardware_info(""), HardwareInfo::default());
+    }
+
+    #[test]
+    fn test_sign_challenge() {
+        let sig = sign_challenge("admin", "abc123", "admin");
+        assert_eq!(sig.len(), 64);
+        assert!(sig.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(sig, sign_challenge("admin", "abc123", "admin"));
+        assert_ne!(sig, sign_challenge("admin", "abc124", "admin"));
+    }
+
+    #[test]
+    fn test_parse_changelog() {
+        let json = r#"{"20230612": ["Fix fan PWM", "Faster boot"], "2024": ["New UI"]}"#;
+        assert_eq!(
+            parse_changelog(json, "20230612").as_deref(),
+            Ok("Fix fan PWM\nFaster boot")
+        );
+        // Build suffixes fall back to the longest matching prefix
+        assert_eq!(
+            parse_changelog(json, "20240101.22.Rel").as_deref(),
+            Ok("New UI")
+        );
+        assert!(parse_changelog(json, "2019").is_err());
+        assert!(parse_changelog("not json", "2019").is_err());
+    }
+
+    // Log pages as served by different firmware generations
+    const LOG_FIXTURE: &str = "slot:0, freq:600, temp:70, step:1\n\
+        nonce valid: 1000(50/s), err:2, crc:1\n\
+        C0 freq:600 vol:300 temp:70 nonce:500 err:1 crc:0 x:0 repeat:0 pct: 99.0%/ 98.0%\n\
+        C1 freq:600 vol:300 temp:72 nonce:500 err:1 crc:1 x:0 repeat:0 pct: 98.0%/ 97.0%\n";
+
+    #[test]
+    fn test_validate_miner_data() {
+        let config = crate::config::lookup("M50S").unwrap();
+        let data = parse_text(LOG_FIXTURE).unwrap();
+        assert_eq!(validate_miner_data(&data, None), vec![]);
+
+        // Two chips on a board that should have over a hundred
+        let warnings = validate_miner_data(&data, Some(config));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "chips");
+        assert_eq!(warnings[0].actual_value, 2);
+
+        // A slot header and nonce line in a format the parser missed
+        let text = LOG_FIXTURE
+            .replace("freq:600, temp", "freq=600, temp")
+            .replace("nonce valid: 1000(", "nonce valid: n/a(")
+            .replace(
+                "C1 freq:600 vol:300 temp:72",
+                "C1 freq:600MHz vol:300 temp:72C",
+            );
+        let data = parse_text(&text).unwrap();
+        let warnings = validate_miner_data(&data, None);
+        let fields: Vec<_> = warnings.iter().map(|w| w.field).collect();
+        assert_eq!(fields, ["freq", "chip freq+temp = 0", "nonce valid"]);
+        assert_eq!(warnings[1].actual_value, 1);
+        assert_eq!(warnings[2].expected_range, "≥ 100");
+    }
+
+    fn html_fixture(format: HtmlApiFormat) -> String {
+        match format {
+            HtmlApiFormat::SyslogTextarea => format!(
+                r#"<html><textarea class="log" id="syslog" readonly>{LOG_FIXTURE}</textarea></html>"#
+            ),
+            HtmlApiFormat::LogbodyTextarea => {
+                format!(r#"<body><textarea id="logbody">{LOG_FIXTURE}</textarea></body>"#)
+            }
+            HtmlApiFormat::MinerLogDiv => {
+                format!(r#"<div class="page"><div class="miner-log">{LOG_FIXTURE}</div></div>"#)
+            }
+            HtmlApiFormat::JsonEmbedded => format!(
+                r#"<script type="application/json">{}</script>"#,
+                serde_json::json!({ "log": LOG_FIXTURE })
+            ),
+        }
+    }
+
+    #[test]
+    fn test_html_api_formats() {
+        for format in HtmlApiFormat::ALL {
+            let html = html_fixture(format);
+            assert_eq!(detect_api_format(&html), format);
+            assert_eq!(
+                extract_log_content(&html, format).as_deref(),
+                Some(LOG_FIXTURE)
+            );
+            let data = parse_html(&html).unwrap();
+            assert_eq!(data.slots.len(), 1);
+            assert_eq!(data.slots[0].chips.len(), 2);
+            assert_eq!(data.slots[0].chips[1].temp, 72);
+        }
+        // Other formats' elements don't match
+        let html = html_fixture(HtmlApiFormat::MinerLogDiv);
+        assert!(extract_log_content(&html, HtmlApiFormat::SyslogTextarea).is_none());
+        assert_eq!(
+            detect_api_format("<html></html>"),
+            HtmlApiFormat::SyslogTextarea
+        );
+        assert!(parse_html("<html></html>").is_err());
+        // A marker with no parsable log falls through to the next format
+        let html = format!(
+            r#"<textarea id="syslog">loading</textarea>{}"#,
+            html_fixture(HtmlApiFormat::MinerLogDiv)
+        );
+        assert_eq!(parse_html(&html).unwrap().slots[0].chips.len(), 2);
+    }
+
+    #[test]
+    fn test_syslog_assembler() {
+        assert_eq!(
+            syslog_message("<134>Oct 15 10:00:01 miner btminer[812]: slot:0, freq:600, temp:70"),
+            "slot:0, freq:600, temp:70"
+        );
+        assert_eq!(
+            syslog_message("<134>Oct 15 10:00:01 miner btminer[812]: C12 freq:600 vol:300"),
+            "C12 freq:600 vol:300"
+        );
+        assert_eq!(syslog_message("C1 freq:600"), "C1 freq:600");
+
+        let packets: Vec<String> = LOG_FIXTURE
+            .lines()
+            .map(|line| format!("<134>Oct 15 10:00:01 miner btminer[812]: {line}"))
+            .collect();
+        let mut assembler = SyslogAssembler::default();
+        for packet in &packets {
+            assert!(assembler.push_packet(packet).is_none());
+        }
+        // The next dump's header completes the previous one
+        let data = assembler.push_packet(&packets[0]).unwrap();
+        assert_eq!(data.slots[0].chips.len(), 2);
+        assert_eq!(data.slots[0].nonce_rate, 50);
+        // A quiet sender's partial dump is parsed on flush
+        assert_eq!(assembler.flush().unwrap().slots[0].chips.len(), 0);
+        assert!(assembler.flush().is_none());
+    }
+
+    #[test]
+    fn test_check_firmware_version() {
+        let db = parse_firmware_db(
+            r#"{"releases": [
+                {"version": "20251016.14.Rel2", "released": "2025-10-16", "changes": "Fan fix"},
+                {"version": "20251121.18.Rel2", "released": "2025-11-21", "changes": "New UI"},
+                {"version": "20250901.9.Rel", "released": "2025-09-01", "changes": "Old"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            check_firmware_version("20250901.9.Rel", &db),
+            FirmwareStatus::UpdateAvailable {
+                latest: "20251121.18.Rel2".into(),
+                changes: "New UI\nFan fix".into(),
+            }
+        );
+        assert_eq!(
+            check_firmware_version("20251121.18.Rel2", &db),
+            FirmwareStatus::UpToDate
+        );
+        // Newer than anything known
+        assert_eq!(
+            check_firmware_version("20260101.1.Rel", &db),
+            FirmwareStatus::UpToDate
+        );
+        assert_eq!(check_firmware_version("", &db), FirmwareStatus::Unknown);
+        assert_eq!(
+            check_firmware_version("20251121.18.Rel2", &FirmwareDb::default()),
+            FirmwareStatus::Unknown
+        );
+        assert!(!FirmwareDb::bundled().releases.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_per_miner_reports_panicked_task() {
+        let jobs: Vec<(String, std::pin::Pin<Box<dyn Future<Output = _> + Send>>)> = vec![
+            ("10.0.0.1".into(), Box::pin(async { Ok(()) })),
+            (
+                "10.0.0.2".into(),
+                Box::pin(async { panic!("reboot task panicked") }),
+            ),
+        ];
+        let mut results = Vec::new();
+        run_per_miner(jobs, |ip, result| results.push((ip, result))).await;
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0], ("10.0.0.1".into(), Ok(())));
+        assert_eq!(results[1].0, "10.0.0.2");
+        assert!(results[1].1.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_awesome_miner() {
+        let server = MockServer::start().await;
+        let body = r#"{
+            "id": 7,
+            "name": "Rack A-3",
+            "model": "M50S++",
+            "firmwareVersion": "20250214.16.Rel",
+            "poolList": [
+                {"url": "stratum+tcp://backup.example:3333", "isActive": false},
+                {"url": "stratum+tcp://main.example:3333", "isActive": true}
+            ],
+            "gpuList": [
+                {"id": 0, "temperature": 71.5, "frequency": 600, "fanSpeed": 5400, "chipList": [
+                    {"id": 0, "temperature": 70, "frequency": 600, "voltage": 320, "nonce": 900, "errors": 2, "crc": 1},
+                    {"id": 1, "temperature": 74, "frequency": 600, "voltage": 320, "nonce": 0}
+                ]},
+                {"id": 1, "temperature": 69.0, "fanSpeed": 5600}
+            ]
+        }"#;
+        Mock::given(method("GET"))
+            .and(path("/api/miners/7"))
+            .and(header(AWESOME_MINER_KEY_HEADER, "secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let (data, info) =
+            fetch_awesome_miner_with_key(&format!("{}/", server.uri()), 7, Some("secret"))
+                .await
+                .unwrap();
+        assert_eq!(info.model, "M50S++");
+        assert_eq!(info.pool_url, "stratum+tcp://main.example:3333");
+        assert_eq!(info.fan_speed_rpm, Some(5600));
+        assert_eq!(data.slots.len(), 2);
+        let slot = &data.slots[0];
+        assert_eq!((slot.freq, slot.nonce_valid, slot.errors), (600, 900, 2));
+        assert_eq!(slot.chips[1].temp, 74);
+        assert!(slot.chips[1].is_dead());
+        assert!(data.slots[1].chips.is_empty());
+
+        // Wrong key and boardless miners are errors
+        assert!(
+            fetch_awesome_miner_with_key(&server.uri(), 7, Some("wrong"))
+                .await
+                .is_err()
+        );
+        assert!(parse_awesome_miner(r#"{"model": "M50S++"}"#).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_login_prefers_hmac() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cgi-bin/luci"))
+            .and(query_param("nonce", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("abc123\n"))
+            .mount(&server)
+            .await;
+        let signature = sign_challenge("admin", "abc123", "secret");
+        Mock::given(method("POST"))
+            .and(path("/cgi-bin/luci"))
+            .and(body_string_contains(format!("signature={signature}")))
+            .respond_with(session_response())
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new();
+        let stages = std::sync::Mutex::new(Vec::new());
+        let method = login_at(&client, &server.uri(), "admin", "secret", &|stage| {
+            stages.lock().unwrap().push(stage);
+        })
+        .await;
+        assert_eq!(method, Ok(AuthMethod::HmacSha256));
+        assert_eq!(*stages.lock().unwrap(), [FetchStage::Authenticating]);
+    }
+
+    #[tokio::test]
+    async fn test_login_falls_back_to_form_post() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cgi-bin/luci"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/cgi-bin/luci"))
+            .and(body_string_contains("luci_password=secret"))
+            .respond_with(session_response())
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::new();
+        let method = login_at(&client, &server.uri(), "admin", "secret", &|_| {}).await;
+        assert_eq!(method, Ok(AuthMethod::FormPost));
+    }
+
+    /// A successful LuCI login answer: 200 with the session cookie
+    fn session_response() -> ResponseTemplate {
+        ResponseTemplate::new(200).insert_header("set-cookie", "sysauth=0123abcd; path=/")
+    }
+
+    #[tokio::test]
+    async fn test_login_falls_back_when_nonce_is_login_page() {
+        for nonce_answer in [
+            ResponseTemplate::new(200)
+                .set_body_string("<!DOCTYPE html>\n<html><form>luci_password</form></html>"),
+            ResponseTemplate::new(403),
+            ResponseTemplate::new(401),
+        ] {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/cgi-bin/luci"))
+                .and(query_param("nonce", "1"))
+                .respond_with(nonce_answer)
+                .mount(&server)
+                .await;
+            Mock::given(method("POST"))
+                .and(path("/cgi-bin/luci"))
+                .and(body_string_contains("luci_password=secret"))
+                .respond_with(session_response())
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let client = Client::new();
+            let method = login_at(&client, &server.uri(), "admin", "secret", &|_| {}).await;
+            assert_eq!(method, Ok(AuthMethod::FormPost));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_without_session_is_rejected() {
+        // Wrong password on stock LuCI: the login page again, 200 and no cookie
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cgi-bin/luci"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<html>login</html>"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/cgi-bin/luci"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<html>login</html>"))
+            .mount(&server)
+            .await;
+
+        let client = Client::new();
+        assert!(
+            login_at(&client, &server.uri(), "admin", "wrong", &|_| {})
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_login_rejected() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cgi-bin/luci"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/cgi-bin/luci"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+
+        let client = Client::new();
+        assert!(
+            login_at(&client, &server.uri(), "admin", "wrong", &|_| {})
+                .await
+                .is_err()
+        );
+    }
 }
@@ -1,6 +1,27 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{LazyLock, RwLock};
+
+use serde::Serialize;
+use toml_edit::DocumentMut;
+
+use crate::models::SystemInfo;
+
+const USER_MODELS_FILE: &str = ".whatsminer_chip_map_user_models.toml";
+
+/// Models added with the model wizard, looked up after CONFIGS; leaked so they
+/// can be handed out as `&'static` like the built-in ones (a handful per install)
+static USER_CONFIGS: RwLock<Vec<&'static MinerConfig>> = RwLock::new(Vec::new());
+
+/// CONFIGS indexed for `lookup`, built on the first lookup (std's `LazyLock`
+/// does what `once_cell::sync::Lazy` would, without the extra dependency)
+static CONFIG_INDEX: LazyLock<ConfigIndex> = LazyLock::new(ConfigIndex::new);
+
 /// Miner hardware configuration data extracted from WhatsMiner firmware
-/// Format: (model, chip_num, chips_per_domain, board_num, slot_link)
+/// Format: (model, chip_num, chips_per_domain, board_num, slot_link, rated power, rated freq,
+/// rated hashrate)
 #[allow(dead_code)]
+#[derive(Serialize)]
 pub struct MinerConfig {
     pub model: &'static str,
     pub chip_num: u16,
@@ -8,6 +29,14 @@ pub struct MinerConfig {
     pub board_num: u8,
     /// Slot link config for hydro/immersion models (e.g. "0:1 2:3" means slots 0+1 and 2+3 are stacked)
     pub slot_link: Option<&'static str>,
+    /// Approximate power per chip at `rated_freq_mhz`: the series' published
+    /// nameplate wattage divided by chip_num × board_num
+    pub rated_power_per_chip_w: f32,
+    /// Typical stock chip frequency for the series
+    pub rated_freq_mhz: u16,
+    /// Approximate nameplate hashrate of the whole miner (TH/s): the nameplate
+    /// wattage divided by the series' published efficiency (J/TH)
+    pub rated_hashrate_ths: f32,
 }
 
 #[allow(dead_code)]
@@ -17,9 +46,12 @@ impl MinerConfig {
         self.chip_num / self.chips_per_domain as u16
     }
 
-    /// Calculate chips per board
+    /// Chips per board
+    ///
+    /// The firmware's chip_num is already per board (e.g. 135 for M50S VH50),
+    /// the same count `domains_per_board` divides into domains.
     pub const fn chips_per_board(&self) -> u16 {
-        self.chip_num / self.board_num as u16
+        self.chip_num
     }
 }
 
@@ -43,6 +75,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lookup_system_prefers_hardware_variant() {
+        let info = SystemInfo {
+            model: "WhatsMiner M50S++".into(),
+            hardware: crate::api::parse_hardware_info("M50S++_VK40.H616-CB6V10.P222B-VE1-197806A"),
+            ..Default::default()
+        };
+        assert_eq!(lookup_system(&info).unwrap().model, "M50S++VK40");
+    }
+
     #[test]
     fn test_lookup_exact_match() {
         let result = lookup("M50SVH50");
@@ -71,37 +113,438 @@ mod tests {
         let cfg = result.unwrap();
         assert_eq!(cfg.model, "M50S++VK40");
     }
+
+    #[test]
+    fn test_audit_missing_reports_unknown_models() {
+        let missing = audit_missing(&["M50SVH50", "WhatsMiner M56S++_VK10", "X99", "M99SVX10"]);
+        assert_eq!(missing, vec!["X99", "M99SVX10"]);
+    }
+
+    #[test]
+    fn test_export_configs_json_contains_all_models() {
+        let json = export_configs_json();
+        let parsed: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.len(), CONFIGS.len());
+        assert_eq!(parsed[0]["model"], CONFIGS[0].model);
+    }
+
+    /// `lookup(input)` finds `model` with `chips_per_domain` chips per domain
+    fn assert_lookup(input: &str, model: &str, chips_per_domain: u8) {
+        let cfg = lookup(input).unwrap_or_else(|| panic!("no config for {input:?}"));
+        assert_eq!(cfg.model, model, "model for {input:?}");
+        assert_eq!(
+            cfg.chips_per_domain, chips_per_domain,
+            "chips_per_domain for {input:?}"
+        );
+    }
+
+    #[test]
+    fn test_lookup_empty_input() {
+        assert!(lookup("").is_none());
+        assert!(lookup("   ").is_none());
+    }
+
+    #[test]
+    fn test_lookup_prefix_only() {
+        assert!(lookup("WhatsMiner").is_none());
+        assert!(lookup("WHATSMINER ").is_none());
+        assert!(lookup("whatsminer m20s").is_none());
+    }
+
+    #[test]
+    fn test_lookup_lowercase() {
+        assert_lookup("m50s_vh55", "M50SVH50", 3);
+        assert_lookup("whatsminer m60s_vk10", "M60SVK10", 5);
+    }
+
+    #[test]
+    fn test_lookup_ignores_dashes_and_spaces() {
+        assert_lookup("M50S-VH50", "M50SVH50", 3);
+        assert_lookup("M50S + + VK40", "M50S++VK40", 3);
+        assert_lookup("WhatsMiner M60S_VK10", "M60SVK10", 5);
+    }
+
+    #[test]
+    fn test_normalize_regional_model_names() {
+        for input in [
+            "M50S++VK40",
+            "WhatsMiner M50S++VK40",
+            "WhatsMiner M50S++ VK40",
+            "BTM-M50S++VK40",
+            "btm-m50s++vk40",
+            "WhatsMiner BTM-M50S++ VK40",
+            "M50S++功率版VK40",
+            "WhatsMiner M50S++ 功率版 VK40",
+            "M50SVK40++",
+            "M50S VK40 ++",
+        ] {
+            assert_eq!(normalize_model(input), "M50S++VK40", "{input:?}");
+            assert_lookup(input, "M50S++VK40", 3);
+        }
+        assert_eq!(normalize_model("M50S+VK30"), "M50S+VK30");
+        assert_eq!(normalize_model("M30SVE10"), "M30SVE10");
+
+        use FirmwareNamingConvention as C;
+        assert_eq!(C::detect("M50S++VK40"), C::Compact);
+        assert_eq!(C::detect("WhatsMiner M50S++VK40"), C::Compact);
+        assert_eq!(C::detect("WhatsMiner M50S++ VK40"), C::SpaceSeparated);
+        assert_eq!(C::detect("M50S++_VK40.H616-CB6V10"), C::Underscore);
+        assert_eq!(C::detect("BTM-M50S++VK40"), C::BtmPrefix);
+        assert_eq!(C::detect("M50S++功率版VK40"), C::Chinese);
+    }
+
+    #[test]
+    fn test_lookup_plus_variants_are_distinct() {
+        assert_lookup("M50S+_VK30", "M50S+VK30", 3);
+        assert_lookup("M50S++_VK30", "M50S++VK30", 4);
+        assert_lookup("M63S+VL10", "M63S+VL10", 4);
+        assert_lookup("M53S++VK30", "M53S++VK30", 4);
+    }
+
+    #[test]
+    fn test_lookup_prefers_longest_contained_model() {
+        // Both contain a shorter config model ("M30S++VH10", "M30S+V10")
+        assert_lookup("M30S++VH100", "M30S++VH100", 2);
+        assert_lookup("M30S+V100", "M30S+V100", 5);
+        assert_lookup("M30S++VH10", "M30S++VH10", 2);
+    }
+
+    #[test]
+    fn test_lookup_long_hardware_string() {
+        assert_lookup("M56S++_VK30.H616-CB6V10.P221B-VE1-123456A", "M56S++VK30", 4);
+        assert_lookup(
+            "M56S++_VK30.H616-CB6V10.P221B-VE1-123456A.EXTRA.SEGMENTS.V2",
+            "M56S++VK30",
+            4,
+        );
+    }
+
+    #[test]
+    fn test_lookup_unknown_variant_matches_same_base() {
+        assert_lookup("M50SVH55", "M50SVH50", 3);
+        assert_lookup("M66S++VR30", "M66S++VL20", 4);
+    }
+
+    #[test]
+    fn test_lookup_prefix_loop_boundary() {
+        // Four characters is the shortest prefix tried
+        assert_lookup("M50S", "M50S++VK10", 3);
+        assert_lookup("M50VH50", "M50VH50", 3);
+        // Three characters skip the prefix loop, and without 'V' or '+' there is no series
+        assert!(lookup("M50").is_none());
+        assert!(lookup("M5").is_none());
+        assert!(lookup("M60X").is_none());
+    }
+
+    #[test]
+    fn test_lookup_series_only_fallback() {
+        // No config starts with "M3V" or "M30+", so only the series before 'V'/'+' matches
+        assert_lookup("M3V", "M30KV10", 3);
+        assert_lookup("M30+", "M30KV10", 3);
+    }
+
+    #[test]
+    fn test_lookup_unknown_series() {
+        assert!(lookup("M21SV20").is_none());
+        assert!(lookup("X99").is_none());
+    }
+
+    #[test]
+    fn test_model_wizard_adds_user_model() {
+        // A made-up model no built-in config resembles
+        let detected = "WhatsMiner M99Z_VX77";
+        assert!(lookup(detected).is_none());
+
+        let mut state = WizardState::Model(ModelDraft {
+            model: detected.into(),
+            detected_chips: 120,
+            detected_boards: 3,
+            ..Default::default()
+        });
+        state = state.next().unwrap();
+        assert!(state.next().is_none(), "chip count is still empty");
+        let mut draft = state.draft().clone();
+        draft.chip_num = draft.detected_chips.to_string();
+        state = state.with_draft(draft).next().unwrap();
+        let mut draft = state.draft().clone();
+        draft.chips_per_domain = Some(4);
+        state = state.with_draft(draft).next().unwrap();
+        let mut draft = state.draft().clone();
+        draft.board_num = "3".into();
+        state = state.with_draft(draft).next().unwrap();
+        assert_eq!(state.step(), WizardState::STEPS);
+
+        let model = state.draft().build().unwrap();
+        assert_eq!(
+            model,
+            UserModel {
+                model: "M99ZVX77".into(),
+                chip_num: 120,
+                chips_per_domain: 4,
+                board_num: 3,
+            }
+        );
+        let toml = user_models_to_toml(std::slice::from_ref(&model));
+        assert_eq!(parse_user_models(&toml).unwrap(), vec![model.clone()]);
+        assert!(parse_user_models("[[model]]\nmodel = \"M1\"").is_err());
+
+        register_user_model(&model);
+        let cfg = lookup(detected).expect("the wizard's model is found after saving");
+        assert_eq!(
+            (cfg.chip_num, cfg.chips_per_domain, cfg.board_num),
+            (120, 4, 3)
+        );
+        assert!(cfg.rated_freq_mhz > 0);
+    }
+
+    #[test]
+    fn test_lookup_system_falls_back_to_model() {
+        let info = SystemInfo {
+            model: "WhatsMiner M60S_VK10".into(),
+            ..Default::default()
+        };
+        assert_eq!(lookup_system(&info).unwrap().chips_per_domain, 5);
+        assert!(lookup_system(&SystemInfo::default()).is_none());
+    }
+
+    #[test]
+    fn test_lookup_first_and_last_configs() {
+        let first = CONFIGS.first().unwrap();
+        let last = CONFIGS.last().unwrap();
+        assert_lookup(first.model, first.model, first.chips_per_domain);
+        assert_lookup(last.model, last.model, last.chips_per_domain);
+    }
+
+    #[test]
+    fn test_lookup_every_config_finds_itself() {
+        for cfg in CONFIGS {
+            assert_lookup(cfg.model, cfg.model, cfg.chips_per_domain);
+        }
+    }
+
+    /// Lookups per second `lookup` must sustain over CONFIGS (startup resolves the
+    /// open miner and every fleet miner)
+    const LOOKUP_MIN_THROUGHPUT: f64 = 10_000.0;
+
+    /// 1000 model strings: exact matches as the web UI writes them, unknown
+    /// variants of known series (prefix fallback) and other makes (misses)
+    fn mixed_models() -> Vec<String> {
+        (0..1000)
+            .map(|i| {
+                let cfg = &CONFIGS[i * 7 % CONFIGS.len()];
+                match i % 3 {
+                    0 => format!("WhatsMiner {}", cfg.model.to_lowercase()),
+                    1 => format!("{}9{}", cfg.model, i % 10),
+                    _ => format!("Antminer S{}", 9 + i % 12),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lookup_mixed_models() {
+        let found = mixed_models()
+            .iter()
+            .filter(|m| lookup(m).is_some())
+            .count();
+        assert!(found >= 667, "exact and fuzzy matches resolve: {found}");
+    }
+
+    #[test]
+    #[ignore = "timing-sensitive; run with `cargo test -- --ignored` on an idle machine"]
+    fn test_lookup_throughput() {
+        let models = mixed_models();
+        let start = std::time::Instant::now();
+        for model in &models {
+            std::hint::black_box(lookup(model));
+        }
+        let throughput = models.len() as f64 / start.elapsed().as_secs_f64();
+        assert!(
+            throughput > LOOKUP_MIN_THROUGHPUT,
+            "{throughput:.0} lookups/s"
+        );
+    }
+}
+
+/// Whether `c` is a CJK character, as in Chinese-market names like "M50S++功率版VK40"
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3000}'..='\u{303F}' // CJK punctuation
+        | '\u{3400}'..='\u{4DBF}' // Extension A
+        | '\u{4E00}'..='\u{9FFF}' // Unified ideographs
+        | '\u{F900}'..='\u{FAFF}' // Compatibility ideographs
+        | '\u{FF00}'..='\u{FFEF}' // Fullwidth forms
+    )
+}
+
+/// How a model string was written, reported by `--audit-models` to explain misses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirmwareNamingConvention {
+    /// "M50S++VK40" or "WhatsMiner M50S++VK40"
+    Compact,
+    /// Hardware info strings: "M50S++_VK40.H616-CB6V10..."
+    Underscore,
+    /// Some regional firmware: "WhatsMiner M50S++ VK40"
+    SpaceSeparated,
+    /// Distributor naming: "BTM-M50S++VK40"
+    BtmPrefix,
+    /// Chinese market, with a product name in the string: "M50S++功率版VK40"
+    Chinese,
+}
+
+impl FirmwareNamingConvention {
+    /// Detect the convention of a raw model string
+    pub fn detect(model: &str) -> Self {
+        let model = model.trim();
+        if model.chars().any(is_cjk) {
+            Self::Chinese
+        } else if model.to_uppercase().starts_with("BTM-") {
+            Self::BtmPrefix
+        } else if model.contains('_') {
+            Self::Underscore
+        } else if model
+            .split_whitespace()
+            .skip(1)
+            .any(|word| word.starts_with(['V', 'v']) && word.len() > 1)
+        {
+            Self::SpaceSeparated
+        } else {
+            Self::Compact
+        }
+    }
 }
 
-/// Normalize model string: uppercase, keep alphanumeric and '+', strip "WHATSMINER" prefix
+impl std::fmt::Display for FirmwareNamingConvention {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Compact => "compact",
+            Self::Underscore => "underscore",
+            Self::SpaceSeparated => "space-separated",
+            Self::BtmPrefix => "BTM prefix",
+            Self::Chinese => "Chinese market",
+        })
+    }
+}
+
+/// Normalize model string to the CONFIGS form, e.g. "M50S++VK40"
+///
+/// Uppercases, keeps alphanumerics and '+', drops CJK product names and the
+/// "WHATSMINER" / "BTM" prefixes, and moves a "++" written after the voltage
+/// code ("M50SVK40++") back onto the series.
 fn normalize_model(model: &str) -> String {
     let upper: String = model.to_uppercase();
     // Keep alphanumeric and '+' (for M50S++ style models)
     let filtered: String = upper
         .chars()
-        .filter(|c| c.is_alphanumeric() || *c == '+')
+        .filter(|c| (c.is_alphanumeric() || *c == '+') && !is_cjk(*c))
         .collect();
-    filtered
-        .strip_prefix("WHATSMINER")
-        .unwrap_or(&filtered)
-        .to_string()
+    let stripped = filtered.strip_prefix("WHATSMINER").unwrap_or(&filtered);
+    let stripped = stripped.strip_prefix("BTM").unwrap_or(stripped);
+    match stripped.find('V') {
+        Some(v) if stripped[v..].contains('+') => {
+            let pluses = "+".repeat(stripped.matches('+').count());
+            let series = stripped[..v].replace('+', "");
+            format!("{series}{pluses}{}", stripped[v..].replace('+', ""))
+        }
+        _ => stripped.to_string(),
+    }
+}
+
+/// Hash lookups standing in for `lookup`'s scans over CONFIGS, with the same results
+struct ConfigIndex {
+    /// Model → (position in CONFIGS, config); the last of duplicate models wins
+    exact: HashMap<&'static str, (usize, &'static MinerConfig)>,
+    /// Every prefix of every model, "" included → first config in CONFIGS with it
+    prefixes: HashMap<&'static str, &'static MinerConfig>,
+    /// Shortest and longest model, bounding the substrings worth looking up
+    model_len: (usize, usize),
+}
+
+impl ConfigIndex {
+    fn new() -> Self {
+        let mut exact = HashMap::new();
+        let mut prefixes = HashMap::new();
+        for (pos, cfg) in CONFIGS.iter().enumerate() {
+            exact.insert(cfg.model, (pos, cfg));
+            for (end, _) in cfg.model.char_indices().chain([(cfg.model.len(), ' ')]) {
+                prefixes.entry(&cfg.model[..end]).or_insert(cfg);
+            }
+        }
+        let lengths = CONFIGS.iter().map(|c| c.model.len());
+        let model_len = (
+            lengths.clone().min().unwrap_or(0),
+            lengths.max().unwrap_or(0),
+        );
+        Self {
+            exact,
+            prefixes,
+            model_len,
+        }
+    }
+
+    /// Longest model contained in `normalized`, the later one in CONFIGS on ties
+    fn longest_contained(&self, normalized: &str) -> Option<&'static MinerConfig> {
+        let (min_len, max_len) = self.model_len;
+        let mut best: Option<(usize, usize, &'static MinerConfig)> = None;
+        for start in 0..normalized.len() {
+            for len in min_len.max(1)..=max_len {
+                let Some(sub) = normalized.get(start..start + len) else {
+                    continue;
+                };
+                if let Some(&(pos, cfg)) = self.exact.get(sub)
+                    && best.is_none_or(|(l, p, _)| (len, pos) > (l, p))
+                {
+                    best = Some((len, pos, cfg));
+                }
+            }
+        }
+        best.map(|(_, _, cfg)| cfg)
+    }
+
+    /// First config whose model starts with `prefix`: CONFIGS first, then `user`
+    fn starting_with(
+        &self,
+        prefix: &str,
+        user: &[&'static MinerConfig],
+    ) -> Option<&'static MinerConfig> {
+        self.prefixes
+            .get(prefix)
+            .copied()
+            .or_else(|| user.iter().copied().find(|c| c.model.starts_with(prefix)))
+    }
 }
 
 /// Lookup miner config by model name (flexible matching)
+///
+/// Models added with the wizard are searched along with CONFIGS.
 pub fn lookup(model: &str) -> Option<&'static MinerConfig> {
     let normalized = normalize_model(model);
+    let user = USER_CONFIGS.read().unwrap_or_else(|e| e.into_inner());
+    let index = &*CONFIG_INDEX;
 
     // Try exact match first (normalized input contains config model)
-    if let Some(cfg) = CONFIGS.iter().find(|c| normalized.contains(c.model)) {
-        return Some(cfg);
+    // The longest match wins: "M30S++VH100" also contains "M30S++VH10"
+    // Wizard models come after CONFIGS, so they win ties
+    let contained = user
+        .iter()
+        .copied()
+        .filter(|c| normalized.contains(c.model))
+        .fold(index.longest_contained(&normalized), |best, c| match best {
+            Some(b) if b.model.len() > c.model.len() => Some(b),
+            _ => Some(c),
+        });
+    if contained.is_some() {
+        return contained;
     }
 
     // Try finding config where config model starts with same base
     // e.g., input "M50SVH55" should match "M50SVH50" (same base M50SVH)
     // Extract base model by finding longest common prefix
     for prefix_len in (4..=normalized.len()).rev() {
-        let prefix = &normalized[..prefix_len];
-        if let Some(cfg) = CONFIGS.iter().find(|c| c.model.starts_with(prefix)) {
+        let Some(prefix) = normalized.get(..prefix_len) else {
+            continue;
+        };
+        if let Some(cfg) = index.starting_with(prefix, &user) {
             return Some(cfg);
         }
     }
@@ -109,7 +552,7 @@ pub fn lookup(model: &str) -> Option<&'static MinerConfig> {
     // Try matching just the series (M50S, M60S, etc.)
     if let Some(series_end) = normalized.find(['V', '+']) {
         let series = &normalized[..series_end];
-        if let Some(cfg) = CONFIGS.iter().find(|c| c.model.starts_with(series)) {
+        if let Some(cfg) = index.starting_with(series, &user) {
             return Some(cfg);
         }
     }
@@ -117,6 +560,277 @@ pub fn lookup(model: &str) -> Option<&'static MinerConfig> {
     None
 }
 
+/// Lookup miner config for a fetched miner
+///
+/// The model variant from the hardware info string ("M50S++_VK40") is tried first
+/// since the Model field doesn't always include the hashboard variant.
+pub fn lookup_system(info: &SystemInfo) -> Option<&'static MinerConfig> {
+    let variant = &info.hardware.model_variant;
+    (!variant.is_empty())
+        .then(|| lookup(variant))
+        .flatten()
+        .or_else(|| lookup(&info.model))
+}
+
+/// Return the model strings for which `lookup` finds no config
+///
+/// Used to audit CONFIGS against a model list scraped from a firmware dump.
+pub fn audit_missing<'a>(known_models: &[&'a str]) -> Vec<&'a str> {
+    known_models
+        .iter()
+        .copied()
+        .filter(|model| lookup(model).is_none())
+        .collect()
+}
+
+/// Serialize CONFIGS to JSON for comparison with community-maintained databases
+pub fn export_configs_json() -> String {
+    serde_json::to_string_pretty(CONFIGS).unwrap_or_default()
+}
+
+/// A model added with the wizard: the layout fields the chip map needs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserModel {
+    /// Normalized model string, e.g. "M70SVK20"
+    pub model: String,
+    pub chip_num: u16,
+    pub chips_per_domain: u8,
+    pub board_num: u8,
+}
+
+/// Built-in config sharing the longest model prefix with `model`; user models
+/// borrow its power and hashrate ratings, which the wizard doesn't ask for
+pub fn similar_config(model: &str) -> &'static MinerConfig {
+    let normalized = normalize_model(model);
+    CONFIGS
+        .iter()
+        .max_by_key(|c| {
+            c.model
+                .bytes()
+                .zip(normalized.bytes())
+                .take_while(|(a, b)| a == b)
+                .count()
+        })
+        .unwrap_or(&CONFIGS[0])
+}
+
+/// Make a user model visible to `lookup`, replacing an earlier one of the same name
+pub fn register_user_model(user_model: &UserModel) {
+    let ratings = similar_config(&user_model.model);
+    let config: &'static MinerConfig = Box::leak(Box::new(MinerConfig {
+        model: Box::leak(normalize_model(&user_model.model).into_boxed_str()),
+        chip_num: user_model.chip_num,
+        chips_per_domain: user_model.chips_per_domain,
+        board_num: user_model.board_num,
+        slot_link: None,
+        rated_power_per_chip_w: ratings.rated_power_per_chip_w,
+        rated_freq_mhz: ratings.rated_freq_mhz,
+        rated_hashrate_ths: ratings.rated_hashrate_ths,
+    }));
+    let mut user = USER_CONFIGS.write().unwrap_or_else(|e| e.into_inner());
+    user.retain(|c| c.model != config.model);
+    user.push(config);
+}
+
+/// Parse the user models file: one `[[model]]` table per model
+pub fn parse_user_models(toml: &str) -> Result<Vec<UserModel>, String> {
+    let doc: DocumentMut = toml.parse().map_err(|e| format!("{e}"))?;
+    let Some(item) = doc.get("model") else {
+        return Ok(Vec::new());
+    };
+    let tables = item
+        .as_array_of_tables()
+        .ok_or("`model` must be a list of [[model]] tables")?;
+    tables
+        .iter()
+        .enumerate()
+        .map(|(idx, table)| {
+            let int = |key: &str| {
+                table
+                    .get(key)
+                    .and_then(toml_edit::Item::as_integer)
+                    .ok_or_else(|| format!("model {}: missing `{key}`", idx + 1))
+            };
+            let out_of_range = |key: &str| format!("model {}: `{key}` out of range", idx + 1);
+            Ok(UserModel {
+                model: table
+                    .get("model")
+                    .and_then(toml_edit::Item::as_str)
+                    .map(normalize_model)
+                    .filter(|m| !m.is_empty())
+                    .ok_or_else(|| format!("model {}: missing `model`", idx + 1))?,
+                chip_num: u16::try_from(int("chip_num")?).map_err(|_| out_of_range("chip_num"))?,
+                chips_per_domain: u8::try_from(int("chips_per_domain")?)
+                    .map_err(|_| out_of_range("chips_per_domain"))?,
+                board_num: u8::try_from(int("board_num")?)
+                    .map_err(|_| out_of_range("board_num"))?,
+            })
+        })
+        .collect()
+}
+
+/// The user models file for `models`
+pub fn user_models_to_toml(models: &[UserModel]) -> String {
+    let mut toml = String::from("# Miner models added with the model wizard\n");
+    for m in models {
+        toml.push_str(&format!(
+            "\n[[model]]\nmodel = \"{}\"\nchip_num = {}\nchips_per_domain = {}\nboard_num = {}\n",
+            m.model, m.chip_num, m.chips_per_domain, m.board_num
+        ));
+    }
+    toml
+}
+
+fn user_models_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(USER_MODELS_FILE))
+}
+
+/// Read the user models file and register its models; a missing file has none
+pub fn load_user_models() -> Result<usize, String> {
+    let Some(path) = user_models_path() else {
+        return Ok(0);
+    };
+    let models = match std::fs::read_to_string(&path) {
+        Ok(toml) => parse_user_models(&toml).map_err(|e| format!("{}: {e}", path.display()))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(format!("{}: {e}", path.display())),
+    };
+    for model in &models {
+        register_user_model(model);
+    }
+    Ok(models.len())
+}
+
+/// Add (or replace) a model in the user models file and register it
+pub async fn save_user_model(user_model: UserModel) -> Result<UserModel, String> {
+    let path = user_models_path().ok_or("No home directory for the user models file")?;
+    let mut models = match tokio::fs::read_to_string(&path).await {
+        Ok(toml) => parse_user_models(&toml).map_err(|e| format!("{}: {e}", path.display()))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(format!("{}: {e}", path.display())),
+    };
+    models.retain(|m| m.model != user_model.model);
+    models.push(user_model.clone());
+    tokio::fs::write(&path, user_models_to_toml(&models))
+        .await
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    register_user_model(&user_model);
+    Ok(user_model)
+}
+
+/// Contents of the model wizard, as typed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModelDraft {
+    /// Model string as detected, editable in the first step
+    pub model: String,
+    pub chip_num: String,
+    pub chips_per_domain: Option<u8>,
+    pub board_num: String,
+    /// Chips on the fullest slot and slots in the fetch, for the "use detected" buttons
+    pub detected_chips: usize,
+    pub detected_boards: usize,
+}
+
+impl ModelDraft {
+    pub fn chip_num(&self) -> Option<u16> {
+        self.chip_num.trim().parse().ok().filter(|&n| n > 0)
+    }
+
+    pub fn board_num(&self) -> Option<u8> {
+        self.board_num.trim().parse().ok().filter(|&n| n > 0)
+    }
+
+    /// Validate the form
+    pub fn build(&self) -> Result<UserModel, String> {
+        let model = normalize_model(&self.model);
+        if model.is_empty() {
+            return Err("Model is empty".into());
+        }
+        Ok(UserModel {
+            model,
+            chip_num: self
+                .chip_num()
+                .ok_or("Chip count must be a positive number")?,
+            chips_per_domain: self
+                .chips_per_domain
+                .ok_or("Chips per domain is not chosen")?,
+            board_num: self
+                .board_num()
+                .ok_or("Board count must be a positive number")?,
+        })
+    }
+}
+
+/// Step of the model wizard, with the form as filled in so far
+#[derive(Debug, Clone, PartialEq)]
+pub enum WizardState {
+    Model(ModelDraft),
+    ChipNum(ModelDraft),
+    ChipsPerDomain(ModelDraft),
+    BoardNum(ModelDraft),
+    Summary(ModelDraft),
+}
+
+impl WizardState {
+    pub const STEPS: usize = 5;
+
+    pub fn draft(&self) -> &ModelDraft {
+        match self {
+            Self::Model(d)
+            | Self::ChipNum(d)
+            | Self::ChipsPerDomain(d)
+            | Self::BoardNum(d)
+            | Self::Summary(d) => d,
+        }
+    }
+
+    /// 1-based step number
+    pub fn step(&self) -> usize {
+        match self {
+            Self::Model(_) => 1,
+            Self::ChipNum(_) => 2,
+            Self::ChipsPerDomain(_) => 3,
+            Self::BoardNum(_) => 4,
+            Self::Summary(_) => 5,
+        }
+    }
+
+    /// This step with an edited form
+    pub fn with_draft(&self, draft: ModelDraft) -> Self {
+        match self {
+            Self::Model(_) => Self::Model(draft),
+            Self::ChipNum(_) => Self::ChipNum(draft),
+            Self::ChipsPerDomain(_) => Self::ChipsPerDomain(draft),
+            Self::BoardNum(_) => Self::BoardNum(draft),
+            Self::Summary(_) => Self::Summary(draft),
+        }
+    }
+
+    /// The following step, once this one's field is valid
+    pub fn next(&self) -> Option<Self> {
+        let d = self.draft().clone();
+        match self {
+            Self::Model(_) if !normalize_model(&d.model).is_empty() => Some(Self::ChipNum(d)),
+            Self::ChipNum(_) if d.chip_num().is_some() => Some(Self::ChipsPerDomain(d)),
+            Self::ChipsPerDomain(_) if d.chips_per_domain.is_some() => Some(Self::BoardNum(d)),
+            Self::BoardNum(_) if d.board_num().is_some() => Some(Self::Summary(d)),
+            _ => None,
+        }
+    }
+
+    pub fn back(&self) -> Option<Self> {
+        let d = self.draft().clone();
+        match self {
+            Self::Model(_) => None,
+            Self::ChipNum(_) => Some(Self::Model(d)),
+            Self::ChipsPerDomain(_) => Some(Self::ChipNum(d)),
+            Self::BoardNum(_) => Some(Self::ChipsPerDomain(d)),
+            Self::Summary(_) => Some(Self::BoardNum(d)),
+        }
+    }
+}
+
 /// All known miner configurations
 pub static CONFIGS: &[MinerConfig] = &[
     // M30 Series
@@ -126,6 +840,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: None,
+        rated_power_per_chip_w: 2.92,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 72.0,
     },
     MinerConfig {
         model: "M30LV10",
@@ -133,6 +850,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: None,
+        rated_power_per_chip_w: 3.82,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 50.0,
     },
     MinerConfig {
         model: "M30S++V10",
@@ -140,6 +860,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: None,
+        rated_power_per_chip_w: 3.40,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++V20",
@@ -147,6 +870,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: None,
+        rated_power_per_chip_w: 3.40,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VE30",
@@ -154,6 +880,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.38,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VE40",
@@ -161,6 +890,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.14,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VE50",
@@ -168,6 +900,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.92,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VF40",
@@ -175,6 +910,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.42,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VG30",
@@ -182,6 +920,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.43,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VG40",
@@ -189,6 +930,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.89,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VG50",
@@ -196,6 +940,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.41,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH10",
@@ -203,6 +950,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.11,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH100",
@@ -210,6 +960,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.11,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH110",
@@ -217,6 +970,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 11.02,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH20",
@@ -224,6 +980,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.46,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH30",
@@ -231,6 +990,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.43,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH40",
@@ -238,6 +1000,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.53,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH50",
@@ -245,6 +1010,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.64,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH60",
@@ -252,6 +1020,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.84,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH70",
@@ -259,6 +1030,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.53,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH80",
@@ -266,6 +1040,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.64,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VH90",
@@ -273,6 +1050,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.84,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VI30",
@@ -280,6 +1060,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.43,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VJ20",
@@ -287,6 +1070,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.53,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VJ30",
@@ -294,6 +1080,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.64,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VJ50",
@@ -301,6 +1090,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.11,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VJ60",
@@ -308,6 +1100,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.43,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VJ70",
@@ -315,6 +1110,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.89,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VK30",
@@ -322,6 +1120,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 2,
         slot_link: None,
+        rated_power_per_chip_w: 23.46,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S++VK40",
@@ -329,6 +1130,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 11.02,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 112.0,
     },
     MinerConfig {
         model: "M30S+V100",
@@ -336,6 +1140,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.27,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+V10",
@@ -343,6 +1150,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.27,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+V20",
@@ -350,6 +1160,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.44,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+V40",
@@ -357,6 +1170,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.82,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+V50",
@@ -364,6 +1180,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.04,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+V60",
@@ -371,6 +1190,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.63,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+V70",
@@ -378,6 +1200,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.82,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+V80",
@@ -385,6 +1210,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.63,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+V90",
@@ -392,6 +1220,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.04,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VE30",
@@ -399,6 +1230,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.66,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VE40",
@@ -406,6 +1240,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.26,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VE50",
@@ -413,6 +1250,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.91,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VE60",
@@ -420,6 +1260,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.59,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VF20",
@@ -427,6 +1270,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.21,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VF30",
@@ -434,6 +1280,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.69,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VG20",
@@ -441,6 +1290,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.82,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VG30",
@@ -448,6 +1300,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.53,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VG40",
@@ -455,6 +1310,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.79,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VG50",
@@ -462,6 +1320,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.21,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VG60",
@@ -469,6 +1330,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.18,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VH10",
@@ -476,6 +1340,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 17.71,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VH20",
@@ -483,6 +1350,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 17.17,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VH30",
@@ -490,6 +1360,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.19,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VH40",
@@ -497,6 +1370,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.32,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VH50",
@@ -504,6 +1380,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 17.71,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VH60",
@@ -511,6 +1390,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 17.17,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VH70",
@@ -518,6 +1400,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.19,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VI30",
@@ -525,6 +1410,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.18,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VJ30",
@@ -532,6 +1420,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.79,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30S+VJ40",
@@ -539,6 +1430,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.69,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 100.0,
     },
     MinerConfig {
         model: "M30SV10",
@@ -546,6 +1440,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.36,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SV20",
@@ -553,6 +1450,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.98,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SV30",
@@ -560,6 +1460,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.64,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SV40",
@@ -567,6 +1470,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.33,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SV50",
@@ -574,6 +1480,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.98,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SV60",
@@ -581,6 +1490,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.64,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SV80",
@@ -588,6 +1500,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.44,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVE10",
@@ -595,6 +1510,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.37,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVE20",
@@ -602,6 +1520,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.81,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVE30",
@@ -609,6 +1530,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.31,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVE40",
@@ -616,6 +1540,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.86,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVE50",
@@ -623,6 +1550,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.44,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVF10",
@@ -630,6 +1560,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.56,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVF20",
@@ -637,6 +1570,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.72,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVF30",
@@ -644,6 +1580,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.97,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVG10",
@@ -651,6 +1590,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.51,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVG20",
@@ -658,6 +1600,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.56,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVG30",
@@ -665,6 +1610,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.72,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVG40",
@@ -672,6 +1620,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.97,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVH10",
@@ -679,6 +1630,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 17.02,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVH20",
@@ -686,6 +1640,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.51,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVH40",
@@ -693,6 +1650,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 17.02,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVH50",
@@ -700,6 +1660,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.51,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVH60",
@@ -707,6 +1670,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.56,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVI20",
@@ -714,6 +1680,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.56,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30SVJ30",
@@ -721,6 +1690,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.37,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30V10",
@@ -728,6 +1700,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.37,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     MinerConfig {
         model: "M30V20",
@@ -735,6 +1710,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.81,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 86.0,
     },
     // M31 Series
     MinerConfig {
@@ -743,6 +1721,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.42,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31HV40",
@@ -750,6 +1731,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.92,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31LV10",
@@ -757,6 +1741,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.42,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 70.0,
     },
     MinerConfig {
         model: "M31SEV10",
@@ -764,6 +1751,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.09,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31SEV20",
@@ -771,6 +1761,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.76,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31SEV30",
@@ -778,6 +1771,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.76,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31S+V100",
@@ -785,6 +1781,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.09,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+V10",
@@ -792,6 +1791,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.67,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+V20",
@@ -799,6 +1801,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.09,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+V30",
@@ -806,6 +1811,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.57,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+V40",
@@ -813,6 +1821,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.11,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+V50",
@@ -820,6 +1831,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.57,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+V60",
@@ -827,6 +1841,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.18,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+V80",
@@ -834,6 +1851,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.68,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+V90",
@@ -841,6 +1861,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.57,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+VE10",
@@ -848,6 +1871,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.66,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+VE20",
@@ -855,6 +1881,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.36,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+VE30",
@@ -862,6 +1891,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.67,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+VE40",
@@ -869,6 +1901,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.09,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+VE50",
@@ -876,6 +1911,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.57,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+VF20",
@@ -883,6 +1921,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.97,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+VG20",
@@ -890,6 +1931,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.97,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31S+VG30",
@@ -897,6 +1941,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 16.00,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 80.0,
     },
     MinerConfig {
         model: "M31SV10",
@@ -904,6 +1951,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.22,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31SV20",
@@ -911,6 +1961,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.67,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31SV30",
@@ -918,6 +1971,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.17,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31SV50",
@@ -925,6 +1981,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.76,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31SV60",
@@ -932,6 +1991,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.22,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31SV90",
@@ -939,6 +2001,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.17,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31SVE10",
@@ -946,6 +2011,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.33,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 77.0,
     },
     MinerConfig {
         model: "M31V10",
@@ -953,6 +2021,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.33,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 73.0,
     },
     MinerConfig {
         model: "M31V20",
@@ -960,6 +2031,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.50,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 73.0,
     },
     // M32/M33 Series
     MinerConfig {
@@ -968,6 +2042,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.29,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 62.0,
     },
     MinerConfig {
         model: "M32V20",
@@ -975,6 +2052,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 15.06,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 62.0,
     },
     MinerConfig {
         model: "M33S++VG40",
@@ -982,6 +2062,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 10.43,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 234.0,
     },
     MinerConfig {
         model: "M33S++VH20",
@@ -989,6 +2072,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 16.21,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 234.0,
     },
     MinerConfig {
         model: "M33S+VG20",
@@ -996,6 +2082,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 16.21,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 214.0,
     },
     MinerConfig {
         model: "M33S+VG30",
@@ -1003,6 +2092,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 11.20,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 213.0,
     },
     MinerConfig {
         model: "M33S+VH20",
@@ -1010,6 +2102,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 18.15,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 214.0,
     },
     MinerConfig {
         model: "M33S+VH30",
@@ -1017,6 +2112,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 15.65,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 214.0,
     },
     MinerConfig {
         model: "M33SVG30",
@@ -1024,6 +2122,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 15.65,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M33V10",
@@ -1031,6 +2132,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 1,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 73.33,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M33V20",
@@ -1038,6 +2142,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 39.03,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M33V30",
@@ -1045,6 +2152,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 36.67,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 191.0,
     },
     // M34/M36/M39 Series
     MinerConfig {
@@ -1053,6 +2163,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.54,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 103.0,
     },
     MinerConfig {
         model: "M36S++VH30",
@@ -1060,6 +2173,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 24.06,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 248.0,
     },
     MinerConfig {
         model: "M36S+VG30",
@@ -1067,6 +2183,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 17.82,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 226.0,
     },
     MinerConfig {
         model: "M36SVE10",
@@ -1074,6 +2193,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 16.89,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 203.0,
     },
     MinerConfig {
         model: "M39V10",
@@ -1081,6 +2203,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 66.67,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 278.0,
     },
     MinerConfig {
         model: "M39V20",
@@ -1088,6 +2213,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 61.73,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 278.0,
     },
     MinerConfig {
         model: "M39V30",
@@ -1095,6 +2223,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 49.02,
+        rated_freq_mhz: 600,
+        rated_hashrate_ths: 278.0,
     },
     // M50 Series
     MinerConfig {
@@ -1103,6 +2234,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.42,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VK20",
@@ -1110,6 +2244,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.96,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VK30",
@@ -1117,6 +2254,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.06,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VK40",
@@ -1124,6 +2264,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.54,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VK50",
@@ -1131,6 +2274,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.16,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VK60",
@@ -1138,6 +2284,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.93,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VL10",
@@ -1145,6 +2294,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.44,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VL20",
@@ -1152,6 +2304,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 12.81,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VL30",
@@ -1159,6 +2314,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.93,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VL40",
@@ -1166,6 +2324,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.42,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VL50",
@@ -1173,6 +2334,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.50,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S++VL60",
@@ -1180,6 +2344,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.93,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 150.0,
     },
     MinerConfig {
         model: "M50S+VH30",
@@ -1187,6 +2354,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.41,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VH40",
@@ -1194,6 +2364,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.12,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VJ30",
@@ -1201,6 +2374,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.06,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VJ40",
@@ -1208,6 +2384,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.72,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VJ60",
@@ -1215,6 +2394,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.72,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VK10",
@@ -1222,6 +2404,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.93,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VK20",
@@ -1229,6 +2414,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.42,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VK30",
@@ -1236,6 +2424,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.96,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VL10",
@@ -1243,6 +2434,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.44,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VL20",
@@ -1250,6 +2444,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 12.81,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50S+VL30",
@@ -1257,6 +2454,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.50,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 138.0,
     },
     MinerConfig {
         model: "M50SVH20",
@@ -1264,6 +2464,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.16,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVH30",
@@ -1271,6 +2474,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.06,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVH40",
@@ -1278,6 +2484,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.45,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVH50",
@@ -1285,6 +2494,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.16,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVJ10",
@@ -1292,6 +2504,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.93,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVJ20",
@@ -1299,6 +2514,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.42,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVJ30",
@@ -1306,6 +2524,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.96,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVJ40",
@@ -1313,6 +2534,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.54,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVJ50",
@@ -1320,6 +2544,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.16,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVK10",
@@ -1327,6 +2554,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.13,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVK20",
@@ -1334,6 +2564,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.93,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVK30",
@@ -1341,6 +2574,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.42,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVK50",
@@ -1348,6 +2584,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.50,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVK60",
@@ -1355,6 +2594,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.93,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVK70",
@@ -1362,6 +2604,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.96,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVK80",
@@ -1369,6 +2614,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 12.81,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVL10",
@@ -1376,6 +2624,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.89,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVL20",
@@ -1383,6 +2634,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.13,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50SVL30",
@@ -1390,6 +2644,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.44,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 127.0,
     },
     MinerConfig {
         model: "M50VE30",
@@ -1397,6 +2654,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: None,
+        rated_power_per_chip_w: 3.21,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VG30",
@@ -1404,6 +2664,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.00,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VH10",
@@ -1411,6 +2674,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 12.70,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VH20",
@@ -1418,6 +2684,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.84,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VH30",
@@ -1425,6 +2694,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.33,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VH40",
@@ -1432,6 +2704,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.00,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VH50",
@@ -1439,6 +2714,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.40,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VH60",
@@ -1446,6 +2724,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.00,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VH70",
@@ -1453,6 +2734,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.40,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VH80",
@@ -1460,6 +2744,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.84,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VH90",
@@ -1467,6 +2754,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.33,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VJ10",
@@ -1474,6 +2764,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 12.70,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VJ20",
@@ -1481,6 +2774,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.84,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VJ30",
@@ -1488,6 +2784,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.33,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VJ40",
@@ -1495,6 +2794,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.88,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VJ60",
@@ -1502,6 +2804,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.66,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VK40",
@@ -1509,6 +2814,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.84,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     MinerConfig {
         model: "M50VK50",
@@ -1516,6 +2824,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.33,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 117.0,
     },
     // M51/M52/M53 Series
     MinerConfig {
@@ -1524,6 +2835,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.51,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 146.0,
     },
     MinerConfig {
         model: "M52S++VL10",
@@ -1531,6 +2845,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 10.06,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 159.0,
     },
     MinerConfig {
         model: "M52SVK30",
@@ -1538,6 +2855,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 14.11,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 135.0,
     },
     MinerConfig {
         model: "M53HVH10",
@@ -1545,6 +2865,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 27.56,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 213.0,
     },
     MinerConfig {
         model: "M53S++VK10",
@@ -1552,6 +2875,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.21,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 296.0,
     },
     MinerConfig {
         model: "M53S++VK20",
@@ -1559,6 +2885,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.46,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 295.0,
     },
     MinerConfig {
         model: "M53S++VK30",
@@ -1566,6 +2895,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.77,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 295.0,
     },
     MinerConfig {
         model: "M53S++VK50",
@@ -1573,6 +2905,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.74,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 296.0,
     },
     MinerConfig {
         model: "M53S++VL10",
@@ -1580,6 +2915,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 12.70,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 296.0,
     },
     MinerConfig {
         model: "M53S++VL30",
@@ -1587,6 +2925,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 9.34,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 295.0,
     },
     MinerConfig {
         model: "M53S+VJ30",
@@ -1594,6 +2935,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.43,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 257.0,
     },
     MinerConfig {
         model: "M53S+VJ40",
@@ -1601,6 +2945,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.22,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 257.0,
     },
     MinerConfig {
         model: "M53S+VJ50",
@@ -1608,6 +2955,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.85,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 257.0,
     },
     MinerConfig {
         model: "M53S+VK30",
@@ -1615,6 +2965,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.57,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 257.0,
     },
     MinerConfig {
         model: "M53SVH20",
@@ -1622,6 +2975,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.80,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 238.0,
     },
     MinerConfig {
         model: "M53SVH30",
@@ -1629,6 +2985,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.57,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 238.0,
     },
     MinerConfig {
         model: "M53SVJ30",
@@ -1636,6 +2995,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.57,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 237.0,
     },
     MinerConfig {
         model: "M53SVJ40",
@@ -1643,6 +3005,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.04,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 237.0,
     },
     MinerConfig {
         model: "M53SVK30",
@@ -1650,6 +3015,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 12.06,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 237.0,
     },
     MinerConfig {
         model: "M53VH30",
@@ -1657,6 +3025,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 12.06,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 213.0,
     },
     MinerConfig {
         model: "M53VH40",
@@ -1664,6 +3035,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.87,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 213.0,
     },
     MinerConfig {
         model: "M53VH50",
@@ -1671,6 +3045,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 9.53,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 213.0,
     },
     MinerConfig {
         model: "M53VK30",
@@ -1678,6 +3055,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 15.44,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 213.0,
     },
     MinerConfig {
         model: "M53VK60",
@@ -1685,6 +3065,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 15.44,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 213.0,
     },
     // M54/M56 Series
     MinerConfig {
@@ -1693,6 +3076,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 14.45,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 252.0,
     },
     MinerConfig {
         model: "M54S++VL30",
@@ -1700,6 +3086,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 20.40,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 252.0,
     },
     MinerConfig {
         model: "M54S++VL40",
@@ -1707,6 +3096,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 15.42,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 252.0,
     },
     MinerConfig {
         model: "M54S+VL30",
@@ -1714,6 +3106,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 16.52,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 231.0,
     },
     MinerConfig {
         model: "M54SVH30",
@@ -1721,6 +3116,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 11.56,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 213.0,
     },
     MinerConfig {
         model: "M54SVK30",
@@ -1728,6 +3126,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 13.60,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 213.0,
     },
     MinerConfig {
         model: "M56S++VK10",
@@ -1735,6 +3136,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.67,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 252.0,
     },
     MinerConfig {
         model: "M56S++VK30",
@@ -1742,6 +3146,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.88,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 252.0,
     },
     MinerConfig {
         model: "M56S++VK40",
@@ -1749,6 +3156,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 10.51,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 252.0,
     },
     MinerConfig {
         model: "M56S++VK50",
@@ -1756,6 +3166,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 9.13,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 252.0,
     },
     MinerConfig {
         model: "M56S+VJ30",
@@ -1763,6 +3176,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.88,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 231.0,
     },
     MinerConfig {
         model: "M56S+VK30",
@@ -1770,6 +3186,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 12.85,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 231.0,
     },
     MinerConfig {
         model: "M56S+VK40",
@@ -1777,6 +3196,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 12.17,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 231.0,
     },
     MinerConfig {
         model: "M56S+VK50",
@@ -1784,6 +3206,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 11.56,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 231.0,
     },
     MinerConfig {
         model: "M56SVH30",
@@ -1791,6 +3216,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 9.13,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 214.0,
     },
     MinerConfig {
         model: "M56SVJ30",
@@ -1798,6 +3226,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 10.51,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 213.0,
     },
     MinerConfig {
         model: "M56SVJ40",
@@ -1805,6 +3236,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 9.13,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 214.0,
     },
     MinerConfig {
         model: "M56VH30",
@@ -1812,6 +3246,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 12.85,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M59VH30",
@@ -1819,6 +3256,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 15.15,
+        rated_freq_mhz: 550,
+        rated_hashrate_ths: 276.0,
     },
     // M60 Series
     MinerConfig {
@@ -1827,6 +3267,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.72,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S++VL30",
@@ -1834,6 +3277,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.19,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S++VL40",
@@ -1841,6 +3287,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.96,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S++VL50",
@@ -1848,6 +3297,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.76,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S++VL70",
@@ -1855,6 +3307,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 6,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 3.97,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S++VM30",
@@ -1862,6 +3317,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.97,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S++VM40",
@@ -1869,6 +3327,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.49,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S++VM50",
@@ -1876,6 +3337,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.04,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S++VM60",
@@ -1883,6 +3347,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.64,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S++VM70",
@@ -1890,6 +3357,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.27,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 219.0,
     },
     MinerConfig {
         model: "M60S+VK30",
@@ -1897,6 +3367,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.63,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VK40",
@@ -1904,6 +3377,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: None,
+        rated_power_per_chip_w: 3.95,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VK50",
@@ -1911,6 +3387,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: None,
+        rated_power_per_chip_w: 3.78,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VK60",
@@ -1918,6 +3397,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 6,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 3.85,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VK70",
@@ -1925,6 +3407,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 6,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 3.70,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VL100",
@@ -1932,6 +3417,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.44,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VL10",
@@ -1939,6 +3427,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.78,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VL30",
@@ -1946,6 +3437,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.04,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VL40",
@@ -1953,6 +3447,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.03,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VL50",
@@ -1960,6 +3457,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.30,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VL60",
@@ -1967,6 +3467,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.59,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VL70",
@@ -1974,6 +3477,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.04,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VL80",
@@ -1981,6 +3487,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.30,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VL90",
@@ -1988,6 +3497,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.16,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VM20",
@@ -1995,6 +3507,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.82,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VM30",
@@ -2002,6 +3517,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 13.18,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VM40",
@@ -2009,6 +3527,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 12.59,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60S+VM50",
@@ -2016,6 +3537,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 11.56,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 200.0,
     },
     MinerConfig {
         model: "M60SVK10",
@@ -2023,6 +3547,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.18,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVK20",
@@ -2030,6 +3557,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.74,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVK30",
@@ -2037,6 +3567,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.55,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVK40",
@@ -2044,6 +3577,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.95,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVK60",
@@ -2051,6 +3587,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.93,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVK70",
@@ -2058,6 +3597,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.69,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVK80",
@@ -2065,6 +3607,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.95,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVK90",
@@ -2072,6 +3617,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.81,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVL10",
@@ -2079,6 +3627,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.58,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVL20",
@@ -2086,6 +3637,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.80,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVL30",
@@ -2093,6 +3647,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.48,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVL40",
@@ -2100,6 +3657,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.19,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVL50",
@@ -2107,6 +3667,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.93,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVL60",
@@ -2114,6 +3677,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.69,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVL70",
@@ -2121,6 +3687,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.91,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVL80",
@@ -2128,6 +3697,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.26,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVM20",
@@ -2135,6 +3707,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 14.29,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60SVM40",
@@ -2142,6 +3717,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 12.96,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 181.0,
     },
     MinerConfig {
         model: "M60VK10",
@@ -2149,6 +3727,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.80,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     MinerConfig {
         model: "M60VK20",
@@ -2156,6 +3737,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.48,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     MinerConfig {
         model: "M60VK30",
@@ -2163,6 +3747,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.18,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     MinerConfig {
         model: "M60VK40",
@@ -2170,6 +3757,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.19,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     MinerConfig {
         model: "M60VK6A",
@@ -2177,6 +3767,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.48,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     MinerConfig {
         model: "M60VL10",
@@ -2184,6 +3777,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.04,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     MinerConfig {
         model: "M60VL20",
@@ -2191,6 +3787,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.53,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     MinerConfig {
         model: "M60VL30",
@@ -2198,6 +3797,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.06,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     MinerConfig {
         model: "M60VL40",
@@ -2205,6 +3807,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.64,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     MinerConfig {
         model: "M60VL50",
@@ -2212,6 +3817,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.26,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 168.0,
     },
     // M61 Series
     MinerConfig {
@@ -2220,6 +3828,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.63,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 224.0,
     },
     MinerConfig {
         model: "M61SVK20",
@@ -2227,6 +3838,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.63,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M61SVK30",
@@ -2234,6 +3848,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.39,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M61SVL10",
@@ -2241,6 +3858,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.72,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M61SVL20",
@@ -2248,6 +3868,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.36,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M61SVL30",
@@ -2255,6 +3878,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.04,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M61SVL60",
@@ -2262,6 +3888,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.04,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M61SVL90",
@@ -2269,6 +3898,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.63,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M61SVM30",
@@ -2276,6 +3908,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 10.83,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M61VK10",
@@ -2283,6 +3918,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.04,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M61VK20",
@@ -2290,6 +3928,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.88,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M61VK30",
@@ -2297,6 +3938,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.74,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M61VK40",
@@ -2304,6 +3948,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.60,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M61VK60",
@@ -2311,6 +3958,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.74,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M61VL10",
@@ -2318,6 +3968,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 9.38,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M61VL30",
@@ -2325,6 +3978,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.98,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M61VL40",
@@ -2332,6 +3988,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.80,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M61VL50",
@@ -2339,6 +3998,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.62,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M61VL60",
@@ -2346,6 +4008,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.44,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     // M62/M63 Series
     MinerConfig {
@@ -2354,6 +4019,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 2.95,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 224.0,
     },
     MinerConfig {
         model: "M63S++VL20",
@@ -2361,6 +4029,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 4.75,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 451.0,
     },
     MinerConfig {
         model: "M63S++VL40",
@@ -2368,6 +4039,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.93,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 451.0,
     },
     MinerConfig {
         model: "M63S++VL50",
@@ -2375,6 +4049,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.31,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 451.0,
     },
     MinerConfig {
         model: "M63S++VL60",
@@ -2382,6 +4059,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 4.75,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 451.0,
     },
     MinerConfig {
         model: "M63S++VM20",
@@ -2389,6 +4069,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 9.11,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 451.0,
     },
     MinerConfig {
         model: "M63S+VK30",
@@ -2396,6 +4079,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 6,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 3.96,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 425.0,
     },
     MinerConfig {
         model: "M63S+VL10",
@@ -2403,6 +4089,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.93,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 424.0,
     },
     MinerConfig {
         model: "M63S+VL20",
@@ -2410,6 +4099,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.31,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 425.0,
     },
     MinerConfig {
         model: "M63S+VL30",
@@ -2417,6 +4109,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 4.88,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 425.0,
     },
     MinerConfig {
         model: "M63S+VL50",
@@ -2424,6 +4119,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.63,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 424.0,
     },
     MinerConfig {
         model: "M63S+VL60",
@@ -2431,6 +4129,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.93,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 424.0,
     },
     MinerConfig {
         model: "M63S+VL70",
@@ -2438,6 +4139,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.52,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 425.0,
     },
     MinerConfig {
         model: "M63S+VL80",
@@ -2445,6 +4149,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.05,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 425.0,
     },
     MinerConfig {
         model: "M63S+VL90",
@@ -2452,6 +4159,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.05,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 425.0,
     },
     MinerConfig {
         model: "M63S+VM30",
@@ -2459,6 +4169,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 13.26,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 424.0,
     },
     MinerConfig {
         model: "M63S+VM40",
@@ -2466,6 +4179,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 12.53,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 425.0,
     },
     MinerConfig {
         model: "M63SVK10",
@@ -2473,6 +4189,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.31,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVK20",
@@ -2480,6 +4199,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.15,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVK30",
@@ -2487,6 +4209,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 4.88,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVK40",
@@ -2494,6 +4219,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.26,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVK50",
@@ -2501,6 +4229,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.01,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVK60",
@@ -2508,6 +4239,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.15,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVK70",
@@ -2515,6 +4249,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.31,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVK80",
@@ -2522,6 +4259,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.26,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVK90",
@@ -2529,6 +4269,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.93,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVL10",
@@ -2536,6 +4279,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.91,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVL20",
@@ -2543,6 +4289,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.35,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVL30",
@@ -2550,6 +4299,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.63,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVL50",
@@ -2557,6 +4309,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.26,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVL60",
@@ -2564,6 +4319,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.26,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVL70",
@@ -2571,6 +4329,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.91,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63SVM30",
@@ -2578,6 +4339,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 2,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 13.66,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 390.0,
     },
     MinerConfig {
         model: "M63VK10",
@@ -2585,6 +4349,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.05,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 363.0,
     },
     MinerConfig {
         model: "M63VK20",
@@ -2592,6 +4359,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.83,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 362.0,
     },
     MinerConfig {
         model: "M63VK30",
@@ -2599,6 +4369,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.63,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 362.0,
     },
     MinerConfig {
         model: "M63VL10",
@@ -2606,6 +4379,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 10.37,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 363.0,
     },
     MinerConfig {
         model: "M63VL20",
@@ -2613,6 +4389,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.84,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 362.0,
     },
     MinerConfig {
         model: "M63VL30",
@@ -2620,6 +4399,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.35,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 363.0,
     },
     MinerConfig {
         model: "M63VL40",
@@ -2627,6 +4409,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 10.02,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 363.0,
     },
     MinerConfig {
         model: "M63VL60",
@@ -2634,6 +4419,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.35,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 363.0,
     },
     MinerConfig {
         model: "M63VL70",
@@ -2641,6 +4429,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 10.37,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 363.0,
     },
     // M64/M65/M66 Series
     MinerConfig {
@@ -2649,6 +4440,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 9.90,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 238.0,
     },
     MinerConfig {
         model: "M64SVL10",
@@ -2656,6 +4450,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.33,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M64SVL20",
@@ -2663,6 +4460,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.92,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M64SVL30",
@@ -2670,6 +4470,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.25,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M64VL20",
@@ -2677,6 +4480,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 9.90,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M64VL30",
@@ -2684,6 +4490,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.33,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M64VL40",
@@ -2691,6 +4500,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.92,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 191.0,
     },
     MinerConfig {
         model: "M65S+VK30",
@@ -2698,6 +4510,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 6,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 2.08,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 223.0,
     },
     MinerConfig {
         model: "M65SVK20",
@@ -2705,6 +4520,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 2.71,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M65SVL60",
@@ -2712,6 +4530,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 3.30,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 205.0,
     },
     MinerConfig {
         model: "M66S++VL20",
@@ -2719,6 +4540,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.98,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 355.0,
     },
     MinerConfig {
         model: "M66S++VL40",
@@ -2726,6 +4550,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 6.37,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 355.0,
     },
     MinerConfig {
         model: "M66S++VL50",
@@ -2733,6 +4560,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.73,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 355.0,
     },
     MinerConfig {
         model: "M66S++VL60",
@@ -2740,6 +4570,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.50,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 355.0,
     },
     MinerConfig {
         model: "M66S++VM30",
@@ -2747,6 +4580,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 9.96,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 355.0,
     },
     MinerConfig {
         model: "M66S+VK30",
@@ -2754,6 +4590,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.17,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 324.0,
     },
     MinerConfig {
         model: "M66S+VL10",
@@ -2761,6 +4600,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.25,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 324.0,
     },
     MinerConfig {
         model: "M66S+VL20",
@@ -2768,6 +4610,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.98,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 324.0,
     },
     MinerConfig {
         model: "M66S+VL30",
@@ -2775,6 +4620,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.73,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 324.0,
     },
     MinerConfig {
         model: "M66S+VL40",
@@ -2782,6 +4630,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.50,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 324.0,
     },
     MinerConfig {
         model: "M66S+VL50",
@@ -2789,6 +4640,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.88,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 324.0,
     },
     MinerConfig {
         model: "M66S+VL60",
@@ -2796,6 +4650,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.88,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 324.0,
     },
     MinerConfig {
         model: "M66S+VL70",
@@ -2803,6 +4660,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.98,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 324.0,
     },
     MinerConfig {
         model: "M66SVK20",
@@ -2810,6 +4670,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.98,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVK30",
@@ -2817,6 +4680,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.77,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVK40",
@@ -2824,6 +4690,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.73,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVK50",
@@ -2831,6 +4700,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.50,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVK60",
@@ -2838,6 +4710,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 5.50,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVK70",
@@ -2845,6 +4720,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.55,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVK80",
@@ -2852,6 +4730,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.25,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVL10",
@@ -2859,6 +4740,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.18,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVL20",
@@ -2866,6 +4750,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.81,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVL30",
@@ -2873,6 +4760,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.16,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVL40",
@@ -2880,6 +4770,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.88,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 298.0,
     },
     MinerConfig {
         model: "M66SVL50",
@@ -2887,6 +4780,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.55,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66SVL80",
@@ -2894,6 +4790,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.59,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 297.0,
     },
     MinerConfig {
         model: "M66VK20",
@@ -2901,6 +4800,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.47,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 276.0,
     },
     MinerConfig {
         model: "M66VK30",
@@ -2908,6 +4810,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.16,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 276.0,
     },
     MinerConfig {
         model: "M66VK60",
@@ -2915,6 +4820,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 7.81,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 276.0,
     },
     MinerConfig {
         model: "M66VL20",
@@ -2922,6 +4830,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.59,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 276.0,
     },
     MinerConfig {
         model: "M66VL30",
@@ -2929,6 +4840,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.18,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 276.0,
     },
     // M67/M69/M70/M73/M76 Series
     MinerConfig {
@@ -2937,6 +4851,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.17,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 298.0,
     },
     MinerConfig {
         model: "M69S++VM30",
@@ -2944,6 +4861,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.03,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 355.0,
     },
     MinerConfig {
         model: "M69VK30",
@@ -2951,6 +4871,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.03,
+        rated_freq_mhz: 500,
+        rated_hashrate_ths: 276.0,
     },
     MinerConfig {
         model: "M70SVM30",
@@ -2958,6 +4881,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.56,
+        rated_freq_mhz: 450,
+        rated_hashrate_ths: 252.0,
     },
     MinerConfig {
         model: "M70VL30",
@@ -2965,6 +4891,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 4.44,
+        rated_freq_mhz: 450,
+        rated_hashrate_ths: 234.0,
     },
     MinerConfig {
         model: "M70VM30",
@@ -2972,6 +4901,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 7.71,
+        rated_freq_mhz: 450,
+        rated_hashrate_ths: 234.0,
     },
     MinerConfig {
         model: "M73SVM30",
@@ -2979,6 +4911,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 6.17,
+        rated_freq_mhz: 450,
+        rated_hashrate_ths: 556.0,
     },
     MinerConfig {
         model: "M73VL30",
@@ -2986,6 +4921,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 5,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 4.93,
+        rated_freq_mhz: 450,
+        rated_hashrate_ths: 517.0,
     },
     MinerConfig {
         model: "M73VM30",
@@ -2993,6 +4931,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 3,
         board_num: 4,
         slot_link: Some("0:1 2:3"),
+        rated_power_per_chip_w: 8.22,
+        rated_freq_mhz: 450,
+        rated_hashrate_ths: 517.0,
     },
     MinerConfig {
         model: "M76SVM30",
@@ -3000,6 +4941,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 8.33,
+        rated_freq_mhz: 450,
+        rated_hashrate_ths: 444.0,
     },
     MinerConfig {
         model: "M76VL30",
@@ -3007,6 +4951,9 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 5.21,
+        rated_freq_mhz: 450,
+        rated_hashrate_ths: 414.0,
     },
     MinerConfig {
         model: "M76VM30",
@@ -3014,5 +4961,8 @@ pub static CONFIGS: &[MinerConfig] = &[
         chips_per_domain: 4,
         board_num: 3,
         slot_link: None,
+        rated_power_per_chip_w: 11.36,
+        rated_freq_mhz: 450,
+        rated_hashrate_ths: 414.0,
     },
 ];
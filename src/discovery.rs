@@ -0,0 +1,266 @@
+//! Miner discovery from LLDP frames (Linux only: needs a raw socket, i.e. root or CAP_NET_RAW)
+//!
+//! Switches don't forward LLDP, so only frames that reach this machine are seen:
+//! miners on the same unmanaged switch, or all of them from a port mirroring the miner ports.
+
+use std::io;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::time::{Duration, Instant};
+
+use pnet::datalink::{self, Channel};
+
+/// Long enough to catch every neighbor's periodic advertisement (30 s by default)
+pub const LLDP_SCAN_SECS: u64 = 35;
+
+const ETHERTYPE_LLDP: u16 = 0x88cc;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+
+const TLV_END: u8 = 0;
+const TLV_CHASSIS_ID: u8 = 1;
+const TLV_PORT_ID: u8 = 2;
+const TLV_PORT_DESCRIPTION: u8 = 4;
+const TLV_SYSTEM_NAME: u8 = 5;
+const TLV_MANAGEMENT_ADDRESS: u8 = 8;
+
+/// Chassis ID subtype carrying a management address
+const CHASSIS_NETWORK_ADDRESS: u8 = 5;
+/// Port ID subtypes holding text: interface alias, interface name, locally assigned
+const PORT_ID_TEXT: [u8; 3] = [1, 5, 7];
+/// IANA address families
+const FAMILY_IPV4: u8 = 1;
+const FAMILY_IPV6: u8 = 2;
+
+/// A WhatsMiner seen announcing itself over LLDP
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LldpDiscovery {
+    /// Management address
+    pub ip: String,
+    /// System name, e.g. "WhatsMiner-M50S"
+    pub hostname: String,
+    /// Port description, or the port ID if there is none
+    pub port: String,
+}
+
+impl LldpDiscovery {
+    /// WhatsMiner firmware puts "WhatsMiner" in the system name
+    pub fn is_whatsminer(&self) -> bool {
+        self.hostname.to_lowercase().contains("whatsminer")
+    }
+}
+
+/// LLDP scan controls and results in the fleet dashboard
+#[derive(Debug, Clone, Default)]
+pub struct LldpScan {
+    pub interfaces: Vec<String>,
+    pub interface: Option<String>,
+    pub scanning: bool,
+    /// Result of the last scan
+    pub found: Option<Vec<LldpDiscovery>>,
+}
+
+impl LldpScan {
+    pub fn new() -> Self {
+        let interfaces = interfaces();
+        Self {
+            interface: interfaces.first().cloned(),
+            interfaces,
+            ..Default::default()
+        }
+    }
+}
+
+/// Names of the interfaces that are up, loopback excluded
+pub fn interfaces() -> Vec<String> {
+    datalink::interfaces()
+        .into_iter()
+        .filter(|i| i.is_up() && !i.is_loopback())
+        .map(|i| i.name)
+        .collect()
+}
+
+/// Listen on `interface` for `timeout` and collect the WhatsMiners that announce themselves
+pub fn scan_lldp(interface: &str, timeout: Duration) -> Result<Vec<LldpDiscovery>, String> {
+    let iface = datalink::interfaces()
+        .into_iter()
+        .find(|i| i.name == interface)
+        .ok_or_else(|| format!("No interface {interface}"))?;
+    let config = datalink::Config {
+        read_timeout: Some(Duration::from_millis(500)),
+        ..Default::default()
+    };
+    let mut rx = match datalink::channel(&iface, config) {
+        Ok(Channel::Ethernet(_, rx)) => rx,
+        Ok(_) => return Err(format!("{interface} is not an Ethernet interface")),
+        Err(e) => return Err(format!("Raw socket on {interface}: {e}")),
+    };
+
+    let deadline = Instant::now() + timeout;
+    let mut found: Vec<LldpDiscovery> = Vec::new();
+    while Instant::now() < deadline {
+        match rx.next() {
+            Ok(frame) => {
+                if let Some(miner) = parse_lldp_frame(frame).filter(LldpDiscovery::is_whatsminer)
+                    && !found.iter().any(|m| m.ip == miner.ip)
+                {
+                    found.push(miner);
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => {}
+            Err(e) => return Err(format!("Reading {interface}: {e}")),
+        }
+    }
+    Ok(found)
+}
+
+/// `scan_lldp` on a blocking thread
+pub async fn scan(interface: String) -> Result<Vec<LldpDiscovery>, String> {
+    tokio::task::spawn_blocking(move || scan_lldp(&interface, Duration::from_secs(LLDP_SCAN_SECS)))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Device behind an Ethernet frame, if it's an LLDP frame with a management address
+pub fn parse_lldp_frame(frame: &[u8]) -> Option<LldpDiscovery> {
+    let mut ethertype = u16::from_be_bytes([*frame.get(12)?, *frame.get(13)?]);
+    let mut offset = 14;
+    if ethertype == ETHERTYPE_VLAN {
+        ethertype = u16::from_be_bytes([*frame.get(16)?, *frame.get(17)?]);
+        offset = 18;
+    }
+    if ethertype != ETHERTYPE_LLDP {
+        return None;
+    }
+
+    let mut ip = None;
+    let mut hostname = String::new();
+    let mut port_id = String::new();
+    let mut port_description = String::new();
+    let mut tlvs = frame.get(offset..)?;
+    while tlvs.len() >= 2 {
+        // 7-bit type, 9-bit length
+        let kind = tlvs[0] >> 1;
+        let len = (usize::from(tlvs[0] & 1) << 8) | usize::from(tlvs[1]);
+        let value = tlvs.get(2..2 + len)?;
+        match kind {
+            TLV_END => break,
+            TLV_CHASSIS_ID if value.first() == Some(&CHASSIS_NETWORK_ADDRESS) => {
+                ip = ip.or_else(|| parse_address(&value[1..]));
+            }
+            TLV_PORT_ID if value.first().is_some_and(|s| PORT_ID_TEXT.contains(s)) => {
+                port_id = text(&value[1..]);
+            }
+            TLV_PORT_DESCRIPTION => port_description = text(value),
+            TLV_SYSTEM_NAME => hostname = text(value),
+            // Address string length (family byte included), then the address
+            TLV_MANAGEMENT_ADDRESS => {
+                let addr_len = usize::from(*value.first()?);
+                if let Some(addr) = value.get(1..1 + addr_len).and_then(parse_address) {
+                    ip = Some(addr);
+                }
+            }
+            _ => {}
+        }
+        tlvs = &tlvs[2 + len..];
+    }
+
+    Some(LldpDiscovery {
+        ip: ip?,
+        hostname,
+        port: if port_description.is_empty() {
+            port_id
+        } else {
+            port_description
+        },
+    })
+}
+
+/// IANA address family byte followed by the address
+fn parse_address(bytes: &[u8]) -> Option<String> {
+    let (family, addr) = bytes.split_first()?;
+    match *family {
+        FAMILY_IPV4 => {
+            let octets: [u8; 4] = addr.try_into().ok()?;
+            Some(Ipv4Addr::from(octets).to_string())
+        }
+        FAMILY_IPV6 => {
+            let octets: [u8; 16] = addr.try_into().ok()?;
+            Some(Ipv6Addr::from(octets).to_string())
+        }
+        _ => None,
+    }
+}
+
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tlv(kind: u8, value: &[u8]) -> Vec<u8> {
+        let len = value.len();
+        let mut out = vec![(kind << 1) | (len >> 8) as u8, len as u8];
+        out.extend_from_slice(value);
+        out
+    }
+
+    fn lldp_frame(tlvs: &[Vec<u8>]) -> Vec<u8> {
+        let mut frame = vec![0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e];
+        frame.extend_from_slice(&[0xc8, 0x33, 0x4b, 0x01, 0x02, 0x03]);
+        frame.extend_from_slice(&ETHERTYPE_LLDP.to_be_bytes());
+        for t in tlvs {
+            frame.extend_from_slice(t);
+        }
+        frame.extend_from_slice(&[0, 0]);
+        frame
+    }
+
+    #[test]
+    fn test_parse_lldp_frame() {
+        let frame = lldp_frame(&[
+            tlv(TLV_CHASSIS_ID, &[4, 0xc8, 0x33, 0x4b, 0x01, 0x02, 0x03]),
+            tlv(TLV_PORT_ID, &[5, b'e', b't', b'h', b'0']),
+            tlv(3, &[0, 120]),
+            tlv(TLV_SYSTEM_NAME, b"WhatsMiner-M50S"),
+            tlv(
+                TLV_MANAGEMENT_ADDRESS,
+                &[5, 1, 10, 0, 3, 21, 2, 0, 0, 0, 1, 0],
+            ),
+        ]);
+        let miner = parse_lldp_frame(&frame).unwrap();
+        assert_eq!(
+            miner,
+            LldpDiscovery {
+                ip: "10.0.3.21".into(),
+                hostname: "WhatsMiner-M50S".into(),
+                port: "eth0".into(),
+            }
+        );
+        assert!(miner.is_whatsminer());
+
+        // Port description wins over the port ID; the chassis ID can carry the address
+        let frame = lldp_frame(&[
+            tlv(TLV_CHASSIS_ID, &[5, 1, 192, 168, 1, 10]),
+            tlv(TLV_PORT_ID, &[3, 0xc8, 0x33, 0x4b, 0x01, 0x02, 0x03]),
+            tlv(TLV_PORT_DESCRIPTION, b"Rack 4 / U12"),
+            tlv(TLV_SYSTEM_NAME, b"core-sw-01"),
+        ]);
+        let switch = parse_lldp_frame(&frame).unwrap();
+        assert_eq!(switch.ip, "192.168.1.10");
+        assert_eq!(switch.port, "Rack 4 / U12");
+        assert!(!switch.is_whatsminer());
+
+        // No address to connect to, truncated TLV, not LLDP
+        assert_eq!(
+            parse_lldp_frame(&lldp_frame(&[tlv(TLV_SYSTEM_NAME, b"WhatsMiner")])),
+            None
+        );
+        let mut truncated = lldp_frame(&[tlv(TLV_CHASSIS_ID, &[5, 1, 192, 168, 1, 10])]);
+        truncated.truncate(20);
+        assert_eq!(parse_lldp_frame(&truncated), None);
+        let mut ipv4 = frame.clone();
+        ipv4[12..14].copy_from_slice(&0x0800u16.to_be_bytes());
+        assert_eq!(parse_lldp_frame(&ipv4), None);
+    }
+}
@@ -0,0 +1,1409 @@
+//! Saving and loading chip data snapshots as JSON, PDF miner reports, RMA health
+//! certificates, CSV chip exports, throttle histories, alert logs and learned
+//! temperature baselines
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use iced::Color;
+use printpdf::{
+    BuiltinFont, Mm, Op, PdfDocument, PdfFontHandle, PdfPage, PdfSaveOptions, Pt, RawImage,
+    RawImageData, RawImageFormat, TextItem, XObjectTransform,
+};
+use sha2::{Digest, Sha256};
+
+use crate::alerts::AlertEvent;
+use crate::analysis::{
+    self, AnalysisConfig, ChipAnalysis, RunningMeanVar, TempBaseline, ThrottleStatus,
+};
+use crate::config::{self, MinerConfig};
+use crate::layout::ChipLayout;
+use crate::metrics;
+use crate::models::{ColorMode, MinerData, Slot, SystemInfo};
+use crate::notes::RackPosition;
+use crate::theme;
+use crate::ui;
+
+// A4 portrait
+const PAGE_WIDTH_MM: f32 = 210.0;
+const PAGE_HEIGHT_MM: f32 = 297.0;
+const MARGIN_MM: f32 = 20.0;
+const LINE_MM: f32 = 6.0;
+
+// Chip map raster: pixels per chip cell, gap between cells and between board sections
+const RASTER_CELL_PX: usize = 12;
+const RASTER_GAP_PX: usize = 2;
+const RASTER_SECTION_GAP_PX: usize = 6;
+const CHIP_MAP_MAX_HEIGHT_MM: f32 = 120.0;
+
+const WORST_CHIPS: usize = 10;
+/// Worst chips listed per slot in the RMA health certificate
+const HEALTH_CERT_WORST_CHIPS: usize = 5;
+/// Domains whose mean chip health is below this are listed as problematic
+const PROBLEM_DOMAIN_HEALTH: f32 = 60.0;
+/// Slots with at least this share of dead chips need a board repair
+const BOARD_REPAIR_DEAD_RATIO: f32 = 0.1;
+const SERVICE_HEALTH: f32 = 80.0;
+
+/// Ask for a destination and write `data` as JSON
+/// Returns Ok(None) if the dialog was cancelled
+pub async fn save_json(data: MinerData, default_name: String) -> Result<Option<PathBuf>, String> {
+    let json = serde_json::to_string_pretty(&data).map_err(|e| e.to_string())?;
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("JSON", &["json"])
+        .set_file_name(default_name)
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let path = file.path().to_path_buf();
+    tokio::fs::write(&path, json)
+        .await
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(Some(path))
+}
+
+/// Ask the user to pick a JSON snapshot
+pub async fn pick_json() -> Option<PathBuf> {
+    pick_file("JSON", &["json"]).await
+}
+
+/// Ask the user to pick a file matching `extensions`
+pub async fn pick_file(filter_name: &str, extensions: &[&str]) -> Option<PathBuf> {
+    rfd::AsyncFileDialog::new()
+        .add_filter(filter_name, extensions)
+        .pick_file()
+        .await
+        .map(|file| file.path().to_path_buf())
+}
+
+/// Ask for a destination file matching `extensions`
+pub async fn pick_save_file(
+    filter_name: &str,
+    extensions: &[&str],
+    default_name: String,
+) -> Option<PathBuf> {
+    rfd::AsyncFileDialog::new()
+        .add_filter(filter_name, extensions)
+        .set_file_name(default_name)
+        .save_file()
+        .await
+        .map(|file| file.path().to_path_buf())
+}
+
+/// Ask for a file and read it as text; Err("") if the dialog was cancelled
+pub async fn pick_and_read(filter_name: &str, extensions: &[&str]) -> Result<String, String> {
+    let path = pick_file(filter_name, extensions)
+        .await
+        .ok_or_else(String::new)?;
+    tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Read a snapshot written by `save_json`
+pub async fn load_json(path: &Path) -> Result<MinerData, String> {
+    let json = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    parse_json(&json)
+}
+
+fn parse_json(json: &str) -> Result<MinerData, String> {
+    let data: MinerData = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    if data.slots.is_empty() {
+        return Err("No slots found".into());
+    }
+    Ok(data)
+}
+
+/// Ask for a destination and write a PDF report of the miner, from the app's
+/// live `analyses` so health matches the chip map
+/// Returns Ok(None) if the dialog was cancelled
+pub async fn save_pdf_report(
+    data: MinerData,
+    info: Option<SystemInfo>,
+    rack_positions: HashMap<i32, RackPosition>,
+    ip: String,
+    fetched_at: DateTime<Local>,
+    analyses: Vec<Vec<ChipAnalysis>>,
+) -> Result<Option<PathBuf>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("PDF", &["pdf"])
+        .set_file_name(format!("{}.pdf", ip.replace(['.', ':'], "_")))
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let path = file.path().to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        generate_pdf_report(
+            &data,
+            info.as_ref(),
+            &analyses,
+            &rack_positions,
+            &ip,
+            fetched_at,
+            &path,
+        )?;
+        Ok(Some(path))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Ask for a destination and write an RMA health certificate of the miner, from
+/// the app's live `analyses` so its figures match the board on screen
+/// Returns Ok(None) if the dialog was cancelled
+pub async fn save_health_cert(
+    data: MinerData,
+    info: Option<SystemInfo>,
+    ip: String,
+    fetched_at: DateTime<Local>,
+    analyses: Vec<Vec<ChipAnalysis>>,
+) -> Result<Option<PathBuf>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("Text", &["txt"])
+        .set_file_name(format!("{}_rma.txt", ip.replace(['.', ':'], "_")))
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let path = file.path().to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        generate_health_cert(&data, info.as_ref(), &analyses, &ip, fetched_at, &path)?;
+        Ok(Some(path))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Ask for a destination and write the selected chips as CSV, with the app's
+/// live `analyses` so session-derived fields (error rates, trends…) are filled in
+/// Returns Ok(None) if the dialog was cancelled
+pub async fn save_selection_csv(
+    data: MinerData,
+    info: Option<SystemInfo>,
+    ip: String,
+    selection: Vec<(i32, usize)>,
+    slot_names: HashMap<i32, String>,
+    analyses: Vec<Vec<ChipAnalysis>>,
+) -> Result<Option<PathBuf>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("CSV", &["csv"])
+        .set_file_name(format!("{}_selection.csv", ip.replace(['.', ':'], "_")))
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let path = file.path().to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let cpd = ui::chips_per_domain_for(&data, info.as_ref().and_then(config::lookup_system));
+        export_selected_chips(&data, &selection, &slot_names, &analyses, cpd, &path)?;
+        Ok(Some(path))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Ask for a destination and write one CSV row per slot, with mean health from
+/// the app's live `analyses`
+/// Returns Ok(None) if the dialog was cancelled
+pub async fn save_slot_summary_csv(
+    data: MinerData,
+    info: Option<SystemInfo>,
+    ip: String,
+    slot_names: HashMap<i32, String>,
+    analyses: Vec<Vec<ChipAnalysis>>,
+    analysis_config: AnalysisConfig,
+) -> Result<Option<PathBuf>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("CSV", &["csv"])
+        .set_file_name(format!("{}_slots.csv", ip.replace(['.', ':'], "_")))
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let path = file.path().to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let cpd = ui::chips_per_domain_for(&data, info.as_ref().and_then(config::lookup_system));
+        export_slot_summary(&data, &slot_names, &analyses, cpd, &analysis_config, &path)?;
+        Ok(Some(path))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Write one CSV row per slot: board readings, chip counts, mean chip health,
+/// thermal uniformity and the bottom/top section stats. Unnamed slots are "Slot {id}".
+#[allow(clippy::cast_precision_loss)] // chip counts fit in f32
+pub fn export_slot_summary(
+    data: &MinerData,
+    slot_names: &HashMap<i32, String>,
+    analyses: &[Vec<ChipAnalysis>],
+    chips_per_domain: usize,
+    analysis_config: &AnalysisConfig,
+    path: &Path,
+) -> Result<(), String> {
+    let section_columns = |section: &str| {
+        [
+            "chips",
+            "mean_temp",
+            "mean_nonce",
+            "dead_chips",
+            "gradient_outliers",
+        ]
+        .map(|field| format!(",{section}_{field}"))
+        .concat()
+    };
+    let mut csv = format!(
+        "slot_id,slot_name,freq,temp,chips,active_chips,dead_chips,mean_health,\
+         temperature_range,coefficient_of_variation,hot_side_bias,vertical_gradient,uniformity\
+         {}{}\n",
+        section_columns("bottom"),
+        section_columns("top"),
+    );
+    let section_row = |s: analysis::SectionStats| {
+        format!(
+            ",{},{:.1},{:.0},{},{}",
+            s.chip_count, s.mean_temp, s.mean_nonce, s.dead_chips, s.gradient_outliers
+        )
+    };
+    for (slot_idx, slot) in data.slots.iter().enumerate() {
+        let slot_name = slot_names
+            .get(&slot.id)
+            .map_or_else(|| format!("Slot {}", slot.id), |name| csv_field(name));
+        let mean_health = analyses
+            .get(slot_idx)
+            .filter(|a| !a.is_empty())
+            .map(|a| a.iter().map(|c| c.health_score).sum::<f32>() / a.len() as f32)
+            .unwrap_or_default();
+        let uniformity = analysis::compute_thermal_uniformity(slot, chips_per_domain);
+        let (bottom, top) =
+            analysis::compute_section_stats(slot, chips_per_domain, analysis_config);
+        csv.push_str(&format!(
+            "{},{slot_name},{},{},{},{},{},{mean_health:.1},{},{:.2},{:.2},{:.2},{:.1}{}{}\n",
+            slot.id,
+            slot.freq,
+            slot.temp,
+            slot.chips.len(),
+            analysis::active_chip_count(slot),
+            slot.chips.iter().filter(|c| c.is_dead()).count(),
+            uniformity.temperature_range,
+            uniformity.coefficient_of_variation,
+            uniformity.hot_side_bias,
+            uniformity.vertical_gradient,
+            uniformity.score(),
+            section_row(bottom),
+            section_row(top),
+        ));
+    }
+    std::fs::write(path, csv).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Ask for a destination and write the throttle history of the monitoring session
+/// Returns Ok(None) if the dialog was cancelled
+pub async fn save_throttle_csv(
+    history: Vec<(DateTime<Local>, Vec<ThrottleStatus>)>,
+    ip: String,
+) -> Result<Option<PathBuf>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("CSV", &["csv"])
+        .set_file_name(format!("{}_throttle.csv", ip.replace(['.', ':'], "_")))
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let path = file.path().to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        export_throttle_history(&history, &path)?;
+        Ok(Some(path))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Write one CSV row per slot per poll: when it was taken, the slot clock and
+/// whether (and how far, in % below the session peak) the slot was throttled
+pub fn export_throttle_history(
+    history: &[(DateTime<Local>, Vec<ThrottleStatus>)],
+    path: &Path,
+) -> Result<(), String> {
+    let mut csv = String::from("timestamp,slot_id,freq,throttled,severity_pct\n");
+    for (at, statuses) in history {
+        for status in statuses {
+            csv.push_str(&format!(
+                "{},{},{},{},{:.1}\n",
+                at.format("%Y-%m-%d %H:%M:%S"),
+                status.slot_id,
+                status.freq,
+                status.is_throttled(),
+                status.severity_pct
+            ));
+        }
+    }
+    std::fs::write(path, csv).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Write the alert log to `path`; Ok(Some(path)) once written
+pub async fn save_alert_log(
+    events: Vec<AlertEvent>,
+    path: PathBuf,
+) -> Result<Option<PathBuf>, String> {
+    tokio::task::spawn_blocking(move || {
+        export_alert_log(&events, &path)?;
+        Ok(Some(path))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Write one CSV row per alert, oldest first; board alerts have no chip id
+pub fn export_alert_log(events: &[AlertEvent], path: &Path) -> Result<(), String> {
+    let mut csv = String::from("timestamp,severity,miner_ip,slot_id,chip_id,message\n");
+    for event in events {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            event.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            event.severity.as_str(),
+            event.miner_ip,
+            event.slot_id,
+            event.chip_id.map(|id| id.to_string()).unwrap_or_default(),
+            csv_field(&event.message),
+        ));
+    }
+    std::fs::write(path, csv).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Write one CSV row per selected (slot id, chip index): slot name, board position,
+/// raw chip fields and the chip's analysis; selections that no longer match a chip
+/// are skipped. Unnamed slots are "Slot {id}".
+pub fn export_selected_chips(
+    data: &MinerData,
+    selection: &[(i32, usize)],
+    slot_names: &HashMap<i32, String>,
+    analyses: &[Vec<ChipAnalysis>],
+    chips_per_domain: usize,
+    path: &Path,
+) -> Result<(), String> {
+    let cpd = chips_per_domain.max(1);
+    let mut csv = format!("{CHIP_CSV_HEADER}\n");
+    for &(slot_id, idx) in selection {
+        let Some((slot_idx, slot)) = data.slots.iter().enumerate().find(|(_, s)| s.id == slot_id)
+        else {
+            continue;
+        };
+        if idx >= slot.chips.len() {
+            continue;
+        }
+        let segments = analysis::attribute_crc_to_segments(&slot.chips, cpd);
+        let a = analyses
+            .get(slot_idx)
+            .and_then(|slot_analysis| slot_analysis.get(idx))
+            .copied()
+            .unwrap_or_default();
+        csv.push_str(&chip_csv_row(slot, idx, slot_names, &a, &segments, cpd));
+        csv.push('\n');
+    }
+    std::fs::write(path, csv).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Ask for a destination and write every chip's spec comparison as CSV, with
+/// the app's live `analyses`
+/// Returns Ok(None) if the dialog was cancelled
+pub async fn save_chip_comparison_csv(
+    data: MinerData,
+    info: Option<SystemInfo>,
+    ip: String,
+    slot_names: HashMap<i32, String>,
+    analyses: Vec<Vec<ChipAnalysis>>,
+) -> Result<Option<PathBuf>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("CSV", &["csv"])
+        .set_file_name(format!("{}_chip_spec.csv", ip.replace(['.', ':'], "_")))
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let path = file.path().to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let config = info.as_ref().and_then(config::lookup_system);
+        export_chip_comparison_csv(&data, config, &slot_names, &analyses, &path)?;
+        Ok(Some(path))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Write one CSV row per chip: the selection CSV's columns, then how the chip
+/// compares to the model spec (see `metrics::compare_chip_to_spec`). Rated columns
+/// are empty for unknown models.
+pub fn export_chip_comparison_csv(
+    data: &MinerData,
+    config: Option<&MinerConfig>,
+    slot_names: &HashMap<i32, String>,
+    analyses: &[Vec<ChipAnalysis>],
+    path: &Path,
+) -> Result<(), String> {
+    let cpd = ui::chips_per_domain_for(data, config).max(1);
+    let mut csv = format!(
+        "{CHIP_CSV_HEADER},rated_freq,freq_vs_rated_pct,expected_nonce,\
+         estimated_nonce_at_rated_freq,typical_temp,temp_vs_typical,performance_vs_spec_pct\n"
+    );
+    let optional = |value: Option<String>| value.unwrap_or_default();
+    for (slot_idx, slot) in data.slots.iter().enumerate() {
+        let segments = analysis::attribute_crc_to_segments(&slot.chips, cpd);
+        for (idx, chip) in slot.chips.iter().enumerate() {
+            let a = analyses
+                .get(slot_idx)
+                .and_then(|slot_analysis| slot_analysis.get(idx))
+                .copied()
+                .unwrap_or_default();
+            let spec = metrics::compare_chip_to_spec(slot, chip, config);
+            csv.push_str(&format!(
+                "{},{},{},{:.0},{},{},{},{}\n",
+                chip_csv_row(slot, idx, slot_names, &a, &segments, cpd),
+                optional(spec.rated_freq.map(|f| f.to_string())),
+                optional(spec.freq_vs_rated_pct.map(|p| format!("{p:.1}"))),
+                spec.expected_nonce,
+                optional(
+                    spec.estimated_nonce_at_rated_freq
+                        .map(|n| format!("{n:.0}"))
+                ),
+                metrics::TYPICAL_CHIP_TEMP,
+                spec.temp_vs_typical,
+                optional(spec.performance_vs_spec_pct.map(|p| format!("{p:.1}"))),
+            ));
+        }
+    }
+    std::fs::write(path, csv).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+const CHIP_CSV_HEADER: &str = "slot_id,slot_name,chip_idx,domain_idx,row_idx,chip_id,freq,vol,temp,\
+     nonce,errors,crc,x,repeat,pct1,pct2,gradient,gradient_significance,cross_slot_zscore,\
+     nonce_deficit,health_score,nonce_per_mhz_zscore,error_rate_per_hour,\
+     crc_neighbor_correlation,freq_noncompliant,cross_miner_zscore,temp_anomaly_score,\
+     data_staleness_polls,ir_temp,efficiency_trend,voltage_group,dead_density,power_state,\
+     heat_cycles,break_after_chip";
+
+/// CHIP_CSV_HEADER columns of the chip at `idx` (no line break): slot name, board
+/// position, raw chip fields, the chip's analysis and the suspected bad bus link in
+/// its domain, from `segments`. Unnamed slots are "Slot {id}".
+fn chip_csv_row(
+    slot: &Slot,
+    idx: usize,
+    slot_names: &HashMap<i32, String>,
+    a: &ChipAnalysis,
+    segments: &[analysis::SegmentHealth],
+    cpd: usize,
+) -> String {
+    let chip = &slot.chips[idx];
+    let break_after = segments
+        .get(idx / cpd)
+        .and_then(|s| s.break_likely_after)
+        .map(|i| i.to_string())
+        .unwrap_or_default();
+    let slot_name = slot_names
+        .get(&slot.id)
+        .map_or_else(|| format!("Slot {}", slot.id), |name| csv_field(name));
+    format!(
+        "{},{slot_name},{idx},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{break_after}",
+        slot.id,
+        idx / cpd,
+        idx % cpd,
+        chip.id,
+        chip.freq,
+        chip.vol,
+        chip.temp,
+        chip.nonce,
+        chip.errors,
+        chip.crc,
+        chip.x,
+        chip.repeat,
+        chip.pct1,
+        chip.pct2,
+        a.gradient,
+        a.gradient_significance,
+        a.cross_slot_zscore,
+        a.nonce_deficit,
+        a.health_score,
+        a.nonce_per_mhz_zscore,
+        a.error_rate_per_hour,
+        a.crc_neighbor_correlation,
+        a.freq_noncompliant,
+        a.cross_miner_zscore,
+        a.temp_anomaly_score,
+        a.data_staleness_polls,
+        a.ir_temp.map(|t| format!("{t:.1}")).unwrap_or_default(),
+        a.efficiency_trend,
+        a.voltage_group,
+        a.dead_density,
+        a.power_state.key(),
+        a.heat_cycles,
+    )
+}
+
+/// Quote a CSV field if it holds a comma, quote or line break ("" for a literal quote)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+const BASELINE_CSV_HEADER: &str = "slot_id,chip_id,polls,mean_temp,variance";
+
+/// Ask for a destination and write the temperature baseline as CSV
+/// Returns Ok(None) if the dialog was cancelled
+pub async fn save_baseline_csv(
+    baseline: TempBaseline,
+    ip: String,
+) -> Result<Option<PathBuf>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("CSV", &["csv"])
+        .set_file_name(format!("{}_baseline.csv", ip.replace(['.', ':'], "_")))
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let path = file.path().to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        export_baseline(&baseline, &path)?;
+        Ok(Some(path))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Write one CSV row per chip position of the baseline, sorted by slot and chip
+pub fn export_baseline(baseline: &TempBaseline, path: &Path) -> Result<(), String> {
+    let mut positions: Vec<_> = baseline.iter().collect();
+    positions.sort_by_key(|&(&key, _)| key);
+    let mut csv = format!("{BASELINE_CSV_HEADER}\n");
+    for (&(slot_id, chip_id), stats) in positions {
+        csv.push_str(&format!(
+            "{slot_id},{chip_id},{},{},{}\n",
+            stats.count,
+            stats.mean,
+            stats.variance()
+        ));
+    }
+    std::fs::write(path, csv).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Parse a baseline written by `export_baseline`
+pub fn import_baseline(content: &str) -> Result<TempBaseline, String> {
+    let mut baseline = TempBaseline::new();
+    for (line_no, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line == BASELINE_CSV_HEADER {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let invalid = || format!("Line {}: expected {BASELINE_CSV_HEADER}", line_no + 1);
+        let [slot_id, chip_id, polls, mean, variance] = fields[..] else {
+            return Err(invalid());
+        };
+        let stats = RunningMeanVar::from_parts(
+            polls.parse().map_err(|_| invalid())?,
+            mean.parse().map_err(|_| invalid())?,
+            variance.parse().map_err(|_| invalid())?,
+        );
+        baseline.insert(
+            (
+                slot_id.parse().map_err(|_| invalid())?,
+                chip_id.parse().map_err(|_| invalid())?,
+            ),
+            stats,
+        );
+    }
+    Ok(baseline)
+}
+
+/// Write a PDF report: an executive summary page with the slots' rack positions,
+/// then one page per slot with its chip map (temperature colors, as on screen)
+/// and its worst chips
+///
+/// The report is in English: the built-in PDF fonts only cover Latin-1.
+pub fn generate_pdf_report(
+    data: &MinerData,
+    info: Option<&SystemInfo>,
+    analyses: &[Vec<ChipAnalysis>],
+    rack_positions: &HashMap<i32, RackPosition>,
+    ip: &str,
+    fetched_at: DateTime<Local>,
+    path: &Path,
+) -> Result<(), String> {
+    let cpd = ui::chips_per_domain_for(data, info.and_then(config::lookup_system));
+    let mut doc = PdfDocument::new(&format!("Miner report {ip}"));
+    let mut pages = vec![summary_page(
+        data,
+        info,
+        analyses,
+        rack_positions,
+        ip,
+        fetched_at,
+        cpd,
+    )];
+
+    for (slot_idx, slot) in data.slots.iter().enumerate() {
+        let slot_analysis = analyses.get(slot_idx).map_or(&[][..], Vec::as_slice);
+        let mut page = PageWriter::new();
+        page.heading(&format!("Slot {}", slot.id));
+        page.line(&format!(
+            "{} chips, {} MHz, {:.1} °C board",
+            slot.chips.len(),
+            slot.freq,
+            slot.temp
+        ));
+        if let Some(position) = rack_positions.get(&slot.id) {
+            page.line(&position.to_string());
+        }
+
+        let (image, width_px, height_px) = rasterize_chip_map(slot, slot_analysis, cpd);
+        if width_px > 0 && height_px > 0 {
+            let id = doc.add_image(&image);
+            page.image(id, width_px, height_px);
+        }
+
+        page.gap();
+        page.subheading(&format!("{WORST_CHIPS} worst chips"));
+        page.table_row(
+            &["Slot", "ID", "Temp", "Errors", "Nonce deficit", "Health"],
+            true,
+        );
+        for idx in worst_chips(slot, slot_analysis, WORST_CHIPS) {
+            let chip = &slot.chips[idx];
+            let a = slot_analysis[idx];
+            page.table_row(
+                &[
+                    &slot.id.to_string(),
+                    &chip.id.to_string(),
+                    &format!("{} °C", chip.temp),
+                    &chip.errors.to_string(),
+                    &format!("{:.0}%", a.nonce_deficit.max(0.0)),
+                    &format!("{:.0}", a.health_score),
+                ],
+                false,
+            );
+        }
+        pages.push(page.finish());
+    }
+
+    let mut warnings = Vec::new();
+    let bytes = doc
+        .with_pages(pages)
+        .save(&PdfSaveOptions::default(), &mut warnings);
+    std::fs::write(path, bytes).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn summary_page(
+    data: &MinerData,
+    info: Option<&SystemInfo>,
+    analyses: &[Vec<ChipAnalysis>],
+    rack_positions: &HashMap<i32, RackPosition>,
+    ip: &str,
+    fetched_at: DateTime<Local>,
+    cpd: usize,
+) -> PdfPage {
+    let health = analysis::overall_health(&data.slots, analyses).unwrap_or(0.0);
+    let dead = data.dead_chips();
+    let domains = problem_domains(data, analyses, cpd);
+
+    let mut page = PageWriter::new();
+    page.heading("Miner report");
+    page.line(&format!("Miner: {ip}"));
+    if let Some(info) = info {
+        page.line(&format!("Model: {}", info.model));
+        page.line(&format!("Firmware: {}", info.firmware_version));
+    }
+    page.line(&format!(
+        "Fetched: {}",
+        fetched_at.format("%Y-%m-%d %H:%M:%S")
+    ));
+    page.gap();
+    page.line(&format!("Overall health: {health:.0}%"));
+    page.line(&format!("Dead chips: {dead} of {}", data.total_chips()));
+    page.line(&format!(
+        "Problematic domains: {}",
+        if domains.is_empty() {
+            "none".to_string()
+        } else {
+            domains
+                .iter()
+                .map(|(slot_id, domain)| format!("S{slot_id} D{domain}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    ));
+    page.gap();
+    page.subheading("Recommended action");
+    page.line(&recommended_action(data, health));
+
+    if !rack_positions.is_empty() {
+        let mut placed: Vec<_> = rack_positions.iter().collect();
+        placed.sort_unstable_by_key(|(slot_id, _)| **slot_id);
+        page.gap();
+        page.subheading("Rack location");
+        page.table_row(&["Slot", "Rack", "Row", "Position"], true);
+        for (slot_id, position) in placed {
+            page.table_row(
+                &[
+                    &slot_id.to_string(),
+                    &position.rack_id,
+                    &position.row.to_string(),
+                    &position.position.to_string(),
+                ],
+                false,
+            );
+        }
+    }
+    page.finish()
+}
+
+/// (slot id, domain index) of domains whose mean health is below PROBLEM_DOMAIN_HEALTH
+#[allow(clippy::cast_precision_loss)] // chip counts fit in f32
+fn problem_domains(
+    data: &MinerData,
+    analyses: &[Vec<ChipAnalysis>],
+    cpd: usize,
+) -> Vec<(i32, usize)> {
+    let cpd = cpd.max(1);
+    let mut domains = Vec::new();
+    for (slot, slot_analysis) in data.slots.iter().zip(analyses) {
+        for (domain, chunk) in slot_analysis.chunks(cpd).enumerate() {
+            let mean = chunk.iter().map(|a| a.health_score).sum::<f32>() / chunk.len() as f32;
+            if mean < PROBLEM_DOMAIN_HEALTH {
+                domains.push((slot.id, domain));
+            }
+        }
+    }
+    domains
+}
+
+/// One-line service recommendation from dead chips and overall health
+#[allow(clippy::cast_precision_loss)] // chip counts fit in f32
+fn recommended_action(data: &MinerData, health: f32) -> String {
+    let failing: Vec<String> = data
+        .slots
+        .iter()
+        .filter(|slot| {
+            let dead = slot.chips.iter().filter(|c| c.is_dead()).count();
+            !slot.chips.is_empty()
+                && dead as f32 / slot.chips.len() as f32 >= BOARD_REPAIR_DEAD_RATIO
+        })
+        .map(|slot| slot.id.to_string())
+        .collect();
+
+    if !failing.is_empty() {
+        format!(
+            "Repair or replace hashboard(s) in slot {}",
+            failing.join(", ")
+        )
+    } else if data.dead_chips() > 0 {
+        "Schedule a board inspection; keep watching the dead chips".into()
+    } else if health < SERVICE_HEALTH {
+        "Check cooling: clean heatsinks and verify fans and airflow".into()
+    } else {
+        "No action needed".into()
+    }
+}
+
+/// Chip indices sorted by health, worst first; placeholders for ids the firmware
+/// didn't report are left out
+fn worst_chips(slot: &Slot, slot_analysis: &[ChipAnalysis], count: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..slot_analysis.len())
+        .filter(|&idx| slot.chips.get(idx).is_some_and(|c| !c.placeholder))
+        .collect();
+    indices.sort_by(|&a, &b| {
+        slot_analysis[a]
+            .health_score
+            .total_cmp(&slot_analysis[b].health_score)
+    });
+    indices.truncate(count);
+    indices
+}
+
+/// Plain-text board health certificate for warranty (RMA) claims: miner, fetch
+/// time, per-slot chip counts, the worst chips, every dead chip id, overall health
+/// and the `chip_data_hash` of the readings
+///
+/// The hash is unkeyed: it shows the readings weren't edited after the fact, as
+/// long as the receiver re-hashes them from the JSON export, but it doesn't prove
+/// who wrote the file.
+pub fn generate_health_cert(
+    data: &MinerData,
+    info: Option<&SystemInfo>,
+    analyses: &[Vec<ChipAnalysis>],
+    ip: &str,
+    fetched_at: DateTime<Local>,
+    path: &Path,
+) -> Result<(), String> {
+    let heading = |doc: &mut String, title: &str| {
+        doc.push_str(&format!(
+            "\n{title}\n{}\n",
+            "-".repeat(title.chars().count())
+        ));
+    };
+    let mut doc = String::from("BOARD HEALTH CERTIFICATE\n========================\n\n");
+    doc.push_str(&format!("Miner:          {ip}\n"));
+    if let Some(info) = info {
+        doc.push_str(&format!("Model:          {}\n", info.model));
+        doc.push_str(&format!("Firmware:       {}\n", info.firmware_version));
+    }
+    doc.push_str(&format!(
+        "Fetched:        {}\n",
+        fetched_at.format("%Y-%m-%d %H:%M:%S %:z")
+    ));
+    doc.push_str(&format!(
+        "Generated:      {}\n",
+        Local::now().format("%Y-%m-%d %H:%M:%S %:z")
+    ));
+    doc.push_str(&format!(
+        "Overall health: {:.0}%\n",
+        analysis::overall_health(&data.slots, analyses).unwrap_or(0.0)
+    ));
+    doc.push_str(&format!(
+        "Dead chips:     {} of {}\n",
+        data.dead_chips(),
+        data.slots
+            .iter()
+            .flat_map(|s| &s.chips)
+            .filter(|c| !c.placeholder)
+            .count()
+    ));
+
+    heading(&mut doc, "Slots");
+    doc.push_str("Slot   Chips  Active   Dead   Freq  Board temp\n");
+    for slot in &data.slots {
+        doc.push_str(&format!(
+            "{:<6} {:>5}  {:>6}  {:>5}  {:>5}  {:>8.1} °C\n",
+            slot.id,
+            slot.chips.iter().filter(|c| !c.placeholder).count(),
+            analysis::active_chip_count(slot),
+            slot.chips.iter().filter(|c| c.is_dead()).count(),
+            slot.freq,
+            slot.temp
+        ));
+    }
+
+    heading(&mut doc, "Worst chips (lowest health)");
+    doc.push_str("Slot   Chip    Temp  Errors    CRC  Nonce deficit  Health\n");
+    for (slot, slot_analysis) in data.slots.iter().zip(analyses) {
+        for idx in worst_chips(slot, slot_analysis, HEALTH_CERT_WORST_CHIPS) {
+            let chip = &slot.chips[idx];
+            let a = slot_analysis[idx];
+            doc.push_str(&format!(
+                "{:<6} {:>4}  {:>3} °C  {:>6}  {:>5}  {:>12.0}%  {:>6.0}\n",
+                slot.id,
+                chip.id,
+                chip.temp,
+                chip.errors,
+                chip.crc,
+                a.nonce_deficit.max(0.0),
+                a.health_score
+            ));
+        }
+    }
+
+    heading(&mut doc, "Dead chips (zero nonces)");
+    for slot in &data.slots {
+        let dead: Vec<String> = slot
+            .chips
+            .iter()
+            .filter(|c| c.is_dead())
+            .map(|c| c.id.to_string())
+            .collect();
+        doc.push_str(&format!(
+            "Slot {}: {}\n",
+            slot.id,
+            if dead.is_empty() {
+                "none".to_string()
+            } else {
+                dead.join(", ")
+            }
+        ));
+    }
+
+    heading(&mut doc, "Data hash");
+    doc.push_str(&format!("SHA-256: {}\n", chip_data_hash(data)));
+    doc.push_str(
+        "Covers every reported chip, slot by slot in log order, one line each:\n\
+         slot,id,freq,vol,temp,nonce,errors,crc,x,repeat,pct1,pct2\n",
+    );
+    std::fs::write(path, doc).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Hex SHA-256 of every numeric chip field, for tamper detection
+///
+/// Each chip the firmware reported (placeholders are skipped) adds a line
+/// `slot,id,freq,vol,temp,nonce,errors,crc,x,repeat,pct1,pct2\n`, in slot and
+/// chip order, with the floats in Rust's shortest round-trip form.
+pub fn chip_data_hash(data: &MinerData) -> String {
+    let mut hasher = Sha256::new();
+    for slot in &data.slots {
+        for chip in slot.chips.iter().filter(|c| !c.placeholder) {
+            hasher.update(format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                slot.id,
+                chip.id,
+                chip.freq,
+                chip.vol,
+                chip.temp,
+                chip.nonce,
+                chip.errors,
+                chip.crc,
+                chip.x,
+                chip.repeat,
+                chip.pct1,
+                chip.pct2
+            ));
+        }
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// RGB raster of a slot's chip map in the snake layout; returns (image, width, height)
+fn rasterize_chip_map(
+    slot: &crate::models::Slot,
+    slot_analysis: &[ChipAnalysis],
+    cpd: usize,
+) -> (RawImage, usize, usize) {
+    let layout = ChipLayout::new(slot.chips.len(), cpd.max(1));
+    let (columns, rows) = (layout.columns(), layout.rows());
+    let two_sections = layout.has_top_section();
+    let pitch = RASTER_CELL_PX + RASTER_GAP_PX;
+    let section_gap = if two_sections {
+        RASTER_SECTION_GAP_PX
+    } else {
+        0
+    };
+    let width = if slot.chips.is_empty() {
+        0
+    } else {
+        columns * pitch - RASTER_GAP_PX
+    };
+    let height = if slot.chips.is_empty() {
+        0
+    } else {
+        rows * pitch - RASTER_GAP_PX + section_gap
+    };
+
+    let mut pixels = vec![255u8; width * height * 3];
+    for (idx, chip) in slot.chips.iter().enumerate() {
+        let (col, row) = layout.position(idx);
+        let fill = if chip.is_dead() {
+            theme::mini_chip_fill(chip.temp, true)
+        } else {
+            // Temperature colors don't depend on the analysis thresholds
+            theme::chip_colors(
+                chip,
+                slot.freq,
+                ColorMode::Temperature,
+                slot_analysis.get(idx).copied(),
+                &AnalysisConfig::default(),
+            )
+            .0
+        };
+        let rgb = color_to_rgb8(fill);
+        let x0 = col * pitch;
+        let y0 = row * pitch + if row >= cpd { section_gap } else { 0 };
+        for y in y0..y0 + RASTER_CELL_PX {
+            for x in x0..x0 + RASTER_CELL_PX {
+                let offset = (y * width + x) * 3;
+                pixels[offset..offset + 3].copy_from_slice(&rgb);
+            }
+        }
+    }
+
+    let image = RawImage {
+        pixels: RawImageData::U8(pixels),
+        width,
+        height,
+        data_format: RawImageFormat::RGB8,
+        tag: Vec::new(),
+    };
+    (image, width, height)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // channels are 0.0..=1.0
+fn color_to_rgb8(color: Color) -> [u8; 3] {
+    let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    [channel(color.r), channel(color.g), channel(color.b)]
+}
+
+/// Top-to-bottom page layout: text lines, table rows and the chip map image
+struct PageWriter {
+    ops: Vec<Op>,
+    /// Baseline of the next line, from the page bottom
+    y_mm: f32,
+}
+
+impl PageWriter {
+    fn new() -> Self {
+        Self {
+            ops: Vec::new(),
+            y_mm: PAGE_HEIGHT_MM - MARGIN_MM,
+        }
+    }
+
+    fn text(&mut self, x_mm: f32, content: &str, font: BuiltinFont, size: f32) {
+        self.ops.extend([
+            Op::StartTextSection,
+            Op::SetFont {
+                font: PdfFontHandle::Builtin(font),
+                size: Pt(size),
+            },
+            Op::SetTextCursor {
+                pos: printpdf::Point::new(Mm(x_mm), Mm(self.y_mm)),
+            },
+            Op::ShowText {
+                items: vec![TextItem::Text(content.to_string())],
+            },
+            Op::EndTextSection,
+        ]);
+    }
+
+    fn heading(&mut self, content: &str) {
+        self.text(MARGIN_MM, content, BuiltinFont::HelveticaBold, 18.0);
+        self.y_mm -= LINE_MM * 2.0;
+    }
+
+    fn subheading(&mut self, content: &str) {
+        self.text(MARGIN_MM, content, BuiltinFont::HelveticaBold, 12.0);
+        self.y_mm -= LINE_MM;
+    }
+
+    fn line(&mut self, content: &str) {
+        self.text(MARGIN_MM, content, BuiltinFont::Helvetica, 11.0);
+        self.y_mm -= LINE_MM;
+    }
+
+    fn gap(&mut self) {
+        self.y_mm -= LINE_MM;
+    }
+
+    fn table_row(&mut self, cells: &[&str], header: bool) {
+        const COLUMN_MM: [f32; 6] = [0.0, 20.0, 40.0, 65.0, 90.0, 125.0];
+        let font = if header {
+            BuiltinFont::HelveticaBold
+        } else {
+            BuiltinFont::Helvetica
+        };
+        for (cell, x) in cells.iter().zip(COLUMN_MM) {
+            self.text(MARGIN_MM + x, cell, font, 10.0);
+        }
+        self.y_mm -= LINE_MM;
+    }
+
+    /// Place an image below the current line, scaled to the page width and CHIP_MAP_MAX_HEIGHT_MM
+    #[allow(clippy::cast_precision_loss)] // pixel sizes fit in f32
+    fn image(&mut self, id: printpdf::XObjectId, width_px: usize, height_px: usize) {
+        let max_width = Pt::from(Mm(PAGE_WIDTH_MM - 2.0 * MARGIN_MM)).0;
+        let max_height = Pt::from(Mm(CHIP_MAP_MAX_HEIGHT_MM)).0;
+        // At 72 dpi one pixel is one point
+        let scale = (max_width / width_px as f32).min(max_height / height_px as f32);
+        let height_mm = Mm::from(Pt(height_px as f32 * scale)).0;
+
+        self.y_mm -= height_mm;
+        self.ops.push(Op::UseXobject {
+            id,
+            transform: XObjectTransform {
+                translate_x: Some(Mm(MARGIN_MM).into()),
+                translate_y: Some(Mm(self.y_mm).into()),
+                scale_x: Some(scale),
+                scale_y: Some(scale),
+                dpi: Some(72.0),
+                ..Default::default()
+            },
+        });
+        self.y_mm -= LINE_MM;
+    }
+
+    fn finish(self) -> PdfPage {
+        PdfPage::new(Mm(PAGE_WIDTH_MM), Mm(PAGE_HEIGHT_MM), self.ops)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Chip, Slot};
+
+    #[test]
+    fn test_json_round_trip() {
+        let data = MinerData {
+            slots: vec![Slot {
+                id: 2,
+                chips: vec![Chip {
+                    id: 0,
+                    temp: 71,
+                    nonce: 1234,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&data).unwrap();
+        let loaded = parse_json(&json).unwrap();
+        assert_eq!(loaded.slots[0].id, 2);
+        assert_eq!(loaded.slots[0].chips[0].temp, 71);
+        assert_eq!(loaded.slots[0].chips[0].nonce, 1234);
+
+        assert!(parse_json(r#"{"slots": []}"#).is_err());
+        assert!(parse_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_pdf_report() {
+        let chips = (0..12)
+            .map(|id| Chip {
+                id,
+                temp: 60 + id,
+                // Chip 11 is dead
+                nonce: if id == 11 { 0 } else { 1000 },
+                ..Default::default()
+            })
+            .collect();
+        let data = MinerData {
+            slots: vec![Slot {
+                id: 0,
+                freq: 500,
+                chips,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let analyses = analysis::analyze_all_slots(&data.slots, 3, &AnalysisConfig::default());
+        assert_eq!(worst_chips(&data.slots[0], &analyses[0], 3)[0], 11);
+        // A skipped id scores 0 but isn't a chip to list
+        let mut skipped = data.slots[0].clone();
+        skipped.chips[4] = Chip::placeholder(4);
+        let skipped_analysis = analysis::analyze_all_slots(
+            std::slice::from_ref(&skipped),
+            3,
+            &AnalysisConfig::default(),
+        );
+        let worst = worst_chips(&skipped, &skipped_analysis[0], 3);
+        assert_eq!(worst[0], 11);
+        assert!(!worst.contains(&4));
+        assert_eq!(
+            recommended_action(&data, 90.0),
+            "Schedule a board inspection; keep watching the dead chips"
+        );
+
+        let path = std::env::temp_dir().join(format!("report-{}.pdf", std::process::id()));
+        let racks = HashMap::from([(
+            1,
+            RackPosition {
+                rack_id: "B".into(),
+                row: 3,
+                position: 4,
+            },
+        )]);
+        generate_pdf_report(
+            &data,
+            None,
+            &analyses,
+            &racks,
+            "10.0.0.1",
+            Local::now(),
+            &path,
+        )
+        .unwrap();
+        let pdf = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn test_health_cert() {
+        let chips: Vec<Chip> = (0..6)
+            .map(|id| Chip {
+                id,
+                freq: 500,
+                temp: 70,
+                // Chips 2 and 4 are dead
+                nonce: if id == 2 || id == 4 { 0 } else { 1000 },
+                ..Default::default()
+            })
+            .collect();
+        let mut data = MinerData {
+            slots: vec![Slot {
+                id: 1,
+                chips,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let analyses = analysis::analyze_all_slots(&data.slots, 3, &AnalysisConfig::default());
+        let path = std::env::temp_dir().join(format!("rma-{}.txt", std::process::id()));
+        generate_health_cert(&data, None, &analyses, "10.0.0.7", Local::now(), &path).unwrap();
+        let cert = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(cert.contains("Miner:          10.0.0.7"));
+        assert!(cert.contains("Dead chips:     2 of 6"));
+        assert!(cert.contains("Slot 1: 2, 4"));
+
+        let hash = chip_data_hash(&data);
+        assert_eq!(hash.len(), 64);
+        assert!(cert.contains(&format!("SHA-256: {hash}")));
+        // Placeholders aren't part of the readings; any edited reading is
+        data.slots[0].chips.push(Chip::placeholder(6));
+        assert_eq!(chip_data_hash(&data), hash);
+        data.slots[0].chips[0].temp = 69;
+        assert_ne!(chip_data_hash(&data), hash);
+    }
+
+    #[test]
+    fn test_export_selected_chips() {
+        let data = MinerData {
+            slots: vec![Slot {
+                id: 4,
+                chips: (0..6)
+                    .map(|id| Chip {
+                        id,
+                        temp: 70 + id,
+                        nonce: 1000,
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut analyses = analysis::analyze_all_slots(&data.slots, 3, &AnalysisConfig::default());
+        // Session-derived fields come from the live analysis
+        analyses[0][4].error_rate_per_hour = 2.5;
+        analyses[0][4].heat_cycles = 7;
+        analyses[0][4].ir_temp = Some(81.0);
+        let path = std::env::temp_dir().join(format!("selection-{}.csv", std::process::id()));
+        // Unknown slot and out-of-range chip are skipped
+        export_selected_chips(
+            &data,
+            &[(4, 4), (9, 0), (4, 10)],
+            &HashMap::new(),
+            &analyses,
+            3,
+            &path,
+        )
+        .unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].split(',').count(), lines[1].split(',').count());
+        assert!(lines[1].starts_with("4,Slot 4,4,1,1,4,0,0,74,1000,"));
+        let field = |name: &str| {
+            let col = lines[0].split(',').position(|h| h == name).unwrap();
+            lines[1].split(',').nth(col).unwrap()
+        };
+        assert_eq!(field("error_rate_per_hour"), "2.5");
+        assert_eq!(field("heat_cycles"), "7");
+        assert_eq!(field("ir_temp"), "81.0");
+        assert_eq!(field("power_state"), "dead");
+        // No CRC errors, so no suspected bus break
+        assert!(lines[0].ends_with(",break_after_chip") && lines[1].ends_with(','));
+        assert_eq!(csv_field("Rack 3, \"top\""), "\"Rack 3, \"\"top\"\"\"");
+    }
+
+    #[test]
+    fn test_export_slot_summary() {
+        let slot = |id| Slot {
+            id,
+            chips: (0..6)
+                .map(|id| Chip {
+                    id,
+                    temp: 75,
+                    nonce: 1000,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let data = MinerData {
+            slots: vec![slot(0), slot(1)],
+            ..Default::default()
+        };
+        let analyses = analysis::analyze_all_slots(&data.slots, 3, &AnalysisConfig::default());
+        let names = HashMap::from([(1, "Rear, left".to_string())]);
+        let path = std::env::temp_dir().join(format!("slots-{}.csv", std::process::id()));
+        export_slot_summary(
+            &data,
+            &names,
+            &analyses,
+            3,
+            &AnalysisConfig::default(),
+            &path,
+        )
+        .unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains(",uniformity,bottom_chips,"));
+        assert!(lines[0].ends_with(",top_gradient_outliers"));
+        assert!(lines[1].starts_with("0,Slot 0,0,0,6,"));
+        assert!(lines[2].starts_with("1,\"Rear, left\","));
+        // Every chip at the same temperature is perfectly uniform; 3-chip domains
+        // put one domain in each section
+        assert!(lines[1].ends_with(",0,0.00,0.00,0.00,100.0,3,75.0,1000,0,0,3,75.0,1000,0,0"));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = TempBaseline::new();
+        for temps in [[70, 80], [72, 80], [74, 81]] {
+            let slot = Slot {
+                id: 2,
+                chips: temps
+                    .iter()
+                    .enumerate()
+                    .map(|(id, &temp)| Chip {
+                        id: i32::try_from(id).unwrap(),
+                        temp,
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            };
+            analysis::update_temp_baseline(&mut baseline, &[slot]);
+        }
+        let path = std::env::temp_dir().join(format!("baseline-{}.csv", std::process::id()));
+        export_baseline(&baseline, &path).unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(csv.starts_with("slot_id,chip_id,polls,mean_temp,variance\n2,0,3,72,"));
+        let imported = import_baseline(&csv).unwrap();
+        assert_eq!(imported.len(), 2);
+        let stats = imported[&(2, 0)];
+        assert_eq!(stats.count, 3);
+        assert!((stats.mean - 72.0).abs() < 0.01);
+        assert!((stats.variance() - baseline[&(2, 0)].variance()).abs() < 0.01);
+
+        assert!(import_baseline("2,0,3,72").is_err());
+        assert!(import_baseline("2,0,three,72,1").is_err());
+    }
+}
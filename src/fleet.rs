@@ -0,0 +1,182 @@
+//! Fleet (multi-miner) state for the dashboard view
+
+use std::time::{Duration, Instant};
+
+use crate::analysis::{self, AnalysisConfig};
+use crate::config;
+use crate::models::{MinerData, SystemInfo};
+use crate::ui;
+
+/// Default time between automatic fetches of a fleet miner
+pub const DEFAULT_POLL_INTERVAL_SECS: u64 = 30;
+
+/// A miner tracked in the fleet dashboard
+#[derive(Debug, Clone, Default)]
+pub struct FleetMiner {
+    pub ip: String,
+    pub user: String,
+    pub pass: String,
+    pub data: Option<MinerData>,
+    pub system_info: Option<SystemInfo>,
+    /// Mean chip health of `data` under the app's analysis settings, kept up to
+    /// date by `update_health` so the dashboard doesn't re-analyze on every frame
+    pub health: Option<f32>,
+    pub loading: bool,
+    pub error: Option<String>,
+    /// Status of the last reboot command sent from the dashboard
+    pub reboot: Option<RebootStatus>,
+    /// Seconds between automatic fetches while auto-refresh is on
+    pub poll_interval_secs: u64,
+    /// When the last fetch was started
+    pub last_poll: Option<Instant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RebootStatus {
+    Pending,
+    Sent,
+    Failed(String),
+}
+
+impl FleetMiner {
+    pub fn new(ip: &str, user: &str, pass: &str) -> Self {
+        Self {
+            ip: ip.into(),
+            user: user.into(),
+            pass: pass.into(),
+            poll_interval_secs: DEFAULT_POLL_INTERVAL_SECS,
+            ..Default::default()
+        }
+    }
+
+    /// Time left until the next automatic fetch (zero if due now)
+    pub fn next_poll_in(&self, now: Instant) -> Duration {
+        let interval = Duration::from_secs(self.poll_interval_secs);
+        self.last_poll
+            .map_or(Duration::ZERO, |last| interval.saturating_sub(now - last))
+    }
+
+    /// Whether auto-refresh should fetch this miner now
+    pub fn poll_due(&self, now: Instant) -> bool {
+        !self.loading && self.next_poll_in(now).is_zero()
+    }
+
+    /// Apply a fetch result to this entry
+    pub fn apply_fetch(
+        &mut self,
+        result: Result<(MinerData, SystemInfo), String>,
+        analysis_config: &AnalysisConfig,
+    ) {
+        self.loading = false;
+        match result {
+            Ok((data, info)) => {
+                self.data = Some(data);
+                self.system_info = Some(info);
+                self.error = None;
+                self.update_health(analysis_config);
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    /// Recompute `health` from `data`, after a fetch or a change of analysis settings
+    pub fn update_health(&mut self, analysis_config: &AnalysisConfig) {
+        self.health = self.data.as_ref().and_then(|data| {
+            let miner_config = self.system_info.as_ref().and_then(config::lookup_system);
+            let chips_per_domain = ui::chips_per_domain_for(data, miner_config);
+            let all_analysis =
+                analysis::analyze_all_slots(&data.slots, chips_per_domain, analysis_config);
+            analysis::overall_health(&data.slots, &all_analysis)
+        });
+    }
+
+    /// Apply a reboot result to this entry
+    pub fn apply_reboot(&mut self, result: Result<(), String>) {
+        self.reboot = Some(match result {
+            Ok(()) => RebootStatus::Sent,
+            Err(e) => RebootStatus::Failed(e),
+        });
+    }
+}
+
+/// Check or uncheck a dashboard miner for the comparison; checking a third
+/// unchecks the oldest, so at most two stay checked
+pub fn toggle_compare_selection(selection: &mut Vec<usize>, idx: usize) {
+    if let Some(pos) = selection.iter().position(|&i| i == idx) {
+        selection.remove(pos);
+    } else {
+        selection.push(idx);
+        if selection.len() > 2 {
+            selection.remove(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_due_per_miner_interval() {
+        let start = Instant::now();
+        let mut fast = FleetMiner::new("10.0.0.1", "admin", "admin");
+        fast.poll_interval_secs = 10;
+        let mut slow = FleetMiner::new("10.0.0.2", "admin", "admin");
+        assert_eq!(slow.poll_interval_secs, DEFAULT_POLL_INTERVAL_SECS);
+        // Never polled: due right away
+        assert!(fast.poll_due(start));
+
+        fast.last_poll = Some(start);
+        slow.last_poll = Some(start);
+        let later = start + Duration::from_secs(12);
+        assert!(fast.poll_due(later));
+        assert!(!slow.poll_due(later));
+        assert_eq!(slow.next_poll_in(later), Duration::from_secs(18));
+
+        // A fetch still running isn't started again
+        fast.loading = true;
+        assert!(!fast.poll_due(later));
+    }
+
+    #[test]
+    fn test_apply_fetch_computes_health() {
+        let data = MinerData {
+            slots: vec![crate::models::Slot {
+                chips: (0..6)
+                    .map(|id| crate::models::Chip {
+                        id,
+                        temp: 70,
+                        nonce: if id == 3 { 0 } else { 1000 },
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut miner = FleetMiner::new("10.0.0.1", "admin", "admin");
+        assert_eq!(miner.health, None);
+        miner.apply_fetch(
+            Ok((data, SystemInfo::default())),
+            &AnalysisConfig::default(),
+        );
+        // One dead chip of six pulls the mean below 100
+        let health = miner.health.unwrap();
+        assert!(health > 50.0 && health < 100.0, "{health}");
+
+        // A failed fetch keeps the last data and its health
+        miner.apply_fetch(Err("timeout".into()), &AnalysisConfig::default());
+        assert_eq!(miner.health, Some(health));
+    }
+
+    #[test]
+    fn test_compare_selection_keeps_two() {
+        let mut selection = Vec::new();
+        for idx in [0, 3, 5] {
+            toggle_compare_selection(&mut selection, idx);
+        }
+        assert_eq!(selection, vec![3, 5]);
+        toggle_compare_selection(&mut selection, 3);
+        assert_eq!(selection, vec![5]);
+    }
+}
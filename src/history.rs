@@ -0,0 +1,199 @@
+//! Fetch history: every fetch is kept in a local SQLite file, pruned to a
+//! number of records and an age so the file doesn't grow without bound
+
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use rusqlite::Connection;
+
+const HISTORY_FILE: &str = ".whatsminer_chip_map_history.db";
+
+pub const DEFAULT_MAX_RECORDS: usize = 1000;
+pub const DEFAULT_MAX_AGE_DAYS: u32 = 30;
+
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+/// Automatic prunes reclaim space once this share of the file is free pages
+const VACUUM_FREE_RATIO: f64 = 0.25;
+
+/// How much history to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryLimits {
+    pub max_records: usize,
+    pub max_age_days: u32,
+}
+
+impl Default for HistoryLimits {
+    fn default() -> Self {
+        Self {
+            max_records: DEFAULT_MAX_RECORDS,
+            max_age_days: DEFAULT_MAX_AGE_DAYS,
+        }
+    }
+}
+
+/// Size of the history file, for the settings panel
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryStats {
+    pub records: u64,
+    pub bytes: u64,
+}
+
+/// Open a history database, creating the table on first use
+pub fn open(path: &std::path::Path) -> Result<Connection, rusqlite::Error> {
+    let db = Connection::open(path)?;
+    create_tables(&db)?;
+    Ok(db)
+}
+
+fn create_tables(db: &Connection) -> Result<(), rusqlite::Error> {
+    db.execute_batch(
+        "CREATE TABLE IF NOT EXISTS fetches (
+             id INTEGER PRIMARY KEY,
+             ip TEXT NOT NULL,
+             fetched_at INTEGER NOT NULL,
+             data TEXT NOT NULL
+         );
+         CREATE INDEX IF NOT EXISTS fetches_fetched_at ON fetches (fetched_at);",
+    )
+}
+
+/// Store one fetch; `json` is the serialized `MinerData`
+pub fn insert_record(
+    db: &Connection,
+    ip: &str,
+    fetched_at: DateTime<Local>,
+    json: &str,
+) -> Result<(), rusqlite::Error> {
+    db.execute(
+        "INSERT INTO fetches (ip, fetched_at, data) VALUES (?1, ?2, ?3)",
+        (ip, fetched_at.timestamp(), json),
+    )?;
+    Ok(())
+}
+
+/// Delete records older than `max_age_days`, then the oldest beyond
+/// `max_records`; returns how many were deleted
+pub fn prune_old_records(
+    db: &Connection,
+    max_records: usize,
+    max_age_days: u32,
+) -> Result<u64, rusqlite::Error> {
+    let cutoff = Local::now().timestamp() - i64::from(max_age_days) * SECS_PER_DAY;
+    let by_age = db.execute("DELETE FROM fetches WHERE fetched_at < ?1", [cutoff])?;
+    let by_count = db.execute(
+        "DELETE FROM fetches WHERE id NOT IN
+             (SELECT id FROM fetches ORDER BY fetched_at DESC, id DESC LIMIT ?1)",
+        [i64::try_from(max_records).unwrap_or(i64::MAX)],
+    )?;
+    Ok(u64::try_from(by_age + by_count).unwrap_or(u64::MAX))
+}
+
+/// Rebuild the file so pages freed by pruning go back to the disk
+pub fn vacuum(db: &Connection) -> Result<(), rusqlite::Error> {
+    db.execute_batch("VACUUM")
+}
+
+/// Record count and file size
+pub fn stats(db: &Connection) -> Result<HistoryStats, rusqlite::Error> {
+    let records: i64 = db.query_row("SELECT COUNT(*) FROM fetches", [], |row| row.get(0))?;
+    let bytes: i64 = db.query_row(
+        "SELECT page_count * page_size FROM pragma_page_count(), pragma_page_size()",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(HistoryStats {
+        records: u64::try_from(records).unwrap_or_default(),
+        bytes: u64::try_from(bytes).unwrap_or_default(),
+    })
+}
+
+/// Share of the file taken by free pages
+fn free_ratio(db: &Connection) -> Result<f64, rusqlite::Error> {
+    db.query_row(
+        "SELECT CAST(freelist_count AS REAL) / MAX(page_count, 1)
+         FROM pragma_freelist_count(), pragma_page_count()",
+        [],
+        |row| row.get(0),
+    )
+}
+
+fn history_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(HISTORY_FILE))
+}
+
+/// Store a fetch and prune to `limits`; vacuums once enough space is free
+pub async fn record(
+    ip: String,
+    fetched_at: DateTime<Local>,
+    json: String,
+    limits: HistoryLimits,
+) -> Result<HistoryStats, String> {
+    with_history(move |db| {
+        insert_record(db, &ip, fetched_at, &json)?;
+        prune_old_records(db, limits.max_records, limits.max_age_days)?;
+        if free_ratio(db)? > VACUUM_FREE_RATIO {
+            vacuum(db)?;
+        }
+        stats(db)
+    })
+    .await
+}
+
+/// Prune to `limits` and vacuum right away
+pub async fn prune(limits: HistoryLimits) -> Result<HistoryStats, String> {
+    with_history(move |db| {
+        prune_old_records(db, limits.max_records, limits.max_age_days)?;
+        vacuum(db)?;
+        stats(db)
+    })
+    .await
+}
+
+/// Run `f` on the history file on a blocking thread
+async fn with_history<F>(f: F) -> Result<HistoryStats, String>
+where
+    F: FnOnce(&Connection) -> Result<HistoryStats, rusqlite::Error> + Send + 'static,
+{
+    let path = history_path().ok_or("No home directory for the history file")?;
+    tokio::task::spawn_blocking(move || {
+        open(&path)
+            .and_then(|db| f(&db))
+            .map_err(|e| format!("{}: {e}", path.display()))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_old_records() {
+        let db = Connection::open_in_memory().unwrap();
+        create_tables(&db).unwrap();
+        let now = Local::now();
+        let old = now - chrono::Duration::days(40);
+        insert_record(&db, "10.0.0.1", old, "{}").unwrap();
+        for _ in 0..5 {
+            insert_record(&db, "10.0.0.1", now, "{}").unwrap();
+        }
+
+        // The 40-day-old record goes first, then the oldest beyond 3
+        assert_eq!(prune_old_records(&db, 3, 30).unwrap(), 3);
+        assert_eq!(stats(&db).unwrap().records, 3);
+        let ids: Vec<i64> = db
+            .prepare("SELECT id FROM fetches ORDER BY id")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(ids, [4, 5, 6]);
+
+        assert_eq!(prune_old_records(&db, 3, 30).unwrap(), 0);
+        vacuum(&db).unwrap();
+        assert!(stats(&db).unwrap().bytes > 0);
+    }
+}
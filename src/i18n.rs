@@ -2,8 +2,16 @@
 
 use std::fmt;
 
+use chrono::{DateTime, Local, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::alerts::AlertSeverity;
+use crate::analysis::{ChipPowerState, DeadChipPattern};
+use crate::api::FetchStage;
+use crate::notes::RackPosition;
+
 /// Supported languages
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Language {
     #[default]
     English,
@@ -49,6 +57,49 @@ impl fmt::Display for Language {
     }
 }
 
+/// Language of the OS locale; English if the locale is unknown or unsupported
+pub fn detect_system_language() -> Language {
+    system_locale()
+        .as_deref()
+        .map_or(Language::English, language_from_locale)
+}
+
+/// Match a locale name ("ru_RU.UTF-8", "zh-CN", "C") by its language code prefix
+fn language_from_locale(locale: &str) -> Language {
+    let code = locale
+        .split(['_', '-', '.', '@'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    Language::ALL
+        .iter()
+        .copied()
+        .find(|lang| lang.to_string() == code)
+        .unwrap_or_default()
+}
+
+#[cfg(not(windows))]
+fn system_locale() -> Option<String> {
+    // POSIX precedence: LC_ALL overrides LC_MESSAGES overrides LANG
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+}
+
+#[cfg(windows)]
+fn system_locale() -> Option<String> {
+    use windows_sys::Win32::Globalization::GetUserDefaultLocaleName;
+    const LOCALE_NAME_MAX_LENGTH: usize = 85;
+
+    let mut buf = [0u16; LOCALE_NAME_MAX_LENGTH];
+    // SAFETY: buf is valid for LOCALE_NAME_MAX_LENGTH UTF-16 units
+    let written = unsafe { GetUserDefaultLocaleName(buf.as_mut_ptr(), buf.len() as i32) };
+    // Count includes the terminating NUL; 0 means failure
+    let len = usize::try_from(written).ok()?.checked_sub(1)?;
+    Some(String::from_utf16_lossy(&buf[..len]))
+}
+
 /// Translation strings
 pub struct Tr;
 
@@ -252,133 +303,4733 @@ impl Tr {
         }
     }
 
-    pub fn chips(lang: Language) -> &'static str {
+    /// "N chips" with the plural form the language requires for N
+    pub fn chips_count(count: usize, lang: Language) -> String {
+        let word = match (lang, plural(count, lang)) {
+            (Language::English | Language::Spanish, PluralCategory::One) => "chip",
+            (Language::English | Language::Spanish, _) => "chips",
+            (Language::Russian, PluralCategory::One) => "чип",
+            (Language::Russian, PluralCategory::Few) => "чипа",
+            (Language::Russian, _) => "чипов",
+            (Language::Ukrainian, PluralCategory::One) => "чіп",
+            (Language::Ukrainian, PluralCategory::Few) => "чіпи",
+            (Language::Ukrainian, _) => "чіпів",
+            (Language::Polish, PluralCategory::One) => "chip",
+            (Language::Polish, PluralCategory::Few) => "chipy",
+            (Language::Polish, _) => "chipów",
+            (Language::Arabic, PluralCategory::Two) => "شريحتان",
+            (Language::Arabic, PluralCategory::Few) => "شرائح",
+            (Language::Arabic, _) => "شريحة",
+            (Language::Persian, _) => "چیپ",
+            (Language::Chinese, _) => "芯片",
+            (Language::Kazakh, _) => "чип",
+        };
+        format!("{count} {word}")
+    }
+
+    /// "N slots" with the plural form the language requires for N
+    pub fn slots_count(count: usize, lang: Language) -> String {
+        let word = match (lang, plural(count, lang)) {
+            (Language::English, PluralCategory::One) => "slot",
+            (Language::English, _) => "slots",
+            (Language::Spanish, PluralCategory::One) => "ranura",
+            (Language::Spanish, _) => "ranuras",
+            (Language::Russian, PluralCategory::One) => "слот",
+            (Language::Russian, PluralCategory::Few) => "слота",
+            (Language::Russian, _) => "слотов",
+            (Language::Ukrainian, PluralCategory::One) => "слот",
+            (Language::Ukrainian, PluralCategory::Few) => "слоти",
+            (Language::Ukrainian, _) => "слотів",
+            (Language::Polish, PluralCategory::One) => "slot",
+            (Language::Polish, PluralCategory::Few) => "sloty",
+            (Language::Polish, _) => "slotów",
+            (Language::Arabic, PluralCategory::Two) => "فتحتان",
+            (Language::Arabic, PluralCategory::Few) => "فتحات",
+            (Language::Arabic, _) => "فتحة",
+            (Language::Persian, _) => "اسلات",
+            (Language::Chinese, _) => "槽位",
+            (Language::Kazakh, _) => "слот",
+        };
+        format!("{count} {word}")
+    }
+
+    // Fleet dashboard
+    pub fn dashboard(lang: Language) -> &'static str {
         match lang {
-            Language::English | Language::Spanish => "chips",
-            Language::Russian => "чипов",
-            Language::Persian => "چیپ",
-            Language::Chinese => "芯片",
-            Language::Ukrainian => "чіпів",
-            Language::Polish => "chipów",
-            Language::Kazakh => "чип",
-            Language::Arabic => "شريحة",
+            Language::English => "Dashboard",
+            Language::Russian => "Панель",
+            Language::Spanish => "Panel",
+            Language::Persian => "داشبورد",
+            Language::Chinese => "仪表盘",
+            Language::Ukrainian => "Панель",
+            Language::Polish => "Pulpit",
+            Language::Kazakh => "Панель",
+            Language::Arabic => "لوحة التحكم",
         }
     }
 
-    pub fn slots(lang: Language) -> &'static str {
+    pub fn chip_map(lang: Language) -> &'static str {
         match lang {
-            Language::English => "slots",
-            Language::Russian => "слотов",
-            Language::Spanish => "ranuras",
-            Language::Persian => "اسلات",
-            Language::Chinese => "槽位",
-            Language::Ukrainian => "слотів",
-            Language::Polish => "slotów",
-            Language::Kazakh => "слот",
-            Language::Arabic => "فتحات",
+            Language::English => "Chip Map",
+            Language::Russian => "Карта чипов",
+            Language::Spanish => "Mapa de chips",
+            Language::Persian => "نقشه چیپ",
+            Language::Chinese => "芯片图",
+            Language::Ukrainian => "Карта чіпів",
+            Language::Polish => "Mapa chipów",
+            Language::Kazakh => "Чип картасы",
+            Language::Arabic => "خريطة الشرائح",
+        }
+    }
+
+    pub fn add_to_fleet(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "+ Fleet",
+            Language::Russian => "+ Парк",
+            Language::Spanish => "+ Flota",
+            Language::Persian => "+ ناوگان",
+            Language::Chinese => "+ 矿场",
+            Language::Ukrainian => "+ Парк",
+            Language::Polish => "+ Flota",
+            Language::Kazakh => "+ Парк",
+            Language::Arabic => "+ الأسطول",
+        }
+    }
+
+    pub fn fetch_all(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Fetch All",
+            Language::Russian => "Загрузить все",
+            Language::Spanish => "Obtener todo",
+            Language::Persian => "دریافت همه",
+            Language::Chinese => "全部获取",
+            Language::Ukrainian => "Завантажити всі",
+            Language::Polish => "Pobierz wszystko",
+            Language::Kazakh => "Барлығын жүктеу",
+            Language::Arabic => "جلب الكل",
+        }
+    }
+
+    pub fn dead(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "dead",
+            Language::Russian => "мёртвых",
+            Language::Spanish => "muertos",
+            Language::Persian => "خراب",
+            Language::Chinese => "失效",
+            Language::Ukrainian => "мертвих",
+            Language::Polish => "martwych",
+            Language::Kazakh => "өлі",
+            Language::Arabic => "معطلة",
+        }
+    }
+
+    pub fn fleet_empty(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "No miners in fleet. Use '+ Fleet' to add the current miner",
+            Language::Russian => "Парк пуст. Нажмите '+ Парк', чтобы добавить текущий майнер",
+            Language::Spanish => "Flota vacía. Use '+ Flota' para añadir el minero actual",
+            Language::Persian => {
+                "ناوگان خالی است. برای افزودن ماینر فعلی از '+ ناوگان' استفاده کنید"
+            }
+            Language::Chinese => "矿场为空。点击'+ 矿场'添加当前矿机",
+            Language::Ukrainian => "Парк порожній. Натисніть '+ Парк', щоб додати поточний майнер",
+            Language::Polish => "Flota jest pusta. Użyj '+ Flota', aby dodać bieżącą koparkę",
+            Language::Kazakh => "Парк бос. Ағымдағы майнерді қосу үшін '+ Парк' басыңыз",
+            Language::Arabic => "الأسطول فارغ. استخدم '+ الأسطول' لإضافة المُعدِّن الحالي",
+        }
+    }
+
+    pub fn reboot_all(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Reboot All",
+            Language::Russian => "Перезагрузить все",
+            Language::Spanish => "Reiniciar todo",
+            Language::Persian => "راه‌اندازی مجدد همه",
+            Language::Chinese => "全部重启",
+            Language::Ukrainian => "Перезавантажити всі",
+            Language::Polish => "Restartuj wszystkie",
+            Language::Kazakh => "Барлығын қайта жүктеу",
+            Language::Arabic => "إعادة تشغيل الكل",
+        }
+    }
+
+    /// "N records" (fetch history) with the plural form the language requires for N
+    pub fn records_count(count: usize, lang: Language) -> String {
+        let word = match (lang, plural(count, lang)) {
+            (Language::English, PluralCategory::One) => "record",
+            (Language::English, _) => "records",
+            (Language::Spanish, PluralCategory::One) => "registro",
+            (Language::Spanish, _) => "registros",
+            (Language::Russian, PluralCategory::One) => "запись",
+            (Language::Russian, PluralCategory::Few) => "записи",
+            (Language::Russian, _) => "записей",
+            (Language::Ukrainian, PluralCategory::One) => "запис",
+            (Language::Ukrainian, PluralCategory::Few) => "записи",
+            (Language::Ukrainian, _) => "записів",
+            (Language::Polish, PluralCategory::One) => "rekord",
+            (Language::Polish, PluralCategory::Few) => "rekordy",
+            (Language::Polish, _) => "rekordów",
+            (Language::Arabic, PluralCategory::Two) => "سجلان",
+            (Language::Arabic, PluralCategory::Few) => "سجلات",
+            (Language::Arabic, _) => "سجل",
+            (Language::Persian, _) => "رکورد",
+            (Language::Chinese, _) => "条记录",
+            (Language::Kazakh, _) => "жазба",
+        };
+        format!("{count} {word}")
+    }
+
+    /// "N polls" (temperature baseline) with the plural form the language requires for N
+    pub fn polls_count(count: usize, lang: Language) -> String {
+        let word = match (lang, plural(count, lang)) {
+            (Language::English, PluralCategory::One) => "poll",
+            (Language::English, _) => "polls",
+            (Language::Spanish, PluralCategory::One) => "lectura",
+            (Language::Spanish, _) => "lecturas",
+            (Language::Russian, PluralCategory::One) => "загрузка",
+            (Language::Russian, PluralCategory::Few) => "загрузки",
+            (Language::Russian, _) => "загрузок",
+            (Language::Ukrainian, PluralCategory::One) => "завантаження",
+            (Language::Ukrainian, PluralCategory::Few) => "завантаження",
+            (Language::Ukrainian, _) => "завантажень",
+            (Language::Polish, PluralCategory::One) => "pobranie",
+            (Language::Polish, PluralCategory::Few) => "pobrania",
+            (Language::Polish, _) => "pobrań",
+            (Language::Arabic, PluralCategory::Two) => "عمليتا جلب",
+            (Language::Arabic, PluralCategory::Few) => "عمليات جلب",
+            (Language::Arabic, _) => "عملية جلب",
+            (Language::Persian, _) => "دریافت",
+            (Language::Chinese, _) => "次",
+            (Language::Kazakh, _) => "жүктеу",
+        };
+        format!("{count} {word}")
+    }
+
+    pub fn confirm_reboot_all(count: usize, lang: Language) -> String {
+        match lang {
+            Language::English => format!("Reboot all {count} miners?"),
+            Language::Russian => format!("Перезагрузить все майнеры ({count})?"),
+            Language::Spanish => format!("¿Reiniciar los {count} mineros?"),
+            Language::Persian => format!("همه {count} ماینر راه‌اندازی مجدد شوند؟"),
+            Language::Chinese => format!("重启全部 {count} 台矿机？"),
+            Language::Ukrainian => format!("Перезавантажити всі майнери ({count})?"),
+            Language::Polish => format!("Zrestartować wszystkie koparki ({count})?"),
+            Language::Kazakh => format!("Барлық майнерді ({count}) қайта жүктеу керек пе?"),
+            Language::Arabic => format!("إعادة تشغيل جميع المُعدِّنات ({count})؟"),
+        }
+    }
+
+    pub fn cancel(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Cancel",
+            Language::Russian => "Отмена",
+            Language::Spanish => "Cancelar",
+            Language::Persian => "لغو",
+            Language::Chinese => "取消",
+            Language::Ukrainian => "Скасувати",
+            Language::Polish => "Anuluj",
+            Language::Kazakh => "Болдырмау",
+            Language::Arabic => "إلغاء",
+        }
+    }
+
+    pub fn confirm(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Confirm",
+            Language::Russian => "Подтвердить",
+            Language::Spanish => "Confirmar",
+            Language::Persian => "تأیید",
+            Language::Chinese => "确认",
+            Language::Ukrainian => "Підтвердити",
+            Language::Polish => "Potwierdź",
+            Language::Kazakh => "Растау",
+            Language::Arabic => "تأكيد",
+        }
+    }
+
+    pub fn rebooting(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Rebooting...",
+            Language::Russian => "Перезагрузка...",
+            Language::Spanish => "Reiniciando...",
+            Language::Persian => "در حال راه‌اندازی مجدد...",
+            Language::Chinese => "重启中...",
+            Language::Ukrainian => "Перезавантаження...",
+            Language::Polish => "Restartowanie...",
+            Language::Kazakh => "Қайта жүктелуде...",
+            Language::Arabic => "جاري إعادة التشغيل...",
+        }
+    }
+
+    pub fn reboot_sent(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Reboot sent",
+            Language::Russian => "Перезагрузка отправлена",
+            Language::Spanish => "Reinicio enviado",
+            Language::Persian => "فرمان راه‌اندازی ارسال شد",
+            Language::Chinese => "已发送重启",
+            Language::Ukrainian => "Перезавантаження надіслано",
+            Language::Polish => "Wysłano restart",
+            Language::Kazakh => "Қайта жүктеу жіберілді",
+            Language::Arabic => "تم إرسال إعادة التشغيل",
+        }
+    }
+
+    // Stats panel
+    pub fn stats(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Stats",
+            Language::Russian => "Статистика",
+            Language::Spanish => "Estadísticas",
+            Language::Persian => "آمار",
+            Language::Chinese => "统计",
+            Language::Ukrainian => "Статистика",
+            Language::Polish => "Statystyki",
+            Language::Kazakh => "Статистика",
+            Language::Arabic => "إحصائيات",
+        }
+    }
+
+    pub fn nonce_distribution(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Nonce distribution",
+            Language::Russian => "Распределение нонсов",
+            Language::Spanish => "Distribución de nonces",
+            Language::Persian => "توزیع نانس",
+            Language::Chinese => "随机数分布",
+            Language::Ukrainian => "Розподіл нонсів",
+            Language::Polish => "Rozkład nonce",
+            Language::Kazakh => "Нонс таралуы",
+            Language::Arabic => "توزيع النونس",
+        }
+    }
+
+    pub fn bimodal_warning(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "⚠ bimodal — possible bad batch",
+            Language::Russian => "⚠ бимодальное — возможно, плохая партия",
+            Language::Spanish => "⚠ bimodal — posible lote defectuoso",
+            Language::Persian => "⚠ دوقله‌ای — احتمال سری معیوب",
+            Language::Chinese => "⚠ 双峰分布 — 可能是不良批次",
+            Language::Ukrainian => "⚠ бімодальний — можливо, погана партія",
+            Language::Polish => "⚠ bimodalny — możliwa wadliwa partia",
+            Language::Kazakh => "⚠ бимодальды — ақаулы партия болуы мүмкін",
+            Language::Arabic => "⚠ ثنائي القمة — دفعة معيبة محتملة",
+        }
+    }
+
+    // Help panel
+
+    pub fn help_title(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Help",
+            Language::Russian => "Справка",
+            Language::Spanish => "Ayuda",
+            Language::Persian => "راهنما",
+            Language::Chinese => "帮助",
+            Language::Ukrainian => "Довідка",
+            Language::Polish => "Pomoc",
+            Language::Kazakh => "Анықтама",
+            Language::Arabic => "مساعدة",
+        }
+    }
+
+    pub fn help_layout_title(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Chip map layout",
+            Language::Russian => "Схема карты чипов",
+            Language::Spanish => "Disposición del mapa de chips",
+            Language::Persian => "چیدمان نقشه تراشه",
+            Language::Chinese => "芯片图布局",
+            Language::Ukrainian => "Схема карти чипів",
+            Language::Polish => "Układ mapy chipów",
+            Language::Kazakh => "Чип картасының орналасуы",
+            Language::Arabic => "تخطيط خريطة الشرائح",
+        }
+    }
+
+    /// Explains domains, the snake pattern and airflow; shown under the layout diagram
+    pub fn help_layout(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Chips are grouped into voltage domains (D0, D1, …). Each column is one domain, with its chips C0, C1, … powered in series.\nDomains snake across the board: the bottom section runs from D0 on the right to the left, then the top section continues from the left back to the right.\nAir enters on the right (intake) and leaves on the left (exhaust), so chips on the left normally run warmer.\nHydro and immersion boards use a linked layout: the first half of the domains fills the right side, D0 at the far right, and the rest fill the left side.\nClick a chip to select it; drag from one chip to another to select a box."
+            }
+            Language::Russian => {
+                "Чипы сгруппированы в домены питания (D0, D1, …). Каждый столбец — один домен, его чипы C0, C1, … питаются последовательно.\nДомены идут змейкой: нижняя секция — от D0 справа налево, затем верхняя секция продолжается слева направо.\nВоздух входит справа (вход) и выходит слева (выход), поэтому чипы слева обычно горячее.\nПлаты гидро- и иммерсионного охлаждения используют связанную схему: первая половина доменов занимает правую сторону (D0 крайний справа), остальные — левую.\nЩелчок по чипу выделяет его; протяните от одного чипа к другому, чтобы выделить прямоугольник."
+            }
+            Language::Spanish => {
+                "Los chips se agrupan en dominios de voltaje (D0, D1, …). Cada columna es un dominio, con sus chips C0, C1, … alimentados en serie.\nLos dominios serpentean por la placa: la sección inferior va desde D0, a la derecha, hacia la izquierda, y la sección superior continúa de izquierda a derecha.\nEl aire entra por la derecha (admisión) y sale por la izquierda (escape), así que los chips de la izquierda suelen estar más calientes.\nLas placas hidro y de inmersión usan un diseño enlazado: la primera mitad de los dominios ocupa el lado derecho, con D0 en el extremo, y el resto el lado izquierdo.\nHaga clic en un chip para seleccionarlo; arrastre de un chip a otro para seleccionar un rectángulo."
+            }
+            Language::Persian => {
+                "تراشه‌ها در دامنه‌های ولتاژ (D0، D1، …) گروه‌بندی شده‌اند. هر ستون یک دامنه است و تراشه‌های آن (C0، C1، …) به‌صورت سری تغذیه می‌شوند.\nدامنه‌ها به شکل مار روی برد قرار دارند: بخش پایین از D0 در سمت راست به چپ می‌رود و بخش بالا از چپ به راست ادامه می‌یابد.\nهوا از راست وارد (ورودی) و از چپ خارج می‌شود (خروجی)، بنابراین تراشه‌های سمت چپ معمولاً گرم‌ترند.\nبردهای هیدرو و غوطه‌وری چیدمان پیوسته دارند: نیمه اول دامنه‌ها سمت راست (D0 در منتهی‌الیه راست) و بقیه سمت چپ قرار دارند.\nبرای انتخاب یک تراشه روی آن کلیک کنید؛ برای انتخاب یک مستطیل از یک تراشه تا تراشه دیگر بکشید."
+            }
+            Language::Chinese => {
+                "芯片按电压域（D0、D1、…）分组。每一列是一个域，其芯片 C0、C1、… 串联供电。\n域在板上呈蛇形排列：下半部分从右侧的 D0 向左排列，上半部分再从左向右继续。\n空气从右侧进入（进风），从左侧排出（出风），因此左侧芯片通常更热。\n水冷和浸没式板采用连接布局：前一半的域位于右侧（D0 在最右），其余位于左侧。\n单击芯片可选中；从一个芯片拖到另一个芯片可框选矩形区域。"
+            }
+            Language::Ukrainian => {
+                "Чипи згруповані в домени живлення (D0, D1, …). Кожен стовпець — один домен, його чипи C0, C1, … живляться послідовно.\nДомени йдуть змійкою: нижня секція — від D0 праворуч ліворуч, потім верхня секція продовжується зліва направо.\nПовітря входить праворуч (вхід) і виходить ліворуч (вихід), тому чипи ліворуч зазвичай гарячіші.\nПлати гідро- та імерсійного охолодження мають зв’язану схему: перша половина доменів займає праву сторону (D0 крайній праворуч), решта — ліву.\nКлацніть чип, щоб виділити його; протягніть від одного чипа до іншого, щоб виділити прямокутник."
+            }
+            Language::Polish => {
+                "Chipy są pogrupowane w domeny napięciowe (D0, D1, …). Każda kolumna to jedna domena, a jej chipy C0, C1, … są zasilane szeregowo.\nDomeny układają się wężykiem: dolna sekcja biegnie od D0 po prawej w lewo, a górna sekcja wraca od lewej do prawej.\nPowietrze wchodzi z prawej (wlot) i wychodzi z lewej (wylot), więc chipy po lewej są zwykle cieplejsze.\nPłyty hydro i immersyjne mają układ połączony: pierwsza połowa domen zajmuje prawą stronę (D0 na samym skraju), a reszta lewą.\nKliknij chip, aby go zaznaczyć; przeciągnij od jednego chipa do drugiego, aby zaznaczyć prostokąt."
+            }
+            Language::Kazakh => {
+                "Чиптер кернеу домендеріне (D0, D1, …) топтастырылған. Әр баған — бір домен, оның C0, C1, … чиптері тізбектей қоректенеді.\nДомендер тақтада жыланша орналасқан: төменгі бөлім оң жақтағы D0-ден солға қарай жүреді, ал жоғарғы бөлім солдан оңға қарай жалғасады.\nАуа оң жақтан кіріп (кіріс), сол жақтан шығады (шығыс), сондықтан сол жақтағы чиптер әдетте ыстығырақ.\nГидро және иммерсиялық тақталарда байланысқан орналасу қолданылады: домендердің бірінші жартысы оң жақта (D0 ең шетте), қалғандары сол жақта.\nЧипті таңдау үшін оны басыңыз; тіктөртбұрышты таңдау үшін бір чиптен екіншісіне дейін сүйреңіз."
+            }
+            Language::Arabic => {
+                "تُجمَّع الشرائح في نطاقات جهد (D0، D1، …). كل عمود نطاق واحد، وتُغذّى شرائحه C0، C1، … على التوالي.\nتتعرّج النطاقات عبر اللوحة: يمتد القسم السفلي من D0 على اليمين نحو اليسار، ثم يستمر القسم العلوي من اليسار إلى اليمين.\nيدخل الهواء من اليمين (السحب) ويخرج من اليسار (العادم)، لذا تكون الشرائح على اليسار أسخن عادةً.\nتستخدم لوحات التبريد المائي والغمر تخطيطًا مرتبطًا: يملأ النصف الأول من النطاقات الجانب الأيمن (D0 في أقصى اليمين) والباقي الجانب الأيسر.\nانقر على شريحة لتحديدها؛ اسحب من شريحة إلى أخرى لتحديد مستطيل."
+            }
+        }
+    }
+
+    pub fn help_color_modes_title(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Color modes",
+            Language::Russian => "Цветовые режимы",
+            Language::Spanish => "Modos de color",
+            Language::Persian => "حالت‌های رنگ",
+            Language::Chinese => "颜色模式",
+            Language::Ukrainian => "Колірні режими",
+            Language::Polish => "Tryby kolorów",
+            Language::Kazakh => "Түс режимдері",
+            Language::Arabic => "أوضاع الألوان",
+        }
+    }
+
+    pub fn help_color_mode_temperature(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Chip temperature: 40°C green to 100°C red",
+            Language::Russian => "Температура чипа: от 40°C (зелёный) до 100°C (красный)",
+            Language::Spanish => "Temperatura del chip: de 40°C (verde) a 100°C (rojo)",
+            Language::Persian => "دمای تراشه: از ۴۰°C (سبز) تا ۱۰۰°C (قرمز)",
+            Language::Chinese => "芯片温度：40°C（绿）到 100°C（红）",
+            Language::Ukrainian => "Температура чипа: від 40°C (зелений) до 100°C (червоний)",
+            Language::Polish => "Temperatura chipa: od 40°C (zielony) do 100°C (czerwony)",
+            Language::Kazakh => "Чип температурасы: 40°C (жасыл) бастап 100°C (қызыл) дейін",
+            Language::Arabic => "حرارة الشريحة: من 40°C (أخضر) إلى 100°C (أحمر)",
+        }
+    }
+
+    pub fn help_color_mode_errors(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Hardware errors: 0 green to 150 red",
+            Language::Russian => "Аппаратные ошибки: от 0 (зелёный) до 150 (красный)",
+            Language::Spanish => "Errores de hardware: de 0 (verde) a 150 (rojo)",
+            Language::Persian => "خطاهای سخت‌افزاری: از ۰ (سبز) تا ۱۵۰ (قرمز)",
+            Language::Chinese => "硬件错误：0（绿）到 150（红）",
+            Language::Ukrainian => "Апаратні помилки: від 0 (зелений) до 150 (червоний)",
+            Language::Polish => "Błędy sprzętowe: od 0 (zielony) do 150 (czerwony)",
+            Language::Kazakh => "Аппараттық қателер: 0 (жасыл) бастап 150 (қызыл) дейін",
+            Language::Arabic => "أخطاء العتاد: من 0 (أخضر) إلى 150 (أحمر)",
+        }
+    }
+
+    pub fn help_color_mode_crc(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "CRC errors on the chip bus: 0 green to 15 red",
+            Language::Russian => "Ошибки CRC на шине чипов: от 0 (зелёный) до 15 (красный)",
+            Language::Spanish => "Errores CRC en el bus de chips: de 0 (verde) a 15 (rojo)",
+            Language::Persian => "خطاهای CRC در گذرگاه تراشه: از ۰ (سبز) تا ۱۵ (قرمز)",
+            Language::Chinese => "芯片总线 CRC 错误：0（绿）到 15（红）",
+            Language::Ukrainian => "Помилки CRC на шині чипів: від 0 (зелений) до 15 (червоний)",
+            Language::Polish => "Błędy CRC na magistrali chipów: od 0 (zielony) do 15 (czerwony)",
+            Language::Kazakh => "Чип шинасындағы CRC қателері: 0 (жасыл) бастап 15 (қызыл) дейін",
+            Language::Arabic => "أخطاء CRC في ناقل الشرائح: من 0 (أخضر) إلى 15 (أحمر)",
+        }
+    }
+
+    pub fn help_color_mode_gradient(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Hotter than its upstream neighbors: 0 green to 15°C red",
+            Language::Russian => "Горячее соседей выше по потоку: от 0 (зелёный) до 15°C (красный)",
+            Language::Spanish => {
+                "Más caliente que sus vecinos aguas arriba: de 0 (verde) a 15°C (rojo)"
+            }
+            Language::Persian => "گرم‌تر از همسایه‌های بالادست: از ۰ (سبز) تا ۱۵°C (قرمز)",
+            Language::Chinese => "比上游相邻芯片更热：0（绿）到 15°C（红）",
+            Language::Ukrainian => {
+                "Гарячіший за сусідів вище за потоком: від 0 (зелений) до 15°C (червоний)"
+            }
+            Language::Polish => {
+                "Cieplejszy od sąsiadów w górę strumienia: od 0 (zielony) do 15°C (czerwony)"
+            }
+            Language::Kazakh => {
+                "Ағын бойынша алдыңғы көршілерінен ыстық: 0 (жасыл) бастап 15°C (қызыл) дейін"
+            }
+            Language::Arabic => "أسخن من جيرانه في اتجاه المنبع: من 0 (أخضر) إلى 15°C (أحمر)",
+        }
+    }
+
+    pub fn help_color_mode_outliers(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Hotter than the same position on other boards: 0 green to 3 std devs red"
+            }
+            Language::Russian => {
+                "Горячее той же позиции на других платах: от 0 (зелёный) до 3 σ (красный)"
+            }
+            Language::Spanish => {
+                "Más caliente que la misma posición en otras placas: de 0 (verde) a 3 σ (rojo)"
+            }
+            Language::Persian => "گرم‌تر از همان موقعیت در بردهای دیگر: از ۰ (سبز) تا ۳ σ (قرمز)",
+            Language::Chinese => "比其他板同一位置更热：0（绿）到 3 个标准差（红）",
+            Language::Ukrainian => {
+                "Гарячіший за ту саму позицію на інших платах: від 0 (зелений) до 3 σ (червоний)"
+            }
+            Language::Polish => {
+                "Cieplejszy niż ta sama pozycja na innych płytach: od 0 (zielony) do 3 σ (czerwony)"
+            }
+            Language::Kazakh => {
+                "Басқа тақталардағы сол позициядан ыстық: 0 (жасыл) бастап 3 σ (қызыл) дейін"
+            }
+            Language::Arabic => "أسخن من الموضع نفسه في اللوحات الأخرى: من 0 (أخضر) إلى 3 σ (أحمر)",
+        }
+    }
+
+    pub fn help_color_mode_nonce(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Nonces below the slot average: 0 green to 50% red",
+            Language::Russian => "Нонсов меньше среднего по слоту: от 0 (зелёный) до 50% (красный)",
+            Language::Spanish => {
+                "Nonces por debajo de la media del slot: de 0 (verde) a 50% (rojo)"
+            }
+            Language::Persian => "nonce کمتر از میانگین اسلات: از ۰ (سبز) تا ۵۰٪ (قرمز)",
+            Language::Chinese => "低于槽位平均 nonce：0（绿）到 50%（红）",
+            Language::Ukrainian => {
+                "Нонсів менше за середнє по слоту: від 0 (зелений) до 50% (червоний)"
+            }
+            Language::Polish => "Nonce poniżej średniej slotu: od 0 (zielony) do 50% (czerwony)",
+            Language::Kazakh => "Слот орташасынан аз nonce: 0 (жасыл) бастап 50% (қызыл) дейін",
+            Language::Arabic => "nonce أقل من متوسط الفتحة: من 0 (أخضر) إلى 50% (أحمر)",
+        }
+    }
+
+    pub fn help_color_mode_freq_relative(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Frequency vs the slot target: within 1% green, 1–5% amber, 5–10% orange, over 10% red"
+            }
+            Language::Russian => {
+                "Частота относительно цели слота: до 1% зелёный, 1–5% жёлтый, 5–10% оранжевый, более 10% красный"
+            }
+            Language::Spanish => {
+                "Frecuencia frente al objetivo del slot: hasta 1% verde, 1–5% ámbar, 5–10% naranja, más de 10% rojo"
+            }
+            Language::Persian => {
+                "فرکانس نسبت به هدف اسلات: تا ۱٪ سبز، ۱ تا ۵٪ کهربایی، ۵ تا ۱۰٪ نارنجی، بیش از ۱۰٪ قرمز"
+            }
+            Language::Chinese => "频率与槽位目标相比：1% 以内绿，1–5% 琥珀，5–10% 橙，超过 10% 红",
+            Language::Ukrainian => {
+                "Частота відносно цілі слота: до 1% зелений, 1–5% жовтий, 5–10% помаранчевий, понад 10% червоний"
+            }
+            Language::Polish => {
+                "Częstotliwość względem celu slotu: do 1% zielony, 1–5% bursztynowy, 5–10% pomarańczowy, ponad 10% czerwony"
+            }
+            Language::Kazakh => {
+                "Слот мақсатына қатысты жиілік: 1% дейін жасыл, 1–5% сары, 5–10% қызғылт сары, 10% жоғары қызыл"
+            }
+            Language::Arabic => {
+                "التردد مقارنة بهدف الفتحة: حتى 1% أخضر، 1–5% كهرماني، 5–10% برتقالي، أكثر من 10% أحمر"
+            }
+        }
+    }
+
+    pub fn help_color_mode_nonce_per_mhz(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Nonces per MHz below the slot average: 0 green to 2 std devs red",
+            Language::Russian => {
+                "Нонсы на МГц ниже среднего по слоту: от 0 (зелёный) до 2 σ (красный)"
+            }
+            Language::Spanish => {
+                "Nonces por MHz por debajo de la media del slot: de 0 (verde) a 2 σ (rojo)"
+            }
+            Language::Persian => {
+                "nonce به ازای هر MHz کمتر از میانگین اسلات: از ۰ (سبز) تا ۲ σ (قرمز)"
+            }
+            Language::Chinese => "每 MHz nonce 低于槽位平均：0（绿）到 2 个标准差（红）",
+            Language::Ukrainian => {
+                "Нонси на МГц нижче за середнє по слоту: від 0 (зелений) до 2 σ (червоний)"
+            }
+            Language::Polish => {
+                "Nonce na MHz poniżej średniej slotu: od 0 (zielony) do 2 σ (czerwony)"
+            }
+            Language::Kazakh => {
+                "Слот орташасынан төмен МГц-ке nonce: 0 (жасыл) бастап 2 σ (қызыл) дейін"
+            }
+            Language::Arabic => "nonce لكل MHz أقل من متوسط الفتحة: من 0 (أخضر) إلى 2 σ (أحمر)",
+        }
+    }
+
+    pub fn help_color_mode_error_rate(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Errors per hour between two fetches: under 5 green, 5–20 amber, 20–50 orange, over 50 red"
+            }
+            Language::Russian => {
+                "Ошибок в час между двумя загрузками: до 5 зелёный, 5–20 жёлтый, 20–50 оранжевый, более 50 красный"
+            }
+            Language::Spanish => {
+                "Errores por hora entre dos lecturas: menos de 5 verde, 5–20 ámbar, 20–50 naranja, más de 50 rojo"
+            }
+            Language::Persian => {
+                "خطا در ساعت بین دو دریافت: کمتر از ۵ سبز، ۵ تا ۲۰ کهربایی، ۲۰ تا ۵۰ نارنجی، بیش از ۵۰ قرمز"
+            }
+            Language::Chinese => {
+                "两次获取之间每小时错误数：低于 5 绿，5–20 琥珀，20–50 橙，超过 50 红"
+            }
+            Language::Ukrainian => {
+                "Помилок на годину між двома завантаженнями: до 5 зелений, 5–20 жовтий, 20–50 помаранчевий, понад 50 червоний"
+            }
+            Language::Polish => {
+                "Błędy na godzinę między dwoma pobraniami: poniżej 5 zielony, 5–20 bursztynowy, 20–50 pomarańczowy, ponad 50 czerwony"
+            }
+            Language::Kazakh => {
+                "Екі жүктеу арасындағы сағатына қателер: 5-тен аз жасыл, 5–20 сары, 20–50 қызғылт сары, 50-ден көп қызыл"
+            }
+            Language::Arabic => {
+                "الأخطاء في الساعة بين عمليتي جلب: أقل من 5 أخضر، 5–20 كهرماني، 20–50 برتقالي، أكثر من 50 أحمر"
+            }
+        }
+    }
+
+    pub fn help_color_mode_temp_anomaly(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Deviation from each position's own temperature, learned over several fetches: under 1σ green, 1–2σ amber, 2–3σ orange, over 3σ red; all green until the baseline is established"
+            }
+            Language::Russian => {
+                "Отклонение от собственной температуры позиции, изученной за несколько загрузок: до 1σ зелёный, 1–2σ жёлтый, 2–3σ оранжевый, более 3σ красный; всё зелёное, пока база не набрана"
+            }
+            Language::Spanish => {
+                "Desviación de la temperatura propia de cada posición, aprendida en varias lecturas: menos de 1σ verde, 1–2σ ámbar, 2–3σ naranja, más de 3σ rojo; todo verde hasta completar la línea base"
+            }
+            Language::Persian => {
+                "انحراف از دمای عادی هر موقعیت که طی چند دریافت آموخته می‌شود: کمتر از ۱σ سبز، ۱ تا ۲σ کهربایی، ۲ تا ۳σ نارنجی، بیش از ۳σ قرمز؛ تا تکمیل خط پایه همه سبز است"
+            }
+            Language::Chinese => {
+                "与多次获取中学习到的各位置自身温度的偏差：低于 1σ 绿，1–2σ 琥珀，2–3σ 橙，超过 3σ 红；基线建立前全部为绿"
+            }
+            Language::Ukrainian => {
+                "Відхилення від власної температури позиції, вивченої за кілька завантажень: до 1σ зелений, 1–2σ жовтий, 2–3σ помаранчевий, понад 3σ червоний; усе зелене, доки базу не набрано"
+            }
+            Language::Polish => {
+                "Odchylenie od własnej temperatury pozycji, wyuczonej z kilku pobrań: poniżej 1σ zielony, 1–2σ bursztynowy, 2–3σ pomarańczowy, ponad 3σ czerwony; wszystko zielone do ustalenia linii bazowej"
+            }
+            Language::Kazakh => {
+                "Бірнеше жүктеуде үйренілген әр позицияның өз температурасынан ауытқу: 1σ-дан аз жасыл, 1–2σ сары, 2–3σ қызғылт сары, 3σ-дан көп қызыл; база жиналғанша бәрі жасыл"
+            }
+            Language::Arabic => {
+                "الانحراف عن درجة الحرارة المعتادة لكل موضع، المتعلَّمة عبر عدة عمليات جلب: أقل من 1σ أخضر، 1–2σ كهرماني، 2–3σ برتقالي، أكثر من 3σ أحمر؛ الكل أخضر حتى يكتمل خط الأساس"
+            }
+        }
+    }
+
+    pub fn help_color_mode_power_state(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Chips by clock: active (green), throttled below 90% of the slot target (amber), parked at a firmware sleep frequency under 100 MHz (blue), not clocked (gray)"
+            }
+            Language::Russian => {
+                "Чипы по частоте: активен (зелёный), троттлинг ниже 90% целевой частоты слота (янтарный), частота сна прошивки ниже 100 МГц (синий), без частоты (серый)"
+            }
+            Language::Spanish => {
+                "Chips por reloj: activo (verde), limitado por debajo del 90% del objetivo de la ranura (ámbar), en frecuencia de reposo del firmware por debajo de 100 MHz (azul), sin reloj (gris)"
+            }
+            Language::Persian => {
+                "چیپ‌ها بر اساس فرکانس: فعال (سبز)، کاهش فرکانس زیر ۹۰٪ هدف اسلات (کهربایی)، فرکانس خواب فریمور زیر ۱۰۰ مگاهرتز (آبی)، بدون فرکانس (خاکستری)"
+            }
+            Language::Chinese => {
+                "按时钟显示芯片：运行（绿色）、低于槽位目标 90% 的降频（琥珀色）、低于 100 MHz 的固件休眠频率（蓝色）、无时钟（灰色）"
+            }
+            Language::Ukrainian => {
+                "Чипи за частотою: активний (зелений), тротлінг нижче 90% цільової частоти слота (бурштиновий), частота сну прошивки нижче 100 МГц (синій), без частоти (сірий)"
+            }
+            Language::Polish => {
+                "Chipy według zegara: aktywny (zielony), dławiony poniżej 90% celu slotu (bursztynowy), uśpiony na częstotliwości firmware poniżej 100 MHz (niebieski), bez zegara (szary)"
+            }
+            Language::Kazakh => {
+                "Чиптер жиілік бойынша: белсенді (жасыл), слот мақсатының 90%-ынан төмен тротлинг (сары), 100 МГц-тен төмен микробағдарлама ұйқы жиілігі (көк), жиіліксіз (сұр)"
+            }
+            Language::Arabic => {
+                "الشرائح حسب التردد: نشطة (أخضر)، مخفّضة دون 90% من هدف الفتحة (كهرماني)، في تردد سكون البرنامج الثابت دون 100 ميغاهرتز (أزرق)، بلا تردد (رمادي)"
+            }
+        }
+    }
+
+    pub fn help_color_mode_heat_cycles(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Times each chip warmed back past 60°C after cooling below it during this session: up to 2 green, amber, orange, over 10 red. Thermal cycling fatigues solder joints, so heavily cycled chips are likelier to fail"
+            }
+            Language::Russian => {
+                "Сколько раз чип снова нагрелся выше 60°C после остывания за эту сессию: до 2 зелёный, янтарный, оранжевый, больше 10 красный. Термоциклы утомляют паяные соединения, поэтому такие чипы чаще выходят из строя"
+            }
+            Language::Spanish => {
+                "Veces que cada chip volvió a superar 60°C tras enfriarse por debajo en esta sesión: hasta 2 verde, ámbar, naranja, más de 10 rojo. Los ciclos térmicos fatigan las soldaduras, así que los chips muy ciclados fallan más"
+            }
+            Language::Persian => {
+                "دفعاتی که هر چیپ در این نشست پس از خنک شدن زیر ۶۰°C دوباره از آن گرم‌تر شد: تا ۲ سبز، کهربایی، نارنجی، بیش از ۱۰ قرمز. چرخه حرارتی اتصالات لحیم را فرسوده می‌کند، پس چیپ‌های پرچرخه بیشتر خراب می‌شوند"
+            }
+            Language::Chinese => {
+                "本次会话中每颗芯片冷却到 60°C 以下后再次升温超过它的次数：不超过 2 绿色，琥珀色，橙色，超过 10 红色。热循环会使焊点疲劳，循环多的芯片更容易失效"
+            }
+            Language::Ukrainian => {
+                "Скільки разів чип знову нагрівся вище 60°C після охолодження за цю сесію: до 2 зелений, бурштиновий, помаранчевий, понад 10 червоний. Термоцикли втомлюють паяні з'єднання, тож такі чипи частіше виходять з ладу"
+            }
+            Language::Polish => {
+                "Ile razy chip ponownie przekroczył 60°C po ostygnięciu poniżej w tej sesji: do 2 zielony, bursztynowy, pomarańczowy, ponad 10 czerwony. Cykle termiczne męczą luty, więc często cyklowane chipy częściej się psują"
+            }
+            Language::Kazakh => {
+                "Осы сеанста әр чип 60°C-тан төмен суығаннан кейін қайта қызған рет саны: 2-ге дейін жасыл, сары, қызғылт сары, 10-нан көп қызыл. Жылу циклдері дәнекерлеу қосылыстарын тоздырады, сондықтан мұндай чиптер жиі істен шығады"
+            }
+            Language::Arabic => {
+                "عدد مرات عودة كل شريحة فوق 60°م بعد تبريدها دونها في هذه الجلسة: حتى 2 أخضر، كهرماني، برتقالي، أكثر من 10 أحمر. الدورات الحرارية تُجهد وصلات اللحام، لذا تتعطل الشرائح كثيرة الدورات أكثر"
+            }
+        }
+    }
+
+    pub fn help_color_mode_voltage_group(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Chips grouped by supply voltage into up to 4 groups (blue, purple, teal, pink); a guess at mixed chip batches on one board, not a measurement of the silicon"
+            }
+            Language::Russian => {
+                "Чипы, сгруппированные по напряжению питания, до 4 групп (синяя, фиолетовая, бирюзовая, розовая); предположение о смешанных партиях чипов на плате, а не измерение кристалла"
+            }
+            Language::Spanish => {
+                "Chips agrupados por voltaje de alimentación en hasta 4 grupos (azul, morado, turquesa, rosa); una suposición de lotes de chips mezclados en una placa, no una medición del silicio"
+            }
+            Language::Persian => {
+                "چیپ‌ها بر اساس ولتاژ تغذیه در حداکثر ۴ گروه (آبی، بنفش، فیروزه‌ای، صورتی)؛ حدسی درباره دسته‌های مختلط چیپ روی یک برد، نه اندازه‌گیری سیلیکون"
+            }
+            Language::Chinese => {
+                "按供电电压将芯片分为最多 4 组（蓝、紫、青、粉）；用于推测同一板上混用的芯片批次，并非对硅片的测量"
+            }
+            Language::Ukrainian => {
+                "Чипи, згруповані за напругою живлення, до 4 груп (синя, фіолетова, бірюзова, рожева); припущення про змішані партії чипів на платі, а не вимірювання кристала"
+            }
+            Language::Polish => {
+                "Chipy pogrupowane według napięcia zasilania w maksymalnie 4 grupy (niebieska, fioletowa, turkusowa, różowa); przypuszczenie o mieszanych partiach chipów na płycie, a nie pomiar krzemu"
+            }
+            Language::Kazakh => {
+                "Чиптер қоректендіру кернеуі бойынша 4 топқа дейін топталған (көк, күлгін, көгілдір, қызғылт); бір платадағы аралас чип партиялары туралы болжам, кремнийді өлшеу емес"
+            }
+            Language::Arabic => {
+                "الشرائح مجمعة حسب جهد التغذية في 4 مجموعات كحد أقصى (أزرق، بنفسجي، فيروزي، وردي)؛ تخمين لدفعات شرائح مختلطة على لوحة واحدة، وليس قياسًا للسيليكون"
+            }
+        }
+    }
+
+    pub fn help_sidebar_title(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Reading the sidebar",
+            Language::Russian => "Как читать боковую панель",
+            Language::Spanish => "Cómo leer la barra lateral",
+            Language::Persian => "خواندن نوار کناری",
+            Language::Chinese => "侧栏说明",
+            Language::Ukrainian => "Як читати бічну панель",
+            Language::Polish => "Jak czytać panel boczny",
+            Language::Kazakh => "Бүйірлік панельді оқу",
+            Language::Arabic => "قراءة الشريط الجانبي",
+        }
+    }
+
+    /// Field descriptions for the sidebar chip rows
+    pub fn help_sidebar(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "System info: model, hardware revision and firmware version with its update status.\nFirst chip line: freq (MHz), vol (voltage), temp (°C, colored by heat), nonce (colored by shortfall vs the slot average).\nSecond line: err (hardware errors), crc (CRC errors), x (bus errors), repeat (repeated nonces), pct (the two percentages from the miner log).\nerr/h appears for chips above 5 errors per hour.\nStats adds a nonce histogram per slot, with a marker at the slot mean."
+            }
+            Language::Russian => {
+                "Сведения о системе: модель, ревизия платы и версия прошивки с её статусом обновления.\nПервая строка чипа: freq (МГц), vol (напряжение), temp (°C, цвет по нагреву), nonce (цвет по отставанию от среднего по слоту).\nВторая строка: err (аппаратные ошибки), crc (ошибки CRC), x (ошибки шины), repeat (повторные нонсы), pct (два процента из лога майнера).\nerr/h показывается для чипов с более чем 5 ошибками в час.\nСтатистика добавляет гистограмму нонсов по каждому слоту с отметкой среднего."
+            }
+            Language::Spanish => {
+                "Información del sistema: modelo, revisión de hardware y versión de firmware con su estado de actualización.\nPrimera línea del chip: freq (MHz), vol (voltaje), temp (°C, color según el calor), nonce (color según el déficit frente a la media del slot).\nSegunda línea: err (errores de hardware), crc (errores CRC), x (errores de bus), repeat (nonces repetidos), pct (los dos porcentajes del registro del minero).\nerr/h aparece en los chips con más de 5 errores por hora.\nEstadísticas añade un histograma de nonces por slot, con una marca en la media."
+            }
+            Language::Persian => {
+                "اطلاعات سیستم: مدل، نسخه سخت‌افزار و نسخه فریمور با وضعیت به‌روزرسانی آن.\nخط اول تراشه: freq (MHz)، vol (ولتاژ)، temp (°C، رنگ بر اساس گرما)، nonce (رنگ بر اساس کمبود نسبت به میانگین اسلات).\nخط دوم: err (خطاهای سخت‌افزاری)، crc (خطاهای CRC)، x (خطاهای گذرگاه)، repeat (nonceهای تکراری)، pct (دو درصد از لاگ ماینر).\nerr/h برای تراشه‌هایی با بیش از ۵ خطا در ساعت نمایش داده می‌شود.\nآمار یک هیستوگرام nonce برای هر اسلات با نشانگر میانگین اضافه می‌کند."
+            }
+            Language::Chinese => {
+                "系统信息：型号、硬件版本以及固件版本和更新状态。\n芯片第一行：freq（MHz）、vol（电压）、temp（°C，按温度着色）、nonce（按低于槽位平均的程度着色）。\n第二行：err（硬件错误）、crc（CRC 错误）、x（总线错误）、repeat（重复 nonce）、pct（矿机日志中的两个百分比）。\n每小时错误超过 5 的芯片会显示 err/h。\n统计会为每个槽位添加 nonce 直方图，并标出平均值。"
+            }
+            Language::Ukrainian => {
+                "Відомості про систему: модель, ревізія плати та версія прошивки з її статусом оновлення.\nПерший рядок чипа: freq (МГц), vol (напруга), temp (°C, колір за нагрівом), nonce (колір за відставанням від середнього по слоту).\nДругий рядок: err (апаратні помилки), crc (помилки CRC), x (помилки шини), repeat (повторні нонси), pct (два відсотки з логу майнера).\nerr/h показується для чипів з понад 5 помилками на годину.\nСтатистика додає гістограму нонсів для кожного слота з позначкою середнього."
+            }
+            Language::Polish => {
+                "Informacje o systemie: model, rewizja sprzętu i wersja firmware wraz ze statusem aktualizacji.\nPierwszy wiersz chipa: freq (MHz), vol (napięcie), temp (°C, kolor wg temperatury), nonce (kolor wg niedoboru względem średniej slotu).\nDrugi wiersz: err (błędy sprzętowe), crc (błędy CRC), x (błędy magistrali), repeat (powtórzone nonce), pct (dwa procenty z logu koparki).\nerr/h pojawia się dla chipów z ponad 5 błędami na godzinę.\nStatystyki dodają histogram nonce dla każdego slotu ze znacznikiem średniej."
+            }
+            Language::Kazakh => {
+                "Жүйе туралы: модель, аппараттық ревизия және жаңарту күйі бар микробағдарлама нұсқасы.\nЧиптің бірінші жолы: freq (МГц), vol (кернеу), temp (°C, қызуы бойынша түсі), nonce (слот орташасынан қалуы бойынша түсі).\nЕкінші жол: err (аппараттық қателер), crc (CRC қателері), x (шина қателері), repeat (қайталанған nonce), pct (майнер журналындағы екі пайыз).\nerr/h сағатына 5-тен көп қатесі бар чиптер үшін көрсетіледі.\nСтатистика әр слот үшін орташа белгісі бар nonce гистограммасын қосады."
+            }
+            Language::Arabic => {
+                "معلومات النظام: الطراز ومراجعة العتاد وإصدار البرنامج الثابت مع حالة تحديثه.\nالسطر الأول للشريحة: freq (ميغاهرتز)، vol (الجهد)، temp (°C، ملوّن حسب الحرارة)، nonce (ملوّن حسب النقص عن متوسط الفتحة).\nالسطر الثاني: err (أخطاء العتاد)، crc (أخطاء CRC)، x (أخطاء الناقل)، repeat (nonce مكررة)، pct (النسبتان من سجل المُعدِّن).\nيظهر err/h للشرائح التي تتجاوز 5 أخطاء في الساعة.\nتضيف الإحصائيات مدرجًا تكراريًا للـ nonce لكل فتحة مع علامة عند المتوسط."
+            }
+        }
+    }
+
+    pub fn help_shortcuts_title(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Keyboard shortcuts",
+            Language::Russian => "Горячие клавиши",
+            Language::Spanish => "Atajos de teclado",
+            Language::Persian => "میانبرهای صفحه‌کلید",
+            Language::Chinese => "键盘快捷键",
+            Language::Ukrainian => "Гарячі клавіші",
+            Language::Polish => "Skróty klawiszowe",
+            Language::Kazakh => "Пернетақта тіркесімдері",
+            Language::Arabic => "اختصارات لوحة المفاتيح",
+        }
+    }
+
+    pub fn help_shortcuts(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "F1 or ?: show or hide this help\nEsc: close this help\nArrow keys: move the selected chip on the grid\nTab: select the next chip"
+            }
+            Language::Russian => {
+                "F1 или ?: показать или скрыть справку\nEsc: закрыть справку\nСтрелки: переместить выбор чипа по сетке\nTab: выбрать следующий чип"
+            }
+            Language::Spanish => {
+                "F1 o ?: mostrar u ocultar esta ayuda\nEsc: cerrar esta ayuda\nFlechas: mover el chip seleccionado por la cuadrícula\nTab: seleccionar el siguiente chip"
+            }
+            Language::Persian => {
+                "F1 یا ?: نمایش یا پنهان کردن این راهنما\nEsc: بستن این راهنما\nکلیدهای جهت: جابه‌جایی تراشه انتخاب‌شده در شبکه\nTab: انتخاب تراشه بعدی"
+            }
+            Language::Chinese => {
+                "F1 或 ?：显示或隐藏帮助\nEsc：关闭帮助\n方向键：在网格中移动所选芯片\nTab：选择下一个芯片"
+            }
+            Language::Ukrainian => {
+                "F1 або ?: показати або сховати довідку\nEsc: закрити довідку\nСтрілки: перемістити вибір чипа сіткою\nTab: вибрати наступний чип"
+            }
+            Language::Polish => {
+                "F1 lub ?: pokaż lub ukryj pomoc\nEsc: zamknij pomoc\nStrzałki: przesuń zaznaczony chip po siatce\nTab: zaznacz następny chip"
+            }
+            Language::Kazakh => {
+                "F1 немесе ?: анықтаманы көрсету немесе жасыру\nEsc: анықтаманы жабу\nБағыт пернелері: таңдалған чипті тор бойымен жылжыту\nTab: келесі чипті таңдау"
+            }
+            Language::Arabic => {
+                "F1 أو ?: إظهار هذه المساعدة أو إخفاؤها\nEsc: إغلاق هذه المساعدة\nمفاتيح الأسهم: تحريك الشريحة المحددة على الشبكة\nTab: تحديد الشريحة التالية"
+            }
         }
     }
 
     // ColorMode translations
-    pub fn color_mode_temperature(lang: Language) -> &'static str {
+    /// Lower half of the snake layout (D0 side)
+    pub fn section_bottom(lang: Language) -> &'static str {
         match lang {
-            Language::English => "Temperature",
-            Language::Russian => "Температура",
-            Language::Spanish => "Temperatura",
-            Language::Persian => "دما",
-            Language::Chinese => "温度",
-            Language::Ukrainian => "Температура",
-            Language::Polish => "Temperatura",
-            Language::Kazakh => "Температура",
-            Language::Arabic => "الحرارة",
+            Language::English => "Bottom",
+            Language::Russian => "Низ",
+            Language::Spanish => "Inferior",
+            Language::Persian => "پایین",
+            Language::Chinese => "下半区",
+            Language::Ukrainian => "Низ",
+            Language::Polish => "Dół",
+            Language::Kazakh => "Төмен",
+            Language::Arabic => "السفلي",
         }
     }
 
-    pub fn color_mode_errors(lang: Language) -> &'static str {
+    /// Upper half of the snake layout
+    pub fn section_top(lang: Language) -> &'static str {
         match lang {
-            Language::English => "Errors",
-            Language::Russian => "Ошибки",
-            Language::Spanish => "Errores",
-            Language::Persian => "خطاها",
-            Language::Chinese => "错误",
-            Language::Ukrainian => "Помилки",
-            Language::Polish => "Błędy",
-            Language::Kazakh => "Қателер",
-            Language::Arabic => "الأخطاء",
+            Language::English => "Top",
+            Language::Russian => "Верх",
+            Language::Spanish => "Superior",
+            Language::Persian => "بالا",
+            Language::Chinese => "上半区",
+            Language::Ukrainian => "Верх",
+            Language::Polish => "Góra",
+            Language::Kazakh => "Жоғары",
+            Language::Arabic => "العلوي",
         }
     }
 
-    pub fn color_mode_crc(_lang: Language) -> &'static str {
-        "CRC"
+    pub fn avg(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "avg",
+            Language::Russian => "сред.",
+            Language::Spanish => "prom.",
+            Language::Persian => "میانگین",
+            Language::Chinese => "平均",
+            Language::Ukrainian => "сер.",
+            Language::Polish => "śr.",
+            Language::Kazakh => "орт.",
+            Language::Arabic => "متوسط",
+        }
     }
 
-    pub fn color_mode_gradient(lang: Language) -> &'static str {
+    pub fn settings(lang: Language) -> &'static str {
         match lang {
-            Language::English => "Gradient",
-            Language::Russian => "Градиент",
-            Language::Spanish => "Gradiente",
-            Language::Persian => "گرادیان",
-            Language::Chinese => "梯度",
-            Language::Ukrainian => "Градієнт",
-            Language::Polish => "Gradient",
-            Language::Kazakh => "Градиент",
-            Language::Arabic => "التدرج",
+            Language::English => "Settings",
+            Language::Russian => "Настройки",
+            Language::Spanish => "Ajustes",
+            Language::Persian => "تنظیمات",
+            Language::Chinese => "设置",
+            Language::Ukrainian => "Налаштування",
+            Language::Polish => "Ustawienia",
+            Language::Kazakh => "Баптаулар",
+            Language::Arabic => "الإعدادات",
         }
     }
 
-    pub fn color_mode_outliers(lang: Language) -> &'static str {
+    /// Label for the gradient noise floor slider
+    pub fn gradient_noise_floor(lang: Language) -> &'static str {
         match lang {
-            Language::English => "Outliers",
-            Language::Russian => "Выбросы",
-            Language::Spanish => "Atípicos",
-            Language::Persian => "پرت‌ها",
-            Language::Chinese => "异常值",
-            Language::Ukrainian => "Викиди",
-            Language::Polish => "Odstające",
-            Language::Kazakh => "Ауытқулар",
-            Language::Arabic => "القيم الشاذة",
+            Language::English => "Gradient noise floor",
+            Language::Russian => "Порог шума градиента",
+            Language::Spanish => "Umbral de ruido del gradiente",
+            Language::Persian => "آستانه نویز گرادیان",
+            Language::Chinese => "梯度噪声阈值",
+            Language::Ukrainian => "Поріг шуму градієнта",
+            Language::Polish => "Próg szumu gradientu",
+            Language::Kazakh => "Градиент шу шегі",
+            Language::Arabic => "عتبة ضوضاء التدرج",
         }
     }
 
-    pub fn color_mode_nonce(lang: Language) -> &'static str {
+    /// Status text for a fetch stage
+    pub fn fetch_stage(stage: FetchStage, lang: Language) -> &'static str {
+        match stage {
+            FetchStage::Connecting => Self::connecting(lang),
+            FetchStage::Authenticating => Self::authenticating(lang),
+            FetchStage::FetchingChipData => Self::fetching_chip_data(lang),
+            FetchStage::FetchingOverview => Self::fetching_overview(lang),
+            FetchStage::Parsing => Self::parsing(lang),
+        }
+    }
+
+    /// Status bar age of the displayed data: "Data: 5s ago", "Data: 5m ago", "Data: >10m ago"
+    pub fn data_age(seconds: u64, lang: Language) -> String {
+        let (secs, mins) = match lang {
+            Language::English => ("s", "m"),
+            Language::Russian | Language::Kazakh => (" с", " мин"),
+            Language::Spanish | Language::Polish => (" s", " min"),
+            Language::Persian => (" ثانیه", " دقیقه"),
+            Language::Chinese => ("秒", "分钟"),
+            Language::Ukrainian => (" с", " хв"),
+            Language::Arabic => (" ثانية", " دقيقة"),
+        };
+        let age = match seconds {
+            0..60 => format!("{seconds}{secs}"),
+            60..600 => format!("{}{mins}", seconds / 60),
+            _ => format!(">10{mins}"),
+        };
         match lang {
-            Language::English | Language::Spanish | Language::Polish => "Nonce",
-            Language::Russian => "Нонс",
-            Language::Persian => "نانس",
-            Language::Chinese => "随机数",
-            Language::Ukrainian => "Нонс",
-            Language::Kazakh => "Нонс",
-            Language::Arabic => "نونس",
+            Language::English => format!("Data: {age} ago"),
+            Language::Russian => format!("Данные: {age} назад"),
+            Language::Spanish => format!("Datos: hace {age}"),
+            Language::Persian => format!("داده: {age} پیش"),
+            Language::Chinese => format!("数据：{age}前"),
+            Language::Ukrainian => format!("Дані: {age} тому"),
+            Language::Polish => format!("Dane: {age} temu"),
+            Language::Kazakh => format!("Деректер: {age} бұрын"),
+            Language::Arabic => format!("البيانات: منذ {age}"),
         }
     }
-}
 
-/// Localized ColorMode for display in picker
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct LocalizedColorMode {
-    pub mode: crate::models::ColorMode,
-    pub lang: Language,
-}
+    /// Short chip label for tooltips and chip lists, e.g. "C42"
+    pub fn chip_label(id: i32, lang: Language) -> String {
+        match lang {
+            Language::English | Language::Spanish | Language::Polish => format!("C{id}"),
+            Language::Russian | Language::Ukrainian | Language::Kazakh => format!("Ч{id}"),
+            Language::Persian => format!("ت{id}"),
+            Language::Chinese => format!("芯片{id}"),
+            Language::Arabic => format!("ش{id}"),
+        }
+    }
 
-impl LocalizedColorMode {
-    pub fn all(lang: Language) -> Vec<Self> {
-        crate::models::ColorMode::ALL
-            .iter()
-            .map(|&mode| Self { mode, lang })
-            .collect()
+    /// Short domain label for tooltips, e.g. "D3"
+    pub fn domain_label(domain: i32, lang: Language) -> String {
+        match lang {
+            Language::English | Language::Spanish | Language::Polish => format!("D{domain}"),
+            Language::Russian | Language::Ukrainian | Language::Kazakh => format!("Д{domain}"),
+            Language::Persian => format!("د{domain}"),
+            Language::Chinese => format!("域{domain}"),
+            Language::Arabic => format!("ن{domain}"),
+        }
     }
-}
 
-impl fmt::Display for LocalizedColorMode {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use crate::models::ColorMode;
-        f.write_str(match self.mode {
-            ColorMode::Temperature => Tr::color_mode_temperature(self.lang),
-            ColorMode::Errors => Tr::color_mode_errors(self.lang),
-            ColorMode::Crc => Tr::color_mode_crc(self.lang),
-            ColorMode::Gradient => Tr::color_mode_gradient(self.lang),
-            ColorMode::Outliers => Tr::color_mode_outliers(self.lang),
-            ColorMode::Nonce => Tr::color_mode_nonce(self.lang),
-        })
+    /// Desktop notification body for a watched chip that drifted from its baseline
+    pub fn watch_alert(slot: i32, chip: i32, temp: i32, drift: i32, lang: Language) -> String {
+        match lang {
+            Language::English => {
+                format!("Slot {slot} chip {chip}: {temp}°C ({drift:+}°C since added to watch)")
+            }
+            Language::Russian => {
+                format!("Слот {slot}, чип {chip}: {temp}°C ({drift:+}°C с начала наблюдения)")
+            }
+            Language::Spanish => {
+                format!("Ranura {slot}, chip {chip}: {temp}°C ({drift:+}°C desde que se vigila)")
+            }
+            Language::Persian => {
+                format!("اسلات {slot} تراشه {chip}: {temp}°C ({drift:+}°C از شروع پایش)")
+            }
+            Language::Chinese => {
+                format!("槽位 {slot} 芯片 {chip}：{temp}°C（自开始监视 {drift:+}°C）")
+            }
+            Language::Ukrainian => {
+                format!("Слот {slot}, чип {chip}: {temp}°C ({drift:+}°C від початку спостереження)")
+            }
+            Language::Polish => {
+                format!("Slot {slot}, chip {chip}: {temp}°C ({drift:+}°C od dodania do obserwacji)")
+            }
+            Language::Kazakh => {
+                format!("Слот {slot}, чип {chip}: {temp}°C (бақылау басталғаннан {drift:+}°C)")
+            }
+            Language::Arabic => {
+                format!("الفتحة {slot} الشريحة {chip}: {temp}°C ({drift:+}°C منذ بدء المراقبة)")
+            }
+        }
+    }
+
+    /// Dead chip pattern with its likely cause, for the slot summary row
+    pub fn dead_chip_pattern(pattern: DeadChipPattern, lang: Language) -> String {
+        use DeadChipPattern::*;
+        match (lang, pattern) {
+            (Language::English, Scattered) => "Dead chips scattered".into(),
+            (Language::English, VerticalLine { domain }) => {
+                format!("Dead chips along D{domain}: domain bus failure")
+            }
+            (Language::English, HorizontalLine { row }) => {
+                format!("Row {row} dead across domains: row power failure")
+            }
+            (Language::English, FullDomain { domain }) => {
+                format!("D{domain} fully dead: domain bus failure")
+            }
+            (Language::English, DomainRange { start, end }) => {
+                format!("D{start}–D{end} fully dead: chain or power failure")
+            }
+            (Language::English, Diagonal) => {
+                "Dead chips on a diagonal: likely a firmware scan artifact".into()
+            }
+            (Language::Russian, Scattered) => "Мёртвые чипы разбросаны".into(),
+            (Language::Russian, VerticalLine { domain }) => {
+                format!("Мёртвые чипы вдоль D{domain}: отказ шины домена")
+            }
+            (Language::Russian, HorizontalLine { row }) => {
+                format!("Ряд {row} мёртв во всех доменах: отказ питания ряда")
+            }
+            (Language::Russian, FullDomain { domain }) => {
+                format!("D{domain} полностью мёртв: отказ шины домена")
+            }
+            (Language::Russian, DomainRange { start, end }) => {
+                format!("D{start}–D{end} полностью мертвы: обрыв цепи или питания")
+            }
+            (Language::Russian, Diagonal) => {
+                "Мёртвые чипы по диагонали: вероятно, артефакт сканирования прошивки".into()
+            }
+            (Language::Spanish, Scattered) => "Chips muertos dispersos".into(),
+            (Language::Spanish, VerticalLine { domain }) => {
+                format!("Chips muertos a lo largo de D{domain}: fallo del bus del dominio")
+            }
+            (Language::Spanish, HorizontalLine { row }) => {
+                format!("Fila {row} muerta en todos los dominios: fallo de alimentación de la fila")
+            }
+            (Language::Spanish, FullDomain { domain }) => {
+                format!("D{domain} completamente muerto: fallo del bus del dominio")
+            }
+            (Language::Spanish, DomainRange { start, end }) => {
+                format!("D{start}–D{end} completamente muertos: fallo de cadena o alimentación")
+            }
+            (Language::Spanish, Diagonal) => {
+                "Chips muertos en diagonal: probablemente un artefacto del escaneo del firmware"
+                    .into()
+            }
+            (Language::Persian, Scattered) => "تراشه‌های مرده پراکنده‌اند".into(),
+            (Language::Persian, VerticalLine { domain }) => {
+                format!("تراشه‌های مرده در امتداد D{domain}: خرابی باس دامنه")
+            }
+            (Language::Persian, HorizontalLine { row }) => {
+                format!("ردیف {row} در همه دامنه‌ها مرده: خرابی تغذیه ردیف")
+            }
+            (Language::Persian, FullDomain { domain }) => {
+                format!("D{domain} کاملاً مرده: خرابی باس دامنه")
+            }
+            (Language::Persian, DomainRange { start, end }) => {
+                format!("D{start}–D{end} کاملاً مرده: خرابی زنجیره یا تغذیه")
+            }
+            (Language::Persian, Diagonal) => {
+                "تراشه‌های مرده روی قطر: احتمالاً اثر اسکن فریمور".into()
+            }
+            (Language::Chinese, Scattered) => "坏芯片分散分布".into(),
+            (Language::Chinese, VerticalLine { domain }) => {
+                format!("坏芯片沿 D{domain} 分布：域总线故障")
+            }
+            (Language::Chinese, HorizontalLine { row }) => {
+                format!("第 {row} 行在各域均失效：行供电故障")
+            }
+            (Language::Chinese, FullDomain { domain }) => {
+                format!("D{domain} 完全失效：域总线故障")
+            }
+            (Language::Chinese, DomainRange { start, end }) => {
+                format!("D{start}–D{end} 完全失效：链路或供电故障")
+            }
+            (Language::Chinese, Diagonal) => "坏芯片呈对角线：可能是固件扫描伪影".into(),
+            (Language::Ukrainian, Scattered) => "Мертві чипи розкидані".into(),
+            (Language::Ukrainian, VerticalLine { domain }) => {
+                format!("Мертві чипи вздовж D{domain}: відмова шини домену")
+            }
+            (Language::Ukrainian, HorizontalLine { row }) => {
+                format!("Ряд {row} мертвий в усіх доменах: відмова живлення ряду")
+            }
+            (Language::Ukrainian, FullDomain { domain }) => {
+                format!("D{domain} повністю мертвий: відмова шини домену")
+            }
+            (Language::Ukrainian, DomainRange { start, end }) => {
+                format!("D{start}–D{end} повністю мертві: обрив ланцюга або живлення")
+            }
+            (Language::Ukrainian, Diagonal) => {
+                "Мертві чипи по діагоналі: ймовірно, артефакт сканування прошивки".into()
+            }
+            (Language::Polish, Scattered) => "Martwe chipy rozproszone".into(),
+            (Language::Polish, VerticalLine { domain }) => {
+                format!("Martwe chipy wzdłuż D{domain}: awaria magistrali domeny")
+            }
+            (Language::Polish, HorizontalLine { row }) => {
+                format!("Rząd {row} martwy we wszystkich domenach: awaria zasilania rzędu")
+            }
+            (Language::Polish, FullDomain { domain }) => {
+                format!("D{domain} całkowicie martwa: awaria magistrali domeny")
+            }
+            (Language::Polish, DomainRange { start, end }) => {
+                format!("D{start}–D{end} całkowicie martwe: awaria łańcucha lub zasilania")
+            }
+            (Language::Polish, Diagonal) => {
+                "Martwe chipy po przekątnej: prawdopodobnie artefakt skanowania firmware".into()
+            }
+            (Language::Kazakh, Scattered) => "Өлі чиптер шашыраңқы".into(),
+            (Language::Kazakh, VerticalLine { domain }) => {
+                format!("Өлі чиптер D{domain} бойында: домен шинасының ақауы")
+            }
+            (Language::Kazakh, HorizontalLine { row }) => {
+                format!("{row}-қатар барлық домендерде өлі: қатар қуатының ақауы")
+            }
+            (Language::Kazakh, FullDomain { domain }) => {
+                format!("D{domain} толық өлі: домен шинасының ақауы")
+            }
+            (Language::Kazakh, DomainRange { start, end }) => {
+                format!("D{start}–D{end} толық өлі: тізбек не қуат ақауы")
+            }
+            (Language::Kazakh, Diagonal) => {
+                "Өлі чиптер диагональ бойында: микробағдарлама сканерлеуінің артефактісі болуы мүмкін"
+                    .into()
+            }
+            (Language::Arabic, Scattered) => "الشرائح الميتة متفرقة".into(),
+            (Language::Arabic, VerticalLine { domain }) => {
+                format!("شرائح ميتة على طول D{domain}: عطل في ناقل النطاق")
+            }
+            (Language::Arabic, HorizontalLine { row }) => {
+                format!("الصف {row} ميت في كل النطاقات: عطل في تغذية الصف")
+            }
+            (Language::Arabic, FullDomain { domain }) => {
+                format!("D{domain} ميت بالكامل: عطل في ناقل النطاق")
+            }
+            (Language::Arabic, DomainRange { start, end }) => {
+                format!("D{start}–D{end} ميتة بالكامل: عطل في السلسلة أو التغذية")
+            }
+            (Language::Arabic, Diagonal) => {
+                "شرائح ميتة على قطر: على الأرجح أثر لمسح البرنامج الثابت".into()
+            }
+        }
+    }
+
+    /// "Rack B / Row 3 / Pos 4"
+    pub fn rack_location(position: &RackPosition, lang: Language) -> String {
+        format!(
+            "{} {} / {} {} / {} {}",
+            Self::rack(lang),
+            position.rack_id,
+            Self::rack_row(lang),
+            position.row,
+            Self::rack_pos(lang),
+            position.position
+        )
+    }
+
+    /// Status bar balance indicator: "Imbalanced ⚠ Slot 1 is 35% below average"
+    pub fn slot_imbalanced(slot_id: i32, deviation_pct: f32, lang: Language) -> String {
+        let pct = deviation_pct.abs().round();
+        let below = deviation_pct < 0.0;
+        match (lang, below) {
+            (Language::English, true) => {
+                format!("Imbalanced ⚠ Slot {slot_id} is {pct}% below average")
+            }
+            (Language::English, false) => {
+                format!("Imbalanced ⚠ Slot {slot_id} is {pct}% above average")
+            }
+            (Language::Russian, true) => {
+                format!("Дисбаланс ⚠ слот {slot_id} на {pct}% ниже среднего")
+            }
+            (Language::Russian, false) => {
+                format!("Дисбаланс ⚠ слот {slot_id} на {pct}% выше среднего")
+            }
+            (Language::Spanish, true) => {
+                format!("Desequilibrio ⚠ slot {slot_id} {pct}% bajo la media")
+            }
+            (Language::Spanish, false) => {
+                format!("Desequilibrio ⚠ slot {slot_id} {pct}% sobre la media")
+            }
+            (Language::Persian, true) => {
+                format!("نامتوازن ⚠ اسلات {slot_id} {pct}% کمتر از میانگین")
+            }
+            (Language::Persian, false) => {
+                format!("نامتوازن ⚠ اسلات {slot_id} {pct}% بیشتر از میانگین")
+            }
+            (Language::Chinese, true) => format!("不平衡 ⚠ 槽位 {slot_id} 低于平均 {pct}%"),
+            (Language::Chinese, false) => format!("不平衡 ⚠ 槽位 {slot_id} 高于平均 {pct}%"),
+            (Language::Ukrainian, true) => {
+                format!("Дисбаланс ⚠ слот {slot_id} на {pct}% нижче середнього")
+            }
+            (Language::Ukrainian, false) => {
+                format!("Дисбаланс ⚠ слот {slot_id} на {pct}% вище середнього")
+            }
+            (Language::Polish, true) => {
+                format!("Niezrównoważone ⚠ slot {slot_id} {pct}% poniżej średniej")
+            }
+            (Language::Polish, false) => {
+                format!("Niezrównoważone ⚠ slot {slot_id} {pct}% powyżej średniej")
+            }
+            (Language::Kazakh, true) => {
+                format!("Теңгерімсіз ⚠ {slot_id} слот орташадан {pct}% төмен")
+            }
+            (Language::Kazakh, false) => {
+                format!("Теңгерімсіз ⚠ {slot_id} слот орташадан {pct}% жоғары")
+            }
+            (Language::Arabic, true) => {
+                format!("غير متوازن ⚠ الفتحة {slot_id} أقل من المتوسط بـ {pct}%")
+            }
+            (Language::Arabic, false) => {
+                format!("غير متوازن ⚠ الفتحة {slot_id} أعلى من المتوسط بـ {pct}%")
+            }
+        }
+    }
+
+    /// Chip count warning, e.g. "⚠ Expected 135 chips, found 129 (−6 missing)"
+    pub fn config_mismatch(expected: u16, actual: usize, lang: Language) -> String {
+        let delta = i64::from(expected) - i64::try_from(actual).unwrap_or(i64::MAX);
+        let detail = if delta > 0 {
+            match lang {
+                Language::English => format!("−{delta} missing"),
+                Language::Russian => format!("−{delta} отсутствует"),
+                Language::Spanish => format!("faltan {delta}"),
+                Language::Persian => format!("{delta} کم"),
+                Language::Chinese => format!("缺少 {delta} 个"),
+                Language::Ukrainian => format!("−{delta} відсутні"),
+                Language::Polish => format!("brakuje {delta}"),
+                Language::Kazakh => format!("−{delta} жоқ"),
+                Language::Arabic => format!("{delta} مفقودة"),
+            }
+        } else {
+            let extra = -delta;
+            match lang {
+                Language::English => format!("+{extra} extra"),
+                Language::Russian => format!("+{extra} лишних"),
+                Language::Spanish => format!("+{extra} de más"),
+                Language::Persian => format!("{extra} اضافه"),
+                Language::Chinese => format!("多出 {extra} 个"),
+                Language::Ukrainian => format!("+{extra} зайвих"),
+                Language::Polish => format!("+{extra} nadmiarowych"),
+                Language::Kazakh => format!("+{extra} артық"),
+                Language::Arabic => format!("{extra} زائدة"),
+            }
+        };
+        match lang {
+            Language::English => format!("⚠ Expected {expected} chips, found {actual} ({detail})"),
+            Language::Russian => {
+                format!("⚠ Ожидалось {expected} чипов, найдено {actual} ({detail})")
+            }
+            Language::Spanish => {
+                format!("⚠ Se esperaban {expected} chips, hay {actual} ({detail})")
+            }
+            Language::Persian => format!("⚠ انتظار {expected} چیپ، یافت شد {actual} ({detail})"),
+            Language::Chinese => format!("⚠ 应有 {expected} 个芯片，实际 {actual} 个（{detail}）"),
+            Language::Ukrainian => {
+                format!("⚠ Очікувалось {expected} чипів, знайдено {actual} ({detail})")
+            }
+            Language::Polish => {
+                format!("⚠ Oczekiwano {expected} chipów, znaleziono {actual} ({detail})")
+            }
+            Language::Kazakh => format!("⚠ {expected} чип күтілді, {actual} табылды ({detail})"),
+            Language::Arabic => format!("⚠ المتوقع {expected} شريحة، الموجود {actual} ({detail})"),
+        }
+    }
+
+    /// Firmware changelog toggle
+    pub fn whats_new(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "What's new?",
+            Language::Russian => "Что нового?",
+            Language::Spanish => "¿Qué hay de nuevo?",
+            Language::Persian => "چه چیزی جدید است؟",
+            Language::Chinese => "更新内容",
+            Language::Ukrainian => "Що нового?",
+            Language::Polish => "Co nowego?",
+            Language::Kazakh => "Не жаңа?",
+            Language::Arabic => "ما الجديد؟",
+        }
+    }
+
+    pub fn export_json(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Export",
+            Language::Russian => "Экспорт",
+            Language::Spanish => "Exportar",
+            Language::Persian => "خروجی",
+            Language::Chinese => "导出",
+            Language::Ukrainian => "Експорт",
+            Language::Polish => "Eksport",
+            Language::Kazakh => "Экспорт",
+            Language::Arabic => "تصدير",
+        }
+    }
+
+    pub fn saved(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Saved",
+            Language::Russian => "Сохранено",
+            Language::Spanish => "Guardado",
+            Language::Persian => "ذخیره شد",
+            Language::Chinese => "已保存",
+            Language::Ukrainian => "Збережено",
+            Language::Polish => "Zapisano",
+            Language::Kazakh => "Сақталды",
+            Language::Arabic => "تم الحفظ",
+        }
+    }
+
+    /// Opens a saved export to compare against
+    pub fn diff(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Diff…",
+            Language::Russian => "Сравнить…",
+            Language::Spanish => "Comparar…",
+            Language::Persian => "مقایسه…",
+            Language::Chinese => "对比…",
+            Language::Ukrainian => "Порівняти…",
+            Language::Polish => "Porównaj…",
+            Language::Kazakh => "Салыстыру…",
+            Language::Arabic => "مقارنة…",
+        }
+    }
+
+    pub fn clear_diff(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Clear Diff",
+            Language::Russian => "Сбросить сравнение",
+            Language::Spanish => "Quitar comparación",
+            Language::Persian => "پاک کردن مقایسه",
+            Language::Chinese => "清除对比",
+            Language::Ukrainian => "Скинути порівняння",
+            Language::Polish => "Wyczyść porównanie",
+            Language::Kazakh => "Салыстыруды тазалау",
+            Language::Arabic => "مسح المقارنة",
+        }
+    }
+
+    /// Heading of the diff target panel
+    pub fn diff_target(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Diff target",
+            Language::Russian => "Для сравнения",
+            Language::Spanish => "Referencia",
+            Language::Persian => "مرجع مقایسه",
+            Language::Chinese => "对比目标",
+            Language::Ukrainian => "Для порівняння",
+            Language::Polish => "Cel porównania",
+            Language::Kazakh => "Салыстыру нысаны",
+            Language::Arabic => "هدف المقارنة",
+        }
+    }
+
+    /// Heading of the current-data panel in the diff view
+    pub fn current(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Current",
+            Language::Russian => "Текущие",
+            Language::Spanish => "Actual",
+            Language::Persian => "فعلی",
+            Language::Chinese => "当前",
+            Language::Ukrainian => "Поточні",
+            Language::Polish => "Bieżące",
+            Language::Kazakh => "Ағымдағы",
+            Language::Arabic => "الحالي",
+        }
+    }
+
+    pub fn model_variant(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Variant",
+            Language::Russian => "Вариант",
+            Language::Spanish => "Variante",
+            Language::Persian => "نوع",
+            Language::Chinese => "型号",
+            Language::Ukrainian => "Варіант",
+            Language::Polish => "Wariant",
+            Language::Kazakh => "Нұсқа",
+            Language::Arabic => "الطراز",
+        }
+    }
+
+    pub fn pcb_revision(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Control board",
+            Language::Russian => "Плата управления",
+            Language::Spanish => "Placa de control",
+            Language::Persian => "برد کنترل",
+            Language::Chinese => "控制板",
+            Language::Ukrainian => "Плата керування",
+            Language::Polish => "Płyta sterująca",
+            Language::Kazakh => "Басқару платасы",
+            Language::Arabic => "لوحة التحكم",
+        }
+    }
+
+    pub fn serial_prefix(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Serial",
+            Language::Russian => "Серийный номер",
+            Language::Spanish => "Número de serie",
+            Language::Persian => "شماره سریال",
+            Language::Chinese => "序列号",
+            Language::Ukrainian => "Серійний номер",
+            Language::Polish => "Numer seryjny",
+            Language::Kazakh => "Сериялық нөмір",
+            Language::Arabic => "الرقم التسلسلي",
+        }
+    }
+
+    /// Watch window toggle and heading
+    pub fn watch(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Watch",
+            Language::Russian => "Наблюдение",
+            Language::Spanish => "Vigilar",
+            Language::Persian => "پایش",
+            Language::Chinese => "监视",
+            Language::Ukrainian => "Спостереження",
+            Language::Polish => "Obserwuj",
+            Language::Kazakh => "Бақылау",
+            Language::Arabic => "مراقبة",
+        }
+    }
+
+    pub fn watch_selected(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "+ Selected chip",
+            Language::Russian => "+ Выбранный чип",
+            Language::Spanish => "+ Chip seleccionado",
+            Language::Persian => "+ تراشه انتخاب‌شده",
+            Language::Chinese => "+ 所选芯片",
+            Language::Ukrainian => "+ Вибраний чип",
+            Language::Polish => "+ Wybrany chip",
+            Language::Kazakh => "+ Таңдалған чип",
+            Language::Arabic => "+ الشريحة المحددة",
+        }
+    }
+
+    pub fn clear(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Clear",
+            Language::Russian => "Очистить",
+            Language::Spanish => "Limpiar",
+            Language::Persian => "پاک کردن",
+            Language::Chinese => "清除",
+            Language::Ukrainian => "Очистити",
+            Language::Polish => "Wyczyść",
+            Language::Kazakh => "Тазалау",
+            Language::Arabic => "مسح",
+        }
+    }
+
+    pub fn watch_empty(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Click a chip, then add it to watch its temperature across polls",
+            Language::Russian => {
+                "Выберите чип и добавьте его, чтобы следить за температурой между опросами"
+            }
+            Language::Spanish => {
+                "Haz clic en un chip y añádelo para vigilar su temperatura entre sondeos"
+            }
+            Language::Persian => "روی یک تراشه کلیک کنید و آن را برای پایش دما اضافه کنید",
+            Language::Chinese => "点击芯片并添加，以在轮询间监视其温度",
+            Language::Ukrainian => {
+                "Виберіть чип і додайте його, щоб стежити за температурою між опитуваннями"
+            }
+            Language::Polish => {
+                "Kliknij chip i dodaj go, aby śledzić jego temperaturę między odczytami"
+            }
+            Language::Kazakh => {
+                "Чипті таңдап, сауалнамалар арасындағы температурасын бақылау үшін қосыңыз"
+            }
+            Language::Arabic => "انقر على شريحة ثم أضفها لمراقبة حرارتها بين الاستطلاعات",
+        }
+    }
+
+    /// Settings label for the watch window alert threshold
+    pub fn watch_alert_delta(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Watch alert Δ",
+            Language::Russian => "Порог наблюдения Δ",
+            Language::Spanish => "Alerta de vigilancia Δ",
+            Language::Persian => "آستانه هشدار پایش Δ",
+            Language::Chinese => "监视警报 Δ",
+            Language::Ukrainian => "Поріг спостереження Δ",
+            Language::Polish => "Próg alarmu Δ",
+            Language::Kazakh => "Бақылау шегі Δ",
+            Language::Arabic => "عتبة تنبيه المراقبة Δ",
+        }
+    }
+
+    pub fn authenticating(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Authenticating...",
+            Language::Russian => "Авторизация...",
+            Language::Spanish => "Autenticando...",
+            Language::Persian => "در حال احراز هویت...",
+            Language::Chinese => "认证中...",
+            Language::Ukrainian => "Авторизація...",
+            Language::Polish => "Uwierzytelnianie...",
+            Language::Kazakh => "Авторизация...",
+            Language::Arabic => "جاري المصادقة...",
+        }
+    }
+
+    pub fn fetching_chip_data(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Fetching chip data...",
+            Language::Russian => "Загрузка данных чипов...",
+            Language::Spanish => "Obteniendo datos de chips...",
+            Language::Persian => "در حال دریافت داده تراشه‌ها...",
+            Language::Chinese => "获取芯片数据中...",
+            Language::Ukrainian => "Завантаження даних чипів...",
+            Language::Polish => "Pobieranie danych chipów...",
+            Language::Kazakh => "Чип деректерін алу...",
+            Language::Arabic => "جاري جلب بيانات الشرائح...",
+        }
+    }
+
+    pub fn fetching_overview(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Fetching overview...",
+            Language::Russian => "Загрузка обзора...",
+            Language::Spanish => "Obteniendo resumen...",
+            Language::Persian => "در حال دریافت نمای کلی...",
+            Language::Chinese => "获取概览中...",
+            Language::Ukrainian => "Завантаження огляду...",
+            Language::Polish => "Pobieranie przeglądu...",
+            Language::Kazakh => "Шолуды алу...",
+            Language::Arabic => "جاري جلب النظرة العامة...",
+        }
+    }
+
+    pub fn parsing(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Parsing...",
+            Language::Russian => "Разбор данных...",
+            Language::Spanish => "Analizando...",
+            Language::Persian => "در حال پردازش...",
+            Language::Chinese => "解析中...",
+            Language::Ukrainian => "Розбір даних...",
+            Language::Polish => "Przetwarzanie...",
+            Language::Kazakh => "Талдау...",
+            Language::Arabic => "جاري التحليل...",
+        }
+    }
+
+    pub fn direct(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Direct",
+            Language::Russian => "Напрямую",
+            Language::Spanish => "Directa",
+            Language::Persian => "مستقیم",
+            Language::Chinese => "直连",
+            Language::Ukrainian => "Напряму",
+            Language::Polish => "Bezpośrednio",
+            Language::Kazakh => "Тікелей",
+            Language::Arabic => "مباشر",
+        }
+    }
+
+    pub fn ssh_tunnel(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "SSH tunnel",
+            Language::Russian => "SSH-туннель",
+            Language::Spanish => "Túnel SSH",
+            Language::Persian => "تونل SSH",
+            Language::Chinese => "SSH 隧道",
+            Language::Ukrainian => "SSH-тунель",
+            Language::Polish => "Tunel SSH",
+            Language::Kazakh => "SSH туннелі",
+            Language::Arabic => "نفق SSH",
+        }
+    }
+
+    /// Placeholder for the SSH jump host address
+    pub fn jump_host(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Jump host[:port]",
+            Language::Russian => "Промежуточный хост[:порт]",
+            Language::Spanish => "Host de salto[:puerto]",
+            Language::Persian => "میزبان واسط[:پورت]",
+            Language::Chinese => "跳板机[:端口]",
+            Language::Ukrainian => "Проміжний хост[:порт]",
+            Language::Polish => "Host pośredni[:port]",
+            Language::Kazakh => "Аралық хост[:порт]",
+            Language::Arabic => "المضيف الوسيط[:المنفذ]",
+        }
+    }
+
+    pub fn ssh_key(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Private key path",
+            Language::Russian => "Путь к закрытому ключу",
+            Language::Spanish => "Ruta de la clave privada",
+            Language::Persian => "مسیر کلید خصوصی",
+            Language::Chinese => "私钥路径",
+            Language::Ukrainian => "Шлях до закритого ключа",
+            Language::Polish => "Ścieżka klucza prywatnego",
+            Language::Kazakh => "Жабық кілт жолы",
+            Language::Arabic => "مسار المفتاح الخاص",
+        }
+    }
+
+    /// Marks a value as an estimate rather than a measurement
+    pub fn estimated(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "(est.)",
+            Language::Russian => "(оценка)",
+            Language::Spanish => "(est.)",
+            Language::Persian => "(تخمینی)",
+            Language::Chinese => "(估算)",
+            Language::Ukrainian => "(оцінка)",
+            Language::Polish => "(szac.)",
+            Language::Kazakh => "(бағалау)",
+            Language::Arabic => "(تقديري)",
+        }
+    }
+
+    pub fn power_budget(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Power (est.)",
+            Language::Russian => "Мощность (оценка)",
+            Language::Spanish => "Potencia (est.)",
+            Language::Persian => "توان (تخمینی)",
+            Language::Chinese => "功耗（估算）",
+            Language::Ukrainian => "Потужність (оцінка)",
+            Language::Polish => "Moc (szac.)",
+            Language::Kazakh => "Қуат (бағалау)",
+            Language::Arabic => "الطاقة (تقديرية)",
+        }
+    }
+
+    pub fn rated(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "rated",
+            Language::Russian => "номинал",
+            Language::Spanish => "nominal",
+            Language::Persian => "نامی",
+            Language::Chinese => "额定",
+            Language::Ukrainian => "номінал",
+            Language::Polish => "znamionowa",
+            Language::Kazakh => "номиналды",
+            Language::Arabic => "الاسمية",
+        }
+    }
+
+    /// Settings toggle for the per-chip domain nonce histograms
+    pub fn nonce_mini_hist(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Nonce mini-histograms",
+            Language::Russian => "Мини-гистограммы нонсов",
+            Language::Spanish => "Mini histogramas de nonces",
+            Language::Persian => "مینی‌هیستوگرام نانس",
+            Language::Chinese => "Nonce 迷你直方图",
+            Language::Ukrainian => "Міні-гістограми нонсів",
+            Language::Polish => "Mini histogramy nonce",
+            Language::Kazakh => "Нонс мини-гистограммалары",
+            Language::Arabic => "مدرجات nonce المصغرة",
+        }
+    }
+
+    pub fn notes(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Notes",
+            Language::Russian => "Заметки",
+            Language::Spanish => "Notas",
+            Language::Persian => "یادداشت‌ها",
+            Language::Chinese => "备注",
+            Language::Ukrainian => "Нотатки",
+            Language::Polish => "Notatki",
+            Language::Kazakh => "Жазбалар",
+            Language::Arabic => "ملاحظات",
+        }
+    }
+
+    pub fn import_csv(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Import CSV…",
+            Language::Russian => "Импорт CSV…",
+            Language::Spanish => "Importar CSV…",
+            Language::Persian => "وارد کردن CSV…",
+            Language::Chinese => "导入 CSV…",
+            Language::Ukrainian => "Імпорт CSV…",
+            Language::Polish => "Importuj CSV…",
+            Language::Kazakh => "CSV импорттау…",
+            Language::Arabic => "استيراد CSV…",
+        }
+    }
+
+    /// Empty notes panel hint: CSV columns
+    pub fn notes_empty(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "No notes. Import a CSV with columns slot_id,chip_id,note",
+            Language::Russian => "Заметок нет. Импортируйте CSV со столбцами slot_id,chip_id,note",
+            Language::Spanish => "Sin notas. Importa un CSV con columnas slot_id,chip_id,note",
+            Language::Persian => "یادداشتی نیست. یک CSV با ستون‌های slot_id,chip_id,note وارد کنید",
+            Language::Chinese => "暂无备注。导入包含 slot_id,chip_id,note 列的 CSV",
+            Language::Ukrainian => {
+                "Нотаток немає. Імпортуйте CSV зі стовпцями slot_id,chip_id,note"
+            }
+            Language::Polish => "Brak notatek. Zaimportuj CSV z kolumnami slot_id,chip_id,note",
+            Language::Kazakh => "Жазба жоқ. slot_id,chip_id,note бағандары бар CSV импорттаңыз",
+            Language::Arabic => "لا توجد ملاحظات. استورد CSV بالأعمدة slot_id,chip_id,note",
+        }
+    }
+
+    pub fn notes_imported(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Notes imported",
+            Language::Russian => "Импортировано заметок",
+            Language::Spanish => "Notas importadas",
+            Language::Persian => "یادداشت‌های واردشده",
+            Language::Chinese => "已导入备注",
+            Language::Ukrainian => "Імпортовано нотаток",
+            Language::Polish => "Zaimportowane notatki",
+            Language::Kazakh => "Импортталған жазбалар",
+            Language::Arabic => "الملاحظات المستوردة",
+        }
+    }
+
+    pub fn notes_unknown_chips(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Not on this miner",
+            Language::Russian => "Нет на этом майнере",
+            Language::Spanish => "No están en este minero",
+            Language::Persian => "در این ماینر نیست",
+            Language::Chinese => "此矿机上不存在",
+            Language::Ukrainian => "Немає на цьому майнері",
+            Language::Polish => "Brak w tej koparce",
+            Language::Kazakh => "Бұл майнерде жоқ",
+            Language::Arabic => "غير موجودة في هذا المعدن",
+        }
+    }
+
+    pub fn notes_conflicts(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Chips that already have a note",
+            Language::Russian => "Чипы, у которых уже есть заметка",
+            Language::Spanish => "Chips que ya tienen nota",
+            Language::Persian => "تراشه‌هایی که از قبل یادداشت دارند",
+            Language::Chinese => "已有备注的芯片",
+            Language::Ukrainian => "Чипи, що вже мають нотатку",
+            Language::Polish => "Chipy, które mają już notatkę",
+            Language::Kazakh => "Жазбасы бар чиптер",
+            Language::Arabic => "شرائح لديها ملاحظة بالفعل",
+        }
+    }
+
+    pub fn keep_existing(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Keep existing",
+            Language::Russian => "Оставить текущие",
+            Language::Spanish => "Mantener existentes",
+            Language::Persian => "حفظ موجود",
+            Language::Chinese => "保留现有",
+            Language::Ukrainian => "Залишити наявні",
+            Language::Polish => "Zachowaj istniejące",
+            Language::Kazakh => "Барын қалдыру",
+            Language::Arabic => "الإبقاء على الحالية",
+        }
+    }
+
+    pub fn replace(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Replace",
+            Language::Russian => "Заменить",
+            Language::Spanish => "Reemplazar",
+            Language::Persian => "جایگزینی",
+            Language::Chinese => "替换",
+            Language::Ukrainian => "Замінити",
+            Language::Polish => "Zastąp",
+            Language::Kazakh => "Ауыстыру",
+            Language::Arabic => "استبدال",
+        }
+    }
+
+    pub fn append(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Append",
+            Language::Russian => "Дописать",
+            Language::Spanish => "Añadir",
+            Language::Persian => "افزودن",
+            Language::Chinese => "追加",
+            Language::Ukrainian => "Дописати",
+            Language::Polish => "Dołącz",
+            Language::Kazakh => "Қосу",
+            Language::Arabic => "إلحاق",
+        }
+    }
+
+    pub fn pdf_report(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "PDF report",
+            Language::Russian => "PDF-отчёт",
+            Language::Spanish => "Informe PDF",
+            Language::Persian => "گزارش PDF",
+            Language::Chinese => "PDF 报告",
+            Language::Ukrainian => "PDF-звіт",
+            Language::Polish => "Raport PDF",
+            Language::Kazakh => "PDF есеп",
+            Language::Arabic => "تقرير PDF",
+        }
+    }
+
+    pub fn firmware_up_to_date(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Up to date",
+            Language::Russian => "Актуальна",
+            Language::Spanish => "Actualizado",
+            Language::Persian => "به‌روز",
+            Language::Chinese => "已是最新",
+            Language::Ukrainian => "Актуальна",
+            Language::Polish => "Aktualne",
+            Language::Kazakh => "Жаңартылған",
+            Language::Arabic => "محدّث",
+        }
+    }
+
+    pub fn firmware_update_available(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Update",
+            Language::Russian => "Обновление",
+            Language::Spanish => "Actualización",
+            Language::Persian => "به‌روزرسانی",
+            Language::Chinese => "可更新",
+            Language::Ukrainian => "Оновлення",
+            Language::Polish => "Aktualizacja",
+            Language::Kazakh => "Жаңарту",
+            Language::Arabic => "تحديث",
+        }
+    }
+
+    /// Label for the count of adjacent failing-chip groups on a board
+    pub fn chip_clusters(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Failing clusters",
+            Language::Russian => "Кластеры сбоев",
+            Language::Spanish => "Grupos con fallos",
+            Language::Persian => "خوشه‌های معیوب",
+            Language::Chinese => "故障簇",
+            Language::Ukrainian => "Кластери збоїв",
+            Language::Polish => "Skupiska awarii",
+            Language::Kazakh => "Ақаулы кластерлер",
+            Language::Arabic => "مجموعات معطلة",
+        }
+    }
+
+    /// Fleet thumbnail label before the per-miner poll interval input
+    pub fn poll_every(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Poll every",
+            Language::Russian => "Опрос каждые",
+            Language::Spanish => "Consultar cada",
+            Language::Persian => "واکشی هر",
+            Language::Chinese => "轮询间隔",
+            Language::Ukrainian => "Опитування кожні",
+            Language::Polish => "Odpytuj co",
+            Language::Kazakh => "Сұрау аралығы",
+            Language::Arabic => "الاستطلاع كل",
+        }
+    }
+
+    pub fn seconds_short(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "s",
+            Language::Russian => "с",
+            Language::Spanish => "s",
+            Language::Persian => "ث",
+            Language::Chinese => "秒",
+            Language::Ukrainian => "с",
+            Language::Polish => "s",
+            Language::Kazakh => "с",
+            Language::Arabic => "ث",
+        }
+    }
+
+    pub fn next_poll_in(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Next poll in",
+            Language::Russian => "Следующий опрос через",
+            Language::Spanish => "Próxima consulta en",
+            Language::Persian => "واکشی بعدی تا",
+            Language::Chinese => "下次轮询",
+            Language::Ukrainian => "Наступне опитування через",
+            Language::Polish => "Następne odpytanie za",
+            Language::Kazakh => "Келесі сұрау",
+            Language::Arabic => "الاستطلاع التالي بعد",
+        }
+    }
+
+    pub fn export_selection(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Export selection",
+            Language::Russian => "Экспорт выделения",
+            Language::Spanish => "Exportar selección",
+            Language::Persian => "خروجی انتخاب",
+            Language::Chinese => "导出选区",
+            Language::Ukrainian => "Експорт виділення",
+            Language::Polish => "Eksport zaznaczenia",
+            Language::Kazakh => "Таңдауды экспорттау",
+            Language::Arabic => "تصدير التحديد",
+        }
+    }
+
+    pub fn chip_groups(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Chip groups",
+            Language::Russian => "Группы чипов",
+            Language::Spanish => "Grupos de chips",
+            Language::Persian => "گروه‌های تراشه",
+            Language::Chinese => "芯片分组",
+            Language::Ukrainian => "Групи чипів",
+            Language::Polish => "Grupy chipów",
+            Language::Kazakh => "Чип топтары",
+            Language::Arabic => "مجموعات الشرائح",
+        }
+    }
+
+    pub fn add_group(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "+ Group",
+            Language::Russian => "+ Группа",
+            Language::Spanish => "+ Grupo",
+            Language::Persian => "+ گروه",
+            Language::Chinese => "+ 分组",
+            Language::Ukrainian => "+ Група",
+            Language::Polish => "+ Grupa",
+            Language::Kazakh => "+ Топ",
+            Language::Arabic => "+ مجموعة",
+        }
+    }
+
+    pub fn group_domains(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Domains",
+            Language::Russian => "Домены",
+            Language::Spanish => "Dominios",
+            Language::Persian => "دامنه‌ها",
+            Language::Chinese => "域",
+            Language::Ukrainian => "Домени",
+            Language::Polish => "Domeny",
+            Language::Kazakh => "Домендер",
+            Language::Arabic => "النطاقات",
+        }
+    }
+
+    pub fn group_rows(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Rows (optional)",
+            Language::Russian => "Строки (необяз.)",
+            Language::Spanish => "Filas (opcional)",
+            Language::Persian => "ردیف‌ها (اختیاری)",
+            Language::Chinese => "行（可选）",
+            Language::Ukrainian => "Рядки (необов.)",
+            Language::Polish => "Wiersze (opcjonalnie)",
+            Language::Kazakh => "Жолдар (міндетті емес)",
+            Language::Arabic => "الصفوف (اختياري)",
+        }
+    }
+
+    pub fn group_label(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Label",
+            Language::Russian => "Подпись",
+            Language::Spanish => "Etiqueta",
+            Language::Persian => "برچسب",
+            Language::Chinese => "标签",
+            Language::Ukrainian => "Підпис",
+            Language::Polish => "Etykieta",
+            Language::Kazakh => "Белгі",
+            Language::Arabic => "التسمية",
+        }
+    }
+
+    pub fn add(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Add",
+            Language::Russian => "Добавить",
+            Language::Spanish => "Añadir",
+            Language::Persian => "افزودن",
+            Language::Chinese => "添加",
+            Language::Ukrainian => "Додати",
+            Language::Polish => "Dodaj",
+            Language::Kazakh => "Қосу",
+            Language::Arabic => "إضافة",
+        }
+    }
+
+    /// Moran's I of CRC errors in the slot stats row
+    pub fn crc_clustering(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "CRC clustering",
+            Language::Russian => "Кластеризация CRC",
+            Language::Spanish => "Agrupamiento CRC",
+            Language::Persian => "خوشه‌بندی CRC",
+            Language::Chinese => "CRC 聚集度",
+            Language::Ukrainian => "Кластеризація CRC",
+            Language::Polish => "Skupienie CRC",
+            Language::Kazakh => "CRC шоғырлануы",
+            Language::Arabic => "تجمّع CRC",
+        }
+    }
+
+    pub fn high(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "HIGH",
+            Language::Russian => "ВЫСОКАЯ",
+            Language::Spanish => "ALTO",
+            Language::Persian => "بالا",
+            Language::Chinese => "高",
+            Language::Ukrainian => "ВИСОКА",
+            Language::Polish => "WYSOKIE",
+            Language::Kazakh => "ЖОҒАРЫ",
+            Language::Arabic => "مرتفع",
+        }
+    }
+
+    /// Chip context menu entry recording a physical chip swap
+    pub fn mark_replaced(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Mark replaced",
+            Language::Russian => "Отметить замену",
+            Language::Spanish => "Marcar como reemplazado",
+            Language::Persian => "علامت‌گذاری به‌عنوان تعویض‌شده",
+            Language::Chinese => "标记为已更换",
+            Language::Ukrainian => "Позначити заміну",
+            Language::Polish => "Oznacz jako wymieniony",
+            Language::Kazakh => "Ауыстырылды деп белгілеу",
+            Language::Arabic => "تعليم كمستبدل",
+        }
+    }
+
+    pub fn replaced(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Replaced",
+            Language::Russian => "Заменён",
+            Language::Spanish => "Reemplazado",
+            Language::Persian => "تعویض شده",
+            Language::Chinese => "已更换",
+            Language::Ukrainian => "Замінено",
+            Language::Polish => "Wymieniony",
+            Language::Kazakh => "Ауыстырылған",
+            Language::Arabic => "مستبدل",
+        }
+    }
+
+    pub fn health_before(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Before",
+            Language::Russian => "До",
+            Language::Spanish => "Antes",
+            Language::Persian => "قبل",
+            Language::Chinese => "更换前",
+            Language::Ukrainian => "До",
+            Language::Polish => "Przed",
+            Language::Kazakh => "Дейін",
+            Language::Arabic => "قبل",
+        }
+    }
+
+    pub fn health_after(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "After",
+            Language::Russian => "После",
+            Language::Spanish => "Después",
+            Language::Persian => "بعد",
+            Language::Chinese => "更换后",
+            Language::Ukrainian => "Після",
+            Language::Polish => "Po",
+            Language::Kazakh => "Кейін",
+            Language::Arabic => "بعد",
+        }
+    }
+
+    pub fn color_mode_temp_anomaly(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Temp σ",
+            Language::Russian => "Темп σ",
+            Language::Spanish => "Temp σ",
+            Language::Persian => "دما σ",
+            Language::Chinese => "温度 σ",
+            Language::Ukrainian => "Темп σ",
+            Language::Polish => "Temp σ",
+            Language::Kazakh => "Темп σ",
+            Language::Arabic => "الحرارة σ",
+        }
+    }
+
+    /// Settings label for the learned temperature baseline
+    pub fn temp_baseline(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Temp baseline",
+            Language::Russian => "База температур",
+            Language::Spanish => "Línea base de temp.",
+            Language::Persian => "خط پایه دما",
+            Language::Chinese => "温度基线",
+            Language::Ukrainian => "База температур",
+            Language::Polish => "Linia bazowa temp.",
+            Language::Kazakh => "Температура базасы",
+            Language::Arabic => "خط أساس الحرارة",
+        }
+    }
+
+    pub fn reset(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Reset",
+            Language::Russian => "Сбросить",
+            Language::Spanish => "Restablecer",
+            Language::Persian => "بازنشانی",
+            Language::Chinese => "重置",
+            Language::Ukrainian => "Скинути",
+            Language::Polish => "Resetuj",
+            Language::Kazakh => "Қалпына келтіру",
+            Language::Arabic => "إعادة تعيين",
+        }
+    }
+
+    pub fn export_baseline(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Export baseline",
+            Language::Russian => "Экспорт базы",
+            Language::Spanish => "Exportar línea base",
+            Language::Persian => "خروجی خط پایه",
+            Language::Chinese => "导出基线",
+            Language::Ukrainian => "Експорт бази",
+            Language::Polish => "Eksportuj linię bazową",
+            Language::Kazakh => "Базаны экспорттау",
+            Language::Arabic => "تصدير خط الأساس",
+        }
+    }
+
+    pub fn import_baseline(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Import baseline",
+            Language::Russian => "Импорт базы",
+            Language::Spanish => "Importar línea base",
+            Language::Persian => "ورود خط پایه",
+            Language::Chinese => "导入基线",
+            Language::Ukrainian => "Імпорт бази",
+            Language::Polish => "Importuj linię bazową",
+            Language::Kazakh => "Базаны импорттау",
+            Language::Arabic => "استيراد خط الأساس",
+        }
+    }
+
+    /// Controls checkbox: receive miner logs pushed over UDP syslog
+    pub fn syslog_mode(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Syslog mode",
+            Language::Russian => "Режим syslog",
+            Language::Spanish => "Modo syslog",
+            Language::Persian => "حالت syslog",
+            Language::Chinese => "Syslog 模式",
+            Language::Ukrainian => "Режим syslog",
+            Language::Polish => "Tryb syslog",
+            Language::Kazakh => "Syslog режимі",
+            Language::Arabic => "وضع syslog",
+        }
+    }
+
+    /// Slot header warning when many chips changed between two fetches
+    pub fn board_changed(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Board changed since last fetch",
+            Language::Russian => "Плата изменилась с прошлой загрузки",
+            Language::Spanish => "La placa cambió desde la última lectura",
+            Language::Persian => "برد از دریافت قبلی تغییر کرده است",
+            Language::Chinese => "自上次获取以来板卡已变化",
+            Language::Ukrainian => "Плата змінилася з минулого завантаження",
+            Language::Polish => "Płyta zmieniła się od ostatniego pobrania",
+            Language::Kazakh => "Плата соңғы жүктеуден бері өзгерді",
+            Language::Arabic => "تغيّرت اللوحة منذ آخر جلب",
+        }
+    }
+
+    pub fn chips_unchanged(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "chips unchanged",
+            Language::Russian => "чипов без изменений",
+            Language::Spanish => "chips sin cambios",
+            Language::Persian => "تراشه بدون تغییر",
+            Language::Chinese => "芯片未变",
+            Language::Ukrainian => "чипів без змін",
+            Language::Polish => "chipów bez zmian",
+            Language::Kazakh => "чип өзгермеген",
+            Language::Arabic => "من الشرائح دون تغيير",
+        }
+    }
+
+    /// Live nonce rate bar label in slot headers
+    pub fn nonce_rate(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Nonce rate",
+            Language::Russian => "Скорость нонсов",
+            Language::Spanish => "Tasa de nonces",
+            Language::Persian => "نرخ نانس",
+            Language::Chinese => "Nonce 速率",
+            Language::Ukrainian => "Швидкість нонсів",
+            Language::Polish => "Tempo nonce",
+            Language::Kazakh => "Нонс жылдамдығы",
+            Language::Arabic => "معدل النونس",
+        }
+    }
+
+    /// Settings: "Keep last [N] fetches"
+    pub fn keep_last(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Keep last",
+            Language::Russian => "Хранить последние",
+            Language::Spanish => "Conservar las últimas",
+            Language::Persian => "نگه‌داشتن آخرین",
+            Language::Chinese => "保留最近",
+            Language::Ukrainian => "Зберігати останні",
+            Language::Polish => "Zachowaj ostatnie",
+            Language::Kazakh => "Соңғыларын сақтау",
+            Language::Arabic => "الاحتفاظ بآخر",
+        }
+    }
+
+    pub fn fetches(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "fetches",
+            Language::Russian => "загрузок",
+            Language::Spanish => "lecturas",
+            Language::Persian => "دریافت",
+            Language::Chinese => "次获取",
+            Language::Ukrainian => "завантажень",
+            Language::Polish => "pobrań",
+            Language::Kazakh => "жүктеу",
+            Language::Arabic => "عمليات جلب",
+        }
+    }
+
+    /// Settings: "Keep history for [N] days"
+    pub fn keep_history_for(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Keep history for",
+            Language::Russian => "Хранить историю",
+            Language::Spanish => "Conservar historial durante",
+            Language::Persian => "نگه‌داشتن تاریخچه برای",
+            Language::Chinese => "历史保留",
+            Language::Ukrainian => "Зберігати історію",
+            Language::Polish => "Przechowuj historię przez",
+            Language::Kazakh => "Тарихты сақтау",
+            Language::Arabic => "الاحتفاظ بالسجل لمدة",
+        }
+    }
+
+    pub fn days(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "days",
+            Language::Russian => "дн.",
+            Language::Spanish => "días",
+            Language::Persian => "روز",
+            Language::Chinese => "天",
+            Language::Ukrainian => "дн.",
+            Language::Polish => "dni",
+            Language::Kazakh => "күн",
+            Language::Arabic => "أيام",
+        }
+    }
+
+    pub fn prune_now(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Prune now",
+            Language::Russian => "Очистить сейчас",
+            Language::Spanish => "Depurar ahora",
+            Language::Persian => "پاک‌سازی اکنون",
+            Language::Chinese => "立即清理",
+            Language::Ukrainian => "Очистити зараз",
+            Language::Polish => "Wyczyść teraz",
+            Language::Kazakh => "Қазір тазалау",
+            Language::Arabic => "تنظيف الآن",
+        }
+    }
+
+    /// Fetch history file stats: "History: 523 records, 45MB"
+    pub fn history(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "History",
+            Language::Russian => "История",
+            Language::Spanish => "Historial",
+            Language::Persian => "تاریخچه",
+            Language::Chinese => "历史",
+            Language::Ukrainian => "Історія",
+            Language::Polish => "Historia",
+            Language::Kazakh => "Тарих",
+            Language::Arabic => "السجل",
+        }
+    }
+
+    /// Performance panel: rated vs estimated hashrate and grade
+    pub fn performance(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Performance",
+            Language::Russian => "Производительность",
+            Language::Spanish => "Rendimiento",
+            Language::Persian => "کارایی",
+            Language::Chinese => "性能",
+            Language::Ukrainian => "Продуктивність",
+            Language::Polish => "Wydajność",
+            Language::Kazakh => "Өнімділік",
+            Language::Arabic => "الأداء",
+        }
+    }
+
+    pub fn grade(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Grade",
+            Language::Russian => "Оценка",
+            Language::Spanish => "Calificación",
+            Language::Persian => "رتبه",
+            Language::Chinese => "等级",
+            Language::Ukrainian => "Оцінка",
+            Language::Polish => "Ocena",
+            Language::Kazakh => "Баға",
+            Language::Arabic => "التقدير",
+        }
+    }
+
+    pub fn rated_hashrate(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Rated hashrate",
+            Language::Russian => "Номинальный хешрейт",
+            Language::Spanish => "Hashrate nominal",
+            Language::Persian => "هش‌ریت اسمی",
+            Language::Chinese => "额定算力",
+            Language::Ukrainian => "Номінальний хешрейт",
+            Language::Polish => "Nominalny hashrate",
+            Language::Kazakh => "Номиналды хешрейт",
+            Language::Arabic => "معدل التجزئة الاسمي",
+        }
+    }
+
+    pub fn est_hashrate(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Est. hashrate",
+            Language::Russian => "Оценка хешрейта",
+            Language::Spanish => "Hashrate est.",
+            Language::Persian => "هش‌ریت تخمینی",
+            Language::Chinese => "估算算力",
+            Language::Ukrainian => "Оцінка хешрейту",
+            Language::Polish => "Szac. hashrate",
+            Language::Kazakh => "Бағаланған хешрейт",
+            Language::Arabic => "معدل التجزئة المقدر",
+        }
+    }
+
+    pub fn efficiency(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Efficiency",
+            Language::Russian => "Эффективность",
+            Language::Spanish => "Eficiencia",
+            Language::Persian => "بازدهی",
+            Language::Chinese => "效率",
+            Language::Ukrainian => "Ефективність",
+            Language::Polish => "Efektywność",
+            Language::Kazakh => "Тиімділік",
+            Language::Arabic => "الكفاءة",
+        }
+    }
+
+    pub fn chip_utilization(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Chip utilization",
+            Language::Russian => "Использование чипов",
+            Language::Spanish => "Uso de chips",
+            Language::Persian => "بهره‌وری چیپ",
+            Language::Chinese => "芯片利用率",
+            Language::Ukrainian => "Використання чипів",
+            Language::Polish => "Wykorzystanie chipów",
+            Language::Kazakh => "Чип пайдалану",
+            Language::Arabic => "استخدام الشرائح",
+        }
+    }
+
+    pub fn mean_health(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Mean health",
+            Language::Russian => "Среднее здоровье",
+            Language::Spanish => "Salud media",
+            Language::Persian => "میانگین سلامت",
+            Language::Chinese => "平均健康度",
+            Language::Ukrainian => "Середнє здоров'я",
+            Language::Polish => "Średnie zdrowie",
+            Language::Kazakh => "Орташа күй",
+            Language::Arabic => "متوسط الصحة",
+        }
+    }
+
+    pub fn working_chips(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Working chips",
+            Language::Russian => "Рабочие чипы",
+            Language::Spanish => "Chips activos",
+            Language::Persian => "چیپ‌های فعال",
+            Language::Chinese => "工作芯片",
+            Language::Ukrainian => "Робочі чипи",
+            Language::Polish => "Działające chipy",
+            Language::Kazakh => "Жұмыс істейтін чиптер",
+            Language::Arabic => "الشرائح العاملة",
+        }
+    }
+
+    pub fn reason_low_efficiency(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Hashrate below rated",
+            Language::Russian => "Хешрейт ниже номинала",
+            Language::Spanish => "Hashrate bajo el nominal",
+            Language::Persian => "هش‌ریت کمتر از اسمی",
+            Language::Chinese => "算力低于额定值",
+            Language::Ukrainian => "Хешрейт нижче номіналу",
+            Language::Polish => "Hashrate poniżej nominalnego",
+            Language::Kazakh => "Хешрейт номиналдан төмен",
+            Language::Arabic => "معدل التجزئة أقل من الاسمي",
+        }
+    }
+
+    pub fn reason_low_utilization(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Missing or dead chips",
+            Language::Russian => "Отсутствующие или мёртвые чипы",
+            Language::Spanish => "Chips ausentes o muertos",
+            Language::Persian => "چیپ‌های غایب یا خراب",
+            Language::Chinese => "缺失或失效芯片",
+            Language::Ukrainian => "Відсутні або мертві чипи",
+            Language::Polish => "Brakujące lub martwe chipy",
+            Language::Kazakh => "Жоқ немесе өлі чиптер",
+            Language::Arabic => "شرائح مفقودة أو معطلة",
+        }
+    }
+
+    pub fn reason_low_health(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Low chip health",
+            Language::Russian => "Низкое здоровье чипов",
+            Language::Spanish => "Salud de chips baja",
+            Language::Persian => "سلامت پایین چیپ‌ها",
+            Language::Chinese => "芯片健康度低",
+            Language::Ukrainian => "Низьке здоров'я чипів",
+            Language::Polish => "Niskie zdrowie chipów",
+            Language::Kazakh => "Чиптердің күйі төмен",
+            Language::Arabic => "صحة الشرائح منخفضة",
+        }
+    }
+
+    pub fn reason_no_config(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Unknown model: graded on chip health only",
+            Language::Russian => "Неизвестная модель: оценка только по здоровью чипов",
+            Language::Spanish => "Modelo desconocido: calificado solo por salud de chips",
+            Language::Persian => "مدل ناشناخته: رتبه فقط بر اساس سلامت چیپ‌ها",
+            Language::Chinese => "未知型号：仅按芯片健康度评级",
+            Language::Ukrainian => "Невідома модель: оцінка лише за здоров'ям чипів",
+            Language::Polish => "Nieznany model: ocena tylko wg zdrowia chipów",
+            Language::Kazakh => "Белгісіз модель: тек чип күйі бойынша бағаланды",
+            Language::Arabic => "طراز غير معروف: التقدير حسب صحة الشرائح فقط",
+        }
+    }
+
+    /// Stats panel scatter plot title
+    pub fn crc_vs_nonce(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "CRC vs nonces",
+            Language::Russian => "CRC и нонсы",
+            Language::Spanish => "CRC vs nonces",
+            Language::Persian => "CRC در برابر نانس",
+            Language::Chinese => "CRC 与 nonce",
+            Language::Ukrainian => "CRC і нонси",
+            Language::Polish => "CRC a nonce",
+            Language::Kazakh => "CRC және нонстар",
+            Language::Arabic => "CRC مقابل النونس",
+        }
+    }
+
+    /// Status bar balance indicator when all slots hash alike
+    pub fn balanced(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Balanced ✓",
+            Language::Russian => "Баланс ✓",
+            Language::Spanish => "Equilibrado ✓",
+            Language::Persian => "متوازن ✓",
+            Language::Chinese => "均衡 ✓",
+            Language::Ukrainian => "Баланс ✓",
+            Language::Polish => "Zrównoważone ✓",
+            Language::Kazakh => "Теңгерімді ✓",
+            Language::Arabic => "متوازن ✓",
+        }
+    }
+
+    /// Status bar badge for suspicious parsed values
+    pub fn data_warnings(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Data warnings",
+            Language::Russian => "Предупреждения данных",
+            Language::Spanish => "Avisos de datos",
+            Language::Persian => "هشدارهای داده",
+            Language::Chinese => "数据警告",
+            Language::Ukrainian => "Попередження даних",
+            Language::Polish => "Ostrzeżenia danych",
+            Language::Kazakh => "Деректер ескертулері",
+            Language::Arabic => "تحذيرات البيانات",
+        }
+    }
+
+    pub fn expected(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "expected",
+            Language::Russian => "ожидалось",
+            Language::Spanish => "esperado",
+            Language::Persian => "مورد انتظار",
+            Language::Chinese => "预期",
+            Language::Ukrainian => "очікувалось",
+            Language::Polish => "oczekiwano",
+            Language::Kazakh => "күтілген",
+            Language::Arabic => "المتوقع",
+        }
+    }
+
+    pub fn lldp_scan(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "LLDP Scan",
+            Language::Russian => "LLDP-поиск",
+            Language::Spanish => "Escaneo LLDP",
+            Language::Persian => "اسکن LLDP",
+            Language::Chinese => "LLDP 扫描",
+            Language::Ukrainian => "LLDP-пошук",
+            Language::Polish => "Skan LLDP",
+            Language::Kazakh => "LLDP іздеу",
+            Language::Arabic => "فحص LLDP",
+        }
+    }
+
+    /// Shown while listening for LLDP frames (about 35 s)
+    pub fn lldp_scanning(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Listening for LLDP…",
+            Language::Russian => "Ожидание LLDP…",
+            Language::Spanish => "Escuchando LLDP…",
+            Language::Persian => "در حال شنود LLDP…",
+            Language::Chinese => "正在监听 LLDP…",
+            Language::Ukrainian => "Очікування LLDP…",
+            Language::Polish => "Nasłuchiwanie LLDP…",
+            Language::Kazakh => "LLDP тыңдалуда…",
+            Language::Arabic => "جارٍ الاستماع إلى LLDP…",
+        }
+    }
+
+    pub fn lldp_none(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "No WhatsMiner announced itself over LLDP",
+            Language::Russian => "Ни один WhatsMiner не объявился по LLDP",
+            Language::Spanish => "Ningún WhatsMiner se anunció por LLDP",
+            Language::Persian => "هیچ WhatsMiner از طریق LLDP اعلام نشد",
+            Language::Chinese => "没有 WhatsMiner 通过 LLDP 广播",
+            Language::Ukrainian => "Жоден WhatsMiner не оголосився через LLDP",
+            Language::Polish => "Żaden WhatsMiner nie ogłosił się przez LLDP",
+            Language::Kazakh => "LLDP арқылы бірде-бір WhatsMiner табылмады",
+            Language::Arabic => "لم يُعلن أي WhatsMiner عن نفسه عبر LLDP",
+        }
+    }
+
+    pub fn rack(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Rack",
+            Language::Russian => "Стойка",
+            Language::Spanish => "Rack",
+            Language::Persian => "رک",
+            Language::Chinese => "机架",
+            Language::Ukrainian => "Стійка",
+            Language::Polish => "Regał",
+            Language::Kazakh => "Стойка",
+            Language::Arabic => "رف",
+        }
+    }
+
+    pub fn rack_row(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Row",
+            Language::Russian => "Ряд",
+            Language::Spanish => "Fila",
+            Language::Persian => "ردیف",
+            Language::Chinese => "排",
+            Language::Ukrainian => "Ряд",
+            Language::Polish => "Rząd",
+            Language::Kazakh => "Қатар",
+            Language::Arabic => "صف",
+        }
+    }
+
+    /// Position within a rack row, abbreviated
+    pub fn rack_pos(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Pos",
+            Language::Russian => "Поз",
+            Language::Spanish => "Pos",
+            Language::Persian => "جایگاه",
+            Language::Chinese => "位",
+            Language::Ukrainian => "Поз",
+            Language::Polish => "Poz",
+            Language::Kazakh => "Орын",
+            Language::Arabic => "موضع",
+        }
+    }
+
+    pub fn rack_positions(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Rack positions",
+            Language::Russian => "Размещение в стойках",
+            Language::Spanish => "Posiciones en rack",
+            Language::Persian => "جایگاه در رک",
+            Language::Chinese => "机架位置",
+            Language::Ukrainian => "Розміщення у стійках",
+            Language::Polish => "Pozycje w regałach",
+            Language::Kazakh => "Стойкадағы орындар",
+            Language::Arabic => "مواقع الرفوف",
+        }
+    }
+
+    pub fn save(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Save",
+            Language::Russian => "Сохранить",
+            Language::Spanish => "Guardar",
+            Language::Persian => "ذخیره",
+            Language::Chinese => "保存",
+            Language::Ukrainian => "Зберегти",
+            Language::Polish => "Zapisz",
+            Language::Kazakh => "Сақтау",
+            Language::Arabic => "حفظ",
+        }
+    }
+
+    /// Dashboard group of miners without a rack position
+    pub fn no_rack(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "No rack",
+            Language::Russian => "Без стойки",
+            Language::Spanish => "Sin rack",
+            Language::Persian => "بدون رک",
+            Language::Chinese => "未分配机架",
+            Language::Ukrainian => "Без стійки",
+            Language::Polish => "Bez regału",
+            Language::Kazakh => "Стойкасыз",
+            Language::Arabic => "بلا رف",
+        }
+    }
+
+    pub fn color_mode_freq_compliance(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Freq ✓",
+            Language::Russian => "Частота ✓",
+            Language::Spanish => "Frec. ✓",
+            Language::Persian => "فرکانس ✓",
+            Language::Chinese => "频率合规",
+            Language::Ukrainian => "Частота ✓",
+            Language::Polish => "Częst. ✓",
+            Language::Kazakh => "Жиілік ✓",
+            Language::Arabic => "امتثال التردد",
+        }
+    }
+
+    pub fn help_color_mode_freq_compliance(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Frequency vs the slot target: within ±5 MHz green, above orange, below blue"
+            }
+            Language::Russian => {
+                "Частота относительно цели слота: в пределах ±5 МГц зелёный, выше оранжевый, ниже синий"
+            }
+            Language::Spanish => {
+                "Frecuencia frente al objetivo del slot: dentro de ±5 MHz verde, por encima naranja, por debajo azul"
+            }
+            Language::Persian => {
+                "فرکانس نسبت به هدف اسلات: در محدوده ±۵ مگاهرتز سبز، بالاتر نارنجی، پایین‌تر آبی"
+            }
+            Language::Chinese => "频率与槽位目标相比：±5 MHz 以内绿，高于橙，低于蓝",
+            Language::Ukrainian => {
+                "Частота відносно цілі слота: у межах ±5 МГц зелений, вище помаранчевий, нижче синій"
+            }
+            Language::Polish => {
+                "Częstotliwość względem celu slotu: w granicach ±5 MHz zielony, powyżej pomarańczowy, poniżej niebieski"
+            }
+            Language::Kazakh => {
+                "Слот мақсатына қатысты жиілік: ±5 МГц шегінде жасыл, жоғары қызғылт сары, төмен көк"
+            }
+            Language::Arabic => {
+                "التردد مقارنة بهدف الفتحة: ضمن ±5 ميغاهرتز أخضر، أعلى برتقالي، أدنى أزرق"
+            }
+        }
+    }
+
+    /// Share of chips within tolerance of the slot target frequency
+    pub fn freq_compliance(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Freq compliance",
+            Language::Russian => "Соответствие частоты",
+            Language::Spanish => "Cumplimiento de frec.",
+            Language::Persian => "انطباق فرکانس",
+            Language::Chinese => "频率合规率",
+            Language::Ukrainian => "Відповідність частоти",
+            Language::Polish => "Zgodność częst.",
+            Language::Kazakh => "Жиілік сәйкестігі",
+            Language::Arabic => "امتثال التردد",
+        }
+    }
+
+    /// Mining pool, as in "Pool: 45ms"
+    pub fn pool(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Pool",
+            Language::Russian => "Пул",
+            Language::Spanish => "Pool",
+            Language::Persian => "استخر",
+            Language::Chinese => "矿池",
+            Language::Ukrainian => "Пул",
+            Language::Polish => "Pula",
+            Language::Kazakh => "Пул",
+            Language::Arabic => "المجمع",
+        }
+    }
+
+    /// Chip readings unchanged, as in "Data stale: 6 polls"
+    pub fn data_stale(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Data stale",
+            Language::Russian => "Данные не менялись",
+            Language::Spanish => "Datos sin cambios",
+            Language::Persian => "داده بدون تغییر",
+            Language::Chinese => "数据未变化",
+            Language::Ukrainian => "Дані не змінювались",
+            Language::Polish => "Dane bez zmian",
+            Language::Kazakh => "Деректер өзгермеген",
+            Language::Arabic => "بيانات راكدة",
+        }
+    }
+
+    /// IR camera photo panel title
+    pub fn ir_photo(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "IR photo",
+            Language::Russian => "ИК-снимок",
+            Language::Spanish => "Foto IR",
+            Language::Persian => "تصویر حرارتی",
+            Language::Chinese => "红外照片",
+            Language::Ukrainian => "ІЧ-знімок",
+            Language::Polish => "Zdjęcie IR",
+            Language::Kazakh => "ИҚ-сурет",
+            Language::Arabic => "صورة حرارية",
+        }
+    }
+
+    pub fn import_ir_image(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Import IR image",
+            Language::Russian => "Импорт ИК-снимка",
+            Language::Spanish => "Importar imagen IR",
+            Language::Persian => "وارد کردن تصویر حرارتی",
+            Language::Chinese => "导入红外图像",
+            Language::Ukrainian => "Імпорт ІЧ-знімка",
+            Language::Polish => "Importuj obraz IR",
+            Language::Kazakh => "ИҚ-суретті импорттау",
+            Language::Arabic => "استيراد صورة حرارية",
+        }
+    }
+
+    pub fn add_ir_point(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Add point",
+            Language::Russian => "Добавить точку",
+            Language::Spanish => "Añadir punto",
+            Language::Persian => "افزودن نقطه",
+            Language::Chinese => "添加点",
+            Language::Ukrainian => "Додати точку",
+            Language::Polish => "Dodaj punkt",
+            Language::Kazakh => "Нүкте қосу",
+            Language::Arabic => "إضافة نقطة",
+        }
+    }
+
+    pub fn ir_overlay(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "IR overlay",
+            Language::Russian => "ИК-наложение",
+            Language::Spanish => "Superposición IR",
+            Language::Persian => "همپوشانی حرارتی",
+            Language::Chinese => "红外叠加",
+            Language::Ukrainian => "ІЧ-накладання",
+            Language::Polish => "Nakładka IR",
+            Language::Kazakh => "ИҚ қабаттастыру",
+            Language::Arabic => "تراكب حراري",
+        }
+    }
+
+    /// Temperature range of the IR camera's color bar
+    pub fn ir_scale(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Color bar",
+            Language::Russian => "Шкала",
+            Language::Spanish => "Barra de color",
+            Language::Persian => "نوار رنگ",
+            Language::Chinese => "色标",
+            Language::Ukrainian => "Шкала",
+            Language::Polish => "Skala barw",
+            Language::Kazakh => "Түс шкаласы",
+            Language::Arabic => "شريط الألوان",
+        }
+    }
+
+    pub fn ir_align_hint(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Click a spot on the photo, select the matching chip, then Add point. 2 points align position, rotation and scale; 4 correct perspective."
+            }
+            Language::Russian => {
+                "Щёлкните точку на снимке, выберите соответствующий чип и нажмите «Добавить точку». 2 точки задают положение, поворот и масштаб; 4 исправляют перспективу."
+            }
+            Language::Spanish => {
+                "Haga clic en un punto de la foto, seleccione el chip correspondiente y pulse Añadir punto. 2 puntos alinean posición, rotación y escala; 4 corrigen la perspectiva."
+            }
+            Language::Persian => {
+                "روی نقطه‌ای از تصویر کلیک کنید، چیپ متناظر را انتخاب کنید و «افزودن نقطه» را بزنید. ۲ نقطه موقعیت، چرخش و مقیاس را تنظیم می‌کند؛ ۴ نقطه پرسپکتیو را اصلاح می‌کند."
+            }
+            Language::Chinese => {
+                "点击照片上的一个位置，选择对应的芯片，然后点“添加点”。2 个点对齐位置、旋转和缩放；4 个点校正透视。"
+            }
+            Language::Ukrainian => {
+                "Клацніть точку на знімку, виберіть відповідний чіп і натисніть «Додати точку». 2 точки задають положення, поворот і масштаб; 4 виправляють перспективу."
+            }
+            Language::Polish => {
+                "Kliknij punkt na zdjęciu, wybierz odpowiadający chip i naciśnij Dodaj punkt. 2 punkty wyrównują położenie, obrót i skalę; 4 korygują perspektywę."
+            }
+            Language::Kazakh => {
+                "Суреттегі нүктені басып, сәйкес чипті таңдаңыз да, «Нүкте қосу» басыңыз. 2 нүкте орнын, бұрылысын және масштабын теңестіреді; 4 нүкте перспективаны түзетеді."
+            }
+            Language::Arabic => {
+                "انقر على نقطة في الصورة، واختر الشريحة المطابقة، ثم «إضافة نقطة». نقطتان تضبطان الموضع والدوران والمقياس؛ 4 نقاط تصحح المنظور."
+            }
+        }
+    }
+
+    /// Chip sensor disagrees with the IR photo
+    pub fn sensor_mismatch(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Sensor mismatch",
+            Language::Russian => "Расхождение датчика",
+            Language::Spanish => "Discrepancia del sensor",
+            Language::Persian => "ناهمخوانی حسگر",
+            Language::Chinese => "传感器不符",
+            Language::Ukrainian => "Розбіжність датчика",
+            Language::Polish => "Niezgodność czujnika",
+            Language::Kazakh => "Датчик сәйкессіздігі",
+            Language::Arabic => "عدم تطابق المستشعر",
+        }
+    }
+
+    pub fn color_mode_efficiency_trend(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Eff. trend",
+            Language::Russian => "Тренд эфф.",
+            Language::Spanish => "Tend. efic.",
+            Language::Persian => "روند بازده",
+            Language::Chinese => "效率趋势",
+            Language::Ukrainian => "Тренд ефект.",
+            Language::Polish => "Trend wyd.",
+            Language::Kazakh => "Тиімділік тренді",
+            Language::Arabic => "اتجاه الكفاءة",
+        }
+    }
+
+    pub fn help_color_mode_efficiency_trend(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Slope of nonce/MHz over the last 10 polls: below -0.5 per poll red, declining amber, within ±0.1 green, improving cyan"
+            }
+            Language::Russian => {
+                "Наклон nonce/МГц за последние 10 загрузок: ниже -0,5 за загрузку красный, снижение жёлтый, в пределах ±0,1 зелёный, рост голубой"
+            }
+            Language::Spanish => {
+                "Pendiente de nonce/MHz en las últimas 10 lecturas: por debajo de -0,5 por lectura rojo, descenso ámbar, dentro de ±0,1 verde, mejora cian"
+            }
+            Language::Persian => {
+                "شیب nonce/مگاهرتز در ۱۰ دریافت اخیر: کمتر از ‎-0.5‎ در هر دریافت قرمز، کاهش کهربایی، در محدوده ±0.1 سبز، بهبود فیروزه‌ای"
+            }
+            Language::Chinese => {
+                "最近 10 次读取的 nonce/MHz 斜率：每次低于 -0.5 红，下降琥珀，±0.1 以内绿，上升青"
+            }
+            Language::Ukrainian => {
+                "Нахил nonce/МГц за останні 10 завантажень: нижче -0,5 за завантаження червоний, спад жовтий, у межах ±0,1 зелений, зростання блакитний"
+            }
+            Language::Polish => {
+                "Nachylenie nonce/MHz z ostatnich 10 pobrań: poniżej -0,5 na pobranie czerwony, spadek bursztynowy, w granicach ±0,1 zielony, poprawa cyjan"
+            }
+            Language::Kazakh => {
+                "Соңғы 10 жүктеудегі nonce/МГц еңісі: бір жүктеуге -0,5-тен төмен қызыл, төмендеу сары, ±0,1 шегінде жасыл, өсу көгілдір"
+            }
+            Language::Arabic => {
+                "ميل nonce/ميغاهرتز خلال آخر 10 عمليات جلب: أقل من -0.5 لكل عملية أحمر، انخفاض كهرماني، ضمن ±0.1 أخضر، تحسن سماوي"
+            }
+        }
+    }
+
+    /// Placeholder of the chip search bar
+    pub fn chip_filter(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Find chips: temp>90 errors>50 health<70",
+            Language::Russian => "Поиск чипов: temp>90 errors>50 health<70",
+            Language::Spanish => "Buscar chips: temp>90 errors>50 health<70",
+            Language::Persian => "جستجوی چیپ: temp>90 errors>50 health<70",
+            Language::Chinese => "查找芯片: temp>90 errors>50 health<70",
+            Language::Ukrainian => "Пошук чіпів: temp>90 errors>50 health<70",
+            Language::Polish => "Szukaj chipów: temp>90 errors>50 health<70",
+            Language::Kazakh => "Чиптерді іздеу: temp>90 errors>50 health<70",
+            Language::Arabic => "البحث عن الشرائح: temp>90 errors>50 health<70",
+        }
+    }
+
+    pub fn invalid_filter(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Invalid filter",
+            Language::Russian => "Неверный фильтр",
+            Language::Spanish => "Filtro no válido",
+            Language::Persian => "فیلتر نامعتبر",
+            Language::Chinese => "无效的筛选条件",
+            Language::Ukrainian => "Невірний фільтр",
+            Language::Polish => "Nieprawidłowy filtr",
+            Language::Kazakh => "Жарамсыз сүзгі",
+            Language::Arabic => "عامل تصفية غير صالح",
+        }
+    }
+
+    pub fn filter_matches(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Matching filter",
+            Language::Russian => "Подходят под фильтр",
+            Language::Spanish => "Coinciden con el filtro",
+            Language::Persian => "منطبق با فیلتر",
+            Language::Chinese => "匹配筛选",
+            Language::Ukrainian => "Відповідають фільтру",
+            Language::Polish => "Pasujące do filtra",
+            Language::Kazakh => "Сүзгіге сәйкес",
+            Language::Arabic => "مطابقة للتصفية",
+        }
+    }
+
+    pub fn fan_curve(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Fan curve",
+            Language::Russian => "Кривая вентилятора",
+            Language::Spanish => "Curva del ventilador",
+            Language::Persian => "منحنی فن",
+            Language::Chinese => "风扇曲线",
+            Language::Ukrainian => "Крива вентилятора",
+            Language::Polish => "Krzywa wentylatora",
+            Language::Kazakh => "Желдеткіш қисығы",
+            Language::Arabic => "منحنى المروحة",
+        }
+    }
+
+    /// Fan speed doesn't follow slot temperature
+    pub fn fan_curve_anomaly(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Fan curve anomaly",
+            Language::Russian => "Аномалия кривой вентилятора",
+            Language::Spanish => "Anomalía en la curva del ventilador",
+            Language::Persian => "ناهنجاری منحنی فن",
+            Language::Chinese => "风扇曲线异常",
+            Language::Ukrainian => "Аномалія кривої вентилятора",
+            Language::Polish => "Anomalia krzywej wentylatora",
+            Language::Kazakh => "Желдеткіш қисығының ауытқуы",
+            Language::Arabic => "خلل في منحنى المروحة",
+        }
+    }
+
+    /// Label of the slot header's enabled chip count
+    pub fn active_chips(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Active",
+            Language::Russian => "Активно",
+            Language::Spanish => "Activos",
+            Language::Persian => "فعال",
+            Language::Chinese => "活动",
+            Language::Ukrainian => "Активні",
+            Language::Polish => "Aktywne",
+            Language::Kazakh => "Белсенді",
+            Language::Arabic => "نشطة",
+        }
+    }
+
+    /// Notification: chips the firmware disabled at once
+    pub fn chips_disabled(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "disabled by firmware",
+            Language::Russian => "отключено прошивкой",
+            Language::Spanish => "desactivados por el firmware",
+            Language::Persian => "توسط فریمور غیرفعال شد",
+            Language::Chinese => "被固件禁用",
+            Language::Ukrainian => "вимкнено прошивкою",
+            Language::Polish => "wyłączone przez firmware",
+            Language::Kazakh => "микробағдарлама өшірді",
+            Language::Arabic => "عطّلها البرنامج الثابت",
+        }
+    }
+
+    /// Opens the model wizard for a miner missing from the database
+    pub fn configure_model(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Configure model",
+            Language::Russian => "Настроить модель",
+            Language::Spanish => "Configurar modelo",
+            Language::Persian => "پیکربندی مدل",
+            Language::Chinese => "配置型号",
+            Language::Ukrainian => "Налаштувати модель",
+            Language::Polish => "Skonfiguruj model",
+            Language::Kazakh => "Үлгіні баптау",
+            Language::Arabic => "تهيئة الطراز",
+        }
+    }
+
+    pub fn model_wizard(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "New miner model",
+            Language::Russian => "Новая модель майнера",
+            Language::Spanish => "Nuevo modelo de minero",
+            Language::Persian => "مدل جدید ماینر",
+            Language::Chinese => "新矿机型号",
+            Language::Ukrainian => "Нова модель майнера",
+            Language::Polish => "Nowy model koparki",
+            Language::Kazakh => "Жаңа майнер үлгісі",
+            Language::Arabic => "طراز معدّن جديد",
+        }
+    }
+
+    pub fn wizard_step(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Step",
+            Language::Russian => "Шаг",
+            Language::Spanish => "Paso",
+            Language::Persian => "مرحله",
+            Language::Chinese => "步骤",
+            Language::Ukrainian => "Крок",
+            Language::Polish => "Krok",
+            Language::Kazakh => "Қадам",
+            Language::Arabic => "الخطوة",
+        }
+    }
+
+    pub fn detected_model(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Detected model",
+            Language::Russian => "Обнаруженная модель",
+            Language::Spanish => "Modelo detectado",
+            Language::Persian => "مدل شناسایی‌شده",
+            Language::Chinese => "检测到的型号",
+            Language::Ukrainian => "Виявлена модель",
+            Language::Polish => "Wykryty model",
+            Language::Kazakh => "Анықталған үлгі",
+            Language::Arabic => "الطراز المكتشف",
+        }
+    }
+
+    pub fn wizard_chip_num(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Chips per board",
+            Language::Russian => "Чипов на плату",
+            Language::Spanish => "Chips por placa",
+            Language::Persian => "چیپ در هر برد",
+            Language::Chinese => "每板芯片数",
+            Language::Ukrainian => "Чіпів на плату",
+            Language::Polish => "Chipów na płytę",
+            Language::Kazakh => "Платадағы чиптер",
+            Language::Arabic => "شرائح لكل لوحة",
+        }
+    }
+
+    pub fn wizard_chips_per_domain(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Chips per domain",
+            Language::Russian => "Чипов в домене",
+            Language::Spanish => "Chips por dominio",
+            Language::Persian => "چیپ در هر دامنه",
+            Language::Chinese => "每域芯片数",
+            Language::Ukrainian => "Чіпів у домені",
+            Language::Polish => "Chipów na domenę",
+            Language::Kazakh => "Домендегі чиптер",
+            Language::Arabic => "شرائح لكل نطاق",
+        }
+    }
+
+    pub fn wizard_board_num(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Hashboards",
+            Language::Russian => "Хэш-платы",
+            Language::Spanish => "Placas de hash",
+            Language::Persian => "هش‌بردها",
+            Language::Chinese => "算力板",
+            Language::Ukrainian => "Хеш-плати",
+            Language::Polish => "Płyty hashujące",
+            Language::Kazakh => "Хэш-платалар",
+            Language::Arabic => "لوحات التجزئة",
+        }
+    }
+
+    pub fn use_detected(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Use detected",
+            Language::Russian => "Использовать обнаруженное",
+            Language::Spanish => "Usar el detectado",
+            Language::Persian => "استفاده از مقدار شناسایی‌شده",
+            Language::Chinese => "使用检测值",
+            Language::Ukrainian => "Використати виявлене",
+            Language::Polish => "Użyj wykrytego",
+            Language::Kazakh => "Анықталғанды қолдану",
+            Language::Arabic => "استخدام المكتشف",
+        }
+    }
+
+    pub fn domains_per_board(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "domains per board",
+            Language::Russian => "доменов на плату",
+            Language::Spanish => "dominios por placa",
+            Language::Persian => "دامنه در هر برد",
+            Language::Chinese => "每板域数",
+            Language::Ukrainian => "доменів на плату",
+            Language::Polish => "domen na płytę",
+            Language::Kazakh => "платадағы домендер",
+            Language::Arabic => "نطاقات لكل لوحة",
+        }
+    }
+
+    pub fn detected(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "detected",
+            Language::Russian => "обнаружено",
+            Language::Spanish => "detectado",
+            Language::Persian => "شناسایی‌شده",
+            Language::Chinese => "检测到",
+            Language::Ukrainian => "виявлено",
+            Language::Polish => "wykryto",
+            Language::Kazakh => "анықталды",
+            Language::Arabic => "مكتشف",
+        }
+    }
+
+    pub fn ratings_from(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Power and hashrate ratings from",
+            Language::Russian => "Номинальная мощность и хешрейт из",
+            Language::Spanish => "Potencia y hashrate nominales de",
+            Language::Persian => "توان و هش‌ریت اسمی از",
+            Language::Chinese => "额定功率和算力取自",
+            Language::Ukrainian => "Номінальна потужність і хешрейт з",
+            Language::Polish => "Moc i hashrate znamionowe z",
+            Language::Kazakh => "Номиналды қуат пен хешрейт көзі",
+            Language::Arabic => "القدرة ومعدل التجزئة الاسميان من",
+        }
+    }
+
+    pub fn back(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Back",
+            Language::Russian => "Назад",
+            Language::Spanish => "Atrás",
+            Language::Persian => "بازگشت",
+            Language::Chinese => "上一步",
+            Language::Ukrainian => "Назад",
+            Language::Polish => "Wstecz",
+            Language::Kazakh => "Артқа",
+            Language::Arabic => "رجوع",
+        }
+    }
+
+    pub fn next(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Next",
+            Language::Russian => "Далее",
+            Language::Spanish => "Siguiente",
+            Language::Persian => "بعدی",
+            Language::Chinese => "下一步",
+            Language::Ukrainian => "Далі",
+            Language::Polish => "Dalej",
+            Language::Kazakh => "Келесі",
+            Language::Arabic => "التالي",
+        }
+    }
+
+    pub fn model_saved(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Model saved",
+            Language::Russian => "Модель сохранена",
+            Language::Spanish => "Modelo guardado",
+            Language::Persian => "مدل ذخیره شد",
+            Language::Chinese => "型号已保存",
+            Language::Ukrainian => "Модель збережено",
+            Language::Polish => "Model zapisany",
+            Language::Kazakh => "Үлгі сақталды",
+            Language::Arabic => "تم حفظ الطراز",
+        }
+    }
+
+    pub fn color_mode_cross_miner_outliers(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Fleet outliers",
+            Language::Russian => "Выбросы по парку",
+            Language::Spanish => "Atípicos de la flota",
+            Language::Persian => "پرت‌های ناوگان",
+            Language::Chinese => "矿场异常值",
+            Language::Ukrainian => "Викиди по парку",
+            Language::Polish => "Odstające we flocie",
+            Language::Kazakh => "Парк бойынша ауытқулар",
+            Language::Arabic => "القيم الشاذة في الأسطول",
+        }
+    }
+
+    pub fn help_color_mode_cross_miner_outliers(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Hotter than the same position on every board of the loaded fleet miners: 0 green to 3 std devs red"
+            }
+            Language::Russian => {
+                "Горячее той же позиции на всех платах загруженных майнеров парка: от 0 (зелёный) до 3 σ (красный)"
+            }
+            Language::Spanish => {
+                "Más caliente que la misma posición en todas las placas de los mineros cargados de la flota: de 0 (verde) a 3 σ (rojo)"
+            }
+            Language::Persian => {
+                "گرم‌تر از همان موقعیت در همه بردهای ماینرهای بارگذاری‌شده ناوگان: از ۰ (سبز) تا ۳ σ (قرمز)"
+            }
+            Language::Chinese => "比已加载矿机所有板同一位置更热：0（绿）到 3 个标准差（红）",
+            Language::Ukrainian => {
+                "Гарячіший за ту саму позицію на всіх платах завантажених майнерів парку: від 0 (зелений) до 3 σ (червоний)"
+            }
+            Language::Polish => {
+                "Cieplejszy niż ta sama pozycja na wszystkich płytach wczytanych koparek floty: od 0 (zielony) do 3 σ (czerwony)"
+            }
+            Language::Kazakh => {
+                "Парктың жүктелген майнерлерінің барлық тақталарындағы сол позициядан ыстық: 0 (жасыл) бастап 3 σ (қызыл) дейін"
+            }
+            Language::Arabic => {
+                "أسخن من الموضع نفسه على كل لوحات معدّنات الأسطول المحمّلة: من 0 (أخضر) إلى 3 σ (أحمر)"
+            }
+        }
+    }
+
+    /// Heading of the analysis thresholds in the settings panel
+    pub fn analysis_settings(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Analysis Settings",
+            Language::Russian => "Настройки анализа",
+            Language::Spanish => "Ajustes de análisis",
+            Language::Persian => "تنظیمات تحلیل",
+            Language::Chinese => "分析设置",
+            Language::Ukrainian => "Налаштування аналізу",
+            Language::Polish => "Ustawienia analizy",
+            Language::Kazakh => "Талдау баптаулары",
+            Language::Arabic => "إعدادات التحليل",
+        }
+    }
+
+    /// Cross-slot z-score at which a chip counts as a full outlier
+    pub fn outlier_sigma_threshold(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Outlier threshold",
+            Language::Russian => "Порог выброса",
+            Language::Spanish => "Umbral de valor atípico",
+            Language::Persian => "آستانه داده پرت",
+            Language::Chinese => "离群阈值",
+            Language::Ukrainian => "Поріг викиду",
+            Language::Polish => "Próg odstających",
+            Language::Kazakh => "Ауытқу шегі",
+            Language::Arabic => "عتبة القيم الشاذة",
+        }
+    }
+
+    /// Nonce deficit at which a chip counts as critical
+    pub fn nonce_deficit_critical(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Critical nonce deficit",
+            Language::Russian => "Критический дефицит nonce",
+            Language::Spanish => "Déficit crítico de nonce",
+            Language::Persian => "کمبود بحرانی nonce",
+            Language::Chinese => "临界 nonce 亏缺",
+            Language::Ukrainian => "Критичний дефіцит nonce",
+            Language::Polish => "Krytyczny deficyt nonce",
+            Language::Kazakh => "Nonce сыни тапшылығы",
+            Language::Arabic => "عجز nonce الحرج",
+        }
+    }
+
+    /// Fewest upstream neighbors a chip needs for a gradient
+    pub fn min_gradient_neighbors(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Min. gradient neighbors",
+            Language::Russian => "Мин. соседей для градиента",
+            Language::Spanish => "Mín. vecinos para gradiente",
+            Language::Persian => "حداقل همسایه برای گرادیان",
+            Language::Chinese => "梯度最少邻居数",
+            Language::Ukrainian => "Мін. сусідів для градієнта",
+            Language::Polish => "Min. sąsiadów gradientu",
+            Language::Kazakh => "Градиент үшін ең аз көрші",
+            Language::Arabic => "الحد الأدنى من الجيران للتدرج",
+        }
+    }
+
+    /// Data source: the miner's own web interface
+    pub fn miner_web_ui(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Miner web UI",
+            Language::Russian => "Веб-интерфейс майнера",
+            Language::Spanish => "Interfaz web del minero",
+            Language::Persian => "رابط وب ماینر",
+            Language::Chinese => "矿机网页",
+            Language::Ukrainian => "Веб-інтерфейс майнера",
+            Language::Polish => "Interfejs WWW koparki",
+            Language::Kazakh => "Майнер веб-интерфейсі",
+            Language::Arabic => "واجهة الويب للمُعدِّن",
+        }
+    }
+
+    /// Placeholder for the Awesome Miner server address
+    pub fn awesome_miner_server(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Server URL, e.g. http://server:8080",
+            Language::Russian => "URL сервера, напр. http://server:8080",
+            Language::Spanish => "URL del servidor, p. ej. http://server:8080",
+            Language::Persian => "نشانی سرور، مثلاً http://server:8080",
+            Language::Chinese => "服务器地址，例如 http://server:8080",
+            Language::Ukrainian => "URL сервера, напр. http://server:8080",
+            Language::Polish => "URL serwera, np. http://server:8080",
+            Language::Kazakh => "Сервер URL, мыс. http://server:8080",
+            Language::Arabic => "عنوان الخادم، مثل http://server:8080",
+        }
+    }
+
+    /// Placeholder for the miner's id in Awesome Miner
+    pub fn awesome_miner_id(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Miner ID",
+            Language::Russian => "ID майнера",
+            Language::Spanish => "ID del minero",
+            Language::Persian => "شناسه ماینر",
+            Language::Chinese => "矿机 ID",
+            Language::Ukrainian => "ID майнера",
+            Language::Polish => "ID koparki",
+            Language::Kazakh => "Майнер ID",
+            Language::Arabic => "معرّف المُعدِّن",
+        }
+    }
+
+    /// Slot header label for the thermal uniformity score
+    pub fn uniformity(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Uniformity",
+            Language::Russian => "Равномерность",
+            Language::Spanish => "Uniformidad",
+            Language::Persian => "یکنواختی",
+            Language::Chinese => "均匀度",
+            Language::Ukrainian => "Рівномірність",
+            Language::Polish => "Równomierność",
+            Language::Kazakh => "Біркелкілік",
+            Language::Arabic => "التجانس",
+        }
+    }
+
+    /// Button: save one CSV row per slot
+    pub fn export_slot_summary(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Slot summary",
+            Language::Russian => "Сводка по слотам",
+            Language::Spanish => "Resumen de slots",
+            Language::Persian => "خلاصه اسلات‌ها",
+            Language::Chinese => "槽位汇总",
+            Language::Ukrainian => "Зведення слотів",
+            Language::Polish => "Podsumowanie slotów",
+            Language::Kazakh => "Слоттар жиынтығы",
+            Language::Arabic => "ملخص الفتحات",
+        }
+    }
+
+    /// Collapsible miner summary bar above the chip grid
+    pub fn summary(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Summary",
+            Language::Russian => "Сводка",
+            Language::Spanish => "Resumen",
+            Language::Persian => "خلاصه",
+            Language::Chinese => "概要",
+            Language::Ukrainian => "Зведення",
+            Language::Polish => "Podsumowanie",
+            Language::Kazakh => "Қорытынды",
+            Language::Arabic => "ملخص",
+        }
+    }
+
+    pub fn health(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Health",
+            Language::Russian => "Здоровье",
+            Language::Spanish => "Salud",
+            Language::Persian => "سلامت",
+            Language::Chinese => "健康度",
+            Language::Ukrainian => "Здоров'я",
+            Language::Polish => "Kondycja",
+            Language::Kazakh => "Денсаулық",
+            Language::Arabic => "الصحة",
+        }
+    }
+
+    pub fn chips(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Chips",
+            Language::Russian => "Чипы",
+            Language::Spanish => "Chips",
+            Language::Persian => "چیپ‌ها",
+            Language::Chinese => "芯片",
+            Language::Ukrainian => "Чипи",
+            Language::Polish => "Chipy",
+            Language::Kazakh => "Чиптер",
+            Language::Arabic => "الشرائح",
+        }
+    }
+
+    pub fn hottest(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Hottest",
+            Language::Russian => "Макс. темп.",
+            Language::Spanish => "Más caliente",
+            Language::Persian => "داغ‌ترین",
+            Language::Chinese => "最高温",
+            Language::Ukrainian => "Макс. темп.",
+            Language::Polish => "Najgorętszy",
+            Language::Kazakh => "Ең ыстық",
+            Language::Arabic => "الأسخن",
+        }
+    }
+
+    /// Slots with any CRC errors
+    pub fn crc_slots(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "CRC slots",
+            Language::Russian => "Слоты с CRC",
+            Language::Spanish => "Ranuras con CRC",
+            Language::Persian => "اسلات‌های CRC",
+            Language::Chinese => "CRC 槽位",
+            Language::Ukrainian => "Слоти з CRC",
+            Language::Polish => "Sloty z CRC",
+            Language::Kazakh => "CRC слоттары",
+            Language::Arabic => "فتحات CRC",
+        }
+    }
+
+    /// Localized alert severity label
+    pub fn alert_severity(severity: AlertSeverity, lang: Language) -> &'static str {
+        match severity {
+            AlertSeverity::Info => Self::alert_info(lang),
+            AlertSeverity::Warning => Self::alert_warning(lang),
+            AlertSeverity::Critical => Self::alert_critical(lang),
+        }
+    }
+
+    /// Localized two- or three-letter weekday name
+    pub fn weekday_short(day: Weekday, lang: Language) -> &'static str {
+        let names = match lang {
+            Language::English => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+            Language::Russian => ["Пн", "Вт", "Ср", "Чт", "Пт", "Сб", "Вс"],
+            Language::Spanish => ["Lun", "Mar", "Mié", "Jue", "Vie", "Sáb", "Dom"],
+            Language::Persian => [
+                "دوشنبه",
+                "سه‌شنبه",
+                "چهارشنبه",
+                "پنجشنبه",
+                "جمعه",
+                "شنبه",
+                "یکشنبه",
+            ],
+            Language::Chinese => ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+            Language::Ukrainian => ["Пн", "Вт", "Ср", "Чт", "Пт", "Сб", "Нд"],
+            Language::Polish => ["Pn", "Wt", "Śr", "Cz", "Pt", "So", "Nd"],
+            Language::Kazakh => ["Дс", "Сс", "Ср", "Бс", "Жм", "Сн", "Жс"],
+            Language::Arabic => [
+                "الإثنين",
+                "الثلاثاء",
+                "الأربعاء",
+                "الخميس",
+                "الجمعة",
+                "السبت",
+                "الأحد",
+            ],
+        };
+        names[day.num_days_from_monday() as usize]
+    }
+
+    /// Localized chip power state label
+    pub fn power_state(state: ChipPowerState, lang: Language) -> &'static str {
+        match state {
+            ChipPowerState::Active => Self::power_active(lang),
+            ChipPowerState::Throttled => Self::throttled(lang),
+            ChipPowerState::Sleep => Self::power_sleep(lang),
+            ChipPowerState::Dead => Self::power_dead(lang),
+        }
+    }
+
+    /// "Throttled" label: a clock below target, for the slot throttle share, throttle
+    /// alerts and the throttled power state
+    pub fn throttled(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Throttled",
+            Language::Russian => "Троттлинг",
+            Language::Spanish => "Limitado",
+            Language::Persian => "کاهش فرکانس",
+            Language::Chinese => "降频",
+            Language::Ukrainian => "Тротлінг",
+            Language::Polish => "Dławienie",
+            Language::Kazakh => "Тротлинг",
+            Language::Arabic => "مخفّض",
+        }
+    }
+
+    pub fn throttle_history(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Throttle history",
+            Language::Russian => "История троттлинга",
+            Language::Spanish => "Historial de limitación",
+            Language::Persian => "تاریخچه کاهش فرکانس",
+            Language::Chinese => "降频历史",
+            Language::Ukrainian => "Історія тротлінгу",
+            Language::Polish => "Historia dławienia",
+            Language::Kazakh => "Тротлинг тарихы",
+            Language::Arabic => "سجل الخفض",
+        }
+    }
+
+    pub fn export_throttle_history(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Export throttle CSV",
+            Language::Russian => "Экспорт троттлинга CSV",
+            Language::Spanish => "Exportar limitación CSV",
+            Language::Persian => "خروجی CSV کاهش فرکانس",
+            Language::Chinese => "导出降频 CSV",
+            Language::Ukrainian => "Експорт тротлінгу CSV",
+            Language::Polish => "Eksport dławienia CSV",
+            Language::Kazakh => "Тротлинг CSV экспорты",
+            Language::Arabic => "تصدير سجل الخفض CSV",
+        }
+    }
+
+    /// Critical alert for a board input voltage below 10 V
+    pub fn low_input_voltage(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "critical input voltage",
+            Language::Russian => "критически низкое входное напряжение",
+            Language::Spanish => "tensión de entrada crítica",
+            Language::Persian => "ولتاژ ورودی بحرانی",
+            Language::Chinese => "输入电压严重过低",
+            Language::Ukrainian => "критично низька вхідна напруга",
+            Language::Polish => "krytyczne napięcie wejściowe",
+            Language::Kazakh => "кіріс кернеуі өте төмен",
+            Language::Arabic => "جهد الدخل حرج",
+        }
+    }
+
+    pub fn color_mode_voltage_group(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "V group",
+            Language::Russian => "Группа U",
+            Language::Spanish => "Grupo V",
+            Language::Persian => "گروه ولتاژ",
+            Language::Chinese => "电压组",
+            Language::Ukrainian => "Група U",
+            Language::Polish => "Grupa U",
+            Language::Kazakh => "U тобы",
+            Language::Arabic => "مجموعة الجهد",
+        }
+    }
+
+    /// Chip tooltip label for its voltage group
+    pub fn voltage_group(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Voltage group",
+            Language::Russian => "Группа напряжения",
+            Language::Spanish => "Grupo de voltaje",
+            Language::Persian => "گروه ولتاژ",
+            Language::Chinese => "电压组",
+            Language::Ukrainian => "Група напруги",
+            Language::Polish => "Grupa napięcia",
+            Language::Kazakh => "Кернеу тобы",
+            Language::Arabic => "مجموعة الجهد",
+        }
+    }
+
+    pub fn save_session(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Save session",
+            Language::Russian => "Сохранить сессию",
+            Language::Spanish => "Guardar sesión",
+            Language::Persian => "ذخیره جلسه",
+            Language::Chinese => "保存会话",
+            Language::Ukrainian => "Зберегти сесію",
+            Language::Polish => "Zapisz sesję",
+            Language::Kazakh => "Сессияны сақтау",
+            Language::Arabic => "حفظ الجلسة",
+        }
+    }
+
+    pub fn load_session(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Load session",
+            Language::Russian => "Загрузить сессию",
+            Language::Spanish => "Cargar sesión",
+            Language::Persian => "بارگذاری جلسه",
+            Language::Chinese => "加载会话",
+            Language::Ukrainian => "Завантажити сесію",
+            Language::Polish => "Wczytaj sesję",
+            Language::Kazakh => "Сессияны жүктеу",
+            Language::Arabic => "تحميل الجلسة",
+        }
+    }
+
+    pub fn session_restored(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Session restored",
+            Language::Russian => "Сессия восстановлена",
+            Language::Spanish => "Sesión restaurada",
+            Language::Persian => "جلسه بازیابی شد",
+            Language::Chinese => "会话已恢复",
+            Language::Ukrainian => "Сесію відновлено",
+            Language::Polish => "Przywrócono sesję",
+            Language::Kazakh => "Сессия қалпына келтірілді",
+            Language::Arabic => "تمت استعادة الجلسة",
+        }
+    }
+
+    pub fn export_chip_comparison(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Chip vs spec",
+            Language::Russian => "Чипы vs спецификация",
+            Language::Spanish => "Chips vs especificación",
+            Language::Persian => "تراشه‌ها در برابر مشخصات",
+            Language::Chinese => "芯片对比规格",
+            Language::Ukrainian => "Чипи vs специфікація",
+            Language::Polish => "Chipy vs specyfikacja",
+            Language::Kazakh => "Чиптер мен спецификация",
+            Language::Arabic => "الشرائح مقابل المواصفات",
+        }
+    }
+
+    /// Air intake side of the board layout diagram
+    pub fn intake(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Intake",
+            Language::Russian => "Вход воздуха",
+            Language::Spanish => "Entrada",
+            Language::Persian => "ورودی هوا",
+            Language::Chinese => "进风",
+            Language::Ukrainian => "Вхід повітря",
+            Language::Polish => "Wlot",
+            Language::Kazakh => "Ауа кірісі",
+            Language::Arabic => "السحب",
+        }
+    }
+
+    /// Air exhaust side of the board layout diagram
+    pub fn exhaust(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Exhaust",
+            Language::Russian => "Выход воздуха",
+            Language::Spanish => "Salida",
+            Language::Persian => "خروجی هوا",
+            Language::Chinese => "出风",
+            Language::Ukrainian => "Вихід повітря",
+            Language::Polish => "Wylot",
+            Language::Kazakh => "Ауа шығысы",
+            Language::Arabic => "العادم",
+        }
+    }
+
+    pub fn alerts(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Alerts",
+            Language::Russian => "Оповещения",
+            Language::Spanish => "Alertas",
+            Language::Persian => "هشدارها",
+            Language::Chinese => "警报",
+            Language::Ukrainian => "Сповіщення",
+            Language::Polish => "Alerty",
+            Language::Kazakh => "Ескертулер",
+            Language::Arabic => "التنبيهات",
+        }
+    }
+
+    pub fn alert_history(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Alert history",
+            Language::Russian => "История оповещений",
+            Language::Spanish => "Historial de alertas",
+            Language::Persian => "تاریخچه هشدارها",
+            Language::Chinese => "警报历史",
+            Language::Ukrainian => "Історія сповіщень",
+            Language::Polish => "Historia alertów",
+            Language::Kazakh => "Ескертулер тарихы",
+            Language::Arabic => "سجل التنبيهات",
+        }
+    }
+
+    pub fn export_alert_log(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Export CSV",
+            Language::Russian => "Экспорт CSV",
+            Language::Spanish => "Exportar CSV",
+            Language::Persian => "خروجی CSV",
+            Language::Chinese => "导出 CSV",
+            Language::Ukrainian => "Експорт CSV",
+            Language::Polish => "Eksport CSV",
+            Language::Kazakh => "CSV экспорттау",
+            Language::Arabic => "تصدير CSV",
+        }
+    }
+
+    pub fn no_alerts(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "No alerts this session",
+            Language::Russian => "В этой сессии оповещений нет",
+            Language::Spanish => "Sin alertas en esta sesión",
+            Language::Persian => "در این جلسه هشداری نیست",
+            Language::Chinese => "本次会话无警报",
+            Language::Ukrainian => "У цій сесії сповіщень немає",
+            Language::Polish => "Brak alertów w tej sesji",
+            Language::Kazakh => "Бұл сессияда ескерту жоқ",
+            Language::Arabic => "لا توجد تنبيهات في هذه الجلسة",
+        }
+    }
+
+    pub fn alert_info(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Info",
+            Language::Russian => "Инфо",
+            Language::Spanish => "Info",
+            Language::Persian => "اطلاع",
+            Language::Chinese => "信息",
+            Language::Ukrainian => "Інфо",
+            Language::Polish => "Info",
+            Language::Kazakh => "Ақпарат",
+            Language::Arabic => "معلومات",
+        }
+    }
+
+    pub fn alert_warning(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Warning",
+            Language::Russian => "Внимание",
+            Language::Spanish => "Aviso",
+            Language::Persian => "هشدار",
+            Language::Chinese => "警告",
+            Language::Ukrainian => "Увага",
+            Language::Polish => "Ostrzeżenie",
+            Language::Kazakh => "Назар",
+            Language::Arabic => "تحذير",
+        }
+    }
+
+    pub fn alert_critical(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Critical",
+            Language::Russian => "Критично",
+            Language::Spanish => "Crítico",
+            Language::Persian => "بحرانی",
+            Language::Chinese => "严重",
+            Language::Ukrainian => "Критично",
+            Language::Polish => "Krytyczny",
+            Language::Kazakh => "Сыни",
+            Language::Arabic => "حرج",
+        }
+    }
+
+    /// Many new CRC errors on a slot since the previous poll
+    pub fn crc_burst(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "CRC error burst",
+            Language::Russian => "всплеск ошибок CRC",
+            Language::Spanish => "ráfaga de errores CRC",
+            Language::Persian => "انفجار خطاهای CRC",
+            Language::Chinese => "CRC 错误激增",
+            Language::Ukrainian => "сплеск помилок CRC",
+            Language::Polish => "wzrost błędów CRC",
+            Language::Kazakh => "CRC қателерінің күрт өсуі",
+            Language::Arabic => "تدفق أخطاء CRC",
+        }
+    }
+
+    pub fn fan_curve_recovered(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "fan follows temperature again",
+            Language::Russian => "вентилятор снова следует за температурой",
+            Language::Spanish => "el ventilador vuelve a seguir la temperatura",
+            Language::Persian => "فن دوباره از دما پیروی می‌کند",
+            Language::Chinese => "风扇再次跟随温度",
+            Language::Ukrainian => "вентилятор знову стежить за температурою",
+            Language::Polish => "wentylator znów nadąża za temperaturą",
+            Language::Kazakh => "желдеткіш қайтадан температураға ілеседі",
+            Language::Arabic => "المروحة تتبع الحرارة مجددًا",
+        }
+    }
+
+    pub fn input_voltage_recovered(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "input voltage back to normal",
+            Language::Russian => "входное напряжение в норме",
+            Language::Spanish => "voltaje de entrada normalizado",
+            Language::Persian => "ولتاژ ورودی به حالت عادی برگشت",
+            Language::Chinese => "输入电压恢复正常",
+            Language::Ukrainian => "вхідна напруга в нормі",
+            Language::Polish => "napięcie wejściowe wróciło do normy",
+            Language::Kazakh => "кіріс кернеуі қалпына келді",
+            Language::Arabic => "عاد جهد الدخل إلى طبيعته",
+        }
+    }
+
+    /// Fleet dashboard button opening the two checked miners side by side
+    pub fn compare(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Compare",
+            Language::Russian => "Сравнить",
+            Language::Spanish => "Comparar",
+            Language::Persian => "مقایسه",
+            Language::Chinese => "对比",
+            Language::Ukrainian => "Порівняти",
+            Language::Polish => "Porównaj",
+            Language::Kazakh => "Салыстыру",
+            Language::Arabic => "مقارنة",
+        }
+    }
+
+    pub fn close_comparison(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Close comparison",
+            Language::Russian => "Закрыть сравнение",
+            Language::Spanish => "Cerrar comparación",
+            Language::Persian => "بستن مقایسه",
+            Language::Chinese => "关闭对比",
+            Language::Ukrainian => "Закрити порівняння",
+            Language::Polish => "Zamknij porównanie",
+            Language::Kazakh => "Салыстыруды жабу",
+            Language::Arabic => "إغلاق المقارنة",
+        }
+    }
+
+    /// Keep both comparison grids at the same scroll position
+    pub fn sync_scroll(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Sync scroll",
+            Language::Russian => "Синхронная прокрутка",
+            Language::Spanish => "Desplazamiento sincronizado",
+            Language::Persian => "پیمایش همزمان",
+            Language::Chinese => "同步滚动",
+            Language::Ukrainian => "Синхронне прокручування",
+            Language::Polish => "Synchronizuj przewijanie",
+            Language::Kazakh => "Синхронды айналдыру",
+            Language::Arabic => "تمرير متزامن",
+        }
+    }
+
+    pub fn miner_a(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Miner A",
+            Language::Russian => "Майнер A",
+            Language::Spanish => "Minero A",
+            Language::Persian => "ماینر A",
+            Language::Chinese => "矿机 A",
+            Language::Ukrainian => "Майнер A",
+            Language::Polish => "Koparka A",
+            Language::Kazakh => "Майнер A",
+            Language::Arabic => "المعدّن A",
+        }
+    }
+
+    pub fn miner_b(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Miner B",
+            Language::Russian => "Майнер B",
+            Language::Spanish => "Minero B",
+            Language::Persian => "ماینر B",
+            Language::Chinese => "矿机 B",
+            Language::Ukrainian => "Майнер B",
+            Language::Polish => "Koparka B",
+            Language::Kazakh => "Майнер B",
+            Language::Arabic => "المعدّن B",
+        }
+    }
+
+    pub fn max(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "max",
+            Language::Russian => "макс.",
+            Language::Spanish => "máx.",
+            Language::Persian => "بیشینه",
+            Language::Chinese => "最高",
+            Language::Ukrainian => "макс.",
+            Language::Polish => "maks.",
+            Language::Kazakh => "макс.",
+            Language::Arabic => "أقصى",
+        }
+    }
+
+    /// Slot header toggle for a board mounted upside-down
+    pub fn rotate_180(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Rotate 180°",
+            Language::Russian => "Повернуть на 180°",
+            Language::Spanish => "Girar 180°",
+            Language::Persian => "چرخش ۱۸۰°",
+            Language::Chinese => "旋转 180°",
+            Language::Ukrainian => "Повернути на 180°",
+            Language::Polish => "Obróć o 180°",
+            Language::Kazakh => "180° бұру",
+            Language::Arabic => "تدوير 180°",
+        }
+    }
+
+    /// Analysis settings field: Gaussian kernel radius of the dead chip density map
+    pub fn dead_density_kernel(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Dead density radius",
+            Language::Russian => "Радиус плотности мёртвых",
+            Language::Spanish => "Radio de densidad de muertos",
+            Language::Persian => "شعاع تراکم چیپ‌های مرده",
+            Language::Chinese => "失效密度半径",
+            Language::Ukrainian => "Радіус щільності мертвих",
+            Language::Polish => "Promień gęstości martwych",
+            Language::Kazakh => "Өлі тығыздық радиусы",
+            Language::Arabic => "نصف قطر كثافة الرقاقات الميتة",
+        }
+    }
+
+    pub fn color_mode_dead_density(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Dead density",
+            Language::Russian => "Плотность мёртвых",
+            Language::Spanish => "Densidad de muertos",
+            Language::Persian => "تراکم چیپ‌های مرده",
+            Language::Chinese => "失效密度",
+            Language::Ukrainian => "Щільність мертвих",
+            Language::Polish => "Gęstość martwych",
+            Language::Kazakh => "Өлі чиптер тығыздығы",
+            Language::Arabic => "كثافة الرقاقات الميتة",
+        }
+    }
+
+    pub fn help_color_mode_dead_density(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Temperature colors tinted red where dead chips concentrate: the Gaussian-weighted share of dead chips around each chip, within the radius set in the analysis settings"
+            }
+            Language::Russian => {
+                "Цвета температуры с красным оттенком там, где скапливаются мёртвые чипы: взвешенная по Гауссу доля мёртвых чипов вокруг каждого чипа в радиусе из настроек анализа"
+            }
+            Language::Spanish => {
+                "Colores de temperatura teñidos de rojo donde se concentran los chips muertos: la proporción de chips muertos alrededor de cada chip, ponderada con una gaussiana, dentro del radio de los ajustes de análisis"
+            }
+            Language::Persian => {
+                "رنگ‌های دما با ته‌رنگ قرمز در جایی که چیپ‌های مرده متمرکزند: سهم وزن‌دار گاوسی چیپ‌های مرده اطراف هر چیپ، در شعاع تنظیمات تحلیل"
+            }
+            Language::Chinese => {
+                "在失效芯片集中处给温度颜色加红：每颗芯片周围失效芯片的高斯加权占比，半径见分析设置"
+            }
+            Language::Ukrainian => {
+                "Кольори температури з червоним відтінком там, де скупчуються мертві чипи: зважена за Гаусом частка мертвих чипів навколо кожного чипа в радіусі з налаштувань аналізу"
+            }
+            Language::Polish => {
+                "Kolory temperatury zabarwione na czerwono tam, gdzie skupiają się martwe czipy: ważony rozkładem Gaussa udział martwych czipów wokół każdego czipa w promieniu z ustawień analizy"
+            }
+            Language::Kazakh => {
+                "Өлі чиптер шоғырланған жерде температура түстері қызылға боялады: әр чип айналасындағы өлі чиптердің Гаусс бойынша өлшенген үлесі, талдау баптауларындағы радиус ішінде"
+            }
+            Language::Arabic => {
+                "ألوان الحرارة مصبوغة بالأحمر حيث تتركز الرقاقات الميتة: حصة الرقاقات الميتة حول كل رقاقة موزونة بدالة غاوس ضمن نصف القطر المحدد في إعدادات التحليل"
+            }
+        }
+    }
+
+    pub fn dead_neighbors(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "dead nearby",
+            Language::Russian => "мёртвых рядом",
+            Language::Spanish => "muertos cerca",
+            Language::Persian => "مرده در اطراف",
+            Language::Chinese => "周围失效",
+            Language::Ukrainian => "мертвих поруч",
+            Language::Polish => "martwych w pobliżu",
+            Language::Kazakh => "маңайда өлі",
+            Language::Arabic => "ميتة بالقرب",
+        }
+    }
+
+    /// Color mode: chip power state
+    pub fn color_mode_power_state(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Power state",
+            Language::Russian => "Питание",
+            Language::Spanish => "Estado energía",
+            Language::Persian => "وضعیت توان",
+            Language::Chinese => "功耗状态",
+            Language::Ukrainian => "Живлення",
+            Language::Polish => "Stan zasilania",
+            Language::Kazakh => "Қуат күйі",
+            Language::Arabic => "حالة الطاقة",
+        }
+    }
+
+    /// Chip power state: running at the target clock
+    pub fn power_active(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Active",
+            Language::Russian => "Активен",
+            Language::Spanish => "Activo",
+            Language::Persian => "فعال",
+            Language::Chinese => "运行",
+            Language::Ukrainian => "Активний",
+            Language::Polish => "Aktywny",
+            Language::Kazakh => "Белсенді",
+            Language::Arabic => "نشط",
+        }
+    }
+
+    /// Chip power state: parked at the firmware sleep clock
+    pub fn power_sleep(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Sleep",
+            Language::Russian => "Сон",
+            Language::Spanish => "Reposo",
+            Language::Persian => "خواب",
+            Language::Chinese => "休眠",
+            Language::Ukrainian => "Сон",
+            Language::Polish => "Uśpiony",
+            Language::Kazakh => "Ұйқы",
+            Language::Arabic => "سكون",
+        }
+    }
+
+    /// Chip power state: not clocked
+    pub fn power_dead(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Dead",
+            Language::Russian => "Мёртв",
+            Language::Spanish => "Muerto",
+            Language::Persian => "خراب",
+            Language::Chinese => "失效",
+            Language::Ukrainian => "Мертвий",
+            Language::Polish => "Martwy",
+            Language::Kazakh => "Өлі",
+            Language::Arabic => "معطل",
+        }
+    }
+
+    /// Settings section: times when alerts don't raise desktop notifications
+    pub fn maintenance_windows(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Maintenance Windows",
+            Language::Russian => "Окна обслуживания",
+            Language::Spanish => "Ventanas de mantenimiento",
+            Language::Persian => "بازه‌های نگهداری",
+            Language::Chinese => "维护时段",
+            Language::Ukrainian => "Вікна обслуговування",
+            Language::Polish => "Okna serwisowe",
+            Language::Kazakh => "Қызмет көрсету терезелері",
+            Language::Arabic => "فترات الصيانة",
+        }
+    }
+
+    /// Adds a maintenance window
+    pub fn add_window(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "+ Window",
+            Language::Russian => "+ Окно",
+            Language::Spanish => "+ Ventana",
+            Language::Persian => "+ بازه",
+            Language::Chinese => "+ 时段",
+            Language::Ukrainian => "+ Вікно",
+            Language::Polish => "+ Okno",
+            Language::Kazakh => "+ Терезе",
+            Language::Arabic => "+ فترة",
+        }
+    }
+
+    /// Highest alert severity a maintenance window silences
+    pub fn silence_up_to(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Silence up to",
+            Language::Russian => "Без уведомлений до",
+            Language::Spanish => "Silenciar hasta",
+            Language::Persian => "بی‌صدا تا",
+            Language::Chinese => "静默至",
+            Language::Ukrainian => "Без сповіщень до",
+            Language::Polish => "Wycisz do",
+            Language::Kazakh => "Үнсіз деңгейі",
+            Language::Arabic => "كتم حتى",
+        }
+    }
+
+    /// Maintenance window explanation in the settings panel
+    pub fn maintenance_windows_hint(lang: Language) -> &'static str {
+        match lang {
+            Language::English => {
+                "Alerts are still logged; windows ending before they start run past midnight"
+            }
+            Language::Russian => {
+                "Оповещения всё равно записываются; окна с концом раньше начала идут через полночь"
+            }
+            Language::Spanish => {
+                "Las alertas se siguen registrando; las ventanas que terminan antes de empezar pasan la medianoche"
+            }
+            Language::Persian => {
+                "هشدارها همچنان ثبت می‌شوند؛ بازه‌هایی که پایانشان قبل از شروع است از نیمه‌شب می‌گذرند"
+            }
+            Language::Chinese => "告警仍会记录；结束早于开始的时段跨越午夜",
+            Language::Ukrainian => {
+                "Сповіщення все одно записуються; вікна з кінцем раніше початку йдуть через північ"
+            }
+            Language::Polish => {
+                "Alerty są nadal zapisywane; okna kończące się przed początkiem trwają po północy"
+            }
+            Language::Kazakh => {
+                "Ескертулер бәрібір жазылады; басталуынан бұрын аяқталатын терезелер түн ортасынан өтеді"
+            }
+            Language::Arabic => {
+                "تُسجَّل التنبيهات مع ذلك؛ الفترات التي تنتهي قبل بدايتها تمتد بعد منتصف الليل"
+            }
+        }
+    }
+
+    /// Button: board health certificate for warranty claims
+    pub fn rma_report(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "RMA report",
+            Language::Russian => "Отчёт RMA",
+            Language::Spanish => "Informe RMA",
+            Language::Persian => "گزارش RMA",
+            Language::Chinese => "RMA 报告",
+            Language::Ukrainian => "Звіт RMA",
+            Language::Polish => "Raport RMA",
+            Language::Kazakh => "RMA есебі",
+            Language::Arabic => "تقرير RMA",
+        }
+    }
+
+    /// Status bar: the firmware left some chip fields out of the log
+    pub fn partial_data(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Partial data",
+            Language::Russian => "Неполные данные",
+            Language::Spanish => "Datos parciales",
+            Language::Persian => "داده ناقص",
+            Language::Chinese => "部分数据",
+            Language::Ukrainian => "Неповні дані",
+            Language::Polish => "Niepełne dane",
+            Language::Kazakh => "Толық емес деректер",
+            Language::Arabic => "بيانات جزئية",
+        }
+    }
+
+    /// Color mode: heat cycles per chip
+    pub fn color_mode_heat_cycles(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Heat cycles",
+            Language::Russian => "Термоциклы",
+            Language::Spanish => "Ciclos térmicos",
+            Language::Persian => "چرخه حرارتی",
+            Language::Chinese => "热循环",
+            Language::Ukrainian => "Термоцикли",
+            Language::Polish => "Cykle cieplne",
+            Language::Kazakh => "Жылу циклдері",
+            Language::Arabic => "دورات حرارية",
+        }
+    }
+
+    /// Chip tooltip: estimated heat cycles this session
+    pub fn est_heat_cycles(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Est. heat cycles",
+            Language::Russian => "Оценка термоциклов",
+            Language::Spanish => "Ciclos térmicos est.",
+            Language::Persian => "چرخه حرارتی تخمینی",
+            Language::Chinese => "估计热循环",
+            Language::Ukrainian => "Оцінка термоциклів",
+            Language::Polish => "Szac. cykle cieplne",
+            Language::Kazakh => "Болжамды жылу циклдері",
+            Language::Arabic => "الدورات الحرارية المقدرة",
+        }
+    }
+
+    pub fn color_mode_temperature(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Temperature",
+            Language::Russian => "Температура",
+            Language::Spanish => "Temperatura",
+            Language::Persian => "دما",
+            Language::Chinese => "温度",
+            Language::Ukrainian => "Температура",
+            Language::Polish => "Temperatura",
+            Language::Kazakh => "Температура",
+            Language::Arabic => "الحرارة",
+        }
+    }
+
+    pub fn color_mode_errors(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Errors",
+            Language::Russian => "Ошибки",
+            Language::Spanish => "Errores",
+            Language::Persian => "خطاها",
+            Language::Chinese => "错误",
+            Language::Ukrainian => "Помилки",
+            Language::Polish => "Błędy",
+            Language::Kazakh => "Қателер",
+            Language::Arabic => "الأخطاء",
+        }
+    }
+
+    pub fn color_mode_crc(_lang: Language) -> &'static str {
+        "CRC"
+    }
+
+    pub fn color_mode_gradient(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Gradient",
+            Language::Russian => "Градиент",
+            Language::Spanish => "Gradiente",
+            Language::Persian => "گرادیان",
+            Language::Chinese => "梯度",
+            Language::Ukrainian => "Градієнт",
+            Language::Polish => "Gradient",
+            Language::Kazakh => "Градиент",
+            Language::Arabic => "التدرج",
+        }
+    }
+
+    pub fn color_mode_outliers(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Outliers",
+            Language::Russian => "Выбросы",
+            Language::Spanish => "Atípicos",
+            Language::Persian => "پرت‌ها",
+            Language::Chinese => "异常值",
+            Language::Ukrainian => "Викиди",
+            Language::Polish => "Odstające",
+            Language::Kazakh => "Ауытқулар",
+            Language::Arabic => "القيم الشاذة",
+        }
+    }
+
+    pub fn color_mode_nonce(lang: Language) -> &'static str {
+        match lang {
+            Language::English | Language::Spanish | Language::Polish => "Nonce",
+            Language::Russian => "Нонс",
+            Language::Persian => "نانس",
+            Language::Chinese => "随机数",
+            Language::Ukrainian => "Нонс",
+            Language::Kazakh => "Нонс",
+            Language::Arabic => "نونس",
+        }
+    }
+
+    pub fn color_mode_freq_relative(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Freq Δ",
+            Language::Russian => "Частота Δ",
+            Language::Spanish => "Frec. Δ",
+            Language::Persian => "فرکانس Δ",
+            Language::Chinese => "频率偏差",
+            Language::Ukrainian => "Частота Δ",
+            Language::Polish => "Częst. Δ",
+            Language::Kazakh => "Жиілік Δ",
+            Language::Arabic => "انحراف التردد",
+        }
+    }
+
+    pub fn color_mode_nonce_per_mhz(lang: Language) -> &'static str {
+        match lang {
+            Language::English | Language::Spanish | Language::Polish => "Nonce/MHz",
+            Language::Russian | Language::Ukrainian | Language::Kazakh => "Нонс/МГц",
+            Language::Persian => "نانس/مگاهرتز",
+            Language::Chinese => "随机数/MHz",
+            Language::Arabic => "نونس/ميغاهرتز",
+        }
+    }
+
+    pub fn color_mode_error_rate(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Err/h",
+            Language::Russian => "Ошибки/ч",
+            Language::Spanish => "Err/h",
+            Language::Persian => "خطا/ساعت",
+            Language::Chinese => "错误/小时",
+            Language::Ukrainian => "Помилки/год",
+            Language::Polish => "Błędy/h",
+            Language::Kazakh => "Қате/сағ",
+            Language::Arabic => "أخطاء/ساعة",
+        }
+    }
+
+    /// Short per-chip error rate label, e.g. "err/h"
+    pub fn errors_per_hour(lang: Language) -> &'static str {
+        match lang {
+            Language::English | Language::Spanish | Language::Polish => "err/h",
+            Language::Russian => "ош/ч",
+            Language::Persian => "خطا/ساعت",
+            Language::Chinese => "错误/时",
+            Language::Ukrainian => "пом/год",
+            Language::Kazakh => "қате/сағ",
+            Language::Arabic => "خطأ/ساعة",
+        }
+    }
+
+    pub fn auto_poll(lang: Language) -> &'static str {
+        match lang {
+            Language::English => "Auto-refresh",
+            Language::Russian => "Автообновление",
+            Language::Spanish => "Auto-actualizar",
+            Language::Persian => "بروزرسانی خودکار",
+            Language::Chinese => "自动刷新",
+            Language::Ukrainian => "Автооновлення",
+            Language::Polish => "Auto-odświeżanie",
+            Language::Kazakh => "Автожаңарту",
+            Language::Arabic => "تحديث تلقائي",
+        }
+    }
+}
+
+/// Localized ColorMode for display in picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalizedColorMode {
+    pub mode: crate::models::ColorMode,
+    pub lang: Language,
+    /// The loaded data lacks a chip field the mode needs; shown marked with ⊘
+    pub unavailable: bool,
+}
+
+impl LocalizedColorMode {
+    pub fn new(mode: crate::models::ColorMode, lang: Language) -> Self {
+        Self {
+            mode,
+            lang,
+            unavailable: false,
+        }
+    }
+
+    pub fn all(lang: Language) -> Vec<Self> {
+        crate::models::ColorMode::ALL
+            .iter()
+            .map(|&mode| Self::new(mode, lang))
+            .collect()
+    }
+
+    /// Every mode, those needing chip fields `quality` lacks marked unavailable
+    pub fn for_data(lang: Language, quality: &crate::models::ChipDataQuality) -> Vec<Self> {
+        crate::models::ColorMode::ALL
+            .iter()
+            .map(|&mode| Self {
+                unavailable: !quality.supports(mode),
+                ..Self::new(mode, lang)
+            })
+            .collect()
+    }
+
+    /// What the mode colors and its thresholds, for the help panel
+    pub fn help(&self) -> &'static str {
+        use crate::models::ColorMode;
+        match self.mode {
+            ColorMode::Temperature => Tr::help_color_mode_temperature(self.lang),
+            ColorMode::Errors => Tr::help_color_mode_errors(self.lang),
+            ColorMode::Crc => Tr::help_color_mode_crc(self.lang),
+            ColorMode::Gradient => Tr::help_color_mode_gradient(self.lang),
+            ColorMode::Outliers => Tr::help_color_mode_outliers(self.lang),
+            ColorMode::CrossMinerOutliers => Tr::help_color_mode_cross_miner_outliers(self.lang),
+            ColorMode::Nonce => Tr::help_color_mode_nonce(self.lang),
+            ColorMode::FreqRelative => Tr::help_color_mode_freq_relative(self.lang),
+            ColorMode::FreqCompliance => Tr::help_color_mode_freq_compliance(self.lang),
+            ColorMode::NoncePerMhz => Tr::help_color_mode_nonce_per_mhz(self.lang),
+            ColorMode::EfficiencyTrend => Tr::help_color_mode_efficiency_trend(self.lang),
+            ColorMode::ErrorRate => Tr::help_color_mode_error_rate(self.lang),
+            ColorMode::TempAnomaly => Tr::help_color_mode_temp_anomaly(self.lang),
+            ColorMode::VoltageGroup => Tr::help_color_mode_voltage_group(self.lang),
+            ColorMode::DeadDensity => Tr::help_color_mode_dead_density(self.lang),
+            ColorMode::PowerState => Tr::help_color_mode_power_state(self.lang),
+            ColorMode::HeatCycles => Tr::help_color_mode_heat_cycles(self.lang),
+            // Only used by the diff view, not offered in the picker
+            ColorMode::Delta => "",
+        }
+    }
+}
+
+impl fmt::Display for LocalizedColorMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::models::ColorMode;
+        if self.unavailable {
+            f.write_str("⊘ ")?;
+        }
+        f.write_str(match self.mode {
+            ColorMode::Temperature => Tr::color_mode_temperature(self.lang),
+            ColorMode::Errors => Tr::color_mode_errors(self.lang),
+            ColorMode::Crc => Tr::color_mode_crc(self.lang),
+            ColorMode::Gradient => Tr::color_mode_gradient(self.lang),
+            ColorMode::Outliers => Tr::color_mode_outliers(self.lang),
+            ColorMode::CrossMinerOutliers => Tr::color_mode_cross_miner_outliers(self.lang),
+            ColorMode::Nonce => Tr::color_mode_nonce(self.lang),
+            ColorMode::FreqRelative => Tr::color_mode_freq_relative(self.lang),
+            ColorMode::FreqCompliance => Tr::color_mode_freq_compliance(self.lang),
+            ColorMode::NoncePerMhz => Tr::color_mode_nonce_per_mhz(self.lang),
+            ColorMode::EfficiencyTrend => Tr::color_mode_efficiency_trend(self.lang),
+            ColorMode::ErrorRate => Tr::color_mode_error_rate(self.lang),
+            ColorMode::TempAnomaly => Tr::color_mode_temp_anomaly(self.lang),
+            ColorMode::VoltageGroup => Tr::color_mode_voltage_group(self.lang),
+            ColorMode::DeadDensity => Tr::color_mode_dead_density(self.lang),
+            ColorMode::PowerState => Tr::color_mode_power_state(self.lang),
+            ColorMode::HeatCycles => Tr::color_mode_heat_cycles(self.lang),
+            ColorMode::Delta => "Δ",
+        })
+    }
+}
+
+/// Connection mode kind (direct or SSH tunnel) for display in picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalizedConnection {
+    pub tunnel: bool,
+    pub lang: Language,
+}
+
+impl LocalizedConnection {
+    pub fn all(lang: Language) -> [Self; 2] {
+        [false, true].map(|tunnel| Self { tunnel, lang })
+    }
+}
+
+impl fmt::Display for LocalizedConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.tunnel {
+            Tr::ssh_tunnel(self.lang)
+        } else {
+            Tr::direct(self.lang)
+        })
+    }
+}
+
+/// Data source kind (miner web UI or Awesome Miner) for display in picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalizedDataSource {
+    pub awesome_miner: bool,
+    pub lang: Language,
+}
+
+impl LocalizedDataSource {
+    pub fn all(lang: Language) -> [Self; 2] {
+        [false, true].map(|awesome_miner| Self {
+            awesome_miner,
+            lang,
+        })
+    }
+}
+
+impl fmt::Display for LocalizedDataSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.awesome_miner {
+            "Awesome Miner"
+        } else {
+            Tr::miner_web_ui(self.lang)
+        })
+    }
+}
+
+/// Alert severity for display in picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalizedSeverity {
+    pub severity: AlertSeverity,
+    pub lang: Language,
+}
+
+impl LocalizedSeverity {
+    pub fn all(lang: Language) -> [Self; 3] {
+        [
+            AlertSeverity::Info,
+            AlertSeverity::Warning,
+            AlertSeverity::Critical,
+        ]
+        .map(|severity| Self { severity, lang })
+    }
+}
+
+impl fmt::Display for LocalizedSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Tr::alert_severity(self.severity, self.lang))
+    }
+}
+
+/// CLDR plural categories used by the supported languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// CLDR cardinal plural category of `n` in `lang`
+///
+/// Kazakh nouns don't inflect after numerals, and Persian/Chinese have no plural
+/// forms after numerals, so those always return Other.
+fn plural(n: usize, lang: Language) -> PluralCategory {
+    match lang {
+        Language::English | Language::Spanish => {
+            if n == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        Language::Russian | Language::Ukrainian => plural_category_slavic(n),
+        // Like Russian, except only exactly 1 is singular (21 = "chipów")
+        Language::Polish => match plural_category_slavic(n) {
+            PluralCategory::One if n != 1 => PluralCategory::Many,
+            category => category,
+        },
+        Language::Arabic => plural_category_arabic(n),
+        Language::Persian | Language::Chinese | Language::Kazakh => PluralCategory::Other,
+    }
+}
+
+/// Russian and Ukrainian: 1, 21, 31… are One; 2–4, 22–24… are Few; the rest Many
+pub fn plural_category_slavic(n: usize) -> PluralCategory {
+    let (n10, n100) = (n % 10, n % 100);
+    if n10 == 1 && n100 != 11 {
+        PluralCategory::One
+    } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+        PluralCategory::Few
+    } else {
+        PluralCategory::Many
+    }
+}
+
+/// Arabic: 0, 1 and 2 have their own forms, then Few for 3–10 and Many for 11–99
+/// (by the last two digits)
+pub fn plural_category_arabic(n: usize) -> PluralCategory {
+    match (n, n % 100) {
+        (0, _) => PluralCategory::Zero,
+        (1, _) => PluralCategory::One,
+        (2, _) => PluralCategory::Two,
+        (_, 3..=10) => PluralCategory::Few,
+        (_, 11..=99) => PluralCategory::Many,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Format a timestamp using the conventions of the given language
+///
+/// e.g. "Jan 15, 2024 2:30 PM" (en), "15.01.2024 14:30" (ru), "2024年1月15日 14:30" (zh)
+pub fn format_datetime(ts: DateTime<Local>, lang: Language) -> String {
+    match lang {
+        Language::English => ts.format("%b %-d, %Y %-I:%M %p").to_string(),
+        Language::Russian | Language::Ukrainian | Language::Polish | Language::Kazakh => {
+            ts.format("%d.%m.%Y %H:%M").to_string()
+        }
+        Language::Spanish => ts.format("%d/%m/%Y %H:%M").to_string(),
+        Language::Chinese => ts.format("%Y年%-m月%-d日 %H:%M").to_string(),
+        Language::Arabic => localize_digits(&ts.format("%d/%m/%Y %H:%M").to_string(), '٠'),
+        Language::Persian => localize_digits(&ts.format("%Y/%m/%d %H:%M").to_string(), '۰'),
+    }
+}
+
+/// Replace ASCII digits with the digit block starting at `zero` (e.g. Arabic-Indic)
+fn localize_digits(s: &str, zero: char) -> String {
+    s.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(d) => char::from_u32(zero as u32 + d).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_language_from_locale() {
+        assert_eq!(language_from_locale("ru_RU.UTF-8"), Language::Russian);
+        assert_eq!(language_from_locale("zh_CN.UTF-8"), Language::Chinese);
+        assert_eq!(language_from_locale("ar_SA.UTF-8"), Language::Arabic);
+        assert_eq!(language_from_locale("kk-KZ"), Language::Kazakh); // Windows style
+        assert_eq!(language_from_locale("uk_UA@euro"), Language::Ukrainian);
+        for english in ["en_US.UTF-8", "en_GB", "en", "EN_au.utf8"] {
+            assert_eq!(
+                language_from_locale(english),
+                Language::English,
+                "{english}"
+            );
+        }
+        // Unsupported and POSIX default locales fall back to English
+        for other in ["de_DE.UTF-8", "C", "POSIX", "C.UTF-8", ""] {
+            assert_eq!(language_from_locale(other), Language::English, "{other}");
+        }
+    }
+
+    fn sample_ts() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 1, 15, 14, 30, 22).unwrap()
+    }
+
+    #[test]
+    fn test_format_datetime_english() {
+        assert_eq!(
+            format_datetime(sample_ts(), Language::English),
+            "Jan 15, 2024 2:30 PM"
+        );
+    }
+
+    #[test]
+    fn test_format_datetime_dotted_languages() {
+        for lang in [Language::Russian, Language::Polish, Language::Ukrainian] {
+            assert_eq!(format_datetime(sample_ts(), lang), "15.01.2024 14:30");
+        }
+    }
+
+    #[test]
+    fn test_format_datetime_chinese() {
+        assert_eq!(
+            format_datetime(sample_ts(), Language::Chinese),
+            "2024年1月15日 14:30"
+        );
+    }
+
+    #[test]
+    fn test_format_datetime_arabic_digits() {
+        assert_eq!(
+            format_datetime(sample_ts(), Language::Arabic),
+            "١٥/٠١/٢٠٢٤ ١٤:٣٠"
+        );
+    }
+
+    #[test]
+    fn test_chips_count_russian() {
+        let forms: Vec<String> = [1, 2, 5, 11, 12, 21, 22, 25, 111]
+            .into_iter()
+            .map(|n| Tr::chips_count(n, Language::Russian))
+            .collect();
+        assert_eq!(
+            forms,
+            [
+                "1 чип",
+                "2 чипа",
+                "5 чипов",
+                "11 чипов",
+                "12 чипов",
+                "21 чип",
+                "22 чипа",
+                "25 чипов",
+                "111 чипов"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_slots_count_polish_and_english() {
+        assert_eq!(Tr::slots_count(1, Language::Polish), "1 slot");
+        assert_eq!(Tr::slots_count(3, Language::Polish), "3 sloty");
+        assert_eq!(Tr::slots_count(21, Language::Polish), "21 slotów");
+        assert_eq!(Tr::slots_count(1, Language::English), "1 slot");
+        assert_eq!(Tr::slots_count(0, Language::English), "0 slots");
+    }
+
+    #[test]
+    fn test_plural_category_slavic() {
+        let categories: Vec<PluralCategory> = [0, 1, 3, 11, 14, 21, 104, 111]
+            .into_iter()
+            .map(plural_category_slavic)
+            .collect();
+        assert_eq!(
+            categories,
+            [
+                PluralCategory::Many,
+                PluralCategory::One,
+                PluralCategory::Few,
+                PluralCategory::Many,
+                PluralCategory::Many,
+                PluralCategory::One,
+                PluralCategory::Few,
+                PluralCategory::Many,
+            ]
+        );
+        assert_eq!(Tr::records_count(523, Language::Russian), "523 записи");
+        assert_eq!(Tr::records_count(21, Language::Polish), "21 rekordów");
+        assert_eq!(Tr::polls_count(1, Language::English), "1 poll");
+    }
+
+    #[test]
+    fn test_plural_arabic() {
+        let categories: Vec<PluralCategory> = [0, 1, 2, 3, 10, 11, 99, 100, 103]
+            .into_iter()
+            .map(plural_category_arabic)
+            .collect();
+        assert_eq!(
+            categories,
+            [
+                PluralCategory::Zero,
+                PluralCategory::One,
+                PluralCategory::Two,
+                PluralCategory::Few,
+                PluralCategory::Few,
+                PluralCategory::Many,
+                PluralCategory::Many,
+                PluralCategory::Other,
+                PluralCategory::Few
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chip_and_domain_labels() {
+        assert_eq!(Tr::chip_label(42, Language::English), "C42");
+        assert_eq!(Tr::chip_label(42, Language::Chinese), "芯片42");
+        assert_eq!(Tr::chip_label(42, Language::Russian), "Ч42");
+        assert_eq!(Tr::domain_label(3, Language::English), "D3");
+        assert_eq!(Tr::domain_label(3, Language::Ukrainian), "Д3");
+    }
+
+    #[test]
+    fn test_data_age() {
+        assert_eq!(Tr::data_age(5, Language::English), "Data: 5s ago");
+        assert_eq!(Tr::data_age(300, Language::English), "Data: 5m ago");
+        assert_eq!(Tr::data_age(601, Language::English), "Data: >10m ago");
+        assert_eq!(Tr::data_age(90, Language::Russian), "Данные: 1 мин назад");
+        assert_eq!(Tr::data_age(30, Language::Chinese), "数据：30秒前");
+    }
+
+    #[test]
+    fn test_color_mode_help() {
+        for &lang in Language::ALL {
+            for mode in LocalizedColorMode::all(lang) {
+                assert!(!mode.help().is_empty(), "{:?} {lang:?}", mode.mode);
+            }
+        }
     }
 }
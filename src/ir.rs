@@ -0,0 +1,434 @@
+//! IR camera overlay: align a thermal photo of a hashboard with its chip grid and
+//! read each chip's temperature off the photo
+//!
+//! IR cameras export false-color images, so a pixel's temperature is taken from its
+//! luminance, scaled linearly to the temperature range of the camera's color bar.
+//! White-hot and ironbow palettes both get brighter with heat.
+
+use std::path::PathBuf;
+
+use iced::widget::image::Handle;
+
+use crate::analysis::ChipAnalysis;
+use crate::layout;
+use crate::models::Slot;
+
+/// Chips whose sensor is further than this from the IR reading (°C) are flagged
+pub const IR_MISMATCH_C: f32 = 10.0;
+/// Color bar range assumed until the user enters the camera's (°C)
+pub const DEFAULT_IR_SCALE: (f32, f32) = (20.0, 120.0);
+/// Two points fix position, rotation and scale; four fix the full perspective
+pub const MAX_CALIBRATION_POINTS: usize = 4;
+// Pixels averaged on each side of a chip's center
+const SAMPLE_RADIUS: i64 = 2;
+// Pivots smaller than this mean the calibration points are degenerate
+const SOLVE_EPSILON: f64 = 1e-9;
+
+/// A decoded IR photo
+#[derive(Debug, Clone)]
+pub struct IrImage {
+    pub path: PathBuf,
+    pub width: u32,
+    pub height: u32,
+    /// RGBA8, row-major
+    pub pixels: Vec<u8>,
+    pub handle: Handle,
+}
+
+impl IrImage {
+    pub fn from_rgba(path: PathBuf, width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        let handle = Handle::from_rgba(width, height, pixels.clone());
+        Self {
+            path,
+            width,
+            height,
+            pixels,
+            handle,
+        }
+    }
+
+    fn pixel(&self, x: i64, y: i64) -> Option<[u8; 3]> {
+        let (x, y) = (u32::try_from(x).ok()?, u32::try_from(y).ok()?);
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let i = (y as usize * self.width as usize + x as usize) * 4;
+        Some([self.pixels[i], self.pixels[i + 1], self.pixels[i + 2]])
+    }
+
+    /// Mean color around a pixel position; None if the position is off the image
+    #[allow(clippy::cast_possible_truncation)] // means of u8 values fit in u8
+    pub fn sample(&self, (x, y): (f64, f64)) -> Option<[u8; 3]> {
+        let (cx, cy) = (x.floor() as i64, y.floor() as i64);
+        self.pixel(cx, cy)?;
+        let mut sum = [0u32; 3];
+        let mut count = 0;
+        for dy in -SAMPLE_RADIUS..=SAMPLE_RADIUS {
+            for dx in -SAMPLE_RADIUS..=SAMPLE_RADIUS {
+                if let Some(rgb) = self.pixel(cx + dx, cy + dy) {
+                    for (s, c) in sum.iter_mut().zip(rgb) {
+                        *s += u32::from(c);
+                    }
+                    count += 1;
+                }
+            }
+        }
+        Some(sum.map(|s| (s / count) as u8))
+    }
+}
+
+/// Decode a PNG or JPEG IR photo on a blocking thread
+pub async fn load_image(path: PathBuf) -> Result<IrImage, String> {
+    tokio::task::spawn_blocking(move || {
+        let rgba = image::open(&path)
+            .map_err(|e| format!("{}: {e}", path.display()))?
+            .to_rgba8();
+        let (width, height) = rgba.dimensions();
+        Ok(IrImage::from_rgba(path, width, height, rgba.into_raw()))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Temperature of an IR pixel: Rec. 601 luminance mapped onto the (min, max) °C scale
+pub fn pixel_temp(rgb: [u8; 3], (min, max): (f32, f32)) -> f32 {
+    let [r, g, b] = rgb.map(f32::from);
+    let luminance = (0.299 * r + 0.587 * g + 0.114 * b) / 255.0;
+    min + luminance * (max - min)
+}
+
+/// (x, y) in grid cells or photo pixels
+pub type Point2 = (f64, f64);
+
+/// 3×3 perspective transform (row-major, bottom-right element 1)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Homography(pub [[f64; 3]; 3]);
+
+impl Homography {
+    /// Transform taking each pair's first point to its second
+    ///
+    /// Two pairs give a similarity (shift, rotation, uniform scale), three an affine
+    /// transform and four a full perspective. None if the points are degenerate,
+    /// e.g. repeated or collinear.
+    pub fn from_pairs(pairs: &[(Point2, Point2)]) -> Option<Self> {
+        match pairs.len() {
+            2 => {
+                // X = a·x − b·y + c, Y = b·x + a·y + f
+                let mut rows = Vec::new();
+                for &((x, y), (tx, ty)) in pairs {
+                    rows.push((vec![x, -y, 1.0, 0.0], tx));
+                    rows.push((vec![y, x, 0.0, 1.0], ty));
+                }
+                let [a, b, c, f] = solve(rows)?[..] else {
+                    return None;
+                };
+                Some(Self([[a, -b, c], [b, a, f], [0.0, 0.0, 1.0]]))
+            }
+            3 => {
+                let mut rows = Vec::new();
+                for &((x, y), (tx, ty)) in pairs {
+                    rows.push((vec![x, y, 1.0, 0.0, 0.0, 0.0], tx));
+                    rows.push((vec![0.0, 0.0, 0.0, x, y, 1.0], ty));
+                }
+                let [a, b, c, d, e, f] = solve(rows)?[..] else {
+                    return None;
+                };
+                Some(Self([[a, b, c], [d, e, f], [0.0, 0.0, 1.0]]))
+            }
+            4 => {
+                // Direct linear transform with h33 = 1
+                let mut rows = Vec::new();
+                for &((x, y), (tx, ty)) in pairs {
+                    rows.push((vec![x, y, 1.0, 0.0, 0.0, 0.0, -x * tx, -y * tx], tx));
+                    rows.push((vec![0.0, 0.0, 0.0, x, y, 1.0, -x * ty, -y * ty], ty));
+                }
+                let [a, b, c, d, e, f, g, h] = solve(rows)?[..] else {
+                    return None;
+                };
+                Some(Self([[a, b, c], [d, e, f], [g, h, 1.0]]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Transformed point; None for points mapped to infinity
+    pub fn apply(&self, (x, y): (f64, f64)) -> Option<(f64, f64)> {
+        let [r0, r1, r2] = self.0;
+        let w = r2[0] * x + r2[1] * y + r2[2];
+        if w.abs() < SOLVE_EPSILON {
+            return None;
+        }
+        Some((
+            (r0[0] * x + r0[1] * y + r0[2]) / w,
+            (r1[0] * x + r1[1] * y + r1[2]) / w,
+        ))
+    }
+}
+
+/// Solve a square linear system given as (coefficients, right-hand side) rows,
+/// by Gaussian elimination with partial pivoting
+fn solve(mut rows: Vec<(Vec<f64>, f64)>) -> Option<Vec<f64>> {
+    let n = rows.len();
+    for col in 0..n {
+        let pivot =
+            (col..n).max_by(|&a, &b| rows[a].0[col].abs().total_cmp(&rows[b].0[col].abs()))?;
+        if rows[pivot].0[col].abs() < SOLVE_EPSILON {
+            return None;
+        }
+        rows.swap(col, pivot);
+        let (coeffs, rhs) = rows[col].clone();
+        for row in rows.iter_mut().skip(col + 1) {
+            let factor = row.0[col] / coeffs[col];
+            for (v, c) in row.0.iter_mut().zip(&coeffs).skip(col) {
+                *v -= factor * c;
+            }
+            row.1 -= factor * rhs;
+        }
+    }
+    let mut solution = vec![0.0; n];
+    for i in (0..n).rev() {
+        let (coeffs, rhs) = &rows[i];
+        let known: f64 = (i + 1..n).map(|j| coeffs[j] * solution[j]).sum();
+        solution[i] = (rhs - known) / coeffs[i];
+    }
+    Some(solution)
+}
+
+/// Center of a chip's cell in the snake grid, in cell units
+#[allow(clippy::cast_precision_loss)] // grid positions fit in f64
+pub fn chip_grid_point(chip_idx: usize, chip_count: usize, cpd: usize) -> (f64, f64) {
+    let cpd = cpd.max(1);
+    let (col, row) = layout::chip_to_grid_position(chip_idx, cpd, chip_count.div_ceil(cpd));
+    (col as f64 + 0.5, row as f64 + 0.5)
+}
+
+/// A chip matched to the spot where it appears on the IR photo
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalibrationPoint {
+    pub chip_idx: usize,
+    /// Cell center in the chip grid
+    pub grid: (f64, f64),
+    /// Pixel on the IR photo
+    pub pixel: (f64, f64),
+}
+
+/// Alignment of the IR photo with one slot's chip grid
+#[derive(Debug, Clone, Default)]
+pub struct IrAlignment {
+    /// Slot the photo shows
+    pub slot_id: Option<i32>,
+    pub points: Vec<CalibrationPoint>,
+    /// Chip grid → IR pixel, once there are at least two points
+    pub homography: Option<Homography>,
+}
+
+impl IrAlignment {
+    /// Match a chip to an IR pixel; a chip of another slot starts the alignment
+    /// over, and past MAX_CALIBRATION_POINTS the oldest point is dropped
+    pub fn add_point(&mut self, slot_id: i32, point: CalibrationPoint) {
+        if self.slot_id != Some(slot_id) {
+            *self = Self {
+                slot_id: Some(slot_id),
+                ..Self::default()
+            };
+        }
+        self.points.retain(|p| p.chip_idx != point.chip_idx);
+        if self.points.len() == MAX_CALIBRATION_POINTS {
+            self.points.remove(0);
+        }
+        self.points.push(point);
+        let pairs: Vec<_> = self.points.iter().map(|p| (p.grid, p.pixel)).collect();
+        self.homography = Homography::from_pairs(&pairs);
+    }
+
+    /// IR pixel over each chip of `slot`, if it's the aligned slot
+    fn chip_pixels(&self, slot: &Slot, cpd: usize) -> Option<Vec<Option<(f64, f64)>>> {
+        let h = self.homography.filter(|_| self.slot_id == Some(slot.id))?;
+        let count = slot.chips.len();
+        Some(
+            (0..count)
+                .map(|idx| h.apply(chip_grid_point(idx, count, cpd)))
+                .collect(),
+        )
+    }
+
+    /// Corners of the aligned slot's snake grid on the photo, clockwise from top-left
+    #[allow(clippy::cast_precision_loss)] // grid dimensions fit in f64
+    pub fn grid_outline(&self, chip_count: usize, cpd: usize) -> Option<[(f64, f64); 4]> {
+        let h = self.homography?;
+        let cpd = cpd.max(1);
+        let domains = chip_count.div_ceil(cpd);
+        let columns = 1 + domains.saturating_sub(1) / 2;
+        let rows = if domains > columns { 2 * cpd } else { cpd };
+        let (w, r) = (columns as f64, rows as f64);
+        Some([
+            h.apply((0.0, 0.0))?,
+            h.apply((w, 0.0))?,
+            h.apply((w, r))?,
+            h.apply((0.0, r))?,
+        ])
+    }
+
+    /// IR color over each chip of `slot`, parallel to its chips; empty for other slots
+    pub fn chip_colors(&self, slot: &Slot, cpd: usize, image: &IrImage) -> Vec<Option<[u8; 3]>> {
+        self.chip_pixels(slot, cpd)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|pixel| image.sample(pixel?))
+            .collect()
+    }
+}
+
+/// Fill in `ir_temp` for the chips of the aligned slot that fall on the photo
+pub fn apply_ir_temps(
+    all_analysis: &mut [Vec<ChipAnalysis>],
+    slots: &[Slot],
+    cpd: usize,
+    alignment: &IrAlignment,
+    image: &IrImage,
+    scale: (f32, f32),
+) {
+    for (slot, analysis) in slots.iter().zip(all_analysis.iter_mut()) {
+        let colors = alignment.chip_colors(slot, cpd, image);
+        for (a, rgb) in analysis.iter_mut().zip(colors) {
+            a.ir_temp = rgb.map(|rgb| pixel_temp(rgb, scale));
+        }
+    }
+}
+
+/// Chip sensor and IR reading differ by more than IR_MISMATCH_C
+#[allow(clippy::cast_precision_loss)] // temps fit in f32
+pub fn is_sensor_mismatch(chip_temp: i32, analysis: &ChipAnalysis) -> bool {
+    analysis
+        .ir_temp
+        .is_some_and(|ir| (chip_temp as f32 - ir).abs() > IR_MISMATCH_C)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Chip;
+
+    fn assert_close(a: (f64, f64), b: (f64, f64)) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn test_homography_from_pairs() {
+        // Scale 10, rotate 90°, shift (100, 50)
+        let h = Homography::from_pairs(&[((0.0, 0.0), (100.0, 50.0)), ((1.0, 0.0), (100.0, 60.0))])
+            .unwrap();
+        assert_close(h.apply((0.0, 1.0)).unwrap(), (90.0, 50.0));
+
+        let h = Homography::from_pairs(&[
+            ((0.0, 0.0), (10.0, 10.0)),
+            ((1.0, 0.0), (30.0, 10.0)),
+            ((0.0, 1.0), (15.0, 40.0)),
+        ])
+        .unwrap();
+        assert_close(h.apply((1.0, 1.0)).unwrap(), (35.0, 40.0));
+
+        // Unit square onto a trapezoid: the far edge is foreshortened
+        let corners = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let photo = [(0.0, 0.0), (100.0, 0.0), (75.0, 50.0), (25.0, 50.0)];
+        let pairs: Vec<_> = corners.into_iter().zip(photo).collect();
+        let h = Homography::from_pairs(&pairs).unwrap();
+        for (corner, pixel) in pairs {
+            assert_close(h.apply(corner).unwrap(), pixel);
+        }
+        // The grid center lands where the photo's diagonals cross, not halfway down
+        assert_close(h.apply((0.5, 0.5)).unwrap(), (50.0, 100.0 / 3.0));
+
+        // Repeated and collinear points
+        assert!(
+            Homography::from_pairs(&[((1.0, 1.0), (0.0, 0.0)), ((1.0, 1.0), (5.0, 5.0))]).is_none()
+        );
+        let line = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)];
+        let pairs: Vec<_> = line.into_iter().zip(photo).collect();
+        assert!(Homography::from_pairs(&pairs).is_none());
+    }
+
+    #[test]
+    fn test_apply_ir_temps() {
+        // 30×20 photo, 10 px per cell: left column white (120 °C on a 20–120 scale), rest black
+        let (width, height) = (30, 20);
+        let pixels = (0..width * height)
+            .flat_map(|i| {
+                let v = if i % width < 10 { 255 } else { 0 };
+                [v, v, v, 255]
+            })
+            .collect();
+        let image = IrImage::from_rgba(PathBuf::new(), width, height, pixels);
+
+        // 6 one-chip domains: D0-D2 right to left along the bottom row, D3-D5 left to right on top
+        let slot = Slot {
+            id: 1,
+            chips: [22, 35, 118, 90, 20, 21]
+                .into_iter()
+                .enumerate()
+                .map(|(i, temp)| Chip {
+                    id: i as i32,
+                    temp,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let mut alignment = IrAlignment::default();
+        for idx in [0, 5] {
+            let grid = chip_grid_point(idx, 6, 1);
+            let pixel = (grid.0 * 10.0, grid.1 * 10.0);
+            alignment.add_point(
+                1,
+                CalibrationPoint {
+                    chip_idx: idx,
+                    grid,
+                    pixel,
+                },
+            );
+        }
+        assert!(alignment.homography.is_some());
+
+        let slots = [slot];
+        let mut analysis = crate::analysis::analyze_all_slots(
+            &slots,
+            1,
+            &crate::analysis::AnalysisConfig::default(),
+        );
+        apply_ir_temps(
+            &mut analysis,
+            &slots,
+            1,
+            &alignment,
+            &image,
+            DEFAULT_IR_SCALE,
+        );
+        let ir: Vec<_> = analysis[0]
+            .iter()
+            .map(|a| a.ir_temp.unwrap().round())
+            .collect();
+        assert_eq!(ir, [20.0, 20.0, 120.0, 120.0, 20.0, 20.0]);
+        let flagged: Vec<bool> = slots[0]
+            .chips
+            .iter()
+            .zip(&analysis[0])
+            .map(|(chip, a)| is_sensor_mismatch(chip.temp, a))
+            .collect();
+        assert_eq!(flagged, [false, true, false, true, false, false]);
+
+        // Another slot's chip starts over
+        alignment.add_point(
+            2,
+            CalibrationPoint {
+                chip_idx: 0,
+                grid: (0.5, 0.5),
+                pixel: (5.0, 5.0),
+            },
+        );
+        assert_eq!(alignment.points.len(), 1);
+        assert!(alignment.homography.is_none());
+    }
+}
@@ -0,0 +1,153 @@
+//! Physical chip placement on a hashboard: the snake-pattern grid shared by the chip
+//! map, its exports and the spatial analysis
+//!
+//! A board's domains are split into two sections. The bottom section holds the first
+//! half (rounded up) with D0 on the right at the air intake; the top section holds
+//! the rest, left to right. Chips run down each domain column, C0 at the top.
+//!
+//! ```text
+//!   Top:    [D3][D4][D5]
+//!   Bottom: [D2][D1][D0]   ← intake
+//! ```
+
+/// Snake grid of one board with `num_domains` domains of `chips_per_domain` chips
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChipLayout {
+    pub chips_per_domain: usize,
+    pub num_domains: usize,
+}
+
+impl ChipLayout {
+    /// Layout of a board with `chip_count` chips; no domains when `chips_per_domain` is 0
+    pub fn new(chip_count: usize, chips_per_domain: usize) -> Self {
+        let num_domains = if chips_per_domain > 0 {
+            chip_count.div_ceil(chips_per_domain)
+        } else {
+            0
+        };
+        Self {
+            chips_per_domain,
+            num_domains,
+        }
+    }
+
+    /// Domains in the bottom section, D0 included; also the grid's column count
+    pub fn bottom_domains(self) -> usize {
+        1 + self.num_domains.saturating_sub(1) / 2
+    }
+
+    /// Domains in the top section (0 for a single-domain board)
+    pub fn top_domains(self) -> usize {
+        self.num_domains.saturating_sub(self.bottom_domains())
+    }
+
+    pub fn columns(self) -> usize {
+        self.bottom_domains()
+    }
+
+    /// Chip rows: one section's worth, or two when the board has a top section
+    pub fn rows(self) -> usize {
+        if self.has_top_section() {
+            2 * self.chips_per_domain
+        } else {
+            self.chips_per_domain
+        }
+    }
+
+    pub fn has_top_section(self) -> bool {
+        self.num_domains > self.bottom_domains()
+    }
+
+    pub fn is_top_section(self, domain: usize) -> bool {
+        domain >= self.bottom_domains()
+    }
+
+    /// (column, row) of a chip: the top section occupies rows 0..cpd and the bottom
+    /// section the cpd rows below it
+    pub fn position(self, chip_idx: usize) -> (usize, usize) {
+        let cpd = self.chips_per_domain.max(1);
+        let bottom_domains = self.bottom_domains();
+        let (domain, row) = (chip_idx / cpd, chip_idx % cpd);
+        if domain >= bottom_domains {
+            // Top section: left to right
+            (domain - bottom_domains, row)
+        } else {
+            // Bottom section: right to left, D0 on the right
+            let top_rows = if self.has_top_section() { cpd } else { 0 };
+            (bottom_domains - 1 - domain, top_rows + row)
+        }
+    }
+
+    /// Chip index drawn at (column, row); None outside the grid or in the top
+    /// section's unused columns. A partial last domain can still give an index past
+    /// the board's last chip, which callers check against their chip count.
+    pub fn chip_at(self, col: usize, row: usize) -> Option<usize> {
+        let cpd = self.chips_per_domain;
+        let bottom_domains = self.bottom_domains();
+        if cpd == 0 || self.num_domains == 0 || col >= bottom_domains {
+            return None;
+        }
+        let top_rows = if self.has_top_section() { cpd } else { 0 };
+        let (domain, row) = if row < top_rows {
+            (bottom_domains + col, row)
+        } else if row < top_rows + cpd {
+            (bottom_domains - 1 - col, row - top_rows)
+        } else {
+            return None;
+        };
+        (domain < self.num_domains).then_some(domain * cpd + row)
+    }
+}
+
+/// (grid column, grid row) of a chip in the snake grid, see `ChipLayout::position`
+pub fn chip_to_grid_position(chip_idx: usize, cpd: usize, num_domains: usize) -> (usize, usize) {
+    ChipLayout {
+        chips_per_domain: cpd,
+        num_domains,
+    }
+    .position(chip_idx)
+}
+
+/// Chip index at a snake grid cell, the inverse of `chip_to_grid_position`
+pub fn grid_position_to_chip_idx(
+    col: usize,
+    row: usize,
+    cpd: usize,
+    num_domains: usize,
+) -> Option<usize> {
+    ChipLayout {
+        chips_per_domain: cpd,
+        num_domains,
+    }
+    .chip_at(col, row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_position_round_trip() {
+        // 6 domains of 2 chips: D0-D2 at the bottom right to left, D3-D5 on top
+        assert_eq!(chip_to_grid_position(0, 2, 6), (2, 2));
+        assert_eq!(chip_to_grid_position(5, 2, 6), (0, 3));
+        assert_eq!(chip_to_grid_position(6, 2, 6), (0, 0));
+        assert_eq!(chip_to_grid_position(11, 2, 6), (2, 1));
+        for num_domains in 1..=7 {
+            let layout = ChipLayout::new(num_domains * 3, 3);
+            assert_eq!(layout.num_domains, num_domains);
+            for idx in 0..num_domains * 3 {
+                let (col, row) = chip_to_grid_position(idx, 3, num_domains);
+                assert!(col < layout.columns() && row < layout.rows());
+                assert_eq!(
+                    grid_position_to_chip_idx(col, row, 3, num_domains),
+                    Some(idx)
+                );
+            }
+        }
+        // 5 domains: the top section has no third column
+        assert_eq!(grid_position_to_chip_idx(2, 0, 2, 5), None);
+        assert_eq!(grid_position_to_chip_idx(0, 4, 2, 5), None);
+        assert_eq!(ChipLayout::new(10, 0).num_domains, 0);
+    }
+}
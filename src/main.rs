@@ -1,37 +1,239 @@
 #![windows_subsystem = "windows"]
 
+mod alerts;
 mod analysis;
 mod api;
+mod cli;
 mod config;
+#[cfg(target_os = "linux")]
+mod discovery;
+mod export;
+mod fleet;
+mod history;
 mod i18n;
+mod ir;
+mod layout;
+mod metrics;
 mod models;
+mod notes;
+mod session;
+mod settings;
 mod theme;
 mod ui;
+mod watch;
 
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use iced::futures::SinkExt;
 use iced::{
-    Element, Length, Task, Theme,
-    widget::{button, column, container, pick_list, row, text, text_input},
+    Element, Length, Subscription, Task, Theme,
+    animation::Animation,
+    keyboard,
+    widget::{
+        button, checkbox, column, container, operation, pick_list, row, stack, text, text_input,
+    },
     window,
 };
 
-use i18n::{Language, LocalizedColorMode, Tr};
+use chrono::{DateTime, Local};
+
+use analysis::{AnalysisConfig, ChipAnalysis, ChipTracker, ConfigMismatch, TempBaseline};
+
+use alerts::{AlertEvent, AlertSeverity, AlertWindow};
+use fleet::{FleetMiner, RebootStatus};
+use i18n::{Language, LocalizedColorMode, LocalizedConnection, LocalizedDataSource, Tr};
 use models::{ColorMode, MinerData, SystemInfo};
+use notes::{
+    ChipKey, ChipReplacement, ConflictResolution, GroupDraft, NoteStore, RackDraft, RackPosition,
+};
+use watch::WatchedChip;
 
 /// Embedded application icon (PNG)
 const ICON_DATA: &[u8] = include_bytes!("../assets/icon.png");
 
+/// Auto-refresh interval for the open miner
+const POLL_INTERVAL_SECS: u64 = 60;
+/// How often fleet miners are checked against their own poll intervals
+const FLEET_POLL_TICK_SECS: u64 = 5;
+/// How often the session is auto-saved while polling
+const AUTOSAVE_INTERVAL_SECS: u64 = 300;
+/// Color mode legend bar under the controls row (px)
+const LEGEND_WIDTH: f32 = 360.0;
+/// Fetches of per-slot nonce rate kept for the bar's running average
+const NONCE_RATE_HISTORY: usize = 60;
+
+/// Mouse presses no widget handled, e.g. beside the slot rename input
+fn click_outside(
+    event: iced::Event,
+    status: iced::event::Status,
+    _window: window::Id,
+) -> Option<Message> {
+    match (event, status) {
+        (
+            iced::Event::Mouse(iced::mouse::Event::ButtonPressed(_)),
+            iced::event::Status::Ignored,
+        ) => Some(Message::SlotRenameBlur),
+        _ => None,
+    }
+}
+
+/// Keyboard shortcuts; keys typed into a text input never reach here
+fn shortcut(event: keyboard::Event) -> Option<Message> {
+    let keyboard::Event::KeyPressed { modified_key, .. } = event else {
+        return None;
+    };
+    match modified_key.as_ref() {
+        keyboard::Key::Named(keyboard::key::Named::F1) | keyboard::Key::Character("?") => {
+            Some(Message::HelpToggle)
+        }
+        keyboard::Key::Named(keyboard::key::Named::Escape) => Some(Message::HelpClose),
+        keyboard::Key::Named(named) => {
+            let direction = match named {
+                keyboard::key::Named::ArrowLeft => ui::Direction::Left,
+                keyboard::key::Named::ArrowRight => ui::Direction::Right,
+                keyboard::key::Named::ArrowUp => ui::Direction::Up,
+                keyboard::key::Named::ArrowDown => ui::Direction::Down,
+                keyboard::key::Named::Tab => ui::Direction::Next,
+                _ => return None,
+            };
+            Some(Message::MoveSelection(direction))
+        }
+        _ => None,
+    }
+}
+
+/// Miner logs pushed to UDP `port`, for the syslog subscription
+fn syslog_stream(port: &u16) -> impl iced::futures::Stream<Item = Message> + use<> {
+    let port = *port;
+    iced::stream::channel(
+        4,
+        async move |mut output| match api::start_syslog_listener(port).await {
+            Ok(mut dumps) => {
+                while let Some(data) = dumps.recv().await {
+                    let _ = output.send(Message::SyslogData(data)).await;
+                }
+            }
+            Err(e) => {
+                let _ = output.send(Message::SyslogFailed(e)).await;
+            }
+        },
+    )
+}
+
+/// Start fetching a fleet miner, recording the poll time
+fn fetch_fleet_miner(miner: &mut FleetMiner, now: Instant) -> Task<Message> {
+    miner.loading = true;
+    miner.last_poll = Some(now);
+    let (ip, user, pass) = (miner.ip.clone(), miner.user.clone(), miner.pass.clone());
+    Task::perform(
+        async move {
+            let result = api::fetch_all(&ip, &user, &pass).await;
+            (ip, result)
+        },
+        |(ip, result)| Message::FleetFetched(ip, result),
+    )
+}
+
+/// ~/.ssh/id_ed25519, the usual OpenSSH default key
+fn default_ssh_key_path() -> PathBuf {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(".ssh")
+        .join("id_ed25519")
+}
+
 fn main() -> iced::Result {
+    // Maintainer commands run headless and exit before the GUI starts
+    if let Some(code) = run_cli_command() {
+        std::process::exit(code);
+    }
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let quick_connect = match cli::parse_args(&args) {
+        Ok(quick_connect) => quick_connect,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        }
+    };
+
     // None for format = auto-detect from file content
     let icon = window::icon::from_file_data(ICON_DATA, None).ok();
 
-    iced::application(App::new, App::update, App::view)
-        .title(App::title)
-        .theme(App::theme)
-        .window(window::Settings {
-            icon,
-            ..Default::default()
-        })
-        .run()
+    iced::application(
+        move || App::new(quick_connect.clone()),
+        App::update,
+        App::view,
+    )
+    .title(App::title)
+    .theme(App::theme)
+    .subscription(App::subscription)
+    .window(window::Settings {
+        icon,
+        ..Default::default()
+    })
+    .run()
+}
+
+/// Handle headless maintainer commands, returning the exit code if one ran
+///
+/// - `--audit-models FILE`: print models from FILE (one per line) missing from CONFIGS,
+///   each with the naming convention it was detected as
+/// - `--export-configs`: print CONFIGS as JSON
+/// - `--register-url-handler`: open `whatsminer://` links with this executable
+fn run_cli_command() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("--audit-models") => {
+            let Some(path) = args.get(1) else {
+                eprintln!("Usage: --audit-models FILE");
+                return Some(2);
+            };
+            let contents = match std::fs::read_to_string(path) {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!("{path}: {e}");
+                    return Some(1);
+                }
+            };
+            let models: Vec<&str> = contents
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .collect();
+            let missing = config::audit_missing(&models);
+            for model in &missing {
+                println!(
+                    "{model}\t{}",
+                    config::FirmwareNamingConvention::detect(model)
+                );
+            }
+            eprintln!(
+                "{} of {} models missing from CONFIGS",
+                missing.len(),
+                models.len()
+            );
+            Some(0)
+        }
+        Some("--export-configs") => {
+            println!("{}", config::export_configs_json());
+            Some(0)
+        }
+        Some("--register-url-handler") => match cli::register_url_handler() {
+            Ok(message) => {
+                println!("{message}");
+                Some(0)
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                Some(1)
+            }
+        },
+        _ => None,
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -39,13 +241,191 @@ pub enum Message {
     IpChanged(String),
     UserChanged(String),
     PassChanged(String),
+    ConnectionModeChanged(LocalizedConnection),
+    SshHostChanged(String),
+    SshUserChanged(String),
+    SshKeyPathChanged(String),
+    DataSourceChanged(LocalizedDataSource),
+    AwesomeMinerUrlChanged(String),
+    AwesomeMinerIdChanged(String),
     Fetch,
+    FetchProgress(api::FetchStage),
     Fetched(Result<(MinerData, SystemInfo), String>),
     DividerDragStart,
     DividerDragEnd,
     DividerDrag(f32),
     ColorModeChanged(LocalizedColorMode),
     LanguageChanged(Language),
+    DashboardToggle,
+    AddToFleet,
+    RemoveFromFleet(usize),
+    OpenFleetMiner(usize),
+    FetchFleet,
+    /// Once a second while data is shown, for the data age label
+    Tick(Instant),
+    /// Auto-refresh tick: fetch fleet miners whose poll interval has elapsed
+    FleetPollTick(Instant),
+    FleetPollIntervalChanged(usize, String),
+    FleetFetched(String, Result<(MinerData, SystemInfo), String>),
+    /// Check or uncheck a dashboard miner for the comparison
+    CompareSelectToggled(usize),
+    /// Show two fleet miners side by side
+    CompareMiners(usize, usize),
+    CloseComparison,
+    SyncScrollToggled(bool),
+    /// Relative vertical offset of either comparison grid after a scroll
+    SyncScrollChanged(f32),
+    StatsPanelToggle,
+    RebootAll,
+    CancelRebootAll,
+    ConfirmRebootAll,
+    RebootFinished(String, Result<(), String>),
+    PollingToggled(bool),
+    /// Listen for miner logs pushed over UDP syslog instead of polling
+    SyslogToggled(bool),
+    SyslogPortChanged(String),
+    SyslogData(MinerData),
+    /// The syslog socket couldn't be bound
+    SyslogFailed(String),
+    PollTick,
+    SettingsToggle,
+    /// Analysis settings form edited; applied once every field is valid
+    AnalysisConfigChanged(settings::AnalysisConfigDraft),
+    /// Maintenance windows edited, added or removed; applied once every window is valid
+    AlertScheduleChanged(Vec<settings::AlertWindowDraft>),
+    SettingsSaved(Result<(), String>),
+    MiniHistToggled(bool),
+    /// Polls before the temperature baseline counts as established
+    BaselinePollsChanged(u32),
+    ResetBaseline,
+    ExportBaseline,
+    ImportBaseline,
+    /// CSV text; Err("") if the file dialog was cancelled
+    BaselineLoaded(Result<String, String>),
+    HistoryMaxRecordsChanged(String),
+    HistoryMaxDaysChanged(String),
+    /// Apply the history limits now and reclaim the freed space
+    PruneHistory,
+    HistoryPruned(Result<history::HistoryStats, String>),
+    /// Chip clicked on a chip grid: (slot id, chip index)
+    ChipSelected(i32, usize),
+    /// Selection box dragged on a chip grid: (slot id, chip indexes)
+    ChipsBoxSelected(i32, Vec<usize>),
+    /// Arrow keys and Tab move the selected chip
+    MoveSelection(ui::Direction),
+    FetchChangelog,
+    ChangelogFetched(Result<String, String>),
+    /// None if no firmware database URL is configured
+    FirmwareDbFetched(Option<Result<api::FirmwareDb, String>>),
+    ExportJson,
+    ExportPdf,
+    /// Write a plain-text board health certificate for an RMA claim
+    GenerateHealthCert,
+    /// Write the box-selected chips to CSV
+    ExportSelection,
+    ExportSlotSummary,
+    /// Write every chip's comparison to the model spec to CSV
+    ExportChipComparison,
+    ExportThrottleHistory,
+    /// Ok(None) if the save dialog was cancelled
+    Exported(Result<Option<PathBuf>, String>),
+    /// Save the whole session to a file of the user's choosing
+    SaveSession,
+    LoadSession,
+    /// Err("") if the file dialog was cancelled
+    SessionLoaded(Result<Box<session::AppState>, String>),
+    /// Auto-save tick while polling
+    AutosaveTick,
+    Autosaved(Result<(), String>),
+    PickDiffTarget,
+    LoadDiffTarget(PathBuf),
+    DiffLoaded(Result<MinerData, String>),
+    ClearDiff,
+    NotesToggle,
+    PerformanceToggle,
+    /// Show or hide the data warning list under the status bar
+    ValidationToggle,
+    /// Collapse or expand the miner summary bar above the chip grid
+    SummaryToggle,
+    /// Show or hide the board layout diagram under the slot headers
+    LayoutDiagramToggle,
+    /// Flip a slot's grid 180° for a miner mounted upside-down
+    RotateSlot(i32),
+    RackToggle,
+    RackDraftChanged(i32, RackDraft),
+    SetRackPosition(i32, RackPosition),
+    ClearRackPosition(i32),
+    IrToggle,
+    PickIrImage,
+    ImportIrImage(PathBuf),
+    IrImageLoaded(Result<ir::IrImage, String>),
+    /// Spot on the IR photo (photo pixels) to pair with the selected chip
+    IrPhotoClicked(f64, f64),
+    /// Pair the clicked IR spot with the selected chip
+    AddIrPoint,
+    ClearIrPoints,
+    IrOverlayToggled(bool),
+    IrScaleMinChanged(String),
+    IrScaleMaxChanged(String),
+    /// Chip search expression as typed
+    ChipFilterChanged(String),
+    /// (slot id, chips lost): the firmware disabled many chips since the last poll
+    ChipDropAlert(i32, usize),
+    #[cfg(target_os = "linux")]
+    LldpInterfaceSelected(String),
+    /// Listen for miners announcing themselves over LLDP
+    #[cfg(target_os = "linux")]
+    LldpScan,
+    #[cfg(target_os = "linux")]
+    LldpScanned(Result<Vec<discovery::LldpDiscovery>, String>),
+    /// Add a miner found by the LLDP scan to the fleet, by IP
+    #[cfg(target_os = "linux")]
+    AddDiscovered(String),
+    /// Slot header double-clicked: edit its name
+    StartSlotRename(i32),
+    SlotRenameInput(String),
+    /// Save a slot name; blank goes back to "Slot {id}"
+    RenameSlot(i32, String),
+    /// Click outside the rename input: save what was typed
+    SlotRenameBlur,
+    /// Open the chip group form for a slot
+    AddGroupAnnotation(i32),
+    GroupDraftChanged(GroupDraft),
+    SaveGroupAnnotation,
+    CancelGroupAnnotation,
+    /// Delete the open miner's chip group at this index
+    RemoveGroupAnnotation(usize),
+    /// (slot id, chip id), from the chip context menu
+    MarkChipReplaced(i32, i32),
+    NotesSaved(Result<(), String>),
+    /// Open the model wizard for the open miner's unknown model
+    ConfigureModel,
+    /// Move the model wizard to a step, or update the current one's form
+    WizardStep(config::WizardState),
+    SaveWizardModel,
+    CancelWizard,
+    UserModelSaved(Result<config::UserModel, String>),
+    HelpToggle,
+    HelpClose,
+    ImportAnnotations,
+    /// CSV text; Err("") if the file dialog was cancelled
+    AnnotationsLoaded(Result<String, String>),
+    ResolveImportConflicts(ConflictResolution),
+    CancelImport,
+    WatchWindowToggle,
+    /// (slot id, chip id)
+    AddToWatchWindow(i32, i32),
+    RemoveFromWatch(usize),
+    ClearWatchWindow,
+    WatchAlertDeltaChanged(i32),
+    /// Blink alerting rows in the watch window
+    WatchFlash,
+    AlertHistoryToggle,
+    ClearAlertHistory,
+    /// Ask where to save the alert log
+    PickAlertLogPath,
+    /// Write the alert log to CSV
+    ExportAlertLog(PathBuf),
 }
 
 #[derive(Default)]
@@ -53,14 +433,169 @@ struct App {
     ip: String,
     user: String,
     pass: String,
+    /// Direct or through an SSH jump host; fleet miners are always fetched directly
+    connection: api::ConnectionMode,
+    /// Miner web UI or Awesome Miner; Awesome Miner ignores `connection` and the login
+    data_source: api::DataSource,
     status: String,
     data: Option<MinerData>,
     system_info: Option<SystemInfo>,
+    /// Wall-clock time of the last successful fetch
+    fetched_at: Option<DateTime<Local>>,
+    /// Monotonic time of the last successful fetch; with `data` this is the
+    /// previous chip state when the next poll arrives
+    fetched_instant: Option<Instant>,
+    /// When the displayed data was fetched; unlike fetched_instant it is kept
+    /// for data opened from the dashboard
+    last_fetch_time: Option<Instant>,
+    /// Time of the last Tick, for the data age label
+    now: Option<Instant>,
+    /// Chip state from the fetch before `data`, and the hours between the two
+    previous: Option<(MinerData, f32)>,
+    /// Per-chip analysis of `data`, recomputed on each fetch
+    analysis: Vec<Vec<ChipAnalysis>>,
+    /// Slots whose chip count doesn't match the model spec
+    config_mismatches: Vec<ConfigMismatch>,
+    /// Thresholds of the chip analysis, from the settings file
+    analysis_config: AnalysisConfig,
+    /// Analysis settings as typed; invalid input leaves `analysis_config` unchanged
+    analysis_config_draft: settings::AnalysisConfigDraft,
+    /// Maintenance windows silencing desktop notifications, from the settings file
+    alert_schedule: Vec<AlertWindow>,
+    /// Maintenance windows as typed; invalid input leaves `alert_schedule` unchanged
+    alert_schedule_draft: Vec<settings::AlertWindowDraft>,
+    show_settings: bool,
+    /// Learned temperature per (slot id, chip id), one sample per fetch
+    temp_baseline: TempBaseline,
+    /// Miner the baseline was learned on; fetching another one starts over
+    baseline_ip: String,
+    baseline_polls: u32,
+    /// "Keep last N fetches" as typed
+    history_max_records: String,
+    /// "Keep history for N days" as typed
+    history_max_days: String,
+    /// Size of the history file after the last prune
+    history_stats: Option<history::HistoryStats>,
+    /// Nonce rate per slot id, easing between fetches
+    nonce_rate_bars: HashMap<i32, Animation<f32>>,
+    /// Nonce rate of each slot id over the last NONCE_RATE_HISTORY fetches, oldest first
+    nonce_rate_history: HashMap<i32, VecDeque<i32>>,
+    /// Fingerprint of each slot at the last fetch, by slot id
+    board_fingerprints: HashMap<i32, u64>,
+    /// Slots that changed noticeably since the previous fetch: slot id -> share of chips unchanged
+    changed_boards: HashMap<i32, f32>,
+    /// Each chip's last readings, to spot firmware repeating cached data
+    tracked_chips: ChipTracker,
+    /// Each chip's recent (nonce, freq) readings, for its efficiency trend
+    efficiency_history: analysis::EfficiencyHistory,
+    /// Each chip's session facts: last temperature and estimated heat cycles
+    chip_metadata: analysis::ChipMetadataMap,
+    /// Slot temperature against fan speed per fetch, and the slots whose fan
+    /// curve anomaly has already been notified
+    fan_curves: metrics::FanCurveHistory,
+    fan_curve_alerts: HashSet<i32>,
+    /// Slots already notified for an input voltage below VOLTAGE_CRITICAL_V
+    low_voltage_alerts: HashSet<i32>,
+    /// Enabled chips of each slot over the last ACTIVE_CHIP_HISTORY_POLLS polls
+    active_chip_count_history: analysis::ActiveChipHistory,
+    /// Throttle status of each slot per auto-poll, over the last hour
+    throttle_history: analysis::ThrottleHistory,
+    /// Nonce mini-histograms in chip cells
+    show_mini_hist: bool,
+    /// (slot id, chip index) of the selected chip
+    selected_chip: Option<(i32, usize)>,
+    /// (slot id, chip index) of the chips in the dragged selection box
+    chip_selection: Vec<(i32, usize)>,
+    /// Firmware version -> changelog entries, one per line
+    changelog_cache: HashMap<String, String>,
+    /// Firmware version whose changelog is being fetched
+    changelog_pending: Option<String>,
+    show_changelog: bool,
+    /// Known firmware releases: bundled, then refreshed from WHATSMINER_FIRMWARE_DB_URL
+    firmware_db: api::FirmwareDb,
+    /// Saved export compared against `data` in the diff view, with its analysis
+    diff_data: Option<(MinerData, Vec<Vec<ChipAnalysis>>)>,
+    /// Per-chip notes of every miner opened this session
+    notes: NoteStore,
+    show_notes: bool,
+    show_performance: bool,
+    show_validation: bool,
+    /// Miner summary bar reduced to its toggle
+    summary_collapsed: bool,
+    /// Board layout diagram under each slot header
+    show_layout_diagram: bool,
+    /// Slots drawn turned 180°, by slot id
+    slot_rotated: HashMap<i32, bool>,
+    show_rack: bool,
+    /// Rack position fields being edited, by slot id
+    rack_drafts: HashMap<i32, RackDraft>,
+    show_ir: bool,
+    /// IR camera photo of one of the open miner's boards
+    ir_image: Option<ir::IrImage>,
+    ir_alignment: ir::IrAlignment,
+    /// Spot clicked on the IR photo, waiting to be paired with a chip
+    ir_pending: Option<(f64, f64)>,
+    /// Blend the IR photo into the aligned slot's chip grid
+    ir_overlay: bool,
+    /// IR color bar range (°C) as typed
+    ir_scale_min: String,
+    ir_scale_max: String,
+    /// Chip search expression as typed, and its parsed form when valid
+    chip_filter_text: String,
+    chip_filter: Option<ui::filter::FilterExpr>,
+    /// Slot whose name is being edited, with the name as typed
+    renaming_slot: Option<(i32, String)>,
+    /// Chip group form being filled in
+    group_draft: Option<GroupDraft>,
+    /// Model wizard step being shown
+    model_wizard: Option<config::WizardState>,
+    show_help: bool,
+    /// Imported notes waiting on the conflict dialog
+    pending_import: Option<HashMap<ChipKey, String>>,
+    /// Chips tracked in the watch window
+    watch: Vec<WatchedChip>,
+    show_watch_window: bool,
+    /// Temperature drift from a watched chip's baseline (°C) that raises an alert
+    watch_alert_delta: i32,
+    /// Blink phase for alerting watch rows
+    watch_flash: bool,
+    /// Alerts raised this session, oldest first, at most ALERT_HISTORY_MAX
+    alert_history: VecDeque<AlertEvent>,
+    /// Alerts logged while the alert history was closed
+    unread_alerts: usize,
+    show_alert_history: bool,
+    /// Re-fetch the open miner every POLL_INTERVAL_SECS
+    polling: bool,
+    syslog_mode: bool,
+    /// UDP port typed in the syslog port field
+    syslog_port: String,
     loading: bool,
+    /// Stage of the running fetch, shown as a step indicator in the status bar
+    fetch_stage: Option<api::FetchStage>,
     sidebar_width: f32,
     dragging: bool,
     color_mode: ColorMode,
     language: Language,
+    /// Show the fleet dashboard instead of the single-miner chip map
+    dashboard: bool,
+    fleet: Vec<FleetMiner>,
+    /// Time of the last fleet poll tick, for the "next poll in" countdowns
+    fleet_tick: Option<Instant>,
+    /// Dashboard miners checked for the comparison, at most two
+    compare_selection: Vec<usize>,
+    /// Fleet indexes of the two miners shown side by side
+    comparison: Option<(usize, usize)>,
+    /// Analyses of the compared miners, in comparison order
+    comparison_analysis: [Vec<Vec<ChipAnalysis>>; 2],
+    /// Scroll both comparison grids together
+    sync_scroll: bool,
+    /// Relative vertical offset the comparison grids were last synced to
+    compare_scroll_y: f32,
+    #[cfg(target_os = "linux")]
+    lldp: discovery::LldpScan,
+    show_stats_panel: bool,
+    /// Reboot-all confirmation dialog is open
+    confirm_reboot_all: bool,
 }
 
 impl App {
@@ -72,54 +607,838 @@ impl App {
         Theme::Dark
     }
 
-    fn new() -> (Self, Task<Message>) {
-        let language = Language::default();
-        (
-            Self {
-                ip: "192.7.1.193".into(),
-                user: "admin".into(),
-                pass: "admin".into(),
-                status: Tr::ready(language).into(),
-                sidebar_width: 400.0,
-                language,
-                ..Default::default()
-            },
-            Task::none(),
-        )
+    fn subscription(&self) -> Subscription<Message> {
+        let poll = if self.polling {
+            iced::time::every(Duration::from_secs(POLL_INTERVAL_SECS)).map(|_| Message::PollTick)
+        } else {
+            Subscription::none()
+        };
+        let flash = if self.watch.iter().any(|w| w.alerting) {
+            iced::time::every(Duration::from_millis(500)).map(|_| Message::WatchFlash)
+        } else {
+            Subscription::none()
+        };
+        let fleet_poll = if self.polling && !self.fleet.is_empty() {
+            iced::time::every(Duration::from_secs(FLEET_POLL_TICK_SECS)).map(Message::FleetPollTick)
+        } else {
+            Subscription::none()
+        };
+        let autosave = if self.polling {
+            iced::time::every(Duration::from_secs(AUTOSAVE_INTERVAL_SECS))
+                .map(|_| Message::AutosaveTick)
+        } else {
+            Subscription::none()
+        };
+        let animating = self
+            .nonce_rate_bars
+            .values()
+            .any(|bar| bar.is_animating(Instant::now()));
+        let frames = if animating {
+            window::frames().map(Message::Tick)
+        } else {
+            Subscription::none()
+        };
+        let tick = if self.data.is_some() {
+            iced::time::every(Duration::from_secs(1)).map(Message::Tick)
+        } else {
+            Subscription::none()
+        };
+        let syslog = match self.syslog_port.trim().parse::<u16>() {
+            Ok(port) if self.syslog_mode => Subscription::run_with(port, syslog_stream),
+            _ => Subscription::none(),
+        };
+        let shortcuts = keyboard::listen().filter_map(shortcut);
+        let rename_blur = if self.renaming_slot.is_some() {
+            iced::event::listen_with(click_outside)
+        } else {
+            Subscription::none()
+        };
+        Subscription::batch([
+            poll,
+            flash,
+            fleet_poll,
+            autosave,
+            tick,
+            frames,
+            syslog,
+            shortcuts,
+            rename_blur,
+        ])
+    }
+
+    /// `quick_connect` comes from `--url`; it replaces the default miner and may fetch right away
+    fn new(quick_connect: Option<cli::QuickConnect>) -> (Self, Task<Message>) {
+        let language = i18n::detect_system_language();
+        let (notes, mut status) = match notes::load() {
+            Ok(notes) => (notes, Tr::ready(language).into()),
+            Err(e) => (
+                NoteStore::default(),
+                format!("{}: {e}", Tr::error(language)),
+            ),
+        };
+        if let Err(e) = config::load_user_models() {
+            status = format!("{}: {e}", Tr::error(language));
+        }
+        let saved_settings = settings::load().unwrap_or_else(|e| {
+            status = format!("{}: {e}", Tr::error(language));
+            settings::Settings::default()
+        });
+        let mut app = Self {
+            ip: "192.7.1.193".into(),
+            user: "admin".into(),
+            pass: "admin".into(),
+            status,
+            notes,
+            sidebar_width: 400.0,
+            analysis_config: saved_settings.analysis,
+            analysis_config_draft: (&saved_settings.analysis).into(),
+            watch_alert_delta: saved_settings.watch_alert_delta,
+            alert_schedule_draft: saved_settings
+                .alert_schedule
+                .iter()
+                .map(Into::into)
+                .collect(),
+            alert_schedule: saved_settings.alert_schedule,
+            syslog_port: api::SYSLOG_PORT.to_string(),
+            baseline_polls: saved_settings.baseline_polls,
+            history_max_records: history::DEFAULT_MAX_RECORDS.to_string(),
+            history_max_days: history::DEFAULT_MAX_AGE_DAYS.to_string(),
+            ir_scale_min: ir::DEFAULT_IR_SCALE.0.to_string(),
+            ir_scale_max: ir::DEFAULT_IR_SCALE.1.to_string(),
+            firmware_db: api::FirmwareDb::bundled(),
+            #[cfg(target_os = "linux")]
+            lldp: discovery::LldpScan::new(),
+            language,
+            ..Default::default()
+        };
+        let mut tasks = vec![Task::perform(
+            api::fetch_firmware_db(),
+            Message::FirmwareDbFetched,
+        )];
+        if let Some(quick) = quick_connect {
+            app.ip = quick.ip;
+            app.user = quick.user.unwrap_or(app.user);
+            app.pass = quick.pass.unwrap_or(app.pass);
+            if quick.fetch {
+                tasks.push(Task::done(Message::Fetch));
+            }
+        }
+        (app, Task::batch(tasks))
     }
 
     fn update(&mut self, msg: Message) -> Task<Message> {
         let lang = self.language;
         match msg {
-            Message::IpChanged(v) => self.ip = v,
+            Message::IpChanged(v) => {
+                self.reset_miner_state();
+                self.ip = v;
+            }
             Message::UserChanged(v) => self.user = v,
             Message::PassChanged(v) => self.pass = v,
+            Message::ConnectionModeChanged(selected) => {
+                self.connection = if selected.tunnel {
+                    api::ConnectionMode::SshTunnel {
+                        host: String::new(),
+                        user: "root".into(),
+                        key_path: default_ssh_key_path(),
+                    }
+                } else {
+                    api::ConnectionMode::Direct
+                };
+            }
+            Message::SshHostChanged(v) => {
+                if let api::ConnectionMode::SshTunnel { host, .. } = &mut self.connection {
+                    *host = v;
+                }
+            }
+            Message::SshUserChanged(v) => {
+                if let api::ConnectionMode::SshTunnel { user, .. } = &mut self.connection {
+                    *user = v;
+                }
+            }
+            Message::SshKeyPathChanged(v) => {
+                if let api::ConnectionMode::SshTunnel { key_path, .. } = &mut self.connection {
+                    *key_path = v.into();
+                }
+            }
+            Message::DataSourceChanged(selected) => {
+                self.data_source = if selected.awesome_miner {
+                    api::DataSource::AwesomeMiner {
+                        url: String::new(),
+                        miner_id: 0,
+                    }
+                } else {
+                    api::DataSource::WhatsMinerNative
+                };
+            }
+            Message::AwesomeMinerUrlChanged(v) => {
+                if let api::DataSource::AwesomeMiner { url, .. } = &mut self.data_source {
+                    *url = v;
+                }
+            }
+            Message::AwesomeMinerIdChanged(v) => {
+                // Non-numeric input is ignored; clearing the field resets the id to 0
+                if let api::DataSource::AwesomeMiner { miner_id, .. } = &mut self.data_source {
+                    match v.trim() {
+                        "" => *miner_id = 0,
+                        v => *miner_id = v.parse().unwrap_or(*miner_id),
+                    }
+                }
+            }
             Message::Fetch => {
                 self.loading = true;
+                self.fetch_stage = Some(api::FetchStage::Connecting);
                 self.status = Tr::connecting(lang).into();
+                if let api::DataSource::AwesomeMiner { url, miner_id } = &self.data_source {
+                    let (url, miner_id) = (url.clone(), *miner_id);
+                    return Task::perform(
+                        async move { api::fetch_from_awesome_miner(&url, miner_id).await },
+                        Message::Fetched,
+                    );
+                }
                 let (ip, user, pass) = (self.ip.clone(), self.user.clone(), self.pass.clone());
-                return Task::perform(
-                    async move { api::fetch_all(&ip, &user, &pass).await },
-                    Message::Fetched,
-                );
+                let connection = self.connection.clone();
+                return Task::stream(iced::stream::channel(8, async move |mut output| {
+                    let report = |stage| {
+                        let _ = output.clone().try_send(Message::FetchProgress(stage));
+                    };
+                    let result =
+                        api::fetch_all_with_mode(&connection, &ip, &user, &pass, &report).await;
+                    let _ = output.send(Message::Fetched(result)).await;
+                }));
+            }
+            Message::FetchProgress(stage) => {
+                if self.loading {
+                    self.fetch_stage = Some(stage);
+                    self.status = Tr::fetch_stage(stage, lang).into();
+                }
             }
             Message::Fetched(Ok((data, info))) => {
                 self.loading = false;
-                self.status = format!(
-                    "{} {}, {} {}",
-                    data.slots.len(),
-                    Tr::slots(lang),
-                    data.total_chips(),
-                    Tr::chips(lang)
-                );
-                self.data = Some(data);
+                self.fetch_stage = None;
+                // Keep the matching dashboard entry in sync
+                if let Some(miner) = self.fleet.iter_mut().find(|m| m.ip == self.ip) {
+                    miner.apply_fetch(Ok((data.clone(), info.clone())), &self.analysis_config);
+                }
+                let fan_alerts = info
+                    .fan_speed_rpm
+                    .map(|rpm| self.record_fan_curve(&data, rpm));
                 self.system_info = Some(info);
+                let shown = self.apply_data(data);
+                return Task::batch([shown].into_iter().chain(fan_alerts));
+            }
+            Message::SyslogData(data) => return self.apply_data(data),
+            Message::SyslogToggled(enabled) => self.syslog_mode = enabled,
+            Message::SyslogPortChanged(port) => self.syslog_port = port,
+            Message::SyslogFailed(e) => {
+                self.syslog_mode = false;
+                self.status = format!("{}: {e}", Tr::error(lang));
             }
             Message::Fetched(Err(e)) => {
                 self.loading = false;
+                self.fetch_stage = None;
                 self.status = format!("{}: {e}", Tr::error(lang));
                 self.data = None;
                 self.system_info = None;
+                self.fetched_at = None;
+                self.fetched_instant = None;
+                self.last_fetch_time = None;
+                self.previous = None;
+                self.analysis.clear();
+                self.config_mismatches.clear();
+            }
+            Message::PollingToggled(enabled) => self.polling = enabled,
+            Message::SettingsToggle => self.show_settings = !self.show_settings,
+            Message::FetchChangelog => {
+                let Some(version) = self
+                    .system_info
+                    .as_ref()
+                    .map(|i| i.firmware_version.clone())
+                else {
+                    return Task::none();
+                };
+                if self.show_changelog || self.changelog_cache.contains_key(&version) {
+                    self.show_changelog = !self.show_changelog;
+                } else if self.changelog_pending.is_none() {
+                    self.changelog_pending = Some(version.clone());
+                    return Task::perform(
+                        async move { api::fetch_firmware_changelog(&version).await },
+                        Message::ChangelogFetched,
+                    );
+                }
+            }
+            Message::ChangelogFetched(result) => {
+                let Some(version) = self.changelog_pending.take() else {
+                    return Task::none();
+                };
+                match result {
+                    Ok(changelog) => {
+                        self.changelog_cache.insert(version, changelog);
+                        self.show_changelog = true;
+                    }
+                    Err(e) => self.status = format!("{}: {e}", Tr::error(lang)),
+                }
+            }
+            Message::FirmwareDbFetched(Some(Ok(db))) => self.firmware_db = db,
+            Message::FirmwareDbFetched(Some(Err(e))) => {
+                self.status = format!("{}: {e}", Tr::error(lang));
+            }
+            Message::FirmwareDbFetched(None) => {}
+            Message::ChipSelected(slot_id, idx) => {
+                // Clicking the selected chip again clears the selection
+                self.selected_chip =
+                    (self.selected_chip != Some((slot_id, idx))).then_some((slot_id, idx));
+                self.chip_selection.clear();
+            }
+            Message::MoveSelection(direction) => {
+                let Some(data) = &self.data else {
+                    return Task::none();
+                };
+                let config = self.system_info.as_ref().and_then(config::lookup_system);
+                let next = match self.selected_chip {
+                    // Arrow keys follow the grid as drawn
+                    Some(current) if self.slot_rotated.get(&current.0) == Some(&true) => {
+                        ui::get_adjacent_chip(data, config, current, direction.rotated())
+                    }
+                    Some(current) => ui::get_adjacent_chip(data, config, current, direction),
+                    // Tab starts from the first chip
+                    None if direction == ui::Direction::Next => data
+                        .slots
+                        .iter()
+                        .find(|s| !s.chips.is_empty())
+                        .map(|s| (s.id, 0)),
+                    None => None,
+                };
+                if next.is_some() {
+                    self.selected_chip = next;
+                    self.chip_selection.clear();
+                }
+            }
+            Message::ChipsBoxSelected(slot_id, chips) => {
+                self.selected_chip = None;
+                self.chip_selection = chips.into_iter().map(|idx| (slot_id, idx)).collect();
+            }
+            Message::ExportJson => {
+                if let Some(data) = &self.data {
+                    let name = format!("{}.json", self.ip.replace(['.', ':'], "_"));
+                    return Task::perform(export::save_json(data.clone(), name), Message::Exported);
+                }
+            }
+            Message::ExportPdf => {
+                if let Some(data) = &self.data {
+                    return Task::perform(
+                        export::save_pdf_report(
+                            data.clone(),
+                            self.system_info.clone(),
+                            self.notes.rack_positions(&self.ip),
+                            self.ip.clone(),
+                            self.fetched_at.unwrap_or_else(Local::now),
+                            self.analysis.clone(),
+                        ),
+                        Message::Exported,
+                    );
+                }
+            }
+            Message::GenerateHealthCert => {
+                if let Some(data) = &self.data {
+                    return Task::perform(
+                        export::save_health_cert(
+                            data.clone(),
+                            self.system_info.clone(),
+                            self.ip.clone(),
+                            self.fetched_at.unwrap_or_else(Local::now),
+                            self.analysis.clone(),
+                        ),
+                        Message::Exported,
+                    );
+                }
+            }
+            Message::ExportSelection => {
+                if let Some(data) = &self.data
+                    && !self.chip_selection.is_empty()
+                {
+                    return Task::perform(
+                        export::save_selection_csv(
+                            data.clone(),
+                            self.system_info.clone(),
+                            self.ip.clone(),
+                            self.chip_selection.clone(),
+                            self.notes.slot_names(&self.ip),
+                            self.analysis.clone(),
+                        ),
+                        Message::Exported,
+                    );
+                }
+            }
+            Message::ExportSlotSummary => {
+                if let Some(data) = &self.data {
+                    return Task::perform(
+                        export::save_slot_summary_csv(
+                            data.clone(),
+                            self.system_info.clone(),
+                            self.ip.clone(),
+                            self.notes.slot_names(&self.ip),
+                            self.analysis.clone(),
+                            self.analysis_config,
+                        ),
+                        Message::Exported,
+                    );
+                }
+            }
+            Message::ExportChipComparison => {
+                if let Some(data) = &self.data {
+                    return Task::perform(
+                        export::save_chip_comparison_csv(
+                            data.clone(),
+                            self.system_info.clone(),
+                            self.ip.clone(),
+                            self.notes.slot_names(&self.ip),
+                            self.analysis.clone(),
+                        ),
+                        Message::Exported,
+                    );
+                }
+            }
+            Message::ExportThrottleHistory => {
+                // Polls are timed with Instant; place them on the wall clock for the CSV
+                let (wall, now) = (Local::now(), Instant::now());
+                let history = self
+                    .throttle_history
+                    .iter()
+                    .map(|(at, statuses)| {
+                        let age =
+                            chrono::Duration::from_std(now.duration_since(*at)).unwrap_or_default();
+                        (wall - age, statuses.clone())
+                    })
+                    .collect();
+                return Task::perform(
+                    export::save_throttle_csv(history, self.ip.clone()),
+                    Message::Exported,
+                );
+            }
+            Message::Exported(Ok(Some(path))) => {
+                self.status = format!("{} {}", Tr::saved(lang), path.display());
+            }
+            Message::Exported(Ok(None)) => {}
+            Message::Exported(Err(e)) => self.status = format!("{}: {e}", Tr::error(lang)),
+            Message::SaveSession => {
+                return Task::perform(
+                    session::save_session_as(self.session_state()),
+                    Message::Exported,
+                );
+            }
+            Message::LoadSession => {
+                return Task::perform(session::pick_and_load(), Message::SessionLoaded);
+            }
+            Message::SessionLoaded(Err(e)) if e.is_empty() => {}
+            Message::SessionLoaded(Ok(state)) => return self.restore_session(*state),
+            Message::SessionLoaded(Err(e)) => self.status = format!("{}: {e}", Tr::error(lang)),
+            Message::AutosaveTick => {
+                return Task::perform(session::autosave(self.session_state()), Message::Autosaved);
+            }
+            Message::Autosaved(Ok(())) => {}
+            Message::Autosaved(Err(e)) => self.status = format!("{}: {e}", Tr::error(lang)),
+            Message::PickDiffTarget => {
+                return Task::perform(export::pick_json(), |path| match path {
+                    Some(path) => Message::LoadDiffTarget(path),
+                    None => Message::DiffLoaded(Err(String::new())),
+                });
+            }
+            Message::LoadDiffTarget(path) => {
+                return Task::perform(
+                    async move { export::load_json(&path).await },
+                    Message::DiffLoaded,
+                );
+            }
+            Message::DiffLoaded(Ok(target)) => {
+                self.diff_data = Some((target, Vec::new()));
+                self.reanalyze();
+            }
+            // Empty error: file dialog cancelled
+            Message::DiffLoaded(Err(e)) if e.is_empty() => {}
+            Message::DiffLoaded(Err(e)) => self.status = format!("{}: {e}", Tr::error(lang)),
+            Message::ClearDiff => self.diff_data = None,
+            Message::NotesToggle => self.show_notes = !self.show_notes,
+            Message::PerformanceToggle => self.show_performance = !self.show_performance,
+            Message::ValidationToggle => self.show_validation = !self.show_validation,
+            Message::SummaryToggle => self.summary_collapsed = !self.summary_collapsed,
+            Message::LayoutDiagramToggle => self.show_layout_diagram = !self.show_layout_diagram,
+            Message::RotateSlot(slot_id) => {
+                let rotated = self.slot_rotated.entry(slot_id).or_default();
+                *rotated = !*rotated;
+            }
+            Message::RackToggle => self.show_rack = !self.show_rack,
+            Message::RackDraftChanged(slot_id, draft) => {
+                self.rack_drafts.insert(slot_id, draft);
+            }
+            Message::SetRackPosition(slot_id, position) => {
+                self.rack_drafts.remove(&slot_id);
+                self.notes
+                    .set_rack_position(&self.ip, slot_id, Some(position));
+                return self.save_notes();
+            }
+            Message::ClearRackPosition(slot_id) => {
+                self.rack_drafts.remove(&slot_id);
+                self.notes.set_rack_position(&self.ip, slot_id, None);
+                return self.save_notes();
+            }
+            Message::IrToggle => self.show_ir = !self.show_ir,
+            Message::PickIrImage => {
+                return Task::perform(
+                    export::pick_file("Image", &["png", "jpg", "jpeg"]),
+                    |path| match path {
+                        Some(path) => Message::ImportIrImage(path),
+                        None => Message::IrImageLoaded(Err(String::new())),
+                    },
+                );
+            }
+            Message::ImportIrImage(path) => {
+                return Task::perform(ir::load_image(path), Message::IrImageLoaded);
+            }
+            Message::IrImageLoaded(Ok(image)) => {
+                self.ir_image = Some(image);
+                self.ir_alignment = ir::IrAlignment::default();
+                self.ir_pending = None;
+                self.reanalyze();
+            }
+            // Empty error: file dialog cancelled
+            Message::IrImageLoaded(Err(e)) if e.is_empty() => {}
+            Message::IrImageLoaded(Err(e)) => self.status = format!("{}: {e}", Tr::error(lang)),
+            Message::IrPhotoClicked(x, y) => self.ir_pending = Some((x, y)),
+            Message::AddIrPoint => {
+                let (Some(pixel), Some((slot_id, chip_idx)), Some(data)) =
+                    (self.ir_pending, self.selected_chip, &self.data)
+                else {
+                    return Task::none();
+                };
+                let Some(slot) = data.slots.iter().find(|s| s.id == slot_id) else {
+                    return Task::none();
+                };
+                let config = self.system_info.as_ref().and_then(config::lookup_system);
+                let cpd = ui::chips_per_domain_for(data, config);
+                self.ir_alignment.add_point(
+                    slot_id,
+                    ir::CalibrationPoint {
+                        chip_idx,
+                        grid: ir::chip_grid_point(chip_idx, slot.chips.len(), cpd),
+                        pixel,
+                    },
+                );
+                self.ir_pending = None;
+                self.reanalyze();
+            }
+            Message::ClearIrPoints => {
+                self.ir_alignment = ir::IrAlignment::default();
+                self.ir_pending = None;
+                self.ir_overlay = false;
+                self.reanalyze();
+            }
+            Message::IrOverlayToggled(enabled) => self.ir_overlay = enabled,
+            Message::IrScaleMinChanged(value) => {
+                self.ir_scale_min = value;
+                self.reanalyze();
+            }
+            Message::IrScaleMaxChanged(value) => {
+                self.ir_scale_max = value;
+                self.reanalyze();
+            }
+            Message::ChipFilterChanged(value) => {
+                self.chip_filter = ui::filter::parse_filter_expr(&value);
+                self.chip_filter_text = value;
+            }
+            Message::ChipDropAlert(slot_id, count) => {
+                let body = format!(
+                    "{} {slot_id}: {} {}",
+                    Tr::slot(lang),
+                    Tr::chips_count(count, lang),
+                    Tr::chips_disabled(lang)
+                );
+                self.log_alert(AlertSeverity::Critical, slot_id, None, body.clone());
+                return self.notify(AlertSeverity::Critical, body);
+            }
+            #[cfg(target_os = "linux")]
+            Message::LldpInterfaceSelected(interface) => self.lldp.interface = Some(interface),
+            #[cfg(target_os = "linux")]
+            Message::LldpScan => {
+                let Some(interface) = self.lldp.interface.clone() else {
+                    return Task::none();
+                };
+                self.lldp.scanning = true;
+                self.lldp.found = None;
+                return Task::perform(discovery::scan(interface), Message::LldpScanned);
+            }
+            #[cfg(target_os = "linux")]
+            Message::LldpScanned(result) => {
+                self.lldp.scanning = false;
+                match result {
+                    Ok(found) => self.lldp.found = Some(found),
+                    Err(e) => self.status = format!("{}: {e}", Tr::error(self.language)),
+                }
+            }
+            #[cfg(target_os = "linux")]
+            Message::AddDiscovered(ip) => {
+                if !self.fleet.iter().any(|m| m.ip == ip) {
+                    self.fleet
+                        .push(FleetMiner::new(&ip, &self.user, &self.pass));
+                }
+            }
+            Message::StartSlotRename(slot_id) => {
+                let name = self.notes.slot_names(&self.ip).remove(&slot_id);
+                self.renaming_slot = Some((slot_id, name.unwrap_or_default()));
+                return Task::batch([
+                    operation::focus(ui::SLOT_RENAME_INPUT),
+                    operation::select_all(ui::SLOT_RENAME_INPUT),
+                ]);
+            }
+            Message::SlotRenameInput(name) => {
+                if let Some((_, draft)) = &mut self.renaming_slot {
+                    *draft = name;
+                }
+            }
+            Message::RenameSlot(slot_id, name) => {
+                self.renaming_slot = None;
+                self.notes.rename_slot(&self.ip, slot_id, &name);
+                return self.save_notes();
+            }
+            Message::SlotRenameBlur => {
+                if let Some((slot_id, name)) = self.renaming_slot.take() {
+                    return self.update(Message::RenameSlot(slot_id, name));
+                }
+            }
+            Message::AddGroupAnnotation(slot_id) => {
+                let color = theme::GROUP_COLORS
+                    [self.notes.groups(&self.ip).len() % theme::GROUP_COLORS.len()];
+                self.group_draft = Some(GroupDraft::new(slot_id, color));
+            }
+            Message::GroupDraftChanged(draft) => self.group_draft = Some(draft),
+            Message::SaveGroupAnnotation => {
+                if let Some(Ok(group)) = self.group_draft.as_ref().map(GroupDraft::build) {
+                    self.group_draft = None;
+                    self.notes.add_group(&self.ip, group);
+                    return self.save_notes();
+                }
+            }
+            Message::CancelGroupAnnotation => self.group_draft = None,
+            Message::ConfigureModel => {
+                if let (Some(data), Some(info)) = (&self.data, &self.system_info) {
+                    let model = if info.hardware.model_variant.is_empty() {
+                        info.model.clone()
+                    } else {
+                        info.hardware.model_variant.clone()
+                    };
+                    let detected_chips = data.slots.iter().map(|s| s.chips.len()).max();
+                    let detected_chips = detected_chips.unwrap_or(0);
+                    self.model_wizard = Some(config::WizardState::Model(config::ModelDraft {
+                        model,
+                        chip_num: detected_chips.to_string(),
+                        chips_per_domain: u8::try_from(ui::chips_per_domain_for(data, None)).ok(),
+                        board_num: data.slots.len().to_string(),
+                        detected_chips,
+                        detected_boards: data.slots.len(),
+                    }));
+                }
+            }
+            Message::WizardStep(state) => self.model_wizard = Some(state),
+            Message::CancelWizard => self.model_wizard = None,
+            Message::SaveWizardModel => {
+                if let Some(Ok(model)) = self.model_wizard.as_ref().map(|w| w.draft().build()) {
+                    self.model_wizard = None;
+                    return Task::perform(config::save_user_model(model), Message::UserModelSaved);
+                }
+            }
+            Message::UserModelSaved(Ok(model)) => {
+                // The saved model must now resolve like a built-in one
+                self.status = if config::lookup(&model.model).is_some() {
+                    format!("{}: {}", Tr::model_saved(lang), model.model)
+                } else {
+                    format!("{}: {}", Tr::error(lang), model.model)
+                };
+                self.reanalyze();
+            }
+            Message::UserModelSaved(Err(e)) => self.status = format!("{}: {e}", Tr::error(lang)),
+            Message::RemoveGroupAnnotation(idx) => {
+                self.notes.remove_group(&self.ip, idx);
+                return self.save_notes();
+            }
+            Message::MarkChipReplaced(slot_id, chip_id) => {
+                let Some(data) = &self.data else {
+                    return Task::none();
+                };
+                let replacement = ChipReplacement {
+                    slot_id,
+                    chip_id,
+                    replaced_at: Local::now(),
+                    previous_health: notes::chip_health(data, &self.analysis, slot_id, chip_id)
+                        .unwrap_or_default(),
+                    note: self
+                        .notes
+                        .notes(&self.ip)
+                        .and_then(|n| n.get(&(slot_id, chip_id)))
+                        .cloned()
+                        .unwrap_or_default(),
+                    post_replacement_health: None,
+                };
+                self.notes.mark_replaced(&self.ip, replacement);
+                return self.save_notes();
+            }
+            Message::NotesSaved(Ok(())) => {}
+            Message::NotesSaved(Err(e)) => self.status = format!("{}: {e}", Tr::error(lang)),
+            Message::HelpToggle => self.show_help = !self.show_help,
+            Message::HelpClose => self.show_help = false,
+            Message::ImportAnnotations => {
+                return Task::perform(
+                    async { export::pick_and_read("CSV", &["csv"]).await },
+                    Message::AnnotationsLoaded,
+                );
+            }
+            Message::AnnotationsLoaded(Err(e)) if e.is_empty() => {}
+            Message::AnnotationsLoaded(Err(e)) => self.status = format!("{}: {e}", Tr::error(lang)),
+            Message::AnnotationsLoaded(Ok(content)) => {
+                match notes::import_annotations_csv(&content) {
+                    Ok(incoming) if self.notes.conflicts(&self.ip, &incoming) > 0 => {
+                        self.pending_import = Some(incoming);
+                    }
+                    Ok(incoming) => {
+                        return self.finish_import(incoming, ConflictResolution::KeepExisting);
+                    }
+                    Err(e) => self.status = format!("{}: {e}", Tr::error(lang)),
+                }
+            }
+            Message::ResolveImportConflicts(resolution) => {
+                if let Some(incoming) = self.pending_import.take() {
+                    return self.finish_import(incoming, resolution);
+                }
+            }
+            Message::CancelImport => self.pending_import = None,
+            Message::WatchWindowToggle => self.show_watch_window = !self.show_watch_window,
+            Message::AlertHistoryToggle => {
+                self.show_alert_history = !self.show_alert_history;
+                if self.show_alert_history {
+                    self.unread_alerts = 0;
+                }
+            }
+            Message::ClearAlertHistory => {
+                self.alert_history.clear();
+                self.unread_alerts = 0;
+            }
+            Message::PickAlertLogPath => {
+                let name = format!("{}_alerts.csv", self.ip.replace(['.', ':'], "_"));
+                return Task::perform(
+                    async { export::pick_save_file("CSV", &["csv"], name).await },
+                    |path| match path {
+                        Some(path) => Message::ExportAlertLog(path),
+                        None => Message::Exported(Ok(None)),
+                    },
+                );
+            }
+            Message::ExportAlertLog(path) => {
+                return Task::perform(
+                    export::save_alert_log(self.alert_history.iter().cloned().collect(), path),
+                    Message::Exported,
+                );
+            }
+            Message::AddToWatchWindow(slot_id, chip_id) => {
+                let watched = self
+                    .watch
+                    .iter()
+                    .any(|w| w.slot_id == slot_id && w.chip_id == chip_id);
+                if let Some(data) = &self.data
+                    && !watched
+                {
+                    self.watch
+                        .extend(WatchedChip::new(data, &self.analysis, slot_id, chip_id));
+                }
+            }
+            Message::RemoveFromWatch(idx) => {
+                if idx < self.watch.len() {
+                    self.watch.remove(idx);
+                }
+            }
+            Message::ClearWatchWindow => self.watch.clear(),
+            Message::WatchAlertDeltaChanged(delta) => {
+                self.watch_alert_delta = delta;
+                return self.save_settings();
+            }
+            Message::WatchFlash => self.watch_flash = !self.watch_flash,
+            Message::MiniHistToggled(enabled) => self.show_mini_hist = enabled,
+            Message::AnalysisConfigChanged(draft) => {
+                let config = draft.build();
+                self.analysis_config_draft = draft;
+                if let Ok(config) = config
+                    && config != self.analysis_config
+                {
+                    self.analysis_config = config;
+                    self.reanalyze();
+                    self.analyze_comparison();
+                    for miner in &mut self.fleet {
+                        miner.update_health(&self.analysis_config);
+                    }
+                    return self.save_settings();
+                }
+            }
+            Message::AlertScheduleChanged(drafts) => {
+                let schedule: Result<Vec<_>, _> = drafts
+                    .iter()
+                    .map(settings::AlertWindowDraft::build)
+                    .collect();
+                self.alert_schedule_draft = drafts;
+                if let Ok(schedule) = schedule
+                    && schedule != self.alert_schedule
+                {
+                    self.alert_schedule = schedule;
+                    return self.save_settings();
+                }
+            }
+            Message::SettingsSaved(Ok(())) => {}
+            Message::SettingsSaved(Err(e)) => self.status = format!("{}: {e}", Tr::error(lang)),
+            Message::BaselinePollsChanged(polls) => {
+                self.baseline_polls = polls;
+                self.reanalyze();
+                return self.save_settings();
+            }
+            Message::ResetBaseline => {
+                self.temp_baseline.clear();
+                self.reanalyze();
+            }
+            Message::ExportBaseline => {
+                return Task::perform(
+                    export::save_baseline_csv(self.temp_baseline.clone(), self.ip.clone()),
+                    Message::Exported,
+                );
+            }
+            Message::ImportBaseline => {
+                return Task::perform(
+                    async { export::pick_and_read("CSV", &["csv"]).await },
+                    Message::BaselineLoaded,
+                );
+            }
+            Message::BaselineLoaded(Err(e)) if e.is_empty() => {}
+            Message::BaselineLoaded(content) => {
+                match content.and_then(|c| export::import_baseline(&c)) {
+                    Ok(baseline) => {
+                        self.temp_baseline = baseline;
+                        self.baseline_ip.clone_from(&self.ip);
+                        self.reanalyze();
+                    }
+                    Err(e) => self.status = format!("{}: {e}", Tr::error(lang)),
+                }
+            }
+            Message::HistoryMaxRecordsChanged(records) => self.history_max_records = records,
+            Message::HistoryMaxDaysChanged(days) => self.history_max_days = days,
+            Message::PruneHistory => {
+                return Task::perform(
+                    history::prune(self.history_limits()),
+                    Message::HistoryPruned,
+                );
+            }
+            Message::HistoryPruned(Ok(stats)) => self.history_stats = Some(stats),
+            Message::HistoryPruned(Err(e)) => {
+                self.status = format!("{}: {e}", Tr::error(lang));
+            }
+            Message::PollTick => {
+                if !self.loading && !self.dashboard {
+                    return self.update(Message::Fetch);
+                }
             }
             Message::DividerDragStart => self.dragging = true,
             Message::DividerDragEnd => self.dragging = false,
@@ -127,23 +1446,920 @@ impl App {
                 self.sidebar_width = x.clamp(150.0, 500.0);
             }
             Message::DividerDrag(_) => {}
+            // Modes the loaded data lacks fields for are listed but can't be picked
+            Message::ColorModeChanged(lcm) if lcm.unavailable => {}
             Message::ColorModeChanged(lcm) => self.color_mode = lcm.mode,
             Message::LanguageChanged(l) => {
                 self.language = l;
                 // Update status message if it's a static message
-                if self.data.is_none() && !self.loading {
-                    self.status = Tr::ready(l).into();
+                if !self.loading {
+                    self.status = self.data_status(l);
+                }
+            }
+            Message::DashboardToggle => self.dashboard = !self.dashboard,
+            Message::StatsPanelToggle => self.show_stats_panel = !self.show_stats_panel,
+            Message::AddToFleet => {
+                if !self.fleet.iter().any(|m| m.ip == self.ip) {
+                    let mut miner = FleetMiner::new(&self.ip, &self.user, &self.pass);
+                    miner.data = self.data.clone();
+                    miner.system_info = self.system_info.clone();
+                    miner.update_health(&self.analysis_config);
+                    self.fleet.push(miner);
+                }
+            }
+            Message::RemoveFromFleet(idx) => {
+                if idx < self.fleet.len() {
+                    self.fleet.remove(idx);
+                    // Indexes past the removed miner shift down
+                    self.compare_selection.clear();
+                    self.comparison = None;
+                }
+            }
+            Message::OpenFleetMiner(idx) => {
+                let Some(miner) = self.fleet.get(idx) else {
+                    return Task::none();
+                };
+                let (data, system_info, last_poll) = (
+                    miner.data.clone(),
+                    miner.system_info.clone(),
+                    miner.last_poll,
+                );
+                self.ip = miner.ip.clone();
+                self.user = miner.user.clone();
+                self.pass = miner.pass.clone();
+                self.dashboard = false;
+                self.reset_miner_state();
+                // The open miner's capture must not become the new one's `previous`
+                self.previous = None;
+                self.data = data;
+                self.system_info = system_info;
+                self.last_fetch_time = last_poll;
+                self.reanalyze();
+                if self.data.is_none() {
+                    return self.update(Message::Fetch);
+                }
+                self.status = self.data_status(lang);
+            }
+            Message::FetchFleet => {
+                let now = Instant::now();
+                let tasks = self
+                    .fleet
+                    .iter_mut()
+                    .map(|miner| fetch_fleet_miner(miner, now));
+                return Task::batch(tasks.collect::<Vec<_>>());
+            }
+            Message::Tick(now) => self.now = Some(now),
+            Message::FleetPollTick(now) => {
+                self.fleet_tick = Some(now);
+                let tasks = self
+                    .fleet
+                    .iter_mut()
+                    .filter(|miner| miner.poll_due(now))
+                    .map(|miner| fetch_fleet_miner(miner, now));
+                return Task::batch(tasks.collect::<Vec<_>>());
+            }
+            Message::FleetPollIntervalChanged(idx, v) => {
+                let digits: String = v.chars().filter(char::is_ascii_digit).collect();
+                if let (Some(miner), Ok(secs)) = (self.fleet.get_mut(idx), digits.parse::<u64>())
+                    && secs > 0
+                {
+                    miner.poll_interval_secs = secs;
+                }
+            }
+            Message::CompareSelectToggled(idx) => {
+                fleet::toggle_compare_selection(&mut self.compare_selection, idx);
+            }
+            Message::CompareMiners(a, b) => {
+                let has_data = |i: usize| self.fleet.get(i).is_some_and(|m| m.data.is_some());
+                if a != b && has_data(a) && has_data(b) {
+                    self.comparison = Some((a, b));
+                    self.compare_scroll_y = 0.0;
+                    self.analyze_comparison();
+                }
+            }
+            Message::CloseComparison => self.comparison = None,
+            Message::SyncScrollToggled(on) => self.sync_scroll = on,
+            Message::SyncScrollChanged(y) => {
+                // Snapping the other grid reports its scroll back; the offset is
+                // already stored then, which ends the loop
+                if self.sync_scroll && y.is_finite() && (y - self.compare_scroll_y).abs() > 1e-3 {
+                    self.compare_scroll_y = y;
+                    let offset = operation::RelativeOffset {
+                        x: None,
+                        y: Some(y),
+                    };
+                    return Task::batch(
+                        ui::COMPARE_SCROLL_IDS.map(|id| operation::snap_to(id, offset)),
+                    );
+                }
+            }
+            Message::RebootAll => self.confirm_reboot_all = true,
+            Message::CancelRebootAll => self.confirm_reboot_all = false,
+            Message::ConfirmRebootAll => {
+                self.confirm_reboot_all = false;
+                let targets: Vec<_> = self
+                    .fleet
+                    .iter_mut()
+                    .map(|miner| {
+                        miner.reboot = Some(RebootStatus::Pending);
+                        (miner.ip.clone(), miner.user.clone(), miner.pass.clone())
+                    })
+                    .collect();
+                // One slot per miner, so no result is dropped by try_send
+                let capacity = targets.len().max(1);
+                return Task::stream(iced::stream::channel(capacity, async move |output| {
+                    api::reboot_all(targets, |ip, result| {
+                        let _ = output.clone().try_send(Message::RebootFinished(ip, result));
+                    })
+                    .await;
+                }));
+            }
+            Message::RebootFinished(ip, result) => {
+                if let Some(miner) = self.fleet.iter_mut().find(|m| m.ip == ip) {
+                    miner.apply_reboot(result);
+                }
+            }
+            Message::FleetFetched(ip, result) => {
+                if let Some(miner) = self.fleet.iter_mut().find(|m| m.ip == ip) {
+                    miner.apply_fetch(result, &self.analysis_config);
+                }
+                if let Some((a, b)) = self.comparison
+                    && [a, b].iter().any(|&i| self.fleet[i].ip == ip)
+                {
+                    self.analyze_comparison();
+                }
+                // A fresh peer moves the open miner's fleet outlier scores
+                if ip != self.ip {
+                    self.reanalyze();
                 }
             }
         }
         Task::none()
     }
 
+    /// `data` (the open miner) followed by the fleet miners with data and the same
+    /// board size, for the cross-miner comparison
+    fn cross_miner_peers<'a>(&'a self, data: &'a MinerData) -> Vec<&'a MinerData> {
+        let board_size = |d: &MinerData| d.slots.iter().map(|s| s.chips.len()).max();
+        std::iter::once(data)
+            .chain(
+                self.fleet
+                    .iter()
+                    .filter(|m| m.ip != self.ip)
+                    .filter_map(|m| m.data.as_ref())
+                    .filter(|d| board_size(d) == board_size(data)),
+            )
+            .collect()
+    }
+
+    /// Show a new capture of the open miner, from a fetch or a syslog push
+    fn apply_data(&mut self, data: MinerData) -> Task<Message> {
+        let lang = self.language;
+        self.fetched_at = Some(Local::now());
+        let now = Instant::now();
+        self.previous = self
+            .data
+            .take()
+            .zip(self.fetched_instant)
+            .map(|(prev, at)| (prev, now.duration_since(at).as_secs_f32() / 3600.0));
+        self.fetched_instant = Some(now);
+        self.last_fetch_time = Some(now);
+        self.now = Some(now);
+        analysis::track_chip_changes(&mut self.tracked_chips, &data.slots, now);
+        analysis::update_efficiency_history(&mut self.efficiency_history, &data.slots);
+        analysis::update_chip_metadata(&mut self.chip_metadata, &data.slots);
+        let chip_drops =
+            analysis::record_active_chips(&mut self.active_chip_count_history, &data.slots);
+        let crc_bursts = self
+            .previous
+            .as_ref()
+            .map(|(prev, _)| analysis::find_crc_bursts(&prev.slots, &data.slots))
+            .unwrap_or_default();
+        for (slot_id, new_errors) in crc_bursts {
+            let message = format!(
+                "{} {slot_id}: {} (+{new_errors})",
+                Tr::slot(lang),
+                Tr::crc_burst(lang)
+            );
+            self.log_alert(AlertSeverity::Warning, slot_id, None, message);
+        }
+        if self.polling {
+            let statuses = analysis::compute_throttle_status(&data.slots, &self.throttle_history);
+            // Log slots that were running at full clock at the previous poll
+            let was_throttled = |slot_id| {
+                self.throttle_history.back().is_some_and(|(_, previous)| {
+                    previous
+                        .iter()
+                        .any(|s| s.slot_id == slot_id && s.is_throttled())
+                })
+            };
+            let newly_throttled: Vec<_> = statuses
+                .iter()
+                .filter(|s| s.is_throttled() && !was_throttled(s.slot_id))
+                .copied()
+                .collect();
+            for status in newly_throttled {
+                let message = format!(
+                    "{} {}: {} −{:.0}% ({} MHz)",
+                    Tr::slot(lang),
+                    status.slot_id,
+                    Tr::throttled(lang),
+                    status.severity_pct,
+                    status.freq
+                );
+                self.log_alert(AlertSeverity::Warning, status.slot_id, None, message);
+            }
+            analysis::record_throttle_status(&mut self.throttle_history, now, statuses);
+        }
+        self.data = Some(data);
+        self.reanalyze();
+        self.track_board_changes();
+        self.track_nonce_rates(now);
+        // Score this fetch against earlier ones before it joins the baseline
+        if self.baseline_ip != self.ip {
+            self.temp_baseline.clear();
+            self.baseline_ip.clone_from(&self.ip);
+        }
+        if let Some(data) = &self.data {
+            analysis::update_temp_baseline(&mut self.temp_baseline, &data.slots);
+        }
+        self.status = self.data_status(lang);
+        let mut tasks = vec![
+            self.record_watch(),
+            self.record_history(),
+            self.check_input_voltage(),
+        ];
+        tasks.extend(
+            chip_drops
+                .into_iter()
+                .map(|(slot_id, count)| Task::done(Message::ChipDropAlert(slot_id, count))),
+        );
+        if let Some(data) = &self.data
+            && self.notes.record_post_replacement_health(
+                &self.ip,
+                data,
+                &self.analysis,
+                Local::now(),
+            )
+        {
+            tasks.push(self.save_notes());
+        }
+        Task::batch(tasks)
+    }
+
+    /// Store the current fetch in the history file, pruning it to the limits
+    fn record_history(&self) -> Task<Message> {
+        let (Some(data), Some(fetched_at)) = (&self.data, self.fetched_at) else {
+            return Task::none();
+        };
+        match serde_json::to_string(data) {
+            Ok(json) => Task::perform(
+                history::record(self.ip.clone(), fetched_at, json, self.history_limits()),
+                Message::HistoryPruned,
+            ),
+            Err(e) => Task::done(Message::HistoryPruned(Err(e.to_string()))),
+        }
+    }
+
+    /// History limits from the settings fields; a field that doesn't parse keeps its default
+    fn history_limits(&self) -> history::HistoryLimits {
+        let defaults = history::HistoryLimits::default();
+        history::HistoryLimits {
+            max_records: self
+                .history_max_records
+                .trim()
+                .parse()
+                .unwrap_or(defaults.max_records),
+            max_age_days: self
+                .history_max_days
+                .trim()
+                .parse()
+                .unwrap_or(defaults.max_age_days),
+        }
+    }
+
+    /// The open miner's aligned slot, with its chip analysis
+    fn ir_slot(&self) -> Option<(&models::Slot, &[ChipAnalysis])> {
+        let slot_id = self.ir_alignment.slot_id?;
+        let data = self.data.as_ref()?;
+        let idx = data.slots.iter().position(|s| s.id == slot_id)?;
+        Some((&data.slots[idx], self.analysis.get(idx)?.as_slice()))
+    }
+
+    /// IR panel state: grid outline and sensor mismatches of the aligned slot
+    fn ir_view(&self) -> ui::IrView<'_> {
+        let cpd = self.data.as_ref().map_or(0, |data| {
+            ui::chips_per_domain_for(
+                data,
+                self.system_info.as_ref().and_then(config::lookup_system),
+            )
+        });
+        let slot = self.ir_slot();
+        ui::IrView {
+            image: self.ir_image.as_ref(),
+            alignment: &self.ir_alignment,
+            pending: self.ir_pending,
+            chip_selected: self.selected_chip.is_some(),
+            grid_corners: slot
+                .and_then(|(slot, _)| self.ir_alignment.grid_outline(slot.chips.len(), cpd)),
+            scale: (&self.ir_scale_min, &self.ir_scale_max),
+            overlay: self.ir_overlay,
+            mismatches: slot
+                .map(|(slot, analysis)| {
+                    slot.chips
+                        .iter()
+                        .zip(analysis)
+                        .filter(|(chip, a)| ir::is_sensor_mismatch(chip.temp, a))
+                        .filter_map(|(chip, a)| Some((chip.id, chip.temp, a.ir_temp?)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Chip search matches of the open miner, by slot id (empty without a valid filter)
+    fn filter_matches(&self) -> HashMap<i32, Vec<bool>> {
+        match (&self.chip_filter, &self.data) {
+            (Some(expr), Some(data)) => ui::filter::match_flags(expr, data, &self.analysis),
+            _ => HashMap::new(),
+        }
+    }
+
+    /// Sampled IR color per chip of the aligned slot, while the overlay is on
+    fn ir_overlay_colors(&self) -> HashMap<i32, Vec<Option<[u8; 3]>>> {
+        let (Some(image), Some(data), true) = (&self.ir_image, &self.data, self.ir_overlay) else {
+            return HashMap::new();
+        };
+        let cpd = ui::chips_per_domain_for(
+            data,
+            self.system_info.as_ref().and_then(config::lookup_system),
+        );
+        data.slots
+            .iter()
+            .filter(|slot| self.ir_alignment.slot_id == Some(slot.id))
+            .map(|slot| (slot.id, self.ir_alignment.chip_colors(slot, cpd, image)))
+            .collect()
+    }
+
+    /// IR color bar range from the settings fields; a field that doesn't parse keeps its default
+    fn ir_scale(&self) -> (f32, f32) {
+        let (min, max) = ir::DEFAULT_IR_SCALE;
+        (
+            self.ir_scale_min.trim().parse().unwrap_or(min),
+            self.ir_scale_max.trim().parse().unwrap_or(max),
+        )
+    }
+
+    /// Slot id -> (animated nonce rate, mean over the kept history)
+    #[allow(clippy::cast_precision_loss)] // nonce rates fit in f32
+    fn nonce_rate_view(&self) -> HashMap<i32, (f32, f32)> {
+        let now = self.now.unwrap_or_else(Instant::now);
+        self.nonce_rate_bars
+            .iter()
+            .map(|(&slot_id, bar)| {
+                let history = self.nonce_rate_history.get(&slot_id);
+                let mean = history.map_or(0.0, |h| {
+                    h.iter().map(|&r| r as f32).sum::<f32>() / h.len().max(1) as f32
+                });
+                (slot_id, (bar.interpolate_with(|v| v, now), mean))
+            })
+            .collect()
+    }
+
+    /// Current nonce rate of the fastest slot
+    #[allow(clippy::cast_precision_loss)] // nonce rates fit in f32
+    fn max_nonce_rate(&self) -> f32 {
+        self.data.as_ref().map_or(0.0, |data| {
+            data.slots
+                .iter()
+                .map(|slot| slot.nonce_rate as f32)
+                .fold(0.0, f32::max)
+        })
+    }
+
+    /// Record each slot's nonce rate and start easing its bar toward it
+    #[allow(clippy::cast_precision_loss)] // nonce rates fit in f32
+    fn track_nonce_rates(&mut self, now: Instant) {
+        let Some(data) = &self.data else {
+            return;
+        };
+        for slot in &data.slots {
+            let history = self.nonce_rate_history.entry(slot.id).or_default();
+            if history.len() == NONCE_RATE_HISTORY {
+                history.pop_front();
+            }
+            history.push_back(slot.nonce_rate);
+            self.nonce_rate_bars
+                .entry(slot.id)
+                .or_insert_with(|| Animation::new(0.0).slow())
+                .go_mut(slot.nonce_rate as f32, now);
+        }
+    }
+
+    /// Compare slot fingerprints with the last fetch; slots whose chips changed
+    /// beyond BOARD_SIMILARITY_MIN are flagged until the next fetch
+    fn track_board_changes(&mut self) {
+        let Some(data) = &self.data else {
+            return;
+        };
+        self.changed_boards.clear();
+        for slot in &data.slots {
+            let fingerprint = analysis::compute_board_fingerprint(slot);
+            let old = self.board_fingerprints.insert(slot.id, fingerprint);
+            if old.is_none_or(|old| old == fingerprint) {
+                continue;
+            }
+            let previous = self
+                .previous
+                .as_ref()
+                .and_then(|(prev, _)| prev.slots.iter().find(|s| s.id == slot.id));
+            if let Some(previous) = previous {
+                let similarity = analysis::board_similarity(previous, slot);
+                if similarity < analysis::BOARD_SIMILARITY_MIN {
+                    self.changed_boards.insert(slot.id, similarity);
+                }
+            }
+        }
+    }
+
+    /// CompareMiners for the two checked dashboard miners, once both have data
+    fn compare_message(&self) -> Option<Message> {
+        match self.compare_selection[..] {
+            [a, b]
+                if [a, b]
+                    .iter()
+                    .all(|&i| self.fleet.get(i).is_some_and(|m| m.data.is_some())) =>
+            {
+                Some(Message::CompareMiners(a, b))
+            }
+            _ => None,
+        }
+    }
+
+    /// Both sides of the open comparison, None if either miner has no data
+    fn comparison_sides(&self) -> Option<[ui::CompareSide<'_>; 2]> {
+        let (a, b) = self.comparison?;
+        let side = |idx: usize, pos: usize| {
+            let miner = self.fleet.get(idx)?;
+            Some(ui::CompareSide {
+                miner,
+                data: miner.data.as_ref()?,
+                analysis: &self.comparison_analysis[pos],
+                opts: ui::ViewOptions {
+                    color_mode: self.color_mode,
+                    lang: self.language,
+                    show_mini_hist: self.show_mini_hist,
+                    slot_names: self.notes.slot_names(&miner.ip),
+                    analysis_config: self.analysis_config,
+                    show_layout_diagram: self.show_layout_diagram,
+                    ..ui::ViewOptions::default()
+                },
+            })
+        };
+        Some([side(a, 0)?, side(b, 1)?])
+    }
+
+    /// Analyze the compared miners with the current thresholds
+    fn analyze_comparison(&mut self) {
+        let Some((a, b)) = self.comparison else {
+            return;
+        };
+        self.comparison_analysis = [a, b].map(|idx| {
+            let miner = &self.fleet[idx];
+            miner.data.as_ref().map_or_else(Vec::new, |data| {
+                let miner_config = miner.system_info.as_ref().and_then(config::lookup_system);
+                let chips_per_domain = ui::chips_per_domain_for(data, miner_config);
+                analysis::analyze_all_slots(&data.slots, chips_per_domain, &self.analysis_config)
+            })
+        });
+    }
+
+    /// Drop per-miner session state before switching to a different miner: error
+    /// deltas, baselines and histories from the old one are meaningless for it
+    fn reset_miner_state(&mut self) {
+        self.fetched_instant = None;
+        self.watch.clear();
+        self.board_fingerprints.clear();
+        self.changed_boards.clear();
+        self.tracked_chips.clear();
+        self.efficiency_history.clear();
+        self.chip_metadata.clear();
+        self.fan_curves.clear();
+        self.fan_curve_alerts.clear();
+        self.low_voltage_alerts.clear();
+        self.active_chip_count_history.clear();
+        self.throttle_history.clear();
+        self.ir_alignment = ir::IrAlignment::default();
+        self.ir_pending = None;
+        self.nonce_rate_bars.clear();
+        self.nonce_rate_history.clear();
+        self.renaming_slot = None;
+        self.rack_drafts.clear();
+    }
+
+    /// Recompute chip analysis for `data`, with error rates when the previous
+    /// chip state is known
+    fn reanalyze(&mut self) {
+        let Some(data) = &self.data else {
+            self.analysis.clear();
+            self.config_mismatches.clear();
+            return;
+        };
+        let miner_config = self.system_info.as_ref().and_then(config::lookup_system);
+        let chips_per_domain = ui::chips_per_domain_for(data, miner_config);
+        self.config_mismatches = miner_config
+            .map(|cfg| analysis::compute_config_match(&data.slots, cfg))
+            .unwrap_or_default();
+        self.analysis =
+            analysis::analyze_all_slots(&data.slots, chips_per_domain, &self.analysis_config);
+        if let Some((prev, interval_h)) = &self.previous {
+            analysis::apply_error_rates(&mut self.analysis, &data.slots, &prev.slots, *interval_h);
+        }
+        analysis::apply_temp_baseline(
+            &mut self.analysis,
+            &data.slots,
+            &self.temp_baseline,
+            self.baseline_polls,
+        );
+        analysis::apply_data_staleness(&mut self.analysis, &data.slots, &self.tracked_chips);
+        let peers = self.cross_miner_peers(data);
+        if let Some(own) = analysis::analyze_cross_miner(&peers, chips_per_domain).first() {
+            analysis::apply_cross_miner(&mut self.analysis, own);
+        }
+        analysis::apply_efficiency_trends(
+            &mut self.analysis,
+            &data.slots,
+            &self.efficiency_history,
+        );
+        analysis::apply_heat_cycles(&mut self.analysis, &data.slots, &self.chip_metadata);
+        let ir_scale = self.ir_scale();
+        if let Some(image) = &self.ir_image {
+            ir::apply_ir_temps(
+                &mut self.analysis,
+                &data.slots,
+                chips_per_domain,
+                &self.ir_alignment,
+                image,
+                ir_scale,
+            );
+        }
+        if let Some((target, target_analysis)) = &mut self.diff_data {
+            *target_analysis =
+                analysis::analyze_all_slots(&target.slots, chips_per_domain, &self.analysis_config);
+        }
+    }
+
+    /// Merge imported notes into the open miner's notes and report chips it doesn't have
+    fn finish_import(
+        &mut self,
+        incoming: HashMap<ChipKey, String>,
+        resolution: ConflictResolution,
+    ) -> Task<Message> {
+        let lang = self.language;
+        let count = incoming.len();
+        let missing = self
+            .data
+            .as_ref()
+            .map(|data| notes::missing_chips(&incoming, data))
+            .unwrap_or_default();
+        self.notes.merge(&self.ip, incoming, resolution);
+        self.show_notes = true;
+
+        self.status = format!("{}: {count}", Tr::notes_imported(lang));
+        if !missing.is_empty() {
+            let chips: Vec<String> = missing
+                .iter()
+                .map(|(slot, chip)| format!("{} {slot} #{chip}", Tr::slot(lang)))
+                .collect();
+            self.status.push_str(&format!(
+                " — ⚠ {}: {}",
+                Tr::notes_unknown_chips(lang),
+                chips.join(", ")
+            ));
+        }
+        self.save_notes()
+    }
+
+    /// Write all notes and chip groups to the notes file
+    fn save_notes(&self) -> Task<Message> {
+        match self.notes.to_json() {
+            Ok(json) => Task::perform(notes::save(json), Message::NotesSaved),
+            Err(e) => Task::done(Message::NotesSaved(Err(e))),
+        }
+    }
+
+    /// Snapshot of everything a saved session restores
+    fn session_state(&self) -> session::AppState {
+        session::AppState {
+            version: session::SESSION_VERSION,
+            saved_at: Some(Local::now()),
+            ip: self.ip.clone(),
+            user: self.user.clone(),
+            data: self.data.clone(),
+            system_info: self.system_info.clone(),
+            fetched_at: self.fetched_at,
+            fleet: self
+                .fleet
+                .iter()
+                .map(|m| session::SessionMiner {
+                    ip: m.ip.clone(),
+                    user: m.user.clone(),
+                    data: m.data.clone(),
+                    system_info: m.system_info.clone(),
+                    poll_interval_secs: m.poll_interval_secs,
+                })
+                .collect(),
+            dashboard: self.dashboard,
+            notes: self.notes.clone(),
+            selected_chip: self.selected_chip,
+            color_mode: self.color_mode,
+            sidebar_width: self.sidebar_width,
+            slot_rotated: self.slot_rotated.clone(),
+            language: self.language,
+            settings: settings::Settings {
+                analysis: self.analysis_config,
+                watch_alert_delta: self.watch_alert_delta,
+                baseline_polls: self.baseline_polls,
+                alert_schedule: self.alert_schedule.clone(),
+            },
+            temp_baseline: self
+                .temp_baseline
+                .iter()
+                .map(|(&key, &stats)| (key, stats))
+                .collect(),
+            baseline_ip: self.baseline_ip.clone(),
+        }
+    }
+
+    /// Replace the open miner, fleet, notes, view and thresholds with a saved
+    /// session's; passwords aren't saved, so restored miners use the one typed in
+    fn restore_session(&mut self, state: session::AppState) -> Task<Message> {
+        // Start over as for a newly typed IP: the old miner's histories don't apply
+        let _ = self.update(Message::IpChanged(state.ip));
+        self.user = state.user;
+        self.data = state.data;
+        self.system_info = state.system_info;
+        self.fetched_at = state.fetched_at;
+        self.last_fetch_time = None;
+        self.previous = None;
+        self.diff_data = None;
+        self.chip_selection.clear();
+        self.compare_selection.clear();
+        self.comparison = None;
+        self.fleet = state
+            .fleet
+            .into_iter()
+            .map(|m| {
+                let mut miner = FleetMiner::new(&m.ip, &m.user, &self.pass);
+                miner.data = m.data;
+                miner.system_info = m.system_info;
+                if m.poll_interval_secs > 0 {
+                    miner.poll_interval_secs = m.poll_interval_secs;
+                }
+                miner
+            })
+            .collect();
+        self.dashboard = state.dashboard;
+        self.notes = state.notes;
+        self.selected_chip = state.selected_chip;
+        self.color_mode = state.color_mode;
+        self.sidebar_width = state.sidebar_width.clamp(150.0, 500.0);
+        self.slot_rotated = state.slot_rotated;
+        self.language = state.language;
+        self.analysis_config = state.settings.analysis;
+        self.analysis_config_draft = (&state.settings.analysis).into();
+        for miner in &mut self.fleet {
+            miner.update_health(&self.analysis_config);
+        }
+        self.watch_alert_delta = state.settings.watch_alert_delta;
+        self.baseline_polls = state.settings.baseline_polls;
+        self.alert_schedule_draft = state
+            .settings
+            .alert_schedule
+            .iter()
+            .map(Into::into)
+            .collect();
+        self.alert_schedule = state.settings.alert_schedule;
+        self.temp_baseline = state.temp_baseline.into_iter().collect();
+        self.baseline_ip = state.baseline_ip;
+        self.reanalyze();
+        self.status = format!(
+            "{} — {}",
+            Tr::session_restored(self.language),
+            self.data_status(self.language)
+        );
+        Task::batch([self.save_settings(), self.save_notes()])
+    }
+
+    /// Write the analysis thresholds and alert settings to the settings file
+    fn save_settings(&self) -> Task<Message> {
+        let saved = settings::Settings {
+            analysis: self.analysis_config,
+            watch_alert_delta: self.watch_alert_delta,
+            baseline_polls: self.baseline_polls,
+            alert_schedule: self.alert_schedule.clone(),
+        };
+        match saved.to_json() {
+            Ok(json) => Task::perform(settings::save(json), Message::SettingsSaved),
+            Err(e) => Task::done(Message::SettingsSaved(Err(e))),
+        }
+    }
+
+    /// Record the latest fetch for watched chips and notify for new alerts
+    fn record_watch(&mut self) -> Task<Message> {
+        let Some(data) = &self.data else {
+            return Task::none();
+        };
+        let crossings: Vec<(i32, i32, String)> = self
+            .watch
+            .iter_mut()
+            .filter_map(|w| {
+                w.record(data, &self.analysis, self.watch_alert_delta)
+                    .then(|| {
+                        let body = Tr::watch_alert(
+                            w.slot_id,
+                            w.chip_id,
+                            w.temp(),
+                            w.drift(),
+                            self.language,
+                        );
+                        (w.slot_id, w.chip_id, body)
+                    })
+            })
+            .collect();
+        let mut alerts = Vec::new();
+        for (slot_id, chip_id, body) in crossings {
+            self.log_alert(AlertSeverity::Warning, slot_id, Some(chip_id), body.clone());
+            alerts.push(self.notify(AlertSeverity::Warning, body));
+        }
+        Task::batch(alerts)
+    }
+
+    /// Add an alert about the open miner to the alert history
+    fn log_alert(
+        &mut self,
+        severity: AlertSeverity,
+        slot_id: i32,
+        chip_id: Option<i32>,
+        message: String,
+    ) {
+        alerts::push_alert(
+            &mut self.alert_history,
+            AlertEvent {
+                timestamp: Local::now(),
+                severity,
+                miner_ip: self.ip.clone(),
+                slot_id,
+                chip_id,
+                message,
+            },
+        );
+        if !self.show_alert_history {
+            self.unread_alerts = (self.unread_alerts + 1).min(alerts::ALERT_HISTORY_MAX);
+        }
+    }
+
+    /// Desktop notification for an alert about the open miner, unless a maintenance
+    /// window silences alerts of its severity right now
+    fn notify(&self, severity: AlertSeverity, body: String) -> Task<Message> {
+        if metrics::is_alert_suppressed(Local::now(), &self.alert_schedule, severity) {
+            return Task::none();
+        }
+        Task::future(watch::notify(format!("WhatsMiner {}", self.ip), body)).discard()
+    }
+
+    /// Add a fetch to the fan curves and notify for slots whose fan just stopped
+    /// following their temperature; syslog pushes carry no fan speed and are skipped
+    fn record_fan_curve(&mut self, data: &MinerData, fan_rpm: i32) -> Task<Message> {
+        metrics::record_fan_curve(&mut self.fan_curves, &data.slots, fan_rpm);
+        let mut alerts = Vec::new();
+        for slot in &data.slots {
+            let anomaly = self
+                .fan_curves
+                .get(&slot.id)
+                .and_then(|points| metrics::fan_curve_anomaly(points));
+            match anomaly {
+                Some(r) if self.fan_curve_alerts.insert(slot.id) => {
+                    let body = format!(
+                        "{} {}: {} (r = {r:.2})",
+                        Tr::slot(self.language),
+                        slot.id,
+                        Tr::fan_curve_anomaly(self.language)
+                    );
+                    self.log_alert(AlertSeverity::Warning, slot.id, None, body.clone());
+                    alerts.push(self.notify(AlertSeverity::Warning, body));
+                }
+                Some(_) => {}
+                None => {
+                    if self.fan_curve_alerts.remove(&slot.id) {
+                        let message = format!(
+                            "{} {}: {}",
+                            Tr::slot(self.language),
+                            slot.id,
+                            Tr::fan_curve_recovered(self.language)
+                        );
+                        self.log_alert(AlertSeverity::Info, slot.id, None, message);
+                    }
+                }
+            }
+        }
+        Task::batch(alerts)
+    }
+
+    /// Notify for slots whose input voltage just fell below VOLTAGE_CRITICAL_V, whatever
+    /// the state of their chips; a slot alerts again after it recovers
+    fn check_input_voltage(&mut self) -> Task<Message> {
+        let Some(data) = &self.data else {
+            return Task::none();
+        };
+        let lang = self.language;
+        let mut events = Vec::new();
+        for slot in &data.slots {
+            match slot.voltage_input {
+                Some(volts) if volts < metrics::VOLTAGE_CRITICAL_V => {
+                    if self.low_voltage_alerts.insert(slot.id) {
+                        let body = format!(
+                            "{} {}: {} {volts:.2}V",
+                            Tr::slot(lang),
+                            slot.id,
+                            Tr::low_input_voltage(lang)
+                        );
+                        events.push((AlertSeverity::Critical, slot.id, body));
+                    }
+                }
+                _ => {
+                    if self.low_voltage_alerts.remove(&slot.id) {
+                        let body = format!(
+                            "{} {}: {}",
+                            Tr::slot(lang),
+                            slot.id,
+                            Tr::input_voltage_recovered(lang)
+                        );
+                        events.push((AlertSeverity::Info, slot.id, body));
+                    }
+                }
+            }
+        }
+        let mut alerts = Vec::new();
+        for (severity, slot_id, body) in events {
+            self.log_alert(severity, slot_id, None, body.clone());
+            // Recoveries only go to the alert history
+            if severity == AlertSeverity::Critical {
+                alerts.push(self.notify(severity, body));
+            }
+        }
+        Task::batch(alerts)
+    }
+
+    /// (slot id, chip id) of the selected chip
+    fn selected_chip_id(&self) -> Option<(i32, i32)> {
+        let (slot_id, idx) = self.selected_chip?;
+        let slot = self.data.as_ref()?.slots.iter().find(|s| s.id == slot_id)?;
+        Some((slot_id, slot.chips.get(idx)?.id))
+    }
+
+    /// Seconds since the displayed data was fetched
+    fn data_age(&self) -> Option<u64> {
+        self.data.as_ref()?;
+        let fetched = self.last_fetch_time?;
+        Some(self.now?.saturating_duration_since(fetched).as_secs())
+    }
+
+    /// Cached changelog for the open miner's firmware, if expanded
+    fn visible_changelog(&self) -> Option<&str> {
+        if !self.show_changelog {
+            return None;
+        }
+        let version = &self.system_info.as_ref()?.firmware_version;
+        self.changelog_cache.get(version).map(String::as_str)
+    }
+
+    /// Status line for loaded data: slot/chip counts and fetch time
+    fn data_status(&self, lang: Language) -> String {
+        let Some(data) = &self.data else {
+            return Tr::ready(lang).into();
+        };
+        let mut status = format!(
+            "{}, {}",
+            Tr::slots_count(data.slots.len(), lang),
+            Tr::chips_count(data.total_chips(), lang)
+        );
+        if let Some(ts) = self.fetched_at {
+            status.push_str(&format!(" — {}", i18n::format_datetime(ts, lang)));
+        }
+        status
+    }
+
     fn view(&self) -> Element<'_, Message> {
         let lang = self.language;
+        let quality = self
+            .data
+            .as_ref()
+            .map(|data| data.data_quality.clone())
+            .unwrap_or_default();
         let selected_color = LocalizedColorMode {
-            mode: self.color_mode,
-            lang,
+            unavailable: !quality.supports(self.color_mode),
+            ..LocalizedColorMode::new(self.color_mode, lang)
         };
 
         let controls = row![
@@ -160,6 +2376,26 @@ impl App {
                 .padding(10)
                 .width(120)
                 .secure(true),
+            pick_list(
+                LocalizedConnection::all(lang),
+                Some(LocalizedConnection {
+                    tunnel: self.connection != api::ConnectionMode::Direct,
+                    lang,
+                }),
+                Message::ConnectionModeChanged
+            )
+            .padding(8)
+            .width(130),
+            pick_list(
+                LocalizedDataSource::all(lang),
+                Some(LocalizedDataSource {
+                    awesome_miner: self.data_source != api::DataSource::WhatsMinerNative,
+                    lang,
+                }),
+                Message::DataSourceChanged
+            )
+            .padding(8)
+            .width(150),
             if self.loading {
                 button(text(Tr::loading(lang))).padding(10)
             } else {
@@ -169,7 +2405,7 @@ impl App {
             },
             text(Tr::color(lang)).size(14),
             pick_list(
-                LocalizedColorMode::all(lang),
+                LocalizedColorMode::for_data(lang, &quality),
                 Some(selected_color),
                 Message::ColorModeChanged
             )
@@ -179,23 +2415,220 @@ impl App {
             pick_list(Language::ALL, Some(lang), Message::LanguageChanged)
                 .padding(8)
                 .width(100),
+            checkbox(self.polling)
+                .label(Tr::auto_poll(lang))
+                .on_toggle(Message::PollingToggled)
+                .size(16),
+            checkbox(self.syslog_mode)
+                .label(Tr::syslog_mode(lang))
+                .on_toggle(Message::SyslogToggled)
+                .size(16),
+            text_input("UDP", &self.syslog_port)
+                .on_input(Message::SyslogPortChanged)
+                .padding(8)
+                .width(65),
+            button(text(Tr::stats(lang)))
+                .on_press(Message::StatsPanelToggle)
+                .padding(10),
+            button(text(Tr::settings(lang)))
+                .on_press(Message::SettingsToggle)
+                .padding(10),
+            button(text(Tr::watch(lang)))
+                .on_press(Message::WatchWindowToggle)
+                .padding(10),
+            button(ui::alerts_button_label(self.unread_alerts, lang))
+                .on_press(Message::AlertHistoryToggle)
+                .padding(10),
+            button(text(Tr::notes(lang)))
+                .on_press(Message::NotesToggle)
+                .padding(10),
+            button(text(Tr::performance(lang)))
+                .on_press(Message::PerformanceToggle)
+                .padding(10),
+            button(text(Tr::rack(lang)))
+                .on_press(Message::RackToggle)
+                .padding(10),
+            button(text(Tr::ir_photo(lang)))
+                .on_press(Message::IrToggle)
+                .padding(10),
+            button(text(Tr::export_json(lang)))
+                .on_press_maybe(self.data.is_some().then_some(Message::ExportJson))
+                .padding(10),
+            button(text(Tr::pdf_report(lang)))
+                .on_press_maybe(self.data.is_some().then_some(Message::ExportPdf))
+                .padding(10),
+            button(text(Tr::rma_report(lang)))
+                .on_press_maybe(self.data.is_some().then_some(Message::GenerateHealthCert))
+                .padding(10),
+            button(text(Tr::export_selection(lang)))
+                .on_press_maybe(
+                    (self.data.is_some() && !self.chip_selection.is_empty())
+                        .then_some(Message::ExportSelection)
+                )
+                .padding(10),
+            button(text(Tr::export_slot_summary(lang)))
+                .on_press_maybe(self.data.is_some().then_some(Message::ExportSlotSummary))
+                .padding(10),
+            button(text(Tr::export_chip_comparison(lang)))
+                .on_press_maybe(self.data.is_some().then_some(Message::ExportChipComparison))
+                .padding(10),
+            button(text(Tr::diff(lang)))
+                .on_press_maybe(self.data.is_some().then_some(Message::PickDiffTarget))
+                .padding(10),
+            button(text(Tr::save_session(lang)))
+                .on_press(Message::SaveSession)
+                .padding(10),
+            button(text(Tr::load_session(lang)))
+                .on_press(Message::LoadSession)
+                .padding(10),
+            button(text(Tr::add_to_fleet(lang)))
+                .on_press(Message::AddToFleet)
+                .padding(10),
+            button(text(if self.dashboard {
+                Tr::chip_map(lang)
+            } else {
+                Tr::dashboard(lang)
+            }))
+            .on_press(Message::DashboardToggle)
+            .padding(10),
+            button(text("?")).on_press(Message::HelpToggle).padding(10),
         ]
         .spacing(10)
         .padding(10)
         .align_y(iced::Alignment::Center);
 
-        let status = container(text(&self.status).size(14))
-            .padding(10)
-            .width(Length::Fill);
+        let power_budget = self.data.as_ref().and_then(|data| {
+            let cfg = config::lookup_system(self.system_info.as_ref()?)?;
+            Some(ui::power_budget(
+                metrics::estimate_miner_power(data, cfg),
+                metrics::rated_miner_power(cfg),
+                lang,
+            ))
+        });
+        let warnings = self
+            .data
+            .as_ref()
+            .filter(|_| !self.dashboard)
+            .map(|data| {
+                let config = self.system_info.as_ref().and_then(config::lookup_system);
+                api::validate_miner_data(data, config)
+            })
+            .unwrap_or_default();
+        let filter_match_count = Some(self.filter_matches())
+            .filter(|matches| !self.dashboard && !matches.is_empty())
+            .map(|matches| matches.values().flatten().filter(|&&m| m).count());
+        let status = container(
+            row![text(&self.status).size(14)]
+                .push(self.data_age().map(|age| ui::data_age(age, lang)))
+                .push(self.fetch_stage.map(ui::fetch_progress))
+                .push(power_budget.filter(|_| !self.dashboard))
+                .push(ui::validation_badge(&warnings, lang))
+                .push(ui::partial_data_warning(&quality, lang))
+                .push(filter_match_count.map(|count| {
+                    text(format!(
+                        "{}: {}",
+                        Tr::filter_matches(lang),
+                        Tr::chips_count(count, lang)
+                    ))
+                    .size(14)
+                    .color(theme::BRAND_ORANGE)
+                }))
+                .push(
+                    self.data
+                        .as_ref()
+                        .filter(|data| !self.dashboard && data.slots.len() > 1)
+                        .map(|data| {
+                            ui::slot_balance(&analysis::compute_slot_balance(&data.slots), lang)
+                        }),
+                )
+                .spacing(15)
+                .align_y(iced::Alignment::Center),
+        )
+        .padding(10)
+        .width(Length::Fill);
 
         let content = match &self.data {
+            _ if self.dashboard
+                && let Some(sides) = self.comparison_sides() =>
+            {
+                ui::compare_view(sides, self.sync_scroll, lang)
+            }
+            _ if self.dashboard => {
+                let actions = row![
+                    button(text(Tr::fetch_all(lang)))
+                        .on_press_maybe((!self.fleet.is_empty()).then_some(Message::FetchFleet))
+                        .padding(8),
+                    button(text(Tr::reboot_all(lang)))
+                        .on_press_maybe((!self.fleet.is_empty()).then_some(Message::RebootAll))
+                        .padding(8),
+                    button(text(Tr::compare(lang)))
+                        .on_press_maybe(self.compare_message())
+                        .padding(8),
+                ]
+                .spacing(10)
+                .padding([0, 10])
+                .align_y(iced::Alignment::Center);
+                #[cfg(target_os = "linux")]
+                let actions = actions.push(ui::lldp_scan_controls(&self.lldp, lang));
+                let dashboard = column![actions];
+                #[cfg(target_os = "linux")]
+                let dashboard = dashboard.push(
+                    self.lldp
+                        .found
+                        .as_ref()
+                        .map(|found| ui::lldp_results(found, &self.fleet, lang)),
+                );
+                dashboard
+                    .push(ui::dashboard_view(
+                        &self.fleet,
+                        &self.notes,
+                        &self.compare_selection,
+                        self.fleet_tick.filter(|_| self.polling),
+                        lang,
+                    ))
+                    .into()
+            }
             Some(data) => ui::miner_view(
                 data,
                 self.system_info.as_ref(),
-                self.sidebar_width,
-                self.dragging,
-                self.color_mode,
-                lang,
+                &self.analysis,
+                &self.config_mismatches,
+                ui::FirmwareView {
+                    status: self.system_info.as_ref().map_or(
+                        api::FirmwareStatus::Unknown,
+                        |info| {
+                            api::check_firmware_version(&info.firmware_version, &self.firmware_db)
+                        },
+                    ),
+                    changelog: self.visible_changelog(),
+                },
+                self.diff_data.as_ref(),
+                ui::ViewOptions {
+                    sidebar_width: self.sidebar_width,
+                    dragging: self.dragging,
+                    color_mode: self.color_mode,
+                    show_stats: self.show_stats_panel,
+                    lang,
+                    selected_chip: self.selected_chip,
+                    show_mini_hist: self.show_mini_hist,
+                    chip_selection: self.chip_selection.clone(),
+                    chip_groups: self.notes.groups(&self.ip).to_vec(),
+                    replacements: self.notes.replacements(&self.ip).to_vec(),
+                    changed_boards: self.changed_boards.clone(),
+                    nonce_rates: self.nonce_rate_view(),
+                    max_nonce_rate: self.max_nonce_rate(),
+                    slot_names: self.notes.slot_names(&self.ip),
+                    renaming_slot: self.renaming_slot.clone(),
+                    rack_positions: self.notes.rack_positions(&self.ip),
+                    ir_overlay: self.ir_overlay_colors(),
+                    filter_matches: self.filter_matches(),
+                    fan_curves: self.fan_curves.clone(),
+                    active_chips: self.active_chip_count_history.clone(),
+                    throttle_history: self.throttle_history.clone(),
+                    analysis_config: self.analysis_config,
+                    show_layout_diagram: self.show_layout_diagram,
+                    slot_rotated: self.slot_rotated.clone(),
+                },
             ),
             None => container(text(Tr::click_fetch(lang)).size(16))
                 .padding(20)
@@ -204,9 +2637,199 @@ impl App {
                 .into(),
         };
 
-        column![controls, status, content]
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .into()
+        let mut page = column![controls].width(Length::Fill).height(Length::Fill);
+        if self.data.is_some() && !self.dashboard {
+            let invalid = !self.chip_filter_text.trim().is_empty() && self.chip_filter.is_none();
+            page = page.push(
+                row![
+                    text_input(Tr::chip_filter(lang), &self.chip_filter_text)
+                        .on_input(Message::ChipFilterChanged)
+                        .padding(8)
+                        .width(360),
+                ]
+                .push(invalid.then(|| {
+                    text(Tr::invalid_filter(lang))
+                        .size(13)
+                        .color(theme::error_color())
+                }))
+                .push((!self.chip_filter_text.is_empty()).then(|| {
+                    button(text(Tr::clear(lang)).size(13))
+                        .on_press(Message::ChipFilterChanged(String::new()))
+                        .padding([4, 8])
+                }))
+                .spacing(10)
+                .padding([0, 10])
+                .align_y(iced::Alignment::Center),
+            );
+        }
+        if self.data.is_some() && !self.dashboard {
+            let stops = theme::legend_stops(self.color_mode, &self.analysis_config);
+            page = page.push(ui::gradient_legend_bar(
+                stops[0].0,
+                stops[3].0,
+                self.color_mode,
+                &self.analysis_config,
+                lang,
+                LEGEND_WIDTH,
+            ));
+        }
+        if let api::ConnectionMode::SshTunnel {
+            host,
+            user,
+            key_path,
+        } = &self.connection
+        {
+            page = page.push(
+                row![
+                    text(Tr::ssh_tunnel(lang)).size(14),
+                    text_input(Tr::jump_host(lang), host)
+                        .on_input(Message::SshHostChanged)
+                        .padding(8)
+                        .width(200),
+                    text_input(Tr::user(lang), user)
+                        .on_input(Message::SshUserChanged)
+                        .padding(8)
+                        .width(120),
+                    text_input(Tr::ssh_key(lang), &key_path.to_string_lossy())
+                        .on_input(Message::SshKeyPathChanged)
+                        .padding(8)
+                        .width(280),
+                ]
+                .spacing(10)
+                .padding([0, 10])
+                .align_y(iced::Alignment::Center),
+            );
+        }
+        if let api::DataSource::AwesomeMiner { url, miner_id } = &self.data_source {
+            page = page.push(
+                row![
+                    text("Awesome Miner").size(14),
+                    text_input(Tr::awesome_miner_server(lang), url)
+                        .on_input(Message::AwesomeMinerUrlChanged)
+                        .padding(8)
+                        .width(280),
+                    text_input(
+                        Tr::awesome_miner_id(lang),
+                        &if *miner_id == 0 {
+                            String::new()
+                        } else {
+                            miner_id.to_string()
+                        }
+                    )
+                    .on_input(Message::AwesomeMinerIdChanged)
+                    .padding(8)
+                    .width(100),
+                ]
+                .spacing(10)
+                .padding([0, 10])
+                .align_y(iced::Alignment::Center),
+            );
+        }
+        if self.show_settings {
+            page = page.push(ui::settings_panel(
+                &self.analysis_config_draft,
+                &self.alert_schedule_draft,
+                self.watch_alert_delta,
+                self.show_mini_hist,
+                (
+                    analysis::temp_baseline_polls(&self.temp_baseline),
+                    self.baseline_polls,
+                ),
+                (
+                    &self.history_max_records,
+                    &self.history_max_days,
+                    self.history_stats,
+                ),
+                lang,
+            ));
+        }
+        if self.show_notes && !self.dashboard {
+            page = page.push(ui::notes_panel(
+                self.notes.notes(&self.ip),
+                self.data.is_some(),
+                lang,
+            ));
+        }
+        if self.show_rack
+            && !self.dashboard
+            && let Some(data) = &self.data
+        {
+            let names = self.notes.slot_names(&self.ip);
+            let slots: Vec<(i32, String)> = data
+                .slots
+                .iter()
+                .map(|slot| {
+                    let name = names
+                        .get(&slot.id)
+                        .cloned()
+                        .unwrap_or_else(|| format!("{} {}", Tr::slot(lang), slot.id));
+                    (slot.id, name)
+                })
+                .collect();
+            page = page.push(ui::rack_panel(
+                &slots,
+                &self.rack_drafts,
+                &self.notes.rack_positions(&self.ip),
+                lang,
+            ));
+        }
+        if self.show_ir && !self.dashboard {
+            page = page.push(ui::ir_panel(self.ir_view(), lang));
+        }
+        if self.show_performance
+            && !self.dashboard
+            && let Some(data) = &self.data
+        {
+            let config = self.system_info.as_ref().and_then(config::lookup_system);
+            page = page.push(ui::performance_panel(
+                &metrics::compute_performance_grade(data, config, &self.analysis),
+                lang,
+            ));
+        }
+        if self.show_watch_window && !self.dashboard {
+            page = page.push(ui::watch_panel(
+                &self.watch,
+                self.selected_chip_id(),
+                self.watch_alert_delta,
+                self.watch_flash,
+                lang,
+            ));
+        }
+        if self.show_alert_history {
+            page = page.push(ui::alert_history_panel(&self.alert_history, lang));
+        }
+        let mut page = page.push(status);
+        if self.show_validation && !warnings.is_empty() {
+            page = page.push(ui::validation_details(
+                &warnings,
+                &self.notes.slot_names(&self.ip),
+                lang,
+            ));
+        }
+        if let Some(data) = self.data.as_ref().filter(|_| !self.dashboard) {
+            page = page.push(ui::miner_summary_bar(
+                data,
+                self.system_info.as_ref(),
+                &self.analysis,
+                self.summary_collapsed,
+                lang,
+            ));
+        }
+        let page = page.push(content);
+
+        if self.dashboard && self.confirm_reboot_all {
+            stack![page, ui::reboot_all_dialog(self.fleet.len(), lang)].into()
+        } else if let Some(incoming) = &self.pending_import {
+            let conflicts = self.notes.conflicts(&self.ip, incoming);
+            stack![page, ui::import_conflict_dialog(conflicts, lang)].into()
+        } else if let Some(draft) = &self.group_draft {
+            stack![page, ui::group_annotation_dialog(draft, lang)].into()
+        } else if let Some(wizard) = &self.model_wizard {
+            stack![page, ui::model_wizard_dialog(wizard, lang)].into()
+        } else if self.show_help {
+            stack![page, ui::help_panel(lang)].into()
+        } else {
+            page.into()
+        }
     }
 }
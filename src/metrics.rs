@@ -0,0 +1,602 @@
+//! Derived miner metrics (power and hashrate estimates, performance grade, fan curve,
+//! alert suppression windows)
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Local};
+
+use crate::alerts::{AlertSeverity, AlertWindow};
+use crate::analysis::ChipAnalysis;
+use crate::config::MinerConfig;
+use crate::models::{Chip, MinerData, Slot};
+
+// Performance grade: composite score thresholds for A, B and C (D below)
+const GRADE_A_MIN: f32 = 0.95;
+const GRADE_B_MIN: f32 = 0.85;
+const GRADE_C_MIN: f32 = 0.7;
+// Composite weights: hashrate efficiency, chip utilization, mean chip health
+const GRADE_WEIGHTS: [f32; 3] = [0.5, 0.3, 0.2];
+// Factors below these are listed in the grade's explanation
+const EXPLAIN_EFFICIENCY_BELOW: f32 = 0.95;
+const EXPLAIN_UTILIZATION_BELOW: f32 = 0.99;
+const EXPLAIN_HEALTH_BELOW: f32 = 90.0;
+
+/// Temperature/fan correlation below which the fan control loop looks stuck
+pub const FAN_CORRELATION_ANOMALY: f32 = 0.5;
+// Fan curve: polls kept per slot, and the polls and temperature spread (°C) needed
+// before a weak correlation means anything
+const FAN_CURVE_MAX_POINTS: usize = 200;
+const FAN_CURVE_MIN_POINTS: usize = 10;
+const FAN_CURVE_MIN_TEMP_SPREAD: f32 = 3.0;
+
+/// Board input voltage of a 12 V power supply
+pub const NOMINAL_INPUT_VOLTAGE: f32 = 12.0;
+/// Input voltage deviation (%) shown in green; up to VOLTAGE_WARN_PCT is amber, beyond red
+pub const VOLTAGE_OK_PCT: f32 = 2.0;
+pub const VOLTAGE_WARN_PCT: f32 = 5.0;
+/// Input voltage (V) below which a board raises a critical alert
+pub const VOLTAGE_CRITICAL_V: f32 = 10.0;
+
+/// Typical chip temperature (°C) of a healthy air-cooled board at stock clocks
+pub const TYPICAL_CHIP_TEMP: i32 = 75;
+
+/// (slot temperature, fan RPM) per poll, by slot id, oldest first
+pub type FanCurveHistory = HashMap<i32, Vec<(f32, i32)>>;
+
+/// Estimated slot power draw (W)
+///
+/// Active (reported, non-dead) chips × rated per-chip power × (freq / rated freq)³; the cube is
+/// the usual CMOS scaling, dynamic power ∝ f·V² with voltage tracking frequency.
+/// This is an estimate from published specs, not a measurement.
+#[allow(clippy::cast_precision_loss)] // chip counts and MHz fit in f32
+pub fn estimate_slot_power(slot: &Slot, config: &MinerConfig) -> f32 {
+    let active = slot
+        .chips
+        .iter()
+        .filter(|c| !c.placeholder && !c.is_dead())
+        .count();
+    let freq_ratio = slot.freq as f32 / f32::from(config.rated_freq_mhz);
+    active as f32 * config.rated_power_per_chip_w * freq_ratio.powi(3)
+}
+
+/// Estimated power draw of all slots (W)
+pub fn estimate_miner_power(data: &MinerData, config: &MinerConfig) -> f32 {
+    data.slots
+        .iter()
+        .map(|slot| estimate_slot_power(slot, config))
+        .sum()
+}
+
+/// Rated (nameplate) power: every chip on every board at the rated frequency (W)
+pub fn rated_miner_power(config: &MinerConfig) -> f32 {
+    config.rated_power_per_chip_w * f32::from(config.chip_num) * f32::from(config.board_num)
+}
+
+/// Estimated hashrate of a slot (TH/s)
+///
+/// The log's nonce rate is counted at a difficulty the firmware doesn't report, so
+/// it can't be turned into TH/s directly. Instead each chip gets the rated per-chip
+/// hashrate scaled by its clock (freq / rated freq) and its nonce share (100% minus
+/// the nonce deficit vs the slot average), so dead and lagging chips count as lost.
+#[allow(clippy::cast_precision_loss)] // MHz fit in f32
+pub fn estimate_slot_hashrate(slot: &Slot, config: &MinerConfig, analysis: &[ChipAnalysis]) -> f32 {
+    let per_chip = config.rated_hashrate_ths
+        / (f32::from(config.chip_num) * f32::from(config.board_num)).max(1.0);
+    let rated_freq = f32::from(config.rated_freq_mhz);
+    slot.chips
+        .iter()
+        .enumerate()
+        .filter(|(_, chip)| !chip.is_dead())
+        .map(|(idx, chip)| {
+            let freq = if chip.freq > 0 { chip.freq } else { slot.freq };
+            let share = analysis
+                .get(idx)
+                .map_or(1.0, |a| (1.0 - a.nonce_deficit / 100.0).clamp(0.0, 1.0));
+            per_chip * (freq as f32 / rated_freq) * share
+        })
+        .sum()
+}
+
+/// Estimated hashrate of all slots (TH/s), see `estimate_slot_hashrate`
+pub fn estimate_miner_hashrate(
+    data: &MinerData,
+    config: &MinerConfig,
+    analysis: &[Vec<ChipAnalysis>],
+) -> f32 {
+    data.slots
+        .iter()
+        .enumerate()
+        .map(|(i, slot)| {
+            estimate_slot_hashrate(slot, config, analysis.get(i).map_or(&[][..], Vec::as_slice))
+        })
+        .sum()
+}
+
+/// How one chip measures up to the model spec; the rated-frequency fields are None
+/// without a known model, the nonce ratios when the slot has no nonces
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ChipSpecComparison {
+    pub rated_freq: Option<u16>,
+    /// Chip frequency as a share of the rated frequency (%)
+    pub freq_vs_rated_pct: Option<f32>,
+    /// The chip's share of the slot's valid nonces if they were spread evenly
+    pub expected_nonce: f64,
+    /// `expected_nonce` scaled from the slot clock to the rated frequency
+    pub estimated_nonce_at_rated_freq: Option<f64>,
+    /// Chip temperature minus TYPICAL_CHIP_TEMP (°C)
+    pub temp_vs_typical: i32,
+    /// Chip nonces as a share of `expected_nonce` (%)
+    pub performance_vs_spec_pct: Option<f32>,
+}
+
+/// Compare a chip of `slot` to its rated frequency, an even share of the slot's
+/// nonces and the typical operating temperature
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)] // chip counts and nonces fit in f64, percentages in f32
+pub fn compare_chip_to_spec(
+    slot: &Slot,
+    chip: &Chip,
+    config: Option<&MinerConfig>,
+) -> ChipSpecComparison {
+    let expected_nonce = if slot.chips.is_empty() {
+        0.0
+    } else {
+        slot.nonce_valid as f64 / slot.chips.len() as f64
+    };
+    let rated_freq = config.map(|cfg| cfg.rated_freq_mhz).filter(|&f| f > 0);
+    ChipSpecComparison {
+        rated_freq,
+        freq_vs_rated_pct: rated_freq.map(|rated| chip.freq as f32 / f32::from(rated) * 100.0),
+        expected_nonce,
+        estimated_nonce_at_rated_freq: rated_freq
+            .filter(|_| slot.freq > 0)
+            .map(|rated| expected_nonce * f64::from(rated) / f64::from(slot.freq)),
+        temp_vs_typical: chip.temp - TYPICAL_CHIP_TEMP,
+        performance_vs_spec_pct: (expected_nonce > 0.0)
+            .then(|| (chip.nonce as f64 / expected_nonce * 100.0) as f32),
+    }
+}
+
+/// Deviation of a measured voltage from nominal (%), negative when below
+pub fn compute_voltage_deviation(actual: f32, nominal: f32) -> f32 {
+    (actual - nominal) / nominal * 100.0
+}
+
+/// Performance grade, best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grade {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Grade {
+    fn from_score(score: f32) -> Self {
+        if score >= GRADE_A_MIN {
+            Self::A
+        } else if score >= GRADE_B_MIN {
+            Self::B
+        } else if score >= GRADE_C_MIN {
+            Self::C
+        } else {
+            Self::D
+        }
+    }
+}
+
+/// A factor that pulled the grade down, localized by the UI
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradeReason {
+    /// Estimated hashrate share of rated (0-1)
+    LowEfficiency(f32),
+    /// Working chips vs the model's chip count: (slot id, working, rated)
+    LowUtilization(i32, usize, u16),
+    /// Mean chip health score (0-100)
+    LowHealth(f32),
+    /// Model not in the config table: efficiency and utilization are unknown
+    NoConfig,
+}
+
+/// Per-board row of the performance table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoardPerformance {
+    pub slot_id: i32,
+    /// None without a model config
+    pub hashrate_ths: Option<f32>,
+    /// Working chips
+    pub active_chips: usize,
+    /// Working chips / rated chip_num; None without a model config
+    pub utilization: Option<f32>,
+}
+
+/// Rated vs estimated performance of a miner with its letter grade
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerformanceGrade {
+    pub grade: Grade,
+    /// Weighted composite, 0-1
+    pub score: f32,
+    pub rated_ths: Option<f32>,
+    pub actual_ths: Option<f32>,
+    /// actual / rated
+    pub efficiency: Option<f32>,
+    /// Working chips / rated chips over all boards
+    pub utilization: Option<f32>,
+    /// Mean chip health score (0-100)
+    pub mean_health: f32,
+    pub boards: Vec<BoardPerformance>,
+    /// Why the grade isn't higher, worst factor first
+    pub reasons: Vec<GradeReason>,
+}
+
+/// Grade a miner on hashrate efficiency, chip utilization and chip health
+///
+/// Without a model config only the health score is known, so it alone decides the grade.
+#[allow(clippy::cast_precision_loss)] // chip counts fit in f32
+pub fn compute_performance_grade(
+    data: &MinerData,
+    config: Option<&MinerConfig>,
+    analysis: &[Vec<ChipAnalysis>],
+) -> PerformanceGrade {
+    let healths: Vec<f32> = analysis.iter().flatten().map(|a| a.health_score).collect();
+    let mean_health = if healths.is_empty() {
+        0.0
+    } else {
+        healths.iter().sum::<f32>() / healths.len() as f32
+    };
+    let boards: Vec<BoardPerformance> = data
+        .slots
+        .iter()
+        .enumerate()
+        .map(|(i, slot)| {
+            let active_chips = slot.chips.iter().filter(|c| !c.is_dead()).count();
+            let slot_analysis = analysis.get(i).map_or(&[][..], Vec::as_slice);
+            BoardPerformance {
+                slot_id: slot.id,
+                hashrate_ths: config.map(|cfg| estimate_slot_hashrate(slot, cfg, slot_analysis)),
+                active_chips,
+                utilization: config
+                    .map(|cfg| active_chips as f32 / f32::from(cfg.chip_num).max(1.0)),
+            }
+        })
+        .collect();
+
+    let mut reasons = Vec::new();
+    let (rated_ths, actual_ths, efficiency, utilization, score) = match config {
+        Some(cfg) => {
+            let actual: f32 = boards.iter().filter_map(|b| b.hashrate_ths).sum();
+            let efficiency = actual / cfg.rated_hashrate_ths.max(f32::EPSILON);
+            let active: usize = boards.iter().map(|b| b.active_chips).sum();
+            let rated_chips = f32::from(cfg.chip_num) * f32::from(cfg.board_num);
+            let utilization = active as f32 / rated_chips.max(1.0);
+            let [w_eff, w_util, w_health] = GRADE_WEIGHTS;
+            let score = w_eff * efficiency.min(1.0)
+                + w_util * utilization.min(1.0)
+                + w_health * mean_health / 100.0;
+            if efficiency < EXPLAIN_EFFICIENCY_BELOW {
+                reasons.push(GradeReason::LowEfficiency(efficiency));
+            }
+            for board in &boards {
+                if board
+                    .utilization
+                    .is_some_and(|u| u < EXPLAIN_UTILIZATION_BELOW)
+                {
+                    reasons.push(GradeReason::LowUtilization(
+                        board.slot_id,
+                        board.active_chips,
+                        cfg.chip_num,
+                    ));
+                }
+            }
+            (
+                Some(cfg.rated_hashrate_ths),
+                Some(actual),
+                Some(efficiency),
+                Some(utilization),
+                score,
+            )
+        }
+        None => {
+            reasons.push(GradeReason::NoConfig);
+            (None, None, None, None, mean_health / 100.0)
+        }
+    };
+    if mean_health < EXPLAIN_HEALTH_BELOW {
+        reasons.push(GradeReason::LowHealth(mean_health));
+    }
+
+    PerformanceGrade {
+        grade: Grade::from_score(score),
+        score,
+        rated_ths,
+        actual_ths,
+        efficiency,
+        utilization,
+        mean_health,
+        boards,
+        reasons,
+    }
+}
+
+/// Add this poll's slot temperatures against the miner's fan speed
+#[allow(clippy::cast_possible_truncation)] // slot temperatures fit in f32
+pub fn record_fan_curve(history: &mut FanCurveHistory, slots: &[Slot], fan_rpm: i32) {
+    for slot in slots {
+        let points = history.entry(slot.id).or_default();
+        points.push((slot.temp as f32, fan_rpm));
+        if points.len() > FAN_CURVE_MAX_POINTS {
+            points.drain(..points.len() - FAN_CURVE_MAX_POINTS);
+        }
+    }
+}
+
+/// Pearson correlation of slot temperature and fan RPM; 0 when either is constant
+#[allow(clippy::cast_precision_loss)] // poll counts and RPM fit in f32
+pub fn compute_fan_correlation(history: &[(f32, i32)]) -> f32 {
+    if history.len() < 2 {
+        return 0.0;
+    }
+    let n = history.len() as f32;
+    let mean_t = history.iter().map(|&(t, _)| t).sum::<f32>() / n;
+    let mean_f = history.iter().map(|&(_, f)| f as f32).sum::<f32>() / n;
+    let (cov, var_t, var_f) =
+        history
+            .iter()
+            .fold((0.0, 0.0, 0.0), |(cov, var_t, var_f), &(t, f)| {
+                let (dt, df) = (t - mean_t, f as f32 - mean_f);
+                (cov + dt * df, var_t + dt * dt, var_f + df * df)
+            });
+    if var_t <= f32::EPSILON || var_f <= f32::EPSILON {
+        return 0.0;
+    }
+    cov / (var_t * var_f).sqrt()
+}
+
+/// Correlation of a slot's fan curve if it is weak enough to flag: the history
+/// needs enough polls over a wide enough temperature range to judge the fan by
+pub fn fan_curve_anomaly(history: &[(f32, i32)]) -> Option<f32> {
+    let (min, max) = history
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), &(t, _)| {
+            (min.min(t), max.max(t))
+        });
+    if history.len() < FAN_CURVE_MIN_POINTS || max - min < FAN_CURVE_MIN_TEMP_SPREAD {
+        return None;
+    }
+    let r = compute_fan_correlation(history);
+    (r < FAN_CORRELATION_ANOMALY).then_some(r)
+}
+
+/// Whether an alert of `severity` at `now` falls in a maintenance window that
+/// silences it; windows past midnight count toward the day they started on
+pub fn is_alert_suppressed(
+    now: DateTime<Local>,
+    schedule: &[AlertWindow],
+    severity: AlertSeverity,
+) -> bool {
+    let time = now.time();
+    let today = now.weekday();
+    schedule.iter().any(|window| {
+        if severity > window.suppress_level {
+            return false;
+        }
+        let (start, end) = (window.start_time, window.end_time);
+        if start < end {
+            window.days.contains(&today) && (start..end).contains(&time)
+        } else if start > end {
+            (window.days.contains(&today) && time >= start)
+                || (window.days.contains(&today.pred()) && time < end)
+        } else {
+            window.days.contains(&today)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Chip;
+
+    fn config() -> MinerConfig {
+        MinerConfig {
+            model: "TEST",
+            chip_num: 4,
+            chips_per_domain: 2,
+            board_num: 2,
+            slot_link: None,
+            rated_power_per_chip_w: 10.0,
+            rated_freq_mhz: 500,
+            rated_hashrate_ths: 100.0,
+        }
+    }
+
+    fn slot(freq: i32, nonces: &[i64]) -> Slot {
+        Slot {
+            freq,
+            chips: nonces
+                .iter()
+                .map(|&nonce| Chip {
+                    nonce,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_slot_power_at_rated_freq() {
+        let power = estimate_slot_power(&slot(500, &[100, 100, 100, 100]), &config());
+        assert!((power - 40.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_slot_power_scales_with_freq_cubed_and_skips_dead_chips() {
+        // Half frequency: 1/8 the power; the zero-nonce chip is dead
+        let power = estimate_slot_power(&slot(250, &[100, 100, 100, 0]), &config());
+        assert!((power - 30.0 / 8.0).abs() < 1e-3);
+
+        // A skipped id isn't a powered chip either
+        let mut skipped = slot(500, &[100, 100, 100]);
+        skipped.chips.push(Chip::placeholder(3));
+        assert!((estimate_slot_power(&skipped, &config()) - 30.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_rated_miner_power() {
+        assert!((rated_miner_power(&config()) - 80.0).abs() < 1e-3);
+        let data = MinerData {
+            slots: vec![slot(500, &[1; 4]), slot(500, &[1; 4])],
+            ..Default::default()
+        };
+        assert!((estimate_miner_power(&data, &config()) - 80.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_compare_chip_to_spec() {
+        let mut slot = slot(400, &[150, 50, 100, 100]);
+        slot.nonce_valid = 400;
+        let chip = Chip {
+            freq: 450,
+            temp: 82,
+            ..slot.chips[0].clone()
+        };
+        let spec = compare_chip_to_spec(&slot, &chip, Some(&config()));
+        assert_eq!(spec.rated_freq, Some(500));
+        assert!((spec.freq_vs_rated_pct.unwrap() - 90.0).abs() < 1e-3);
+        assert!((spec.expected_nonce - 100.0).abs() < 1e-9);
+        assert!((spec.estimated_nonce_at_rated_freq.unwrap() - 125.0).abs() < 1e-9);
+        assert_eq!(spec.temp_vs_typical, 82 - TYPICAL_CHIP_TEMP);
+        assert!((spec.performance_vs_spec_pct.unwrap() - 150.0).abs() < 1e-3);
+
+        let unknown = compare_chip_to_spec(&slot, &chip, None);
+        assert_eq!(unknown.rated_freq, None);
+        assert_eq!(unknown.estimated_nonce_at_rated_freq, None);
+        assert!(unknown.performance_vs_spec_pct.is_some());
+    }
+
+    #[test]
+    fn test_voltage_deviation() {
+        assert!((compute_voltage_deviation(12.24, NOMINAL_INPUT_VOLTAGE) - 2.0).abs() < 1e-3);
+        assert!((compute_voltage_deviation(11.4, NOMINAL_INPUT_VOLTAGE) + 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_performance_grade() {
+        let healthy = |n| {
+            vec![
+                ChipAnalysis {
+                    health_score: 100.0,
+                    ..Default::default()
+                };
+                n
+            ]
+        };
+        let data = MinerData {
+            slots: vec![slot(500, &[100; 4]), slot(500, &[100; 4])],
+            ..Default::default()
+        };
+        let grade = compute_performance_grade(&data, Some(&config()), &[healthy(4), healthy(4)]);
+        assert_eq!(grade.grade, Grade::A);
+        assert!((grade.actual_ths.unwrap() - 100.0).abs() < 1e-3);
+        assert!(grade.reasons.is_empty());
+
+        // One dead chip and a lagging one on slot 1: 3/4 chips, 3.5/4 of the hashrate there
+        let mut lagging = healthy(4);
+        lagging[1].nonce_deficit = 50.0;
+        let data = MinerData {
+            slots: vec![slot(500, &[100; 4]), slot(500, &[100, 50, 100, 0])],
+            ..Default::default()
+        };
+        let analysis = [healthy(4), lagging];
+        let grade = compute_performance_grade(&data, Some(&config()), &analysis);
+        assert!((grade.efficiency.unwrap() - 0.8125).abs() < 1e-3);
+        assert!((estimate_miner_hashrate(&data, &config(), &analysis) - 81.25).abs() < 1e-3);
+        assert_eq!(grade.boards[1].active_chips, 3);
+        assert_eq!(grade.grade, Grade::B);
+        assert_eq!(
+            grade.reasons,
+            [
+                GradeReason::LowEfficiency(0.8125),
+                GradeReason::LowUtilization(0, 3, 4)
+            ]
+        );
+
+        let grade = compute_performance_grade(&data, None, &[healthy(4), healthy(4)]);
+        assert_eq!((grade.grade, grade.rated_ths), (Grade::A, None));
+        assert_eq!(grade.reasons, [GradeReason::NoConfig]);
+    }
+
+    #[test]
+    fn test_fan_correlation() {
+        // Fan follows temperature: strong positive correlation, no anomaly
+        let tracking: Vec<(f32, i32)> = (0..12u8)
+            .map(|i| (60.0 + f32::from(i), 4000 + i32::from(i) * 100))
+            .collect();
+        assert!((compute_fan_correlation(&tracking) - 1.0).abs() < 1e-4);
+        assert_eq!(fan_curve_anomaly(&tracking), None);
+
+        // Fan stuck at one speed while the board heats up
+        let stuck: Vec<(f32, i32)> = (0..12u8).map(|i| (60.0 + f32::from(i), 4000)).collect();
+        assert_eq!(fan_curve_anomaly(&stuck), Some(0.0));
+
+        // Too few polls, or too little temperature change, to judge
+        assert_eq!(fan_curve_anomaly(&stuck[..5]), None);
+        let steady: Vec<(f32, i32)> = (0..12).map(|i| (70.0, 4000 + i * 10)).collect();
+        assert_eq!(fan_curve_anomaly(&steady), None);
+
+        let mut history = FanCurveHistory::new();
+        let slots = [Slot {
+            id: 2,
+            temp: 65.0,
+            ..Default::default()
+        }];
+        for _ in 0..FAN_CURVE_MAX_POINTS + 5 {
+            record_fan_curve(&mut history, &slots, 5000);
+        }
+        assert_eq!(history[&2].len(), FAN_CURVE_MAX_POINTS);
+        assert_eq!(history[&2][0], (65.0, 5000));
+    }
+
+    #[test]
+    fn test_alert_suppression_windows() {
+        use chrono::{NaiveTime, TimeZone, Weekday};
+        // 2026-10-12 is a Monday
+        let at = |day, hour| Local.with_ymd_and_hms(2026, 10, day, hour, 30, 0).unwrap();
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        let night = AlertWindow {
+            days: vec![Weekday::Mon],
+            start_time: time(22),
+            end_time: time(6),
+            suppress_level: AlertSeverity::Warning,
+        };
+        let schedule = [night.clone()];
+        let suppressed = |now, severity| is_alert_suppressed(now, &schedule, severity);
+
+        assert!(suppressed(at(12, 23), AlertSeverity::Warning));
+        // Past midnight belongs to Monday's window
+        assert!(suppressed(at(13, 3), AlertSeverity::Info));
+        assert!(!suppressed(at(13, 6), AlertSeverity::Warning));
+        assert!(!suppressed(at(12, 3), AlertSeverity::Warning));
+        assert!(!suppressed(at(12, 21), AlertSeverity::Warning));
+        // Above the window's level still notifies
+        assert!(!suppressed(at(12, 23), AlertSeverity::Critical));
+        assert!(!is_alert_suppressed(at(12, 23), &[], AlertSeverity::Info));
+
+        let all_day = [AlertWindow {
+            start_time: time(9),
+            end_time: time(9),
+            suppress_level: AlertSeverity::Critical,
+            ..night
+        }];
+        assert!(is_alert_suppressed(
+            at(12, 20),
+            &all_day,
+            AlertSeverity::Critical
+        ));
+        assert!(!is_alert_suppressed(
+            at(13, 20),
+            &all_day,
+            AlertSeverity::Critical
+        ));
+    }
+}
@@ -1,7 +1,9 @@
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 /// Color coding mode for chip visualization
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ColorMode {
     #[default]
     Temperature,
@@ -11,8 +13,32 @@ pub enum ColorMode {
     Gradient,
     /// Statistical outliers: chips N std devs from local mean
     Outliers,
+    /// Fleet outliers: chips hotter than the same position across all loaded miners
+    CrossMinerOutliers,
     /// Nonce performance: chips underperforming vs slot average
     Nonce,
+    /// Frequency deviation: chip freq vs the slot's programmed target
+    FreqRelative,
+    /// Frequency compliance: chips within tolerance of the slot target, over or under it
+    FreqCompliance,
+    /// Silicon efficiency: nonces per MHz vs slot average
+    NoncePerMhz,
+    /// Slope of nonces per MHz over the recent polls
+    EfficiencyTrend,
+    /// Errors per hour since the previous poll
+    ErrorRate,
+    /// Temperature vs the position's baseline learned over many polls
+    TempAnomaly,
+    /// Chips grouped by supply voltage, a guess at mixed chip batches on one board
+    VoltageGroup,
+    /// Temperature colors tinted red where dead chips concentrate
+    DeadDensity,
+    /// Active / throttled / sleeping / dead, from the chip clock vs the slot target
+    PowerState,
+    /// Times each chip warmed up from cool this session (thermal cycling wears solder joints)
+    HeatCycles,
+    /// Temperature change vs a diff target (only used by the diff view's delta panel)
+    Delta,
 }
 
 impl ColorMode {
@@ -22,7 +48,18 @@ impl ColorMode {
         Self::Crc,
         Self::Gradient,
         Self::Outliers,
+        Self::CrossMinerOutliers,
         Self::Nonce,
+        Self::FreqRelative,
+        Self::FreqCompliance,
+        Self::NoncePerMhz,
+        Self::EfficiencyTrend,
+        Self::ErrorRate,
+        Self::TempAnomaly,
+        Self::VoltageGroup,
+        Self::DeadDensity,
+        Self::PowerState,
+        Self::HeatCycles,
     ];
 }
 
@@ -34,31 +71,164 @@ impl fmt::Display for ColorMode {
             Self::Crc => "CRC",
             Self::Gradient => "Gradient",
             Self::Outliers => "Outliers",
+            Self::CrossMinerOutliers => "Fleet outliers",
             Self::Nonce => "Nonce",
+            Self::FreqRelative => "Freq Δ",
+            Self::FreqCompliance => "Freq ✓",
+            Self::EfficiencyTrend => "Eff. trend",
+            Self::NoncePerMhz => "Nonce/MHz",
+            Self::ErrorRate => "Err/h",
+            Self::TempAnomaly => "Temp σ",
+            Self::VoltageGroup => "V group",
+            Self::DeadDensity => "Dead density",
+            Self::PowerState => "Power state",
+            Self::HeatCycles => "Heat cycles",
+            Self::Delta => "Δ",
         })
     }
 }
 
+impl ColorMode {
+    /// Chip fields the mode colors by, beyond freq, temp and nonce
+    pub fn required_fields(self) -> &'static [ChipField] {
+        match self {
+            Self::Errors | Self::ErrorRate => &[ChipField::Errors],
+            Self::Crc => &[ChipField::Crc],
+            Self::VoltageGroup => &[ChipField::Vol],
+            _ => &[],
+        }
+    }
+}
+
+/// Chip log fields that early firmware leaves out; freq, temp and nonce are always there
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChipField {
+    Vol,
+    Errors,
+    Crc,
+    X,
+    Repeat,
+}
+
+impl ChipField {
+    pub const ALL: [Self; 5] = [Self::Vol, Self::Errors, Self::Crc, Self::X, Self::Repeat];
+
+    /// Key of the field in a chip line ("err:12")
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Vol => "vol",
+            Self::Errors => "err",
+            Self::Crc => "crc",
+            Self::X => "x",
+            Self::Repeat => "repeat",
+        }
+    }
+}
+
+/// Whether the firmware logged every chip field; missing fields read as 0
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChipDataQuality {
+    #[default]
+    Full,
+    /// No chip line had these fields
+    Partial { missing_fields: Vec<ChipField> },
+}
+
+impl ChipDataQuality {
+    pub fn is_missing(&self, field: ChipField) -> bool {
+        match self {
+            Self::Full => false,
+            Self::Partial { missing_fields } => missing_fields.contains(&field),
+        }
+    }
+
+    /// Whether the data has every field `mode` colors by
+    pub fn supports(&self, mode: ColorMode) -> bool {
+        !mode.required_fields().iter().any(|&f| self.is_missing(f))
+    }
+}
+
 /// System information from the miner's overview page
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SystemInfo {
     pub model: String,
     pub hardware_info: String,
+    /// `hardware_info` split into its fields
+    pub hardware: HardwareInfo,
     pub firmware_version: String,
+    /// Active pool address, e.g. "stratum+tcp://btc.pool.example:3333" (empty if not shown)
+    pub pool_url: String,
+    /// Pool response time reported by the firmware, or measured with a TCP connect
+    pub pool_latency_ms: Option<u32>,
+    /// Fastest fan (RPM) among the overview's fan readings
+    pub fan_speed_rpm: Option<i32>,
+}
+
+/// Fields of the hardware info string, e.g. "M50S++_VK40.H616-CB6V10.P222B-VE1-197806A"
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardwareInfo {
+    /// Model with hashboard variant: "M50S++_VK40"
+    pub model_variant: String,
+    /// Control board: "H616-CB6V10"
+    pub pcb_revision: String,
+    /// Power supply and serial: "P222B-VE1-197806A"
+    pub serial_prefix: String,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MinerData {
     pub slots: Vec<Slot>,
+    /// Chip fields the firmware left out of the log
+    #[serde(default)]
+    pub data_quality: ChipDataQuality,
 }
 
 impl MinerData {
     pub fn total_chips(&self) -> usize {
         self.slots.iter().map(|s| s.chips.len()).sum()
     }
+
+    /// Number of chips reporting zero nonces across all slots
+    pub fn dead_chips(&self) -> usize {
+        self.slots
+            .iter()
+            .flat_map(|s| &s.chips)
+            .filter(|c| c.is_dead())
+            .count()
+    }
+
+    /// Mean chip temperature across all slots
+    pub fn mean_chip_temp(&self) -> Option<f32> {
+        let temps: Vec<i32> = self
+            .slots
+            .iter()
+            .flat_map(|s| &s.chips)
+            .filter(|c| !c.placeholder)
+            .map(|c| c.temp)
+            .collect();
+        #[allow(clippy::cast_precision_loss)] // chip counts are far below f32 precision
+        (!temps.is_empty()).then(|| temps.iter().sum::<i32>() as f32 / temps.len() as f32)
+    }
+
+    /// Hottest chip temperature across all slots
+    pub fn max_chip_temp(&self) -> Option<i32> {
+        self.slots
+            .iter()
+            .flat_map(|s| &s.chips)
+            .map(|c| c.temp)
+            .max()
+    }
+
+    /// Number of slots where the slot or any of its chips reports CRC errors
+    pub fn slots_with_crc_errors(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|s| s.crc > 0 || s.chips.iter().any(|c| c.crc > 0))
+            .count()
+    }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Slot {
     pub id: i32,
     pub freq: i32,
@@ -68,10 +238,12 @@ pub struct Slot {
     pub nonce_rate: i32,
     pub errors: i32,
     pub crc: i32,
+    /// Board input voltage (V), on firmware that logs it in the slot header
+    pub voltage_input: Option<f32>,
     pub chips: Vec<Chip>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Chip {
     pub id: i32,
     pub freq: i32,
@@ -84,4 +256,24 @@ pub struct Chip {
     pub repeat: i32,
     pub pct1: f32,
     pub pct2: f32,
+    /// Stand-in for a chip id the firmware skipped; every reading is 0
+    #[serde(default)]
+    pub placeholder: bool,
+}
+
+impl Chip {
+    /// Stand-in for a chip id missing from the log, keeping later chips in place
+    pub fn placeholder(id: i32) -> Self {
+        Self {
+            id,
+            placeholder: true,
+            ..Self::default()
+        }
+    }
+
+    /// A chip that has produced no nonces is considered dead; placeholders
+    /// have no readings at all and are not
+    pub fn is_dead(&self) -> bool {
+        self.nonce == 0 && !self.placeholder
+    }
 }
@@ -0,0 +1,791 @@
+//! Per-chip notes, labeled chip groups, chip replacement records, slot names and
+//! rack positions, keyed by miner IP, saved to a JSON file in the home directory
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::ChipAnalysis;
+use crate::models::MinerData;
+
+const NOTES_FILE: &str = ".whatsminer_chip_map_notes.json";
+
+/// (slot id, chip id)
+pub type ChipKey = (i32, i32);
+
+/// How imported notes treat chips that already have one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    KeepExisting,
+    Replace,
+    /// Existing note, then the imported one
+    Append,
+}
+
+/// A labeled region of a slot's chip grid, e.g. "Replaced TIM (2024-01-20)" on domains 5–15
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChipGroupAnnotation {
+    pub slot_id: i32,
+    /// First and last domain, inclusive
+    pub domain_range: (usize, usize),
+    /// First and last row within each domain, inclusive; None for whole domains
+    pub row_range: Option<(usize, usize)>,
+    pub label: String,
+    pub color: [u8; 3],
+}
+
+impl ChipGroupAnnotation {
+    /// Whether chip `idx` of the slot lies inside the group
+    pub fn contains(&self, idx: usize, chips_per_domain: usize) -> bool {
+        let cpd = chips_per_domain.max(1);
+        let in_range = |(first, last): (usize, usize), value| (first..=last).contains(&value);
+        in_range(self.domain_range, idx / cpd)
+            && self.row_range.is_none_or(|rows| in_range(rows, idx % cpd))
+    }
+}
+
+/// A chip that was physically swapped, with its health before and after
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChipReplacement {
+    pub slot_id: i32,
+    pub chip_id: i32,
+    pub replaced_at: DateTime<Local>,
+    /// Health score shown when the chip was marked
+    pub previous_health: f32,
+    /// The chip's note at the time of the swap
+    pub note: String,
+    /// Health score from the first fetch after the swap
+    #[serde(default)]
+    pub post_replacement_health: Option<f32>,
+}
+
+/// Where a slot's hashboard sits in the facility, e.g. rack B, row 3, position 4
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct RackPosition {
+    pub rack_id: String,
+    pub row: u8,
+    pub position: u8,
+}
+
+impl fmt::Display for RackPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Rack {} / Row {} / Pos {}",
+            self.rack_id, self.row, self.position
+        )
+    }
+}
+
+/// Contents of a slot's rack position fields, as typed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RackDraft {
+    pub rack_id: String,
+    pub row: String,
+    pub position: String,
+}
+
+impl RackDraft {
+    pub fn from_position(position: &RackPosition) -> Self {
+        Self {
+            rack_id: position.rack_id.clone(),
+            row: position.row.to_string(),
+            position: position.position.to_string(),
+        }
+    }
+
+    /// Validate the fields; row and position are numbers from 1
+    pub fn build(&self) -> Result<RackPosition, String> {
+        let rack_id = self.rack_id.trim();
+        if rack_id.is_empty() {
+            return Err("Rack is empty".into());
+        }
+        let number = |value: &str| {
+            let value = value.trim();
+            value
+                .parse::<u8>()
+                .ok()
+                .filter(|&n| n > 0)
+                .ok_or_else(|| format!("Invalid number: {value}"))
+        };
+        Ok(RackPosition {
+            rack_id: rack_id.to_string(),
+            row: number(&self.row)?,
+            position: number(&self.position)?,
+        })
+    }
+}
+
+/// Health score of a chip, looked up by slot id and chip id
+pub fn chip_health(
+    data: &MinerData,
+    analyses: &[Vec<ChipAnalysis>],
+    slot_id: i32,
+    chip_id: i32,
+) -> Option<f32> {
+    let (slot_idx, slot) = data
+        .slots
+        .iter()
+        .enumerate()
+        .find(|(_, s)| s.id == slot_id)?;
+    let chip_idx = slot.chips.iter().position(|c| c.id == chip_id)?;
+    Some(analyses.get(slot_idx)?.get(chip_idx)?.health_score)
+}
+
+/// Contents of the add-group form, as typed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GroupDraft {
+    pub slot_id: i32,
+    pub first_domain: String,
+    pub last_domain: String,
+    /// Both empty for whole domains
+    pub first_row: String,
+    pub last_row: String,
+    pub label: String,
+    pub color: [u8; 3],
+}
+
+impl GroupDraft {
+    pub fn new(slot_id: i32, color: [u8; 3]) -> Self {
+        Self {
+            slot_id,
+            color,
+            ..Default::default()
+        }
+    }
+
+    /// Validate the form; a single filled bound selects one domain or row, and
+    /// bounds may be typed in either order
+    pub fn build(&self) -> Result<ChipGroupAnnotation, String> {
+        let label = self.label.trim();
+        if label.is_empty() {
+            return Err("Label is empty".into());
+        }
+        let domain_range = parse_range(&self.first_domain, &self.last_domain)?
+            .ok_or_else(|| "Domain range is empty".to_string())?;
+        let row_range = parse_range(&self.first_row, &self.last_row)?;
+        Ok(ChipGroupAnnotation {
+            slot_id: self.slot_id,
+            domain_range,
+            row_range,
+            label: label.to_string(),
+            color: self.color,
+        })
+    }
+}
+
+/// Inclusive range from two optional bounds; Ok(None) if both are empty
+fn parse_range(first: &str, last: &str) -> Result<Option<(usize, usize)>, String> {
+    let parse = |value: &str| {
+        let value = value.trim();
+        (!value.is_empty())
+            .then(|| {
+                value
+                    .parse::<usize>()
+                    .map_err(|_| format!("Invalid number: {value}"))
+            })
+            .transpose()
+    };
+    Ok(match (parse(first)?, parse(last)?) {
+        (Some(a), Some(b)) => Some((a.min(b), a.max(b))),
+        (Some(a), None) | (None, Some(a)) => Some((a, a)),
+        (None, None) => None,
+    })
+}
+
+/// Notes, chip groups, chip replacements, slot names and rack positions of every miner
+#[derive(Debug, Clone, Default)]
+pub struct NoteStore {
+    by_miner: HashMap<String, HashMap<ChipKey, String>>,
+    groups: HashMap<String, Vec<ChipGroupAnnotation>>,
+    replacements: HashMap<String, Vec<ChipReplacement>>,
+    slot_names: HashMap<String, HashMap<i32, String>>,
+    /// (miner IP, slot id) -> position
+    rack_map: HashMap<(String, i32), RackPosition>,
+}
+
+/// Layout of the notes file: JSON objects can't have (slot, chip) keys
+#[derive(Serialize, Deserialize)]
+struct NotesFile {
+    miners: Vec<MinerNotes>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MinerNotes {
+    ip: String,
+    #[serde(default)]
+    chips: Vec<ChipNote>,
+    #[serde(default)]
+    groups: Vec<ChipGroupAnnotation>,
+    #[serde(default)]
+    replacements: Vec<ChipReplacement>,
+    /// Slot id -> name shown instead of "Slot {id}"
+    #[serde(default)]
+    slot_names: BTreeMap<i32, String>,
+    /// Slot id -> rack position
+    #[serde(default)]
+    rack_positions: BTreeMap<i32, RackPosition>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChipNote {
+    slot_id: i32,
+    chip_id: i32,
+    note: String,
+}
+
+/// Same layout as the notes file, so sessions can embed the store
+impl Serialize for NoteStore {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_file().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NoteStore {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NotesFile::deserialize(deserializer).map(Self::from_file)
+    }
+}
+
+impl NoteStore {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    /// Miners sorted by IP and notes by chip, so saves diff cleanly
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    fn from_file(file: NotesFile) -> Self {
+        let mut store = Self::default();
+        for miner in file.miners {
+            let notes = miner
+                .chips
+                .into_iter()
+                .map(|n| ((n.slot_id, n.chip_id), n.note))
+                .collect();
+            store.by_miner.insert(miner.ip.clone(), notes);
+            store.groups.insert(miner.ip.clone(), miner.groups);
+            store
+                .replacements
+                .insert(miner.ip.clone(), miner.replacements);
+            store.rack_map.extend(
+                miner
+                    .rack_positions
+                    .into_iter()
+                    .map(|(slot_id, position)| ((miner.ip.clone(), slot_id), position)),
+            );
+            store
+                .slot_names
+                .insert(miner.ip, miner.slot_names.into_iter().collect());
+        }
+        store
+    }
+
+    fn to_file(&self) -> NotesFile {
+        let mut ips: Vec<&String> = self
+            .by_miner
+            .keys()
+            .chain(self.groups.keys())
+            .chain(self.replacements.keys())
+            .chain(self.slot_names.keys())
+            .chain(self.rack_map.keys().map(|(ip, _)| ip))
+            .collect();
+        ips.sort_unstable();
+        ips.dedup();
+        let miners = ips
+            .into_iter()
+            .map(|ip| {
+                let mut chips: Vec<ChipNote> = self
+                    .by_miner
+                    .get(ip)
+                    .into_iter()
+                    .flatten()
+                    .map(|(&(slot_id, chip_id), note)| ChipNote {
+                        slot_id,
+                        chip_id,
+                        note: note.clone(),
+                    })
+                    .collect();
+                chips.sort_unstable_by_key(|n| (n.slot_id, n.chip_id));
+                MinerNotes {
+                    ip: ip.clone(),
+                    chips,
+                    groups: self.groups(ip).to_vec(),
+                    replacements: self.replacements(ip).to_vec(),
+                    slot_names: self
+                        .slot_names
+                        .get(ip)
+                        .into_iter()
+                        .flatten()
+                        .map(|(&id, name)| (id, name.clone()))
+                        .collect(),
+                    rack_positions: self.rack_positions(ip).into_iter().collect(),
+                }
+            })
+            .filter(|m| {
+                !m.chips.is_empty()
+                    || !m.groups.is_empty()
+                    || !m.replacements.is_empty()
+                    || !m.slot_names.is_empty()
+                    || !m.rack_positions.is_empty()
+            })
+            .collect();
+        NotesFile { miners }
+    }
+
+    /// Chip groups of one miner, in the order they were added
+    pub fn groups(&self, ip: &str) -> &[ChipGroupAnnotation] {
+        self.groups.get(ip).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn add_group(&mut self, ip: &str, group: ChipGroupAnnotation) {
+        self.groups.entry(ip.to_string()).or_default().push(group);
+    }
+
+    /// Custom slot names of one miner, by slot id
+    pub fn slot_names(&self, ip: &str) -> HashMap<i32, String> {
+        self.slot_names.get(ip).cloned().unwrap_or_default()
+    }
+
+    /// Name a slot; a blank name goes back to the default "Slot {id}"
+    pub fn rename_slot(&mut self, ip: &str, slot_id: i32, name: &str) {
+        let name = name.trim();
+        let names = self.slot_names.entry(ip.to_string()).or_default();
+        if name.is_empty() {
+            names.remove(&slot_id);
+        } else {
+            names.insert(slot_id, name.to_string());
+        }
+    }
+
+    /// Rack positions of one miner's slots, by slot id
+    pub fn rack_positions(&self, ip: &str) -> HashMap<i32, RackPosition> {
+        self.rack_map
+            .iter()
+            .filter(|((miner, _), _)| miner == ip)
+            .map(|((_, slot_id), position)| (*slot_id, position.clone()))
+            .collect()
+    }
+
+    /// Place a slot in a rack; None clears its position
+    pub fn set_rack_position(&mut self, ip: &str, slot_id: i32, position: Option<RackPosition>) {
+        let key = (ip.to_string(), slot_id);
+        match position {
+            Some(position) => self.rack_map.insert(key, position),
+            None => self.rack_map.remove(&key),
+        };
+    }
+
+    /// Where a miner sits: the position of its lowest placed slot
+    pub fn miner_rack_position(&self, ip: &str) -> Option<&RackPosition> {
+        self.rack_map
+            .iter()
+            .filter(|((miner, _), _)| miner == ip)
+            .min_by_key(|((_, slot_id), _)| *slot_id)
+            .map(|(_, position)| position)
+    }
+
+    /// Replacement records of one miner, oldest first
+    pub fn replacements(&self, ip: &str) -> &[ChipReplacement] {
+        self.replacements.get(ip).map_or(&[], Vec::as_slice)
+    }
+
+    /// Record a swap; a chip replaced again keeps only its latest record
+    pub fn mark_replaced(&mut self, ip: &str, replacement: ChipReplacement) {
+        let records = self.replacements.entry(ip.to_string()).or_default();
+        records.retain(|r| (r.slot_id, r.chip_id) != (replacement.slot_id, replacement.chip_id));
+        records.push(replacement);
+    }
+
+    /// Fill in the after-swap health of replacements made before `fetched_at`
+    /// that don't have one yet; returns whether any record changed
+    pub fn record_post_replacement_health(
+        &mut self,
+        ip: &str,
+        data: &MinerData,
+        analyses: &[Vec<ChipAnalysis>],
+        fetched_at: DateTime<Local>,
+    ) -> bool {
+        let mut changed = false;
+        for record in self.replacements.get_mut(ip).into_iter().flatten() {
+            if record.post_replacement_health.is_some() || record.replaced_at >= fetched_at {
+                continue;
+            }
+            record.post_replacement_health =
+                chip_health(data, analyses, record.slot_id, record.chip_id);
+            changed |= record.post_replacement_health.is_some();
+        }
+        changed
+    }
+
+    pub fn remove_group(&mut self, ip: &str, idx: usize) {
+        if let Some(groups) = self.groups.get_mut(ip)
+            && idx < groups.len()
+        {
+            groups.remove(idx);
+        }
+    }
+
+    /// Notes of one miner, if it has any
+    pub fn notes(&self, ip: &str) -> Option<&HashMap<ChipKey, String>> {
+        self.by_miner.get(ip).filter(|notes| !notes.is_empty())
+    }
+
+    /// Number of incoming notes for chips that already have a note
+    pub fn conflicts(&self, ip: &str, incoming: &HashMap<ChipKey, String>) -> usize {
+        self.by_miner.get(ip).map_or(0, |existing| {
+            incoming.keys().filter(|k| existing.contains_key(k)).count()
+        })
+    }
+
+    pub fn merge(
+        &mut self,
+        ip: &str,
+        incoming: HashMap<ChipKey, String>,
+        resolution: ConflictResolution,
+    ) {
+        let existing = self.by_miner.entry(ip.to_string()).or_default();
+        for (key, note) in incoming {
+            match existing.get_mut(&key) {
+                None => {
+                    existing.insert(key, note);
+                }
+                Some(current) => match resolution {
+                    ConflictResolution::KeepExisting => {}
+                    ConflictResolution::Replace => *current = note,
+                    ConflictResolution::Append => {
+                        current.push_str("; ");
+                        current.push_str(&note);
+                    }
+                },
+            }
+        }
+    }
+}
+
+fn notes_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(NOTES_FILE))
+}
+
+/// Read the notes file; a missing file is an empty store
+pub fn load() -> Result<NoteStore, String> {
+    let Some(path) = notes_path() else {
+        return Ok(NoteStore::default());
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(json) => NoteStore::from_json(&json).map_err(|e| format!("{}: {e}", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(NoteStore::default()),
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
+
+/// Write a `NoteStore::to_json` snapshot to the notes file
+pub async fn save(json: String) -> Result<(), String> {
+    let path = notes_path().ok_or("No home directory for the notes file")?;
+    tokio::fs::write(&path, json)
+        .await
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Parse `slot_id,chip_id,note` rows, as saved by Excel or LibreOffice
+///
+/// The header row is optional. Notes may be quoted to contain commas or line
+/// breaks ("" for a literal quote). Empty rows are skipped; a later row for
+/// the same chip replaces an earlier one.
+pub fn import_annotations_csv(content: &str) -> Result<HashMap<ChipKey, String>, String> {
+    let content = content.trim_start_matches('\u{feff}');
+    let mut notes = HashMap::new();
+    for (idx, row) in csv_rows(content).into_iter().enumerate() {
+        let line = idx + 1;
+        if row.iter().all(|field| field.trim().is_empty()) {
+            continue;
+        }
+        let [slot, chip, note, ..] = row.as_slice() else {
+            return Err(format!("Line {line}: expected slot_id,chip_id,note"));
+        };
+        let (Ok(slot), Ok(chip)) = (slot.trim().parse(), chip.trim().parse()) else {
+            if idx == 0 {
+                continue; // header
+            }
+            return Err(format!("Line {line}: invalid slot_id or chip_id"));
+        };
+        let note = note.trim();
+        if !note.is_empty() {
+            notes.insert((slot, chip), note.to_string());
+        }
+    }
+    Ok(notes)
+}
+
+/// Keys that don't match a chip in `data`, sorted
+pub fn missing_chips(notes: &HashMap<ChipKey, String>, data: &MinerData) -> Vec<ChipKey> {
+    let mut missing: Vec<ChipKey> = notes
+        .keys()
+        .copied()
+        .filter(|&(slot_id, chip_id)| {
+            !data
+                .slots
+                .iter()
+                .any(|s| s.id == slot_id && s.chips.iter().any(|c| c.id == chip_id))
+        })
+        .collect();
+    missing.sort_unstable();
+    missing
+}
+
+/// Split CSV text into rows of fields (RFC 4180 quoting)
+fn csv_rows(content: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Chip, Slot};
+
+    #[test]
+    fn test_import_csv() {
+        let csv = "\u{feff}slot_id,chip_id,note\r\n\
+                   1,12,hot spot\r\n\
+                   \r\n\
+                   2,0,\"reflowed, check \"\"again\"\"\"\r\n\
+                   2,5,\"line one\nline two\"\n\
+                   3,1,\n";
+        let notes = import_annotations_csv(csv).unwrap();
+        assert_eq!(notes.len(), 3);
+        assert_eq!(notes[&(1, 12)], "hot spot");
+        assert_eq!(notes[&(2, 0)], "reflowed, check \"again\"");
+        assert_eq!(notes[&(2, 5)], "line one\nline two");
+
+        assert!(import_annotations_csv("1,2,ok\n1,x,bad").is_err());
+        assert!(import_annotations_csv("1,2,ok\n3,4").is_err());
+        // Header is optional
+        assert_eq!(import_annotations_csv("4,7,note").unwrap()[&(4, 7)], "note");
+    }
+
+    #[test]
+    fn test_merge_resolutions() {
+        let incoming = HashMap::from([((1, 1), "new".to_string()), ((1, 2), "b".to_string())]);
+        for (resolution, expected) in [
+            (ConflictResolution::KeepExisting, "old"),
+            (ConflictResolution::Replace, "new"),
+            (ConflictResolution::Append, "old; new"),
+        ] {
+            let mut store = NoteStore::default();
+            store.merge(
+                "ip",
+                HashMap::from([((1, 1), "old".to_string())]),
+                resolution,
+            );
+            assert_eq!(store.conflicts("ip", &incoming), 1);
+            store.merge("ip", incoming.clone(), resolution);
+            let notes = store.notes("ip").unwrap();
+            assert_eq!(notes[&(1, 1)], expected);
+            assert_eq!(notes[&(1, 2)], "b");
+        }
+    }
+
+    #[test]
+    fn test_missing_chips() {
+        let data = MinerData {
+            slots: vec![Slot {
+                id: 1,
+                chips: vec![Chip {
+                    id: 3,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let notes = HashMap::from([
+            ((1, 3), String::new()),
+            ((1, 4), String::new()),
+            ((0, 3), String::new()),
+        ]);
+        assert_eq!(missing_chips(&notes, &data), [(0, 3), (1, 4)]);
+    }
+
+    #[test]
+    fn test_group_draft() {
+        let mut draft = GroupDraft::new(2, [255, 0, 0]);
+        draft.label = " Replaced TIM ".into();
+        assert!(draft.build().is_err()); // no domains
+        draft.first_domain = "15".into();
+        draft.last_domain = "5".into();
+        let group = draft.build().unwrap();
+        assert_eq!((group.domain_range, group.row_range), ((5, 15), None));
+        assert_eq!(group.label, "Replaced TIM");
+
+        draft.last_row = "1".into();
+        let group = draft.build().unwrap();
+        assert_eq!(group.row_range, Some((1, 1)));
+        // cpd 3: chip 16 is D5 row 1, chip 15 is D5 row 0, chip 49 is D16 row 1
+        assert!(group.contains(16, 3));
+        assert!(!group.contains(15, 3));
+        assert!(!group.contains(49, 3));
+
+        draft.first_row = "x".into();
+        assert!(draft.build().is_err());
+    }
+
+    #[test]
+    fn test_notes_json_round_trip() {
+        let mut store = NoteStore::default();
+        store.merge(
+            "10.0.0.1",
+            HashMap::from([((1, 4), "hot".to_string())]),
+            ConflictResolution::Replace,
+        );
+        let group = ChipGroupAnnotation {
+            slot_id: 1,
+            domain_range: (5, 15),
+            row_range: None,
+            label: "Replaced TIM".into(),
+            color: [0, 128, 255],
+        };
+        store.add_group("10.0.0.2", group.clone());
+        store.rename_slot("10.0.0.3", 2, "  Rear left ");
+        store.rename_slot("10.0.0.3", 0, "Front");
+        store.rename_slot("10.0.0.3", 0, " ");
+
+        let loaded = NoteStore::from_json(&store.to_json().unwrap()).unwrap();
+        assert_eq!(loaded.notes("10.0.0.1").unwrap()[&(1, 4)], "hot");
+        assert_eq!(loaded.groups("10.0.0.2"), [group]);
+        assert!(loaded.groups("10.0.0.1").is_empty());
+        assert_eq!(
+            loaded.slot_names("10.0.0.3"),
+            HashMap::from([(2, "Rear left".to_string())])
+        );
+
+        store.remove_group("10.0.0.2", 0);
+        assert!(store.groups("10.0.0.2").is_empty());
+    }
+
+    #[test]
+    fn test_rack_positions() {
+        let draft = RackDraft {
+            rack_id: " B ".into(),
+            row: "3".into(),
+            position: " 4".into(),
+        };
+        let position = draft.build().unwrap();
+        assert_eq!(position.to_string(), "Rack B / Row 3 / Pos 4");
+        assert_eq!(
+            RackDraft::from_position(&position).build(),
+            Ok(position.clone())
+        );
+        for (rack_id, row, pos) in [("", "3", "4"), ("B", "0", "4"), ("B", "3", "x")] {
+            let draft = RackDraft {
+                rack_id: rack_id.into(),
+                row: row.into(),
+                position: pos.into(),
+            };
+            assert!(draft.build().is_err(), "{draft:?}");
+        }
+
+        let mut store = NoteStore::default();
+        let lower = RackPosition {
+            rack_id: "A".into(),
+            row: 1,
+            position: 2,
+        };
+        store.set_rack_position("10.0.0.4", 3, Some(position.clone()));
+        store.set_rack_position("10.0.0.4", 1, Some(lower.clone()));
+        store.set_rack_position("10.0.0.5", 0, Some(lower.clone()));
+        store.set_rack_position("10.0.0.5", 0, None);
+
+        let loaded = NoteStore::from_json(&store.to_json().unwrap()).unwrap();
+        assert_eq!(
+            loaded.rack_positions("10.0.0.4"),
+            HashMap::from([(3, position), (1, lower.clone())])
+        );
+        assert_eq!(loaded.miner_rack_position("10.0.0.4"), Some(&lower));
+        assert_eq!(loaded.miner_rack_position("10.0.0.5"), None);
+    }
+
+    #[test]
+    fn test_replacement_health() {
+        let data = MinerData {
+            slots: vec![Slot {
+                id: 1,
+                chips: (0..3)
+                    .map(|id| Chip {
+                        id,
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let analyses = vec![
+            [40.0, 87.0, 90.0]
+                .iter()
+                .map(|&health_score| ChipAnalysis {
+                    health_score,
+                    ..Default::default()
+                })
+                .collect(),
+        ];
+        let replaced_at = Local::now();
+        let mut store = NoteStore::default();
+        store.mark_replaced(
+            "ip",
+            ChipReplacement {
+                slot_id: 1,
+                chip_id: 1,
+                replaced_at,
+                previous_health: 45.0,
+                note: String::new(),
+                post_replacement_health: None,
+            },
+        );
+        // Data fetched before the swap doesn't count
+        assert!(!store.record_post_replacement_health("ip", &data, &analyses, replaced_at));
+        let later = replaced_at + chrono::Duration::minutes(5);
+        assert!(store.record_post_replacement_health("ip", &data, &analyses, later));
+        assert_eq!(
+            store.replacements("ip")[0].post_replacement_health,
+            Some(87.0)
+        );
+        assert!(!store.record_post_replacement_health("ip", &data, &analyses, later));
+
+        let loaded = NoteStore::from_json(&store.to_json().unwrap()).unwrap();
+        assert_eq!(loaded.replacements("ip"), store.replacements("ip"));
+        assert_eq!(chip_health(&data, &analyses, 1, 2), Some(90.0));
+        assert_eq!(chip_health(&data, &analyses, 2, 0), None);
+    }
+}
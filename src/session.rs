@@ -0,0 +1,199 @@
+//! Saved sessions: the open miner, the fleet, notes, view state, thresholds and the
+//! learned temperature baseline in one JSON file, written on demand or auto-saved
+//! while polling
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::RunningMeanVar;
+use crate::i18n::Language;
+use crate::models::{ColorMode, MinerData, SystemInfo};
+use crate::notes::NoteStore;
+use crate::settings::Settings;
+
+/// Schema version written to new sessions; files from a newer version are refused
+pub const SESSION_VERSION: u32 = 1;
+
+const AUTOSAVE_FILE: &str = ".whatsminer_chip_map_autosave.session";
+
+/// Everything a session restores; fields missing from older files keep their defaults.
+/// Passwords are never written, and analyses are recomputed from the chip data on load.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppState {
+    pub version: u32,
+    pub saved_at: Option<DateTime<Local>>,
+    pub ip: String,
+    pub user: String,
+    pub data: Option<MinerData>,
+    pub system_info: Option<SystemInfo>,
+    /// When `data` was fetched
+    pub fetched_at: Option<DateTime<Local>>,
+    pub fleet: Vec<SessionMiner>,
+    pub dashboard: bool,
+    pub notes: NoteStore,
+    /// (slot id, chip index) of the selected chip
+    pub selected_chip: Option<(i32, usize)>,
+    pub color_mode: ColorMode,
+    pub sidebar_width: f32,
+    /// Slots drawn turned 180°, by slot id
+    pub slot_rotated: HashMap<i32, bool>,
+    pub language: Language,
+    pub settings: Settings,
+    /// Learned temperature per (slot id, chip id); JSON objects can't have tuple keys
+    pub temp_baseline: Vec<((i32, i32), RunningMeanVar)>,
+    /// Miner the baseline was learned on
+    pub baseline_ip: String,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self {
+            version: SESSION_VERSION,
+            saved_at: None,
+            ip: String::new(),
+            user: String::new(),
+            data: None,
+            system_info: None,
+            fetched_at: None,
+            fleet: Vec::new(),
+            dashboard: false,
+            notes: NoteStore::default(),
+            selected_chip: None,
+            color_mode: ColorMode::default(),
+            sidebar_width: 400.0,
+            slot_rotated: HashMap::new(),
+            language: Language::default(),
+            settings: Settings::default(),
+            temp_baseline: Vec::new(),
+            baseline_ip: String::new(),
+        }
+    }
+}
+
+/// A fleet dashboard miner and its last fetch
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionMiner {
+    pub ip: String,
+    pub user: String,
+    pub data: Option<MinerData>,
+    pub system_info: Option<SystemInfo>,
+    pub poll_interval_secs: u64,
+}
+
+/// Only the version, read before the rest so newer files fail with a clear message
+#[derive(Deserialize)]
+struct SessionHeader {
+    version: u32,
+}
+
+impl AppState {
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let header: SessionHeader =
+            serde_json::from_str(json).map_err(|e| format!("Not a session file: {e}"))?;
+        if header.version == 0 || header.version > SESSION_VERSION {
+            return Err(format!(
+                "Unsupported session version {} (this build reads up to {SESSION_VERSION})",
+                header.version
+            ));
+        }
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+}
+
+fn autosave_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(AUTOSAVE_FILE))
+}
+
+pub fn save_session(state: &AppState, path: &Path) -> Result<(), String> {
+    std::fs::write(path, state.to_json()?).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+pub fn load_session(path: &Path) -> Result<AppState, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    AppState::from_json(&json).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Ask for a destination and save the session there
+pub async fn save_session_as(state: AppState) -> Result<Option<PathBuf>, String> {
+    let name = format!("{}.session", state.ip.replace(['.', ':'], "_"));
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .add_filter("Session", &["session"])
+        .set_file_name(name)
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let path = file.path().to_path_buf();
+    tokio::task::spawn_blocking(move || save_session(&state, &path).map(|()| Some(path)))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Ask the user to pick a session file and read it; Err("") if the dialog was cancelled
+pub async fn pick_and_load() -> Result<Box<AppState>, String> {
+    let path = crate::export::pick_file("Session", &["session", "json"])
+        .await
+        .ok_or_else(String::new)?;
+    tokio::task::spawn_blocking(move || load_session(&path).map(Box::new))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Overwrite the auto-save file in the home directory
+pub async fn autosave(state: AppState) -> Result<(), String> {
+    let path = autosave_path().ok_or("No home directory for the auto-save file")?;
+    tokio::task::spawn_blocking(move || save_session(&state, &path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_round_trip_and_version() {
+        let mut notes = NoteStore::default();
+        notes.rename_slot("10.0.0.5", 1, "Left");
+        let state = AppState {
+            ip: "10.0.0.5".into(),
+            fleet: vec![SessionMiner {
+                ip: "10.0.0.6".into(),
+                poll_interval_secs: 120,
+                ..SessionMiner::default()
+            }],
+            notes,
+            selected_chip: Some((1, 7)),
+            slot_rotated: HashMap::from([(2, true)]),
+            color_mode: ColorMode::VoltageGroup,
+            language: Language::Polish,
+            temp_baseline: vec![((1, 7), RunningMeanVar::from_parts(12, 71.5, 0.8))],
+            ..AppState::default()
+        };
+        let restored = AppState::from_json(&state.to_json().unwrap()).unwrap();
+        assert_eq!(restored.version, SESSION_VERSION);
+        assert_eq!(restored.fleet[0].poll_interval_secs, 120);
+        assert_eq!(restored.notes.slot_names("10.0.0.5")[&1], "Left");
+        assert_eq!(restored.selected_chip, Some((1, 7)));
+        assert_eq!(restored.slot_rotated, state.slot_rotated);
+        assert_eq!(restored.color_mode, ColorMode::VoltageGroup);
+        assert_eq!(restored.language, Language::Polish);
+        assert_eq!(restored.temp_baseline, state.temp_baseline);
+
+        let newer = format!(r#"{{"version": {}}}"#, SESSION_VERSION + 1);
+        assert!(AppState::from_json(&newer).is_err());
+        assert!(AppState::from_json(r#"{"ip": "10.0.0.5"}"#).is_err());
+    }
+}
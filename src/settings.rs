@@ -0,0 +1,282 @@
+//! Analysis thresholds and alert settings from the settings panel, saved to a JSON
+//! file in the home directory
+
+use std::path::PathBuf;
+
+use chrono::{NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::alerts::{AlertSeverity, AlertWindow};
+use crate::analysis::{self, AnalysisConfig};
+use crate::watch;
+
+const SETTINGS_FILE: &str = ".whatsminer_chip_map_settings.json";
+
+/// Settings that persist across runs; fields missing from the file keep their defaults
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub analysis: AnalysisConfig,
+    /// °C rise over its first reading that flashes a watched chip
+    pub watch_alert_delta: i32,
+    /// Polls a position needs before its temperature baseline counts as established
+    pub baseline_polls: u32,
+    /// Maintenance windows that silence desktop notifications
+    pub alert_schedule: Vec<AlertWindow>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            analysis: AnalysisConfig::default(),
+            watch_alert_delta: watch::DEFAULT_ALERT_DELTA,
+            baseline_polls: analysis::TEMP_BASELINE_POLLS,
+            alert_schedule: Vec::new(),
+        }
+    }
+}
+
+impl Settings {
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+}
+
+fn settings_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(SETTINGS_FILE))
+}
+
+/// Read the settings file; a missing file gives the defaults
+pub fn load() -> Result<Settings, String> {
+    let Some(path) = settings_path() else {
+        return Ok(Settings::default());
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(json) => Settings::from_json(&json).map_err(|e| format!("{}: {e}", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Settings::default()),
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
+
+/// Write a `Settings::to_json` snapshot to the settings file
+pub async fn save(json: String) -> Result<(), String> {
+    let path = settings_path().ok_or("No home directory for the settings file")?;
+    tokio::fs::write(&path, json)
+        .await
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Analysis settings as typed in the settings panel
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnalysisConfigDraft {
+    pub gradient_noise_floor: String,
+    pub outlier_sigma_threshold: String,
+    pub nonce_deficit_critical_pct: String,
+    pub min_neighbors_for_gradient: String,
+    pub dead_density_kernel: String,
+}
+
+impl From<&AnalysisConfig> for AnalysisConfigDraft {
+    fn from(config: &AnalysisConfig) -> Self {
+        Self {
+            gradient_noise_floor: config.gradient_noise_floor.to_string(),
+            outlier_sigma_threshold: config.outlier_sigma_threshold.to_string(),
+            nonce_deficit_critical_pct: config.nonce_deficit_critical_pct.to_string(),
+            min_neighbors_for_gradient: config.min_neighbors_for_gradient.to_string(),
+            dead_density_kernel: config.dead_density_kernel.to_string(),
+        }
+    }
+}
+
+impl AnalysisConfigDraft {
+    /// Validate the fields: a non-negative noise floor, a positive sigma threshold,
+    /// a deficit of 0-100%, at most as many neighbors as a chip can have and a
+    /// dead density kernel of 1-5 cells
+    pub fn build(&self) -> Result<AnalysisConfig, String> {
+        let number = |value: &str| {
+            let value = value.trim();
+            value
+                .parse::<f32>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .ok_or_else(|| format!("Invalid number: {value}"))
+        };
+        let gradient_noise_floor = number(&self.gradient_noise_floor)?;
+        if gradient_noise_floor < 0.0 {
+            return Err("Gradient noise floor must not be negative".into());
+        }
+        let outlier_sigma_threshold = number(&self.outlier_sigma_threshold)?;
+        if outlier_sigma_threshold <= 0.0 {
+            return Err("Outlier threshold must be positive".into());
+        }
+        let nonce_deficit_critical_pct = number(&self.nonce_deficit_critical_pct)?;
+        if nonce_deficit_critical_pct <= 0.0 || nonce_deficit_critical_pct > 100.0 {
+            return Err("Critical nonce deficit must be between 0 and 100%".into());
+        }
+        let min_neighbors = self.min_neighbors_for_gradient.trim();
+        let min_neighbors_for_gradient = min_neighbors
+            .parse::<usize>()
+            .ok()
+            .filter(|&n| n <= analysis::MAX_GRADIENT_NEIGHBORS)
+            .ok_or_else(|| {
+                format!(
+                    "Minimum neighbors must be 0-{}: {min_neighbors}",
+                    analysis::MAX_GRADIENT_NEIGHBORS
+                )
+            })?;
+        let kernel = self.dead_density_kernel.trim();
+        let dead_density_kernel = kernel
+            .parse::<usize>()
+            .ok()
+            .filter(|k| {
+                (analysis::DEAD_DENSITY_KERNEL_MIN..=analysis::DEAD_DENSITY_KERNEL_MAX).contains(k)
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Dead density kernel must be {}-{}: {kernel}",
+                    analysis::DEAD_DENSITY_KERNEL_MIN,
+                    analysis::DEAD_DENSITY_KERNEL_MAX
+                )
+            })?;
+        Ok(AnalysisConfig {
+            gradient_noise_floor,
+            outlier_sigma_threshold,
+            nonce_deficit_critical_pct,
+            min_neighbors_for_gradient,
+            dead_density_kernel,
+        })
+    }
+}
+
+/// Time format of the maintenance window fields
+const WINDOW_TIME_FORMAT: &str = "%H:%M";
+
+/// Maintenance window as typed in the settings panel
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertWindowDraft {
+    pub days: Vec<Weekday>,
+    pub start_time: String,
+    pub end_time: String,
+    pub suppress_level: AlertSeverity,
+}
+
+impl From<&AlertWindow> for AlertWindowDraft {
+    fn from(window: &AlertWindow) -> Self {
+        Self {
+            days: window.days.clone(),
+            start_time: window.start_time.format(WINDOW_TIME_FORMAT).to_string(),
+            end_time: window.end_time.format(WINDOW_TIME_FORMAT).to_string(),
+            suppress_level: window.suppress_level,
+        }
+    }
+}
+
+impl AlertWindowDraft {
+    /// Turn a day on or off, keeping the days in week order
+    pub fn toggle_day(&mut self, day: Weekday) {
+        if let Some(pos) = self.days.iter().position(|&d| d == day) {
+            self.days.remove(pos);
+        } else {
+            self.days.push(day);
+            self.days.sort_by_key(Weekday::num_days_from_monday);
+        }
+    }
+
+    /// Validate the fields: at least one day and HH:MM start and end times
+    pub fn build(&self) -> Result<AlertWindow, String> {
+        if self.days.is_empty() {
+            return Err("Maintenance window needs at least one day".into());
+        }
+        let time = |value: &str| {
+            let value = value.trim();
+            NaiveTime::parse_from_str(value, WINDOW_TIME_FORMAT)
+                .map_err(|_| format!("Invalid time (HH:MM): {value}"))
+        };
+        Ok(AlertWindow {
+            days: self.days.clone(),
+            start_time: time(&self.start_time)?,
+            end_time: time(&self.end_time)?,
+            suppress_level: self.suppress_level,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_round_trip_and_draft() {
+        let settings = Settings {
+            analysis: AnalysisConfig {
+                outlier_sigma_threshold: 2.5,
+                min_neighbors_for_gradient: 2,
+                ..AnalysisConfig::default()
+            },
+            watch_alert_delta: 8,
+            ..Settings::default()
+        };
+        let json = settings.to_json().unwrap();
+        assert_eq!(Settings::from_json(&json).unwrap(), settings);
+
+        // Older files without the analysis section keep the default thresholds
+        let old = Settings::from_json(r#"{"watch_alert_delta": 3}"#).unwrap();
+        assert_eq!(old.analysis, AnalysisConfig::default());
+        assert_eq!(old.watch_alert_delta, 3);
+
+        let mut draft = AnalysisConfigDraft::from(&settings.analysis);
+        assert_eq!(draft.build().unwrap(), settings.analysis);
+        draft.outlier_sigma_threshold = " 4 ".into();
+        assert_eq!(draft.build().unwrap().outlier_sigma_threshold, 4.0);
+        draft.outlier_sigma_threshold = "0".into();
+        assert!(draft.build().is_err());
+        draft.outlier_sigma_threshold = "3".into();
+        draft.nonce_deficit_critical_pct = "120".into();
+        assert!(draft.build().is_err());
+        draft.nonce_deficit_critical_pct = "40".into();
+        draft.min_neighbors_for_gradient = "4".into();
+        assert!(draft.build().is_err());
+        draft.min_neighbors_for_gradient = "2".into();
+        draft.dead_density_kernel = "6".into();
+        assert!(draft.build().is_err());
+        draft.dead_density_kernel = "5".into();
+        assert_eq!(draft.build().unwrap().dead_density_kernel, 5);
+        draft.gradient_noise_floor = "-1".into();
+        draft.min_neighbors_for_gradient = "3".into();
+        assert!(draft.build().is_err());
+    }
+
+    #[test]
+    fn test_alert_window_draft() {
+        let window = AlertWindow::default();
+        let settings = Settings {
+            alert_schedule: vec![window.clone()],
+            ..Settings::default()
+        };
+        let json = settings.to_json().unwrap();
+        assert_eq!(Settings::from_json(&json).unwrap(), settings);
+
+        let mut draft = AlertWindowDraft::from(&window);
+        assert_eq!(draft.start_time, "22:00");
+        assert_eq!(draft.build().unwrap(), window);
+        draft.toggle_day(Weekday::Sun);
+        draft.toggle_day(Weekday::Mon);
+        assert_eq!(draft.days.first(), Some(&Weekday::Tue));
+        assert_eq!(draft.days.last(), Some(&Weekday::Sun));
+        draft.end_time = " 07:30 ".into();
+        assert_eq!(
+            draft.build().unwrap().end_time,
+            NaiveTime::from_hms_opt(7, 30, 0).unwrap()
+        );
+        draft.end_time = "25:00".into();
+        assert!(draft.build().is_err());
+        draft.end_time = "07:30".into();
+        draft.days.clear();
+        assert!(draft.build().is_err());
+    }
+}
@@ -1,11 +1,38 @@
-use iced::{Background, Border, Color, color, widget::container};
+use std::cmp::Ordering;
 
-use crate::analysis::ChipAnalysis;
-use crate::models::ColorMode;
+use iced::{
+    Background, Border, Color, color,
+    widget::{button, container, progress_bar},
+};
+
+use crate::alerts::AlertSeverity;
+use crate::analysis::{self, AnalysisConfig, ChipAnalysis, ChipPowerState, EfficiencyTrend};
+use crate::metrics;
+use crate::models::{Chip, ColorMode};
 
 // Brand colors
 pub const BRAND_ORANGE: Color = color!(0xF7, 0x93, 0x1A);
 
+/// Colors offered for chip group annotations (RGB)
+pub const GROUP_COLORS: [[u8; 3]; 6] = [
+    [0x4F, 0xC3, 0xF7],
+    [0xBA, 0x68, 0xC8],
+    [0xFF, 0xD5, 0x4F],
+    [0x81, 0xC7, 0x84],
+    [0xF0, 0x62, 0x92],
+    [0xE0, 0xE0, 0xE0],
+];
+// Nonce rate bar: share of the fastest slot's rate below which it turns red / amber
+const NONCE_RATE_RED: f32 = 0.75;
+const NONCE_RATE_AMBER: f32 = 0.9;
+// Tooltip error bars: amber above the slot average, red above this multiple of it
+const ERROR_BAR_RED_RATIO: f32 = 2.0;
+/// Pool latency bands (ms): green below the first, red above the second
+const POOL_LATENCY_BANDS_MS: (u32, u32) = (50, 200);
+
+// Chip group overlay fill opacity; labels and outlines are opaque
+const GROUP_FILL_ALPHA: f32 = 0.35;
+
 // Base colors
 const BG_DARK: Color = color!(0x0D, 0x0D, 0x0D);
 const BG_PANEL: Color = color!(0x1A, 0x1A, 0x1A);
@@ -17,8 +44,64 @@ const TEMP_RANGE: (f32, f32) = (40.0, 100.0);
 const ERROR_RANGE: (f32, f32) = (0.0, 150.0);
 const CRC_RANGE: (f32, f32) = (0.0, 15.0);
 const LAPLACIAN_RANGE: (f32, f32) = (0.0, 15.0); // Degrees difference from neighbors
-const ZSCORE_RANGE: (f32, f32) = (0.0, 3.0); // Standard deviations
-const NONCE_DEFICIT_RANGE: (f32, f32) = (0.0, 50.0); // Percentage below average
+const NONCE_PER_MHZ_RANGE: (f32, f32) = (0.0, 2.0); // Std devs below average (negated z-score)
+const DELTA_RANGE: (f32, f32) = (0.0, 15.0); // °C hotter than the diff target
+
+// Outlier and nonce modes run from 0 up to the configured analysis thresholds
+
+/// Standard deviations, red at the outlier threshold
+fn zscore_range(config: &AnalysisConfig) -> (f32, f32) {
+    (0.0, config.outlier_sigma_threshold)
+}
+
+/// Percentage below the slot average, red at the critical deficit
+fn nonce_deficit_range(config: &AnalysisConfig) -> (f32, f32) {
+    (0.0, config.nonce_deficit_critical_pct)
+}
+
+// Frequency deviation bands (percent from slot target): green / amber / orange / red
+const FREQ_DEVIATION_BANDS: [f32; 3] = [1.0, 5.0, 10.0];
+/// Improving chips in EfficiencyTrend mode: (background, border)
+const IMPROVING_COLORS: (Color, Color) = (color!(0x15, 0x5E, 0x75), color!(0x22, 0xD3, 0xEE));
+/// VoltageGroup mode: (background, border) per group, away from the health colors
+const VOLTAGE_GROUP_COLORS: [(Color, Color); analysis::VOLTAGE_GROUPS_MAX] = [
+    (color!(0x1E, 0x3A, 0x8A), color!(0x60, 0xA5, 0xFA)), // Blue
+    (color!(0x58, 0x1C, 0x87), color!(0xC0, 0x84, 0xFC)), // Purple
+    (color!(0x13, 0x4E, 0x4A), color!(0x2D, 0xD4, 0xBF)), // Teal
+    (color!(0x83, 0x18, 0x43), color!(0xF4, 0x72, 0xB6)), // Pink
+];
+/// PowerState mode: (background, border) for active, throttled, sleeping and dead chips
+const POWER_STATE_COLORS: [(Color, Color); 4] = [
+    (color!(0x14, 0x53, 0x2D), color!(0x22, 0xC5, 0x5E)), // Green
+    (color!(0x78, 0x35, 0x0F), color!(0xF5, 0x9E, 0x0B)), // Amber
+    (color!(0x1E, 0x3A, 0x8A), color!(0x60, 0xA5, 0xFA)), // Blue
+    (color!(0x27, 0x27, 0x2A), color!(0x52, 0x52, 0x5B)), // Gray
+];
+/// Under-frequency chips in FreqCompliance mode: (background, border)
+const UNDER_FREQ_COLORS: (Color, Color) = (color!(0x1E, 0x3A, 0x8A), color!(0x3B, 0x82, 0xF6));
+
+// Heat cycle bands (warm-ups this session): green / amber / orange / red
+const HEAT_CYCLE_BANDS: [f32; 3] = [2.0, 5.0, 10.0];
+
+// Error rate bands (errors per hour): green / amber / orange / red
+const ERROR_RATE_BANDS: [f32; 3] = [5.0, 20.0, 50.0];
+
+// Temperature anomaly bands (std devs from the learned baseline, either way):
+// green / amber / orange / red, red beyond analysis::TEMP_ANOMALY_SIGMA
+const TEMP_ANOMALY_BANDS: [f32; 3] = [1.0, 2.0, crate::analysis::TEMP_ANOMALY_SIGMA];
+
+// Health score range (inverted: 100 = green, 50 and below = red)
+const HEALTH_RANGE: (f32, f32) = (50.0, 100.0);
+
+// Fill for dead chips in miniature maps
+const DEAD_CHIP_FILL: Color = color!(0x2A, 0x2A, 0x2A);
+
+// Cell for a chip id the firmware skipped: (fill, border, dash)
+const PLACEHOLDER_CHIP_COLORS: (Color, Color, Color) = (
+    color!(0x1F, 0x1F, 0x1F),
+    color!(0x3A, 0x3A, 0x3A),
+    color!(0x6B, 0x6B, 0x6B),
+);
 
 // Board temperature range for sidebar
 const BOARD_TEMP_RANGE: (f32, f32) = (30.0, 90.0);
@@ -101,49 +184,534 @@ pub fn color_for_board_temp(temp: f64) -> Color {
     gradient_text_color(t)
 }
 
+/// Text color for a board input voltage: green within VOLTAGE_OK_PCT of nominal,
+/// amber up to VOLTAGE_WARN_PCT, red beyond or below the critical voltage
+pub fn color_for_input_voltage(volts: f32) -> Color {
+    let deviation = metrics::compute_voltage_deviation(volts, metrics::NOMINAL_INPUT_VOLTAGE).abs();
+    if volts < metrics::VOLTAGE_CRITICAL_V || deviation > metrics::VOLTAGE_WARN_PCT {
+        error_color()
+    } else if deviation > metrics::VOLTAGE_OK_PCT {
+        warning_color()
+    } else {
+        ok_color()
+    }
+}
+
 /// Text color for nonce deficit display (gradient)
-/// deficit is percentage below slot average (0 = good, 50+ = bad)
-pub fn color_for_nonce_deficit(deficit: f32) -> Color {
-    let t = normalize(deficit, NONCE_DEFICIT_RANGE.0, NONCE_DEFICIT_RANGE.1);
+/// deficit is percentage below slot average (0 = good, critical deficit and above = bad)
+pub fn color_for_nonce_deficit(deficit: f32, config: &AnalysisConfig) -> Color {
+    let (min, max) = nonce_deficit_range(config);
+    let t = normalize(deficit, min, max);
     gradient_text_color(t)
 }
 
-/// Chip cell style with gradient coloring based on mode
+/// Green text for values within limits
+pub fn ok_color() -> Color {
+    TEXT_GRADIENT_STOPS[0].1
+}
+
+/// Amber text for warnings that need attention but aren't failures
+pub fn warning_color() -> Color {
+    TEXT_GRADIENT_STOPS[1].1
+}
+
+/// Red text for failures
+pub fn error_color() -> Color {
+    TEXT_GRADIENT_STOPS[3].1
+}
+
+/// Text color for error rate display (same bands as the Err/h color mode)
+pub fn color_for_error_rate(rate: f32) -> Color {
+    TEXT_GRADIENT_STOPS[band_index(rate, &ERROR_RATE_BANDS)].1
+}
+
+/// Text color for pool latency: green under 50 ms, amber to 200 ms, red above
+pub fn color_for_pool_latency(ms: u32) -> Color {
+    if ms < POOL_LATENCY_BANDS_MS.0 {
+        ok_color()
+    } else if ms <= POOL_LATENCY_BANDS_MS.1 {
+        warning_color()
+    } else {
+        error_color()
+    }
+}
+
+/// Discrete (background, border) colors for chip freq deviation from the slot target
+/// Green = within 1%, amber = 1-5%, orange = 5-10%, red = >10%
+#[allow(clippy::cast_precision_loss)] // freq values fit in f32
+pub fn chip_colors_for_freq_relative(chip_freq: i32, slot_freq: i32) -> (Color, Color) {
+    let deviation = if slot_freq > 0 {
+        ((chip_freq - slot_freq) as f32 / slot_freq as f32 * 100.0).abs()
+    } else {
+        0.0
+    };
+    banded_colors(deviation, &FREQ_DEVIATION_BANDS)
+}
+
+/// (background, border) colors for frequency compliance: green within
+/// FREQ_COMPLIANCE_TOLERANCE_MHZ of the slot target, orange over it, blue under it
+pub fn chip_colors_for_freq_compliance(chip_freq: i32, slot_freq: i32) -> (Color, Color) {
+    let stop = |i: usize| (GRADIENT_STOPS[i].1, GRADIENT_STOPS[i].2);
+    match analysis::freq_compliance(
+        chip_freq,
+        slot_freq,
+        analysis::FREQ_COMPLIANCE_TOLERANCE_MHZ,
+    ) {
+        Ordering::Less => UNDER_FREQ_COLORS,
+        Ordering::Equal => stop(0),
+        Ordering::Greater => stop(2),
+    }
+}
+
+/// (background, border) colors for a nonce/MHz trend slope: red for a steady decline,
+/// amber for a slight one, green when stable, cyan when improving
+pub fn chip_colors_for_efficiency_trend(slope: f32) -> (Color, Color) {
+    let stop = |i: usize| (GRADIENT_STOPS[i].1, GRADIENT_STOPS[i].2);
+    match EfficiencyTrend::from_slope(slope) {
+        EfficiencyTrend::SteadyDecline => stop(3),
+        EfficiencyTrend::SlightDecline => stop(1),
+        EfficiencyTrend::Stable => stop(0),
+        EfficiencyTrend::Improving => IMPROVING_COLORS,
+    }
+}
+
+/// Text color for a nonce/MHz trend slope, same bands as the chip colors
+pub fn color_for_efficiency_trend(slope: f32) -> Color {
+    match EfficiencyTrend::from_slope(slope) {
+        EfficiencyTrend::SteadyDecline => error_color(),
+        EfficiencyTrend::SlightDecline => warning_color(),
+        EfficiencyTrend::Stable => ok_color(),
+        EfficiencyTrend::Improving => IMPROVING_COLORS.1,
+    }
+}
+
+/// Discrete (background, border) colors for chip error rate
+/// Green = <5/h, amber = 5-20/h, orange = 20-50/h, red = >50/h
+pub fn chip_colors_for_error_rate(rate: f32) -> (Color, Color) {
+    banded_colors(rate, &ERROR_RATE_BANDS)
+}
+
+/// Gradient stop index for a value: how many band limits it exceeds
+fn band_index(value: f32, bands: &[f32; 3]) -> usize {
+    bands.iter().take_while(|&&limit| value > limit).count()
+}
+
+fn banded_colors(value: f32, bands: &[f32; 3]) -> (Color, Color) {
+    let (_, bg, border) = GRADIENT_STOPS[band_index(value, bands)];
+    (bg, border)
+}
+
+/// (background, border) colors for nonce/MHz efficiency z-score
+/// Above average = green (efficient), 2+ std devs below = red (inefficient)
+pub fn chip_colors_for_nonce_per_mhz(zscore: f32) -> (Color, Color) {
+    gradient_colors(normalize(
+        -zscore,
+        NONCE_PER_MHZ_RANGE.0,
+        NONCE_PER_MHZ_RANGE.1,
+    ))
+}
+
+/// (background, border) colors for a chip's voltage group (discrete palette)
+pub fn voltage_group_colors(group: u8) -> (Color, Color) {
+    VOLTAGE_GROUP_COLORS[usize::from(group).min(VOLTAGE_GROUP_COLORS.len() - 1)]
+}
+
+/// (background, border) colors for a chip's power state (discrete palette)
+pub fn power_state_colors(state: ChipPowerState) -> (Color, Color) {
+    POWER_STATE_COLORS[power_state_index(state)]
+}
+
+/// Position of a power state in POWER_STATE_COLORS and the legend
+fn power_state_index(state: ChipPowerState) -> usize {
+    match state {
+        ChipPowerState::Active => 0,
+        ChipPowerState::Throttled => 1,
+        ChipPowerState::Sleep => 2,
+        ChipPowerState::Dead => 3,
+    }
+}
+
+/// (background, border) colors for a temperature delta vs the diff target
+/// Same or cooler = green, 15°C+ hotter = red
+#[allow(clippy::cast_precision_loss)] // temp deltas fit in f32
+pub fn chip_colors_for_delta(delta: i32) -> (Color, Color) {
+    gradient_colors(normalize(delta as f32, DELTA_RANGE.0, DELTA_RANGE.1))
+}
+
+/// Chip cell (background, border) colors based on mode
 #[allow(clippy::cast_precision_loss)] // small integer values fit in f32
-pub fn chip_cell(
-    temp: i32,
-    errors: i32,
-    crc: i32,
+pub fn chip_colors(
+    chip: &Chip,
+    slot_freq: i32,
     mode: ColorMode,
     analysis: Option<ChipAnalysis>,
-) -> container::Style {
+    config: &AnalysisConfig,
+) -> (Color, Color) {
     let t = match mode {
-        ColorMode::Temperature => normalize(temp as f32, TEMP_RANGE.0, TEMP_RANGE.1),
-        ColorMode::Errors => normalize(errors as f32, ERROR_RANGE.0, ERROR_RANGE.1),
-        ColorMode::Crc => normalize(crc as f32, CRC_RANGE.0, CRC_RANGE.1),
+        ColorMode::Temperature => normalize(chip.temp as f32, TEMP_RANGE.0, TEMP_RANGE.1),
+        ColorMode::Errors => normalize(chip.errors as f32, ERROR_RANGE.0, ERROR_RANGE.1),
+        ColorMode::Crc => normalize(chip.crc as f32, CRC_RANGE.0, CRC_RANGE.1),
         ColorMode::Gradient => {
             let gradient = analysis.map_or(0.0, |a| a.gradient);
             normalize(gradient, LAPLACIAN_RANGE.0, LAPLACIAN_RANGE.1)
         }
         ColorMode::Outliers => {
             let zscore = analysis.map_or(0.0, |a| a.cross_slot_zscore);
-            normalize(zscore, ZSCORE_RANGE.0, ZSCORE_RANGE.1)
+            let (min, max) = zscore_range(config);
+            normalize(zscore, min, max)
+        }
+        ColorMode::CrossMinerOutliers => {
+            let zscore = analysis.map_or(0.0, |a| a.cross_miner_zscore);
+            let (min, max) = zscore_range(config);
+            normalize(zscore, min, max)
         }
         ColorMode::Nonce => {
             // Higher deficit = worse performance = red
             let deficit = analysis.map_or(0.0, |a| a.nonce_deficit);
-            normalize(deficit, NONCE_DEFICIT_RANGE.0, NONCE_DEFICIT_RANGE.1)
+            let (min, max) = nonce_deficit_range(config);
+            normalize(deficit, min, max)
         }
+        // Discrete bands / own color mapping, handled below
+        ColorMode::FreqRelative
+        | ColorMode::FreqCompliance
+        | ColorMode::NoncePerMhz
+        | ColorMode::EfficiencyTrend
+        | ColorMode::ErrorRate
+        | ColorMode::TempAnomaly
+        | ColorMode::VoltageGroup
+        | ColorMode::DeadDensity
+        | ColorMode::PowerState
+        | ColorMode::HeatCycles => 0.0,
+        // Deltas aren't part of ChipAnalysis; the diff panel uses chip_colors_for_delta
+        ColorMode::Delta => 0.0,
+    };
+    match mode {
+        ColorMode::FreqRelative => chip_colors_for_freq_relative(chip.freq, slot_freq),
+        ColorMode::FreqCompliance => chip_colors_for_freq_compliance(chip.freq, slot_freq),
+        ColorMode::NoncePerMhz => {
+            chip_colors_for_nonce_per_mhz(analysis.map_or(0.0, |a| a.nonce_per_mhz_zscore))
+        }
+        ColorMode::EfficiencyTrend => {
+            chip_colors_for_efficiency_trend(analysis.map_or(0.0, |a| a.efficiency_trend))
+        }
+        ColorMode::ErrorRate => {
+            chip_colors_for_error_rate(analysis.map_or(0.0, |a| a.error_rate_per_hour))
+        }
+        ColorMode::TempAnomaly => banded_colors(
+            analysis.map_or(0.0, |a| a.temp_anomaly_score.abs()),
+            &TEMP_ANOMALY_BANDS,
+        ),
+        ColorMode::VoltageGroup => voltage_group_colors(analysis.map_or(0, |a| a.voltage_group)),
+        ColorMode::DeadDensity => {
+            let temp = normalize(chip.temp as f32, TEMP_RANGE.0, TEMP_RANGE.1);
+            dead_density_colors(temp, analysis.map_or(0.0, |a| a.dead_density))
+        }
+        ColorMode::HeatCycles => banded_colors(
+            analysis.map_or(0.0, |a| a.heat_cycles as f32),
+            &HEAT_CYCLE_BANDS,
+        ),
+        ColorMode::PowerState => power_state_colors(analysis.map_or_else(
+            || analysis::compute_chip_power_state(chip, slot_freq),
+            |a| a.power_state,
+        )),
+        _ => gradient_colors(t),
+    }
+}
+
+/// Temperature colors (`temp` normalized 0-1) blended toward the hottest red by
+/// the dead chip density, whatever the chip's own temperature
+fn dead_density_colors(temp: f32, density: f32) -> (Color, Color) {
+    let (bg, border) = gradient_colors(temp);
+    let (_, red_bg, red_border) = GRADIENT_STOPS[3];
+    let t = density.clamp(0.0, 1.0);
+    (lerp_color(bg, red_bg, t), lerp_color(border, red_border, t))
+}
+
+/// Legend stops of a color mode, green to red: (value in the mode's units, chip border color)
+///
+/// Gradient modes spread GRADIENT_STOPS over their range; banded modes put each
+/// stop's color at the band limit where chips switch to it.
+pub fn legend_stops(mode: ColorMode, config: &AnalysisConfig) -> [(f32, Color); 4] {
+    let ranged = |(min, max): (f32, f32)| {
+        GRADIENT_STOPS.map(|(pos, _, border)| (min + pos * (max - min), border))
+    };
+    let banded = |bands: [f32; 3]| {
+        let limits = [0.0, bands[0], bands[1], bands[2]];
+        std::array::from_fn(|i| (limits[i], GRADIENT_STOPS[i].2))
     };
+    match mode {
+        ColorMode::Temperature => ranged(TEMP_RANGE),
+        ColorMode::Errors => ranged(ERROR_RANGE),
+        ColorMode::Crc => ranged(CRC_RANGE),
+        ColorMode::Gradient => ranged(LAPLACIAN_RANGE),
+        ColorMode::Outliers | ColorMode::CrossMinerOutliers => ranged(zscore_range(config)),
+        ColorMode::Nonce => ranged(nonce_deficit_range(config)),
+        ColorMode::NoncePerMhz => ranged(NONCE_PER_MHZ_RANGE),
+        ColorMode::Delta => ranged(DELTA_RANGE),
+        // Share of dead neighbors; the tint is drawn over the temperature colors
+        ColorMode::DeadDensity => ranged((0.0, 100.0)),
+        ColorMode::FreqRelative => banded(FREQ_DEVIATION_BANDS),
+        // MHz off the slot target: under (blue), compliant (green), over (orange)
+        ColorMode::FreqCompliance => {
+            let tolerance = analysis::FREQ_COMPLIANCE_TOLERANCE_MHZ as f32;
+            [
+                (-2.0 * tolerance, UNDER_FREQ_COLORS.1),
+                (-tolerance, GRADIENT_STOPS[0].2),
+                (tolerance, GRADIENT_STOPS[0].2),
+                (2.0 * tolerance, GRADIENT_STOPS[2].2),
+            ]
+        }
+        ColorMode::ErrorRate => banded(ERROR_RATE_BANDS),
+        ColorMode::TempAnomaly => banded(TEMP_ANOMALY_BANDS),
+        ColorMode::HeatCycles => banded(HEAT_CYCLE_BANDS),
+        // Group numbers 1-4, one palette color each
+        ColorMode::VoltageGroup => {
+            std::array::from_fn(|i| ((i + 1) as f32, VOLTAGE_GROUP_COLORS[i].1))
+        }
+        // States 1-4: active, throttled, sleep, dead
+        ColorMode::PowerState => std::array::from_fn(|i| ((i + 1) as f32, POWER_STATE_COLORS[i].1)),
+        // Slope per poll: steady decline (red), slight decline (amber), stable (green), improving (cyan)
+        ColorMode::EfficiencyTrend => [
+            (analysis::EFFICIENCY_STEEP_DECLINE, GRADIENT_STOPS[3].2),
+            (-analysis::EFFICIENCY_STABLE, GRADIENT_STOPS[1].2),
+            (analysis::EFFICIENCY_STABLE, GRADIENT_STOPS[0].2),
+            (2.0 * analysis::EFFICIENCY_STABLE, IMPROVING_COLORS.1),
+        ],
+    }
+}
+
+/// Color at `value` between legend stops (sorted by value), clamped to the ends
+pub fn legend_color(stops: &[(f32, Color)], value: f32) -> Color {
+    for window in stops.windows(2) {
+        let ((lo, color_lo), (hi, color_hi)) = (window[0], window[1]);
+        if value <= hi {
+            let t = if hi > lo {
+                (value - lo) / (hi - lo)
+            } else {
+                1.0
+            };
+            return lerp_color(color_lo, color_hi, t.clamp(0.0, 1.0));
+        }
+    }
+    stops.last().map_or(Color::WHITE, |&(_, color)| color)
+}
+
+/// (fill, border, "—" text) of a placeholder chip cell
+pub fn chip_cell_placeholder() -> (Color, Color, Color) {
+    PLACEHOLDER_CHIP_COLORS
+}
+
+/// Fill color for a chip in miniature (dashboard) maps
+#[allow(clippy::cast_precision_loss)] // temp values fit in f32
+pub fn mini_chip_fill(temp: i32, dead: bool) -> Color {
+    if dead {
+        return DEAD_CHIP_FILL;
+    }
+    let t = normalize(temp as f32, TEMP_RANGE.0, TEMP_RANGE.1);
+    gradient_colors(t).1
+}
+
+/// Badge style for a 0-100 health score
+pub fn health_badge(score: f32) -> container::Style {
+    let t = 1.0 - normalize(score, HEALTH_RANGE.0, HEALTH_RANGE.1);
     let (bg, border) = gradient_colors(t);
+    container::Style {
+        text_color: Some(Color::WHITE),
+        background: Some(Background::Color(bg)),
+        border: Border {
+            color: border,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        ..Default::default()
+    }
+}
+
+/// Text color for dead chip counts (red when any chip is dead)
+pub fn color_for_dead_count(dead: usize) -> Color {
+    gradient_text_color(if dead > 0 { 1.0 } else { 0.0 })
+}
+
+/// Comparison summary color where higher is worse: red for the higher side,
+/// green for the lower, None when both are equal
+pub fn color_for_comparison(value: f32, other: f32) -> Option<Color> {
+    if (value - other).abs() < f32::EPSILON {
+        None
+    } else {
+        Some(gradient_text_color(if value > other { 1.0 } else { 0.0 }))
+    }
+}
 
+/// Unread count on the alerts toolbar button
+pub fn unread_badge() -> container::Style {
+    container::Style {
+        text_color: Some(Color::WHITE),
+        background: Some(Background::Color(error_color())),
+        border: Border {
+            radius: 8.0.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Alert history text: green for conditions that cleared, amber, red
+pub fn alert_severity_color(severity: AlertSeverity) -> Color {
+    match severity {
+        AlertSeverity::Info => ok_color(),
+        AlertSeverity::Warning => warning_color(),
+        AlertSeverity::Critical => error_color(),
+    }
+}
+
+/// Alert history row tinted with its severity color
+pub fn alert_log_row(color: Color) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color { a: 0.15, ..color })),
+        border: Border {
+            radius: 3.0.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Summary bar badge for the dead chip count (red when any chip is dead)
+pub fn dead_count_badge(dead: usize) -> container::Style {
+    let (bg, border) = gradient_colors(if dead > 0 { 1.0 } else { 0.0 });
     container::Style {
         text_color: Some(Color::WHITE),
         background: Some(Background::Color(bg)),
         border: Border {
             color: border,
-            width: 1.5,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        ..Default::default()
+    }
+}
+
+/// Nonce histogram bar, colored by how far the bin sits below the slot mean
+pub fn histogram_bar(deficit: f32, config: &AnalysisConfig) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(color_for_nonce_deficit(deficit, config))),
+        border: Border {
+            radius: 1.0.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Vertical marker for the slot mean in the nonce histogram
+pub fn mean_marker() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(BRAND_ORANGE)),
+        ..Default::default()
+    }
+}
+
+/// Fetch progress segment: brand orange once its stage is reached
+pub fn progress_segment(reached: bool) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(if reached {
+            BRAND_ORANGE
+        } else {
+            BORDER_ACCENT
+        })),
+        border: Border {
+            radius: 2.0.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Throttle history segment: red for a throttled poll, green otherwise
+pub fn throttle_segment(throttled: bool) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(if throttled {
+            error_color()
+        } else {
+            ok_color()
+        })),
+        ..Default::default()
+    }
+}
+
+/// Live nonce rate bar; `ratio` is the slot's rate over the fastest slot's
+pub fn nonce_rate_bar(ratio: f32) -> progress_bar::Style {
+    let bar = if ratio < NONCE_RATE_RED {
+        error_color()
+    } else if ratio < NONCE_RATE_AMBER {
+        warning_color()
+    } else {
+        ok_color()
+    };
+    progress_bar::Style {
+        background: Background::Color(BG_DARK),
+        bar: Background::Color(bar),
+        border: Border {
+            color: BORDER_ACCENT,
+            width: 1.0,
+            radius: 3.0.into(),
+        },
+    }
+}
+
+/// Amber status bar badge, e.g. the data warning count
+pub fn warning_badge(status: button::Status) -> button::Style {
+    let alpha = if status == button::Status::Hovered {
+        1.0
+    } else {
+        0.85
+    };
+    button::Style {
+        background: Some(Background::Color(Color {
+            a: alpha,
+            ..warning_color()
+        })),
+        text_color: Color::BLACK,
+        border: Border {
             radius: 4.0.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Blink phase of an alerting row in the watch window
+pub fn alert_row_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color {
+            a: 0.35,
+            ..error_color()
+        })),
+        border: Border {
+            radius: 3.0.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Semi-transparent backdrop behind modal dialogs
+pub fn modal_backdrop() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color { a: 0.7, ..BG_DARK })),
+        ..Default::default()
+    }
+}
+
+/// Modal dialog box
+pub fn modal_dialog() -> container::Style {
+    container::Style {
+        text_color: Some(Color::WHITE),
+        background: Some(Background::Color(BG_PANEL)),
+        border: Border {
+            color: BRAND_ORANGE,
+            width: 1.0,
+            radius: 8.0.into(),
         },
         ..Default::default()
     }
@@ -193,15 +761,98 @@ pub fn linked_divider_style() -> container::Style {
     }
 }
 
-pub fn tooltip_style() -> container::Style {
+/// (domain, D0, last domain) fills of the board layout diagram
+pub fn layout_diagram_colors() -> (Color, Color, Color) {
+    (BORDER_ACCENT, BRAND_ORANGE, color!(0x4F, 0xC3, 0xF7))
+}
+
+/// Axes and axis labels of canvas plots
+pub fn plot_axis_color() -> Color {
+    color!(0x9A, 0x9A, 0x9A)
+}
+
+/// (background, border) for tooltips drawn on a canvas
+pub fn tooltip_colors() -> (Color, Color) {
+    (BG_PANEL, BRAND_ORANGE)
+}
+
+/// Bars of the other chips in a chip cell's nonce mini-histogram
+pub fn mini_hist_muted() -> Color {
+    Color {
+        a: 0.5,
+        ..color!(0x9A, 0x9A, 0x9A)
+    }
+}
+
+/// Folded corner on chips whose readings stopped changing
+pub fn stale_marker_color() -> Color {
+    color!(0x9A, 0x9A, 0x9A)
+}
+
+/// Tooltip error counter bar: gray up to the slot average, amber above it,
+/// red past ERROR_BAR_RED_RATIO times it
+#[allow(clippy::cast_precision_loss)] // error counters fit in f32
+pub fn error_bar_color(value: i32, slot_avg: f32) -> Color {
+    let value = value as f32;
+    if value <= slot_avg {
+        color!(0x9A, 0x9A, 0x9A)
+    } else if value <= slot_avg * ERROR_BAR_RED_RATIO {
+        warning_color()
+    } else {
+        error_color()
+    }
+}
+
+/// Nonce timeline segment: green at or above the slot's mean chip count for the
+/// segment (`density` 1), red with no nonces
+pub fn nonce_density_color(density: f32) -> Color {
+    gradient_colors(1.0 - density.clamp(0.0, 1.0)).1
+}
+
+/// Opaque color of a chip group annotation
+pub fn group_color([r, g, b]: [u8; 3]) -> Color {
+    Color::from_rgb8(r, g, b)
+}
+
+/// Translucent overlay over a chip group's cells
+pub fn group_fill(rgb: [u8; 3]) -> Color {
+    Color {
+        a: GROUP_FILL_ALPHA,
+        ..group_color(rgb)
+    }
+}
+
+/// Color swatch in the chip group form, outlined when picked
+pub fn group_swatch(rgb: [u8; 3], picked: bool) -> container::Style {
     container::Style {
-        text_color: Some(Color::WHITE),
-        background: Some(Background::Color(BG_PANEL)),
+        background: Some(Background::Color(group_color(rgb))),
         border: Border {
-            color: BRAND_ORANGE,
-            width: 1.0,
+            color: if picked { Color::WHITE } else { BORDER_SUBTLE },
+            width: if picked { 2.0 } else { 1.0 },
             radius: 4.0.into(),
         },
         ..Default::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chip_colors_for_freq_relative() {
+        let band = |i: usize| (GRADIENT_STOPS[i].1, GRADIENT_STOPS[i].2);
+        // At target, and within 1% either way
+        assert_eq!(chip_colors_for_freq_relative(600, 600), band(0));
+        assert_eq!(chip_colors_for_freq_relative(605, 600), band(0));
+        // Above target: 3% amber, 8% orange, 20% red
+        assert_eq!(chip_colors_for_freq_relative(618, 600), band(1));
+        assert_eq!(chip_colors_for_freq_relative(648, 600), band(2));
+        assert_eq!(chip_colors_for_freq_relative(720, 600), band(3));
+        // Below target is banded by the same distance
+        assert_eq!(chip_colors_for_freq_relative(582, 600), band(1));
+        assert_eq!(chip_colors_for_freq_relative(480, 600), band(3));
+        // No target to compare against
+        assert_eq!(chip_colors_for_freq_relative(600, 0), band(0));
+    }
+}
@@ -0,0 +1,1187 @@
+//! Chip grid drawn on a single canvas
+//!
+//! One widget per slot instead of a container/column/row/text tree per chip:
+//! a 456-chip board would otherwise build ~1800 widget nodes every view.
+
+use iced::{
+    Color, Element, Length, Point, Rectangle, Renderer, Size, Theme,
+    alignment::Vertical,
+    mouse,
+    widget::{
+        canvas::{self, Action, Event, Frame, Path, Stroke},
+        text::Alignment,
+    },
+};
+
+use crate::Message;
+use crate::analysis::{self, AnalysisConfig, ChipAnalysis, ChipCluster, ErrorBar, NONCE_HIST_BINS};
+use crate::i18n::{Language, Tr};
+use crate::ir;
+use crate::layout::ChipLayout;
+use crate::models::{Chip, ColorMode, Slot};
+use crate::notes::{ChipGroupAnnotation, ChipReplacement};
+use crate::theme;
+
+use super::ViewOptions;
+
+const CHIP_SIZE: f32 = 55.0; // Square aspect ratio
+const CHIP_SPACING: f32 = 3.0;
+// Extra space between the two board sections (on top of CHIP_SPACING)
+const SECTION_GAP: f32 = CHIP_SPACING * 3.0;
+const CHIP_RADIUS: f32 = 4.0;
+
+// Nonce mini-histogram: cells grow to make room below the bottom text line
+const MINI_HIST_CHIP_SIZE: f32 = 64.0;
+const MINI_HIST_MIN_CHIP_SIZE: f32 = 60.0;
+const MINI_HIST_BAR_WIDTH: f32 = 2.0;
+const MINI_HIST_BAR_GAP: f32 = 1.0;
+const MINI_HIST_HEIGHT: f32 = 9.0;
+// Opacity of the IR photo blended over chip cells
+const IR_OVERLAY_ALPHA: f32 = 0.6;
+// Side of the folded corner on chips with stale data
+const STALE_MARKER_SIZE: f32 = 10.0;
+// Opacity of chips that don't match the chip search filter
+const FILTERED_OUT_ALPHA: f32 = 0.25;
+
+// Signal break slash: share of the half cell width it spans on each side of the gap center
+const SIGNAL_BREAK_REACH: f32 = 0.6;
+// CRC segment bar: share of the half cell width it spans on each side of the gap center
+const CRC_BREAK_REACH: f32 = 0.9;
+
+// Hover tooltip drawn inside the canvas
+const TOOLTIP_TEXT_SIZE: f32 = 12.0;
+const TOOLTIP_CHAR_WIDTH: f32 = 7.0; // rough width estimate, canvas can't measure text
+const TOOLTIP_HEIGHT: f32 = 20.0;
+// Error breakdown rows under the tooltip label: field name, bar, value
+const BREAKDOWN_ROW_HEIGHT: f32 = 14.0;
+const BREAKDOWN_NAME_WIDTH: f32 = 44.0;
+const BREAKDOWN_BAR_WIDTH: f32 = 110.0;
+const BREAKDOWN_BAR_HEIGHT: f32 = 8.0;
+const BREAKDOWN_VALUE_WIDTH: f32 = 40.0;
+const BREAKDOWN_TEXT_SIZE: f32 = 11.0;
+
+// Right-click menu, opened at the center of the clicked chip
+const MENU_ITEM_HEIGHT: f32 = 24.0;
+
+// Chip group label tag in the group's top-left cell
+const GROUP_LABEL_SIZE: f32 = 11.0;
+const GROUP_LABEL_CHAR_WIDTH: f32 = 6.5;
+const GROUP_LABEL_HEIGHT: f32 = 15.0;
+
+/// Physical arrangement of a slot's domains
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridLayout {
+    /// Standard boards: snake pattern, top section left→right, bottom section right→left
+    Snake,
+    /// Hydro/immersion boards: no snake, both halves with D0-ward domains on the right
+    Linked,
+}
+
+/// Grid cell assignment for every chip of a slot
+#[derive(Debug, Clone, PartialEq)]
+struct CellLayout {
+    /// (column, row) per chip, parallel to slot.chips
+    cells: Vec<(usize, usize)>,
+    columns: usize,
+    rows: usize,
+    /// First row of the second section, if the board has two
+    split_row: Option<usize>,
+    chip_size: f32,
+}
+
+impl CellLayout {
+    fn new(chip_count: usize, cpd: usize, layout: GridLayout, chip_size: f32) -> Self {
+        if cpd == 0 {
+            return Self {
+                cells: vec![],
+                columns: 0,
+                rows: 0,
+                split_row: None,
+                chip_size,
+            };
+        }
+        let num_domains = chip_count.div_ceil(cpd);
+
+        let (cells, columns, second_section) = match layout {
+            GridLayout::Snake => {
+                let snake = ChipLayout {
+                    chips_per_domain: cpd,
+                    num_domains,
+                };
+                let cells = (0..chip_count).map(|idx| snake.position(idx)).collect();
+                (cells, snake.columns(), snake.has_top_section())
+            }
+            GridLayout::Linked => {
+                // Right half: first half of domains, D0 on the far right, C0 at the bottom
+                // Left half: remaining domains left to right, rows top to bottom
+                let right_domains = num_domains.div_ceil(2);
+                let cells = (0..chip_count)
+                    .map(|idx| {
+                        let (domain, row) = (idx / cpd, idx % cpd);
+                        if domain < right_domains {
+                            (right_domains - 1 - domain, cpd - 1 - row)
+                        } else {
+                            (domain - right_domains, cpd + row)
+                        }
+                    })
+                    .collect();
+                (cells, right_domains, num_domains > right_domains)
+            }
+        };
+
+        Self {
+            cells,
+            columns,
+            rows: if second_section { 2 * cpd } else { cpd },
+            split_row: second_section.then_some(cpd),
+            chip_size,
+        }
+    }
+
+    /// The same grid turned 180° for a board mounted upside-down: the sections
+    /// swap, and columns and rows run the other way. Both sections are the same
+    /// height, so the section gap stays put.
+    fn rotated(mut self) -> Self {
+        let (columns, rows) = (self.columns, self.rows);
+        for (col, row) in &mut self.cells {
+            *col = columns - 1 - *col;
+            *row = rows - 1 - *row;
+        }
+        self
+    }
+
+    fn pitch(&self) -> f32 {
+        self.chip_size + CHIP_SPACING
+    }
+
+    #[allow(clippy::cast_precision_loss)] // grid dimensions fit in f32
+    fn size(&self) -> Size {
+        if self.columns == 0 || self.rows == 0 {
+            return Size::ZERO;
+        }
+        let gap = if self.split_row.is_some() {
+            SECTION_GAP
+        } else {
+            0.0
+        };
+        Size::new(
+            self.columns as f32 * self.pitch() - CHIP_SPACING,
+            self.rows as f32 * self.pitch() - CHIP_SPACING + gap,
+        )
+    }
+
+    /// Top-left corner of a grid cell
+    #[allow(clippy::cast_precision_loss)] // grid dimensions fit in f32
+    fn origin(&self, (col, row): (usize, usize)) -> Point {
+        let gap = match self.split_row {
+            Some(split) if row >= split => SECTION_GAP,
+            _ => 0.0,
+        };
+        Point::new(col as f32 * self.pitch(), row as f32 * self.pitch() + gap)
+    }
+
+    /// Chips inside the grid-aligned box spanned by chips `a` and `b`
+    fn within_box(&self, a: usize, b: usize) -> Vec<usize> {
+        let (Some(&(col_a, row_a)), Some(&(col_b, row_b))) = (self.cells.get(a), self.cells.get(b))
+        else {
+            return Vec::new();
+        };
+        let cols = col_a.min(col_b)..=col_a.max(col_b);
+        let rows = row_a.min(row_b)..=row_a.max(row_b);
+        (0..self.cells.len())
+            .filter(|&idx| {
+                let (col, row) = self.cells[idx];
+                cols.contains(&col) && rows.contains(&row)
+            })
+            .collect()
+    }
+
+    /// Index of the chip under `point` (canvas-local coordinates)
+    fn hit_test(&self, point: Point) -> Option<usize> {
+        self.cells.iter().position(|&cell| {
+            let origin = self.origin(cell);
+            Rectangle::new(origin, Size::new(self.chip_size, self.chip_size)).contains(point)
+        })
+    }
+}
+
+/// (column, row) of every chip in a slot's grid, as drawn
+pub fn grid_cells(chip_count: usize, cpd: usize, layout: GridLayout) -> Vec<(usize, usize)> {
+    CellLayout::new(chip_count, cpd, layout, CHIP_SIZE).cells
+}
+
+/// Canvas chip grid for one slot; `mini_hist` enlarges the cells to fit nonce mini-histograms
+/// and `clusters` are outlined; the slot's chip groups are tinted and labeled
+pub fn chip_grid<'a>(
+    slot: &'a Slot,
+    chips_per_domain: usize,
+    analysis: &'a [ChipAnalysis],
+    clusters: Vec<ChipCluster>,
+    layout: GridLayout,
+    opts: &ViewOptions,
+) -> Element<'a, Message> {
+    let chip_size = if opts.show_mini_hist {
+        MINI_HIST_CHIP_SIZE
+    } else {
+        CHIP_SIZE
+    };
+    let mut cells = CellLayout::new(slot.chips.len(), chips_per_domain, layout, chip_size);
+    if opts.is_rotated(slot.id) {
+        cells = cells.rotated();
+    }
+    let size = cells.size();
+    let nonce_hists =
+        (chip_size >= MINI_HIST_MIN_CHIP_SIZE).then(|| nonce_histograms(slot, chips_per_domain));
+
+    canvas::Canvas::new(ChipCanvas {
+        slot,
+        analysis,
+        color_mode: opts.color_mode,
+        chips_per_domain,
+        cells,
+        selected: opts.selected_in(slot),
+        box_selection: opts.box_selection_in(slot),
+        deltas: None,
+        nonce_hists,
+        signal_breaks: analysis::find_signal_break_domain(&slot.chips, chips_per_domain)
+            .into_iter()
+            .flatten()
+            .collect(),
+        crc_breaks: analysis::attribute_crc_to_segments(&slot.chips, chips_per_domain)
+            .into_iter()
+            .filter_map(|s| s.break_likely_after)
+            .collect(),
+        clusters,
+        groups: opts.groups_in(slot),
+        replacements: opts
+            .replacements
+            .iter()
+            .filter(|r| r.slot_id == slot.id)
+            .cloned()
+            .collect(),
+        ir_colors: opts.ir_overlay.get(&slot.id).cloned().unwrap_or_default(),
+        filter_matches: opts
+            .filter_matches
+            .get(&slot.id)
+            .cloned()
+            .unwrap_or_default(),
+        analysis_config: opts.analysis_config,
+        lang: opts.lang,
+    })
+    .width(Length::Fixed(size.width))
+    .height(Length::Fixed(size.height))
+    .into()
+}
+
+/// Canvas grid of per-chip temperature deltas vs a diff target (ColorMode::Delta)
+pub fn delta_grid<'a>(
+    slot: &'a Slot,
+    deltas: Vec<i32>,
+    chips_per_domain: usize,
+    layout: GridLayout,
+    rotated: bool,
+    lang: Language,
+) -> Element<'a, Message> {
+    let mut cells = CellLayout::new(slot.chips.len(), chips_per_domain, layout, CHIP_SIZE);
+    if rotated {
+        cells = cells.rotated();
+    }
+    let size = cells.size();
+
+    canvas::Canvas::new(ChipCanvas {
+        slot,
+        analysis: &[],
+        color_mode: ColorMode::Delta,
+        chips_per_domain,
+        cells,
+        selected: None,
+        box_selection: Vec::new(),
+        deltas: Some(deltas),
+        nonce_hists: None,
+        signal_breaks: Vec::new(),
+        crc_breaks: Vec::new(),
+        clusters: Vec::new(),
+        groups: Vec::new(),
+        replacements: Vec::new(),
+        ir_colors: Vec::new(),
+        filter_matches: Vec::new(),
+        analysis_config: AnalysisConfig::default(),
+        lang,
+    })
+    .width(Length::Fixed(size.width))
+    .height(Length::Fixed(size.height))
+    .into()
+}
+
+struct ChipCanvas<'a> {
+    slot: &'a Slot,
+    analysis: &'a [ChipAnalysis],
+    color_mode: ColorMode,
+    chips_per_domain: usize,
+    cells: CellLayout,
+    /// Selected chip index, drawn with an accent border
+    selected: Option<usize>,
+    /// Chips picked by dragging a selection box, outlined with a thinner accent border
+    box_selection: Vec<usize>,
+    /// Temperature deltas vs a diff target, parallel to slot.chips (Delta mode)
+    deltas: Option<Vec<i32>>,
+    /// (domain nonce histogram, own bin) per chip, parallel to slot.chips
+    nonce_hists: Option<Vec<([u8; NONCE_HIST_BINS], usize)>>,
+    /// First chip past each suspected daisy-chain break, slashed in the gap before it
+    signal_breaks: Vec<usize>,
+    /// Last chip before a suspected bad bus link (CRC errors start after it), barred in the gap
+    crc_breaks: Vec<usize>,
+    /// Adjacent failing chips, outlined together
+    clusters: Vec<ChipCluster>,
+    /// Labeled regions of this slot
+    groups: Vec<ChipGroupAnnotation>,
+    /// Swapped chips of this slot, shown in the tooltip
+    replacements: Vec<ChipReplacement>,
+    /// IR photo color over each chip, blended into its cell (empty without an overlay)
+    ir_colors: Vec<Option<[u8; 3]>>,
+    /// Whether each chip matches the chip search; the rest are faded (empty without a filter)
+    filter_matches: Vec<bool>,
+    /// Outlier and nonce deficit thresholds the chip colors are scaled to
+    analysis_config: AnalysisConfig,
+    /// Language of the hover tooltip
+    lang: Language,
+}
+
+/// Domain nonce histogram and bin for every chip of a slot
+fn nonce_histograms(slot: &Slot, cpd: usize) -> Vec<([u8; NONCE_HIST_BINS], usize)> {
+    let domains: Vec<_> = (0..slot.chips.len().div_ceil(cpd.max(1)))
+        .map(|domain| analysis::compute_domain_nonce_histogram(&slot.chips, cpd, domain))
+        .collect();
+    (0..slot.chips.len())
+        .map(|idx| {
+            (
+                domains[idx / cpd.max(1)],
+                analysis::domain_nonce_bin(&slot.chips, cpd, idx),
+            )
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct CanvasState {
+    hovered: Option<usize>,
+    /// Chip the left button went down on; releasing over another chip selects the box between them
+    drag_from: Option<usize>,
+    /// Chip whose right-click menu is open
+    menu: Option<usize>,
+}
+
+impl canvas::Program<Message> for ChipCanvas<'_> {
+    type State = CanvasState;
+
+    fn update(
+        &self,
+        state: &mut CanvasState,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<Action<Message>> {
+        let under_cursor = cursor
+            .position_in(bounds)
+            .and_then(|p| self.cells.hit_test(p));
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                if under_cursor == state.hovered {
+                    return None;
+                }
+                state.hovered = under_cursor;
+                Some(Action::request_redraw())
+            }
+            // Diff grids show another capture's chips, nothing to mark there
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if self.deltas.is_none() =>
+            {
+                let idx = under_cursor?;
+                state.menu = Some(idx);
+                Some(Action::request_redraw().and_capture())
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if state.menu.is_some() =>
+            {
+                let idx = state.menu.take()?;
+                let on_item = cursor
+                    .position_in(bounds)
+                    .is_some_and(|p| self.menu_rect(idx, bounds.size()).contains(p));
+                match self.slot.chips.get(idx) {
+                    Some(chip) if on_item => Some(
+                        Action::publish(Message::MarkChipReplaced(self.slot.id, chip.id))
+                            .and_capture(),
+                    ),
+                    // Clicking anywhere else just closes the menu
+                    _ => Some(Action::request_redraw().and_capture()),
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.drag_from = Some(under_cursor?);
+                Some(Action::request_redraw().and_capture())
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let from = state.drag_from.take()?;
+                let message = match under_cursor {
+                    Some(to) if to == from => Message::ChipSelected(self.slot.id, from),
+                    Some(to) => {
+                        Message::ChipsBoxSelected(self.slot.id, self.cells.within_box(from, to))
+                    }
+                    // Released outside the grid: drop the box
+                    None => return Some(Action::request_redraw()),
+                };
+                Some(Action::publish(message).and_capture())
+            }
+            _ => None,
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &CanvasState,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        for (idx, (chip, &cell)) in self.slot.chips.iter().zip(&self.cells.cells).enumerate() {
+            self.draw_chip(&mut frame, idx, chip, self.cells.origin(cell));
+        }
+
+        for group in &self.groups {
+            self.draw_group(&mut frame, group);
+        }
+        for &idx in &self.signal_breaks {
+            self.draw_signal_break(&mut frame, idx);
+        }
+        for &idx in &self.crc_breaks {
+            self.draw_crc_break(&mut frame, idx);
+        }
+        for cluster in &self.clusters {
+            self.draw_cluster_outline(&mut frame, cluster);
+        }
+
+        // While dragging, preview the box under the cursor instead of the stored selection
+        let box_chips = match (state.drag_from, state.hovered) {
+            (Some(from), Some(to)) if from != to => self.cells.within_box(from, to),
+            _ => self.box_selection.clone(),
+        };
+        for idx in box_chips {
+            self.draw_accent_border(&mut frame, idx, 2.0);
+        }
+        if let Some(idx) = self.selected {
+            self.draw_accent_border(&mut frame, idx, 3.0);
+        }
+
+        // Menu and tooltip last so they sit above neighboring chips
+        if let Some(idx) = state.menu {
+            self.draw_menu(&mut frame, idx, bounds.size());
+        } else if let Some(idx) = state.hovered
+            && let (Some(chip), Some(&cell)) = (self.slot.chips.get(idx), self.cells.cells.get(idx))
+            && !chip.placeholder
+        {
+            let domain = idx / self.chips_per_domain.max(1);
+            let mut label = chip_label(
+                chip,
+                domain,
+                self.color_mode,
+                self.analysis.get(idx).copied(),
+                self.lang,
+            );
+            if let Some(replacement) = self.replacements.iter().find(|r| r.chip_id == chip.id) {
+                label = format!(
+                    "{label}  {}",
+                    super::replacement_text(replacement, self.lang)
+                );
+            }
+            let origin = self.cells.origin(cell);
+            // Diff grids show another capture's chips: no error counters to compare
+            if self.deltas.is_some() {
+                draw_tooltip(
+                    &mut frame,
+                    &label,
+                    origin,
+                    self.cells.chip_size,
+                    bounds.size(),
+                );
+            } else {
+                draw_error_breakdown_tooltip(
+                    &mut frame,
+                    &label,
+                    &analysis::error_breakdown(chip, self.slot),
+                    &nonce_timeline_densities(chip, self.slot),
+                    origin,
+                    self.cells.chip_size,
+                    bounds.size(),
+                );
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &CanvasState,
+        _bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if state.drag_from.is_some() {
+            mouse::Interaction::Crosshair
+        } else if state.hovered.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl ChipCanvas<'_> {
+    fn menu_label(&self) -> String {
+        format!("🔧 {}", Tr::mark_replaced(self.lang))
+    }
+
+    /// Context menu box of chip `idx`, kept inside the canvas
+    #[allow(clippy::cast_precision_loss)] // label lengths fit in f32
+    fn menu_rect(&self, idx: usize, canvas_size: Size) -> Rectangle {
+        let width = self.menu_label().chars().count() as f32 * TOOLTIP_CHAR_WIDTH + 16.0;
+        let half = self.cells.chip_size / 2.0;
+        let origin = self
+            .cells
+            .cells
+            .get(idx)
+            .map_or(Point::ORIGIN, |&cell| self.cells.origin(cell));
+        Rectangle::new(
+            Point::new(
+                (origin.x + half).min((canvas_size.width - width).max(0.0)),
+                (origin.y + half).min((canvas_size.height - MENU_ITEM_HEIGHT).max(0.0)),
+            ),
+            Size::new(width, MENU_ITEM_HEIGHT),
+        )
+    }
+
+    fn draw_menu(&self, frame: &mut Frame, idx: usize, canvas_size: Size) {
+        let rect = self.menu_rect(idx, canvas_size);
+        let (bg, _) = theme::tooltip_colors();
+        let path = Path::rounded_rectangle(rect.position(), rect.size(), CHIP_RADIUS.into());
+        frame.fill(&path, bg);
+        frame.stroke(
+            &path,
+            Stroke::default()
+                .with_color(theme::BRAND_ORANGE)
+                .with_width(1.0),
+        );
+        frame.fill_text(canvas::Text {
+            content: self.menu_label(),
+            position: Point::new(rect.x + 8.0, rect.center_y()),
+            color: Color::WHITE,
+            size: TOOLTIP_TEXT_SIZE.into(),
+            align_y: Vertical::Center,
+            ..Default::default()
+        });
+    }
+
+    /// Tint a group's cells and tag its top-left cell with the label
+    #[allow(clippy::cast_precision_loss)] // label lengths fit in f32
+    fn draw_group(&self, frame: &mut Frame, group: &ChipGroupAnnotation) {
+        // Cells grow by half the spacing on each side so a region tints as one block
+        let pad = CHIP_SPACING / 2.0;
+        let tile = Size::new(self.cells.pitch(), self.cells.pitch());
+        let mut label_at: Option<Point> = None;
+        for (idx, &cell) in self.cells.cells.iter().enumerate() {
+            if !group.contains(idx, self.chips_per_domain) {
+                continue;
+            }
+            let origin = self.cells.origin(cell);
+            frame.fill_rectangle(
+                Point::new(origin.x - pad, origin.y - pad),
+                tile,
+                theme::group_fill(group.color),
+            );
+            if label_at.is_none_or(|p| (origin.y, origin.x) < (p.y, p.x)) {
+                label_at = Some(origin);
+            }
+        }
+
+        let Some(origin) = label_at else {
+            return;
+        };
+        let width = group.label.chars().count() as f32 * GROUP_LABEL_CHAR_WIDTH + 6.0;
+        frame.fill_rectangle(
+            origin,
+            Size::new(width, GROUP_LABEL_HEIGHT),
+            theme::group_color(group.color),
+        );
+        frame.fill_text(canvas::Text {
+            content: group.label.clone(),
+            position: Point::new(origin.x + 3.0, origin.y + GROUP_LABEL_HEIGHT / 2.0),
+            color: Color::BLACK,
+            size: GROUP_LABEL_SIZE.into(),
+            align_y: Vertical::Center,
+            ..Default::default()
+        });
+    }
+
+    fn draw_accent_border(&self, frame: &mut Frame, idx: usize, width: f32) {
+        let Some(&cell) = self.cells.cells.get(idx) else {
+            return;
+        };
+        let chip_size = self.cells.chip_size;
+        let path = Path::rounded_rectangle(
+            self.cells.origin(cell),
+            Size::new(chip_size, chip_size),
+            CHIP_RADIUS.into(),
+        );
+        frame.stroke(
+            &path,
+            Stroke::default()
+                .with_color(theme::BRAND_ORANGE)
+                .with_width(width),
+        );
+    }
+
+    /// Rounded rectangle around the bounding box of a cluster's cells
+    fn draw_cluster_outline(&self, frame: &mut Frame, cluster: &ChipCluster) {
+        let origins: Vec<Point> = cluster
+            .chips
+            .iter()
+            .filter_map(|&idx| self.cells.cells.get(idx))
+            .map(|&cell| self.cells.origin(cell))
+            .collect();
+        let (Some(min_x), Some(min_y), Some(max_x), Some(max_y)) = (
+            origins.iter().map(|p| p.x).reduce(f32::min),
+            origins.iter().map(|p| p.y).reduce(f32::min),
+            origins.iter().map(|p| p.x).reduce(f32::max),
+            origins.iter().map(|p| p.y).reduce(f32::max),
+        ) else {
+            return;
+        };
+        // Sits in the spacing around the cells
+        let pad = CHIP_SPACING / 2.0;
+        let size = self.cells.chip_size;
+        let path = Path::rounded_rectangle(
+            Point::new(min_x - pad, min_y - pad),
+            Size::new(
+                max_x - min_x + size + 2.0 * pad,
+                max_y - min_y + size + 2.0 * pad,
+            ),
+            (CHIP_RADIUS + pad).into(),
+        );
+        frame.stroke(
+            &path,
+            Stroke::default()
+                .with_color(theme::error_color())
+                .with_width(2.0),
+        );
+    }
+
+    /// Red slash across the gap between chip `idx - 1` and chip `idx`
+    fn draw_signal_break(&self, frame: &mut Frame, idx: usize) {
+        let (Some(&before), Some(&after)) = (
+            self.cells.cells.get(idx.wrapping_sub(1)),
+            self.cells.cells.get(idx),
+        ) else {
+            return;
+        };
+        let half = self.cells.chip_size / 2.0;
+        let (a, b) = (self.cells.origin(before), self.cells.origin(after));
+        let mid = Point::new((a.x + b.x) / 2.0 + half, (a.y + b.y) / 2.0 + half);
+        // Rises left to right across the gap between vertically adjacent cells
+        let (dx, dy) = (half * SIGNAL_BREAK_REACH, CHIP_SPACING * 2.0);
+        frame.stroke(
+            &Path::line(
+                Point::new(mid.x - dx, mid.y + dy),
+                Point::new(mid.x + dx, mid.y - dy),
+            ),
+            Stroke::default()
+                .with_color(theme::error_color())
+                .with_width(3.0),
+        );
+    }
+
+    /// Red bar across the gap between chip `idx` and chip `idx + 1`, perpendicular to the chain
+    fn draw_crc_break(&self, frame: &mut Frame, idx: usize) {
+        let (Some(&before), Some(&after)) =
+            (self.cells.cells.get(idx), self.cells.cells.get(idx + 1))
+        else {
+            return;
+        };
+        let half = self.cells.chip_size / 2.0;
+        let (a, b) = (self.cells.origin(before), self.cells.origin(after));
+        let mid = Point::new((a.x + b.x) / 2.0 + half, (a.y + b.y) / 2.0 + half);
+        let reach = half * CRC_BREAK_REACH;
+        let (from, to) = if before.0 == after.0 {
+            // Same column: the chips are stacked, so the bar runs across
+            (
+                Point::new(mid.x - reach, mid.y),
+                Point::new(mid.x + reach, mid.y),
+            )
+        } else {
+            (
+                Point::new(mid.x, mid.y - reach),
+                Point::new(mid.x, mid.y + reach),
+            )
+        };
+        frame.stroke(
+            &Path::line(from, to),
+            Stroke::default()
+                .with_color(theme::error_color())
+                .with_width(3.0),
+        );
+    }
+
+    fn draw_chip(&self, frame: &mut Frame, idx: usize, chip: &Chip, origin: Point) {
+        if chip.placeholder {
+            draw_placeholder_chip(frame, origin, self.cells.chip_size);
+            return;
+        }
+        let slot_freq = self.slot.freq;
+        let delta = self.deltas.as_ref().and_then(|d| d.get(idx)).copied();
+        let alpha = if self.filter_matches.get(idx) == Some(&false) {
+            FILTERED_OUT_ALPHA
+        } else {
+            1.0
+        };
+        let (bg, border) = match delta {
+            Some(delta) => theme::chip_colors_for_delta(delta),
+            None => theme::chip_colors(
+                chip,
+                slot_freq,
+                self.color_mode,
+                self.analysis.get(idx).copied(),
+                &self.analysis_config,
+            ),
+        };
+        let (bg, border) = (bg.scale_alpha(alpha), border.scale_alpha(alpha));
+        let chip_size = self.cells.chip_size;
+        let path =
+            Path::rounded_rectangle(origin, Size::new(chip_size, chip_size), CHIP_RADIUS.into());
+        frame.fill(&path, bg);
+        if let Some(&Some([r, g, b])) = self.ir_colors.get(idx) {
+            frame.fill(
+                &path,
+                Color::from_rgb8(r, g, b).scale_alpha(IR_OVERLAY_ALPHA * alpha),
+            );
+        }
+        frame.stroke(&path, Stroke::default().with_color(border).with_width(1.5));
+
+        // Freq modes show deviation from the slot target instead of absolute freq
+        let freq_label = if matches!(
+            self.color_mode,
+            ColorMode::FreqRelative | ColorMode::FreqCompliance
+        ) {
+            format!("{:+}", chip.freq - slot_freq)
+        } else {
+            chip.freq.to_string()
+        };
+
+        let center_x = origin.x + chip_size / 2.0;
+        let lines = [
+            (format!("{freq_label}  {}", chip.vol), 10.0, 3.0),
+            (
+                delta.map_or_else(|| chip.temp.to_string(), |d| format!("{d:+}")),
+                20.0,
+                16.0,
+            ),
+            (
+                format!("{} {} {} {}", chip.errors, chip.crc, chip.x, chip.repeat),
+                9.0,
+                41.0,
+            ),
+        ];
+        for (content, size, offset_y) in lines {
+            frame.fill_text(canvas::Text {
+                content,
+                position: Point::new(center_x, origin.y + offset_y),
+                color: Color::WHITE.scale_alpha(alpha),
+                size: size.into(),
+                align_x: Alignment::Center,
+                align_y: Vertical::Top,
+                ..Default::default()
+            });
+        }
+
+        if let Some(&(bins, own_bin)) = self.nonce_hists.as_ref().and_then(|h| h.get(idx)) {
+            draw_mini_hist(frame, &bins, own_bin, border, origin, chip_size);
+        }
+        if self
+            .analysis
+            .get(idx)
+            .is_some_and(|a| a.data_staleness_polls > analysis::STALE_DATA_POLLS)
+        {
+            draw_stale_marker(frame, origin, chip_size);
+        }
+    }
+}
+
+/// Gray "—" cell for a chip id the firmware skipped
+fn draw_placeholder_chip(frame: &mut Frame, origin: Point, chip_size: f32) {
+    let (bg, border, dash) = theme::chip_cell_placeholder();
+    let path = Path::rounded_rectangle(origin, Size::new(chip_size, chip_size), CHIP_RADIUS.into());
+    frame.fill(&path, bg);
+    frame.stroke(&path, Stroke::default().with_color(border).with_width(1.5));
+    frame.fill_text(canvas::Text {
+        content: "—".into(),
+        position: Point::new(origin.x + chip_size / 2.0, origin.y + chip_size / 2.0),
+        color: dash,
+        size: 20.0.into(),
+        align_x: Alignment::Center,
+        align_y: Vertical::Center,
+        ..Default::default()
+    });
+}
+
+/// Folded top-right corner on a chip cell whose readings have stopped changing
+fn draw_stale_marker(frame: &mut Frame, origin: Point, chip_size: f32) {
+    let right = origin.x + chip_size;
+    let corner = Path::new(|p| {
+        p.move_to(Point::new(right - STALE_MARKER_SIZE, origin.y));
+        p.line_to(Point::new(right, origin.y));
+        p.line_to(Point::new(right, origin.y + STALE_MARKER_SIZE));
+        p.close();
+    });
+    frame.fill(&corner, theme::stale_marker_color());
+}
+
+/// Tiny bar chart along the bottom of a chip cell; the chip's own bin uses its border color
+#[allow(clippy::cast_precision_loss)] // bin counts and indices fit in f32
+fn draw_mini_hist(
+    frame: &mut Frame,
+    bins: &[u8; NONCE_HIST_BINS],
+    own_bin: usize,
+    color: Color,
+    origin: Point,
+    chip_size: f32,
+) {
+    let max = f32::from(bins.iter().copied().max().unwrap_or(0).max(1));
+    let width =
+        NONCE_HIST_BINS as f32 * (MINI_HIST_BAR_WIDTH + MINI_HIST_BAR_GAP) - MINI_HIST_BAR_GAP;
+    let left = origin.x + (chip_size - width) / 2.0;
+    let bottom = origin.y + chip_size - 3.0;
+    for (bin, &count) in bins.iter().enumerate() {
+        let height = (f32::from(count) / max * MINI_HIST_HEIGHT).max(1.0);
+        let x = left + bin as f32 * (MINI_HIST_BAR_WIDTH + MINI_HIST_BAR_GAP);
+        let bar = Path::rectangle(
+            Point::new(x, bottom - height),
+            Size::new(MINI_HIST_BAR_WIDTH, height),
+        );
+        let fill = if bin == own_bin {
+            color
+        } else {
+            theme::mini_hist_muted()
+        };
+        frame.fill(&bar, fill);
+    }
+}
+
+/// Hover label: chip and domain; Gradient mode adds the hot-spot size and how
+/// well neighbors support it, Temp σ mode the deviation from the learned baseline,
+/// Dead density mode the share of dead chips nearby, Power state mode the state;
+/// chips repeating the same readings for over STALE_DATA_POLLS polls say so, and
+/// chips on an aligned IR photo add its reading
+fn chip_label(
+    chip: &Chip,
+    domain: usize,
+    color_mode: ColorMode,
+    analysis: Option<ChipAnalysis>,
+    lang: Language,
+) -> String {
+    let domain = i32::try_from(domain).unwrap_or(i32::MAX);
+    let label = format!(
+        "{} · {}",
+        Tr::chip_label(chip.id, lang),
+        Tr::domain_label(domain, lang)
+    );
+    let label = match analysis {
+        Some(a) if color_mode == ColorMode::Gradient && a.gradient > 0.0 => format!(
+            "{label}  +{:.1}°C (sig {:.0})",
+            a.gradient, a.gradient_significance
+        ),
+        Some(a) if color_mode == ColorMode::TempAnomaly && a.temp_anomaly_score != 0.0 => {
+            format!("{label}  {:+.1}σ", a.temp_anomaly_score)
+        }
+        Some(a) if color_mode == ColorMode::DeadDensity && a.dead_density > 0.0 => format!(
+            "{label}  {:.0}% {}",
+            a.dead_density * 100.0,
+            Tr::dead_neighbors(lang)
+        ),
+        Some(a) if color_mode == ColorMode::PowerState && !chip.placeholder => {
+            format!("{label}  {}", Tr::power_state(a.power_state, lang))
+        }
+        Some(a) if color_mode == ColorMode::VoltageGroup && chip.vol > 0 => format!(
+            "{label}  {} {} ({})",
+            Tr::voltage_group(lang),
+            a.voltage_group + 1,
+            chip.vol
+        ),
+        _ => label,
+    };
+    let label = match analysis {
+        Some(a) if a.data_staleness_polls > analysis::STALE_DATA_POLLS => format!(
+            "{label}  ⏸ {}: {}",
+            Tr::data_stale(lang),
+            Tr::polls_count(a.data_staleness_polls as usize, lang)
+        ),
+        _ => label,
+    };
+    let label = match analysis {
+        Some(a) if a.heat_cycles > 0 => {
+            format!("{label}  {}: {}", Tr::est_heat_cycles(lang), a.heat_cycles)
+        }
+        _ => label,
+    };
+    match analysis {
+        Some(a) if ir::is_sensor_mismatch(chip.temp, &a) => format!(
+            "{label}  ⚠ {}: IR {:.0}°C",
+            Tr::sensor_mismatch(lang),
+            a.ir_temp.unwrap_or_default()
+        ),
+        Some(ChipAnalysis {
+            ir_temp: Some(ir_temp),
+            ..
+        }) => format!("{label}  IR {ir_temp:.0}°C"),
+        _ => label,
+    }
+}
+
+/// Draw a label box above the chip (below it on the first row), kept inside the canvas
+#[allow(clippy::cast_precision_loss)] // label lengths fit in f32
+pub(super) fn draw_tooltip(
+    frame: &mut Frame,
+    label: &str,
+    chip_origin: Point,
+    chip_size: f32,
+    canvas_size: Size,
+) {
+    let width = label.chars().count() as f32 * TOOLTIP_CHAR_WIDTH + 10.0;
+    let Point { x, y } = draw_tooltip_box(
+        frame,
+        Size::new(width, TOOLTIP_HEIGHT),
+        chip_origin,
+        chip_size,
+        canvas_size,
+    );
+    draw_tooltip_label(frame, label, x + width / 2.0, y);
+}
+
+/// A chip's nonce timeline segments relative to the slot's mean chip, 1 = on par
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)] // nonce counts fit in f32
+fn nonce_timeline_densities(chip: &Chip, slot: &Slot) -> Vec<f32> {
+    let window = analysis::NONCE_TIMELINE_WINDOW_SECS;
+    let mean_chip = Chip {
+        nonce: analysis::compute_slot_avg_nonce(&slot.chips).round() as i64,
+        ..Chip::default()
+    };
+    let expected = analysis::compute_nonce_timeline(&mean_chip, slot.nonce_rate, window);
+    analysis::compute_nonce_timeline(chip, slot.nonce_rate, window)
+        .iter()
+        .zip(&expected)
+        .map(|(&n, &mean)| n as f32 / mean.max(1) as f32)
+        .collect()
+}
+
+/// Tooltip with the label over one bar per error counter (err, crc, x, repeat),
+/// each scaled against twice the slot average with a tick at the average, and a
+/// nonce timeline row: one segment per slice of the window, colored by density
+#[allow(clippy::cast_precision_loss)] // label lengths and counters fit in f32
+fn draw_error_breakdown_tooltip(
+    frame: &mut Frame,
+    label: &str,
+    bars: &[ErrorBar],
+    timeline: &[f32],
+    chip_origin: Point,
+    chip_size: f32,
+    canvas_size: Size,
+) {
+    let rows_width = BREAKDOWN_NAME_WIDTH + BREAKDOWN_BAR_WIDTH + BREAKDOWN_VALUE_WIDTH;
+    let width = (label.chars().count() as f32 * TOOLTIP_CHAR_WIDTH).max(rows_width) + 10.0;
+    let rows = bars.len() + usize::from(!timeline.is_empty());
+    let height = TOOLTIP_HEIGHT + rows as f32 * BREAKDOWN_ROW_HEIGHT + 4.0;
+    let Point { x, y } = draw_tooltip_box(
+        frame,
+        Size::new(width, height),
+        chip_origin,
+        chip_size,
+        canvas_size,
+    );
+    draw_tooltip_label(frame, label, x + width / 2.0, y);
+
+    let small_text = |content: String, position: Point, align_x: Alignment| canvas::Text {
+        content,
+        position,
+        color: Color::WHITE,
+        size: BREAKDOWN_TEXT_SIZE.into(),
+        align_x,
+        align_y: Vertical::Center,
+        ..Default::default()
+    };
+    for (row, bar) in bars.iter().enumerate() {
+        let left = x + 5.0;
+        let center_y = y + TOOLTIP_HEIGHT + (row as f32 + 0.5) * BREAKDOWN_ROW_HEIGHT;
+        frame.fill_text(small_text(
+            bar.field.to_string(),
+            Point::new(left, center_y),
+            Alignment::Left,
+        ));
+
+        let bar_x = left + BREAKDOWN_NAME_WIDTH;
+        let bar_y = center_y - BREAKDOWN_BAR_HEIGHT / 2.0;
+        let scale = (bar.value as f32).max(bar.slot_avg * 2.0).max(1.0);
+        frame.fill_rectangle(
+            Point::new(bar_x, bar_y),
+            Size::new(BREAKDOWN_BAR_WIDTH, BREAKDOWN_BAR_HEIGHT),
+            theme::mini_hist_muted().scale_alpha(0.4),
+        );
+        frame.fill_rectangle(
+            Point::new(bar_x, bar_y),
+            Size::new(
+                BREAKDOWN_BAR_WIDTH * (bar.value.max(0) as f32 / scale),
+                BREAKDOWN_BAR_HEIGHT,
+            ),
+            theme::error_bar_color(bar.value, bar.slot_avg),
+        );
+        let avg_x = bar_x + BREAKDOWN_BAR_WIDTH * (bar.slot_avg / scale);
+        frame.fill_rectangle(
+            Point::new(avg_x - 0.5, bar_y - 2.0),
+            Size::new(1.0, BREAKDOWN_BAR_HEIGHT + 4.0),
+            Color::WHITE,
+        );
+
+        frame.fill_text(small_text(
+            bar.value.to_string(),
+            Point::new(bar_x + BREAKDOWN_BAR_WIDTH + 6.0, center_y),
+            Alignment::Left,
+        ));
+    }
+
+    if !timeline.is_empty() {
+        let left = x + 5.0;
+        let center_y = y + TOOLTIP_HEIGHT + (bars.len() as f32 + 0.5) * BREAKDOWN_ROW_HEIGHT;
+        frame.fill_text(small_text(
+            "nonce".into(),
+            Point::new(left, center_y),
+            Alignment::Left,
+        ));
+        let bar_x = left + BREAKDOWN_NAME_WIDTH;
+        let bar_y = center_y - BREAKDOWN_BAR_HEIGHT / 2.0;
+        let segment = BREAKDOWN_BAR_WIDTH / timeline.len() as f32;
+        for (i, &density) in timeline.iter().enumerate() {
+            frame.fill_rectangle(
+                Point::new(bar_x + i as f32 * segment, bar_y),
+                Size::new((segment - 1.0).max(1.0), BREAKDOWN_BAR_HEIGHT),
+                theme::nonce_density_color(density),
+            );
+        }
+        frame.fill_text(small_text(
+            format!("{}s", analysis::NONCE_TIMELINE_WINDOW_SECS),
+            Point::new(bar_x + BREAKDOWN_BAR_WIDTH + 6.0, center_y),
+            Alignment::Left,
+        ));
+    }
+}
+
+/// Tooltip background above the chip (below it when there's no room), kept inside
+/// the canvas; returns its top-left corner
+fn draw_tooltip_box(
+    frame: &mut Frame,
+    size: Size,
+    chip_origin: Point,
+    chip_size: f32,
+    canvas_size: Size,
+) -> Point {
+    let x = (chip_origin.x + (chip_size - size.width) / 2.0)
+        .clamp(0.0, (canvas_size.width - size.width).max(0.0));
+    let y = if chip_origin.y >= size.height + 2.0 {
+        chip_origin.y - size.height - 2.0
+    } else {
+        (chip_origin.y + chip_size + 2.0).min((canvas_size.height - size.height).max(0.0))
+    };
+
+    let (bg, border) = theme::tooltip_colors();
+    let path = Path::rounded_rectangle(Point::new(x, y), size, CHIP_RADIUS.into());
+    frame.fill(&path, bg);
+    frame.stroke(&path, Stroke::default().with_color(border).with_width(1.0));
+    Point::new(x, y)
+}
+
+/// Tooltip label line, centered on `center_x` in the box's first TOOLTIP_HEIGHT
+fn draw_tooltip_label(frame: &mut Frame, label: &str, center_x: f32, y: f32) {
+    frame.fill_text(canvas::Text {
+        content: label.to_string(),
+        position: Point::new(center_x, y + TOOLTIP_HEIGHT / 2.0),
+        color: Color::WHITE,
+        size: TOOLTIP_TEXT_SIZE.into(),
+        align_x: Alignment::Center,
+        align_y: Vertical::Center,
+        ..Default::default()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snake_layout_cells() {
+        // 4 domains × 2 chips: bottom = D0, D1 (right to left), top = D2, D3 (left to right)
+        let cells = CellLayout::new(8, 2, GridLayout::Snake, CHIP_SIZE);
+        assert_eq!(
+            (cells.columns, cells.rows, cells.split_row),
+            (2, 4, Some(2))
+        );
+        assert_eq!(cells.cells[0], (1, 2)); // C0 bottom-right
+        assert_eq!(cells.cells[2], (0, 2)); // D1 left of D0
+        assert_eq!(cells.cells[4], (0, 0)); // D2 top-left
+        assert_eq!(cells.cells[7], (1, 1));
+    }
+
+    #[test]
+    fn test_rotated_layout_cells() {
+        // Turned 180°: D0 C0 moves to the top-left, the top section to the bottom
+        let cells = CellLayout::new(8, 2, GridLayout::Snake, CHIP_SIZE).rotated();
+        assert_eq!(
+            (cells.columns, cells.rows, cells.split_row),
+            (2, 4, Some(2))
+        );
+        assert_eq!(cells.cells[0], (0, 1));
+        assert_eq!(cells.cells[2], (1, 1)); // D1 right of D0
+        assert_eq!(cells.cells[4], (1, 3)); // D2 bottom-right
+        assert_eq!(cells.hit_test(Point::new(5.0, 5.0)), Some(1));
+    }
+
+    #[test]
+    fn test_linked_layout_cells() {
+        let cells = CellLayout::new(8, 2, GridLayout::Linked, CHIP_SIZE);
+        assert_eq!((cells.columns, cells.rows), (2, 4));
+        assert_eq!(cells.cells[0], (1, 1)); // C0 at bottom-right of the right half
+        assert_eq!(cells.cells[4], (0, 2)); // D2 starts the left half
+    }
+
+    #[test]
+    fn test_hit_test() {
+        let cells = CellLayout::new(8, 2, GridLayout::Snake, CHIP_SIZE);
+        // Inside C0's cell (second column, third row, below the section gap)
+        let c0 = cells.origin((1, 2));
+        assert_eq!(cells.hit_test(Point::new(c0.x + 5.0, c0.y + 5.0)), Some(0));
+        // Spacing between cells hits nothing
+        assert_eq!(
+            cells.hit_test(Point::new(CHIP_SIZE + CHIP_SPACING / 2.0, 5.0)),
+            None
+        );
+        assert_eq!(cells.hit_test(Point::new(5.0, 5.0)), Some(4));
+    }
+
+    #[test]
+    fn test_within_box() {
+        let cells = CellLayout::new(8, 2, GridLayout::Snake, CHIP_SIZE);
+        // C0 (1, 2) to C3 (0, 3): the whole bottom section
+        assert_eq!(cells.within_box(0, 3), vec![0, 1, 2, 3]);
+        // Same column across both sections: D2 C4 (0, 0) down to D1 C2 (0, 2)
+        assert_eq!(cells.within_box(2, 4), vec![2, 4, 5]);
+        assert_eq!(cells.within_box(6, 6), vec![6]);
+        assert!(cells.within_box(0, 99).is_empty());
+    }
+}
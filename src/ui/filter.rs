@@ -0,0 +1,216 @@
+//! Chip search expressions such as `temp>90 errors>50`
+//!
+//! An expression is a list of `field op value` conditions separated by spaces (an
+//! optional `and` between them is accepted); a chip matches when it meets all of them.
+
+use std::collections::HashMap;
+
+use crate::analysis::ChipAnalysis;
+use crate::models::{Chip, MinerData};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterField {
+    Temp,
+    Errors,
+    Crc,
+    Nonce,
+    Gradient,
+    Zscore,
+    Health,
+}
+
+impl FilterField {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "temp" => Some(Self::Temp),
+            "errors" => Some(Self::Errors),
+            "crc" => Some(Self::Crc),
+            "nonce" => Some(Self::Nonce),
+            "gradient" => Some(Self::Gradient),
+            "zscore" => Some(Self::Zscore),
+            "health" => Some(Self::Health),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)] // nonce counts are compared approximately
+    fn value(self, chip: &Chip, analysis: &ChipAnalysis) -> f64 {
+        match self {
+            Self::Temp => f64::from(chip.temp),
+            Self::Errors => f64::from(chip.errors),
+            Self::Crc => f64::from(chip.crc),
+            Self::Nonce => chip.nonce as f64,
+            Self::Gradient => f64::from(analysis.gradient),
+            Self::Zscore => f64::from(analysis.cross_slot_zscore),
+            Self::Health => f64::from(analysis.health_score),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Lt,
+    Gt,
+    Eq,
+    Ge,
+    Le,
+}
+
+impl CompareOp {
+    fn holds(self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Self::Lt => lhs < rhs,
+            Self::Gt => lhs > rhs,
+            Self::Eq => (lhs - rhs).abs() < f64::EPSILON,
+            Self::Ge => lhs >= rhs,
+            Self::Le => lhs <= rhs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FilterCondition {
+    pub field: FilterField,
+    pub op: CompareOp,
+    pub value: f64,
+}
+
+impl FilterCondition {
+    /// One `field op value` term, e.g. `temp>=90`
+    fn parse(term: &str) -> Option<Self> {
+        let op_start = term.find(['<', '>', '='])?;
+        let (name, rest) = term.split_at(op_start);
+        let (op, value) = if let Some(v) = rest.strip_prefix(">=") {
+            (CompareOp::Ge, v)
+        } else if let Some(v) = rest.strip_prefix("<=") {
+            (CompareOp::Le, v)
+        } else if let Some(v) = rest.strip_prefix('>') {
+            (CompareOp::Gt, v)
+        } else if let Some(v) = rest.strip_prefix('<') {
+            (CompareOp::Lt, v)
+        } else {
+            (CompareOp::Eq, rest.strip_prefix('=')?)
+        };
+        Some(Self {
+            field: FilterField::parse(name)?,
+            op,
+            value: value.parse().ok().filter(|v: &f64| v.is_finite())?,
+        })
+    }
+}
+
+/// Conditions that must all hold for a chip to match
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterExpr {
+    pub conditions: Vec<FilterCondition>,
+}
+
+impl FilterExpr {
+    pub fn matches(&self, chip: &Chip, analysis: &ChipAnalysis) -> bool {
+        self.conditions
+            .iter()
+            .all(|c| c.op.holds(c.field.value(chip, analysis), c.value))
+    }
+}
+
+/// Parse a search expression; None when it is empty or any term is malformed
+pub fn parse_filter_expr(s: &str) -> Option<FilterExpr> {
+    // Glue operators to their operands so `temp > 90` reads as `temp>90`
+    let mut compact = String::with_capacity(s.len());
+    for c in s.chars() {
+        let after_op = compact.ends_with(['<', '>', '=']);
+        if c.is_whitespace() && after_op {
+            continue;
+        }
+        if matches!(c, '<' | '>' | '=') {
+            compact.truncate(compact.trim_end().len());
+        }
+        compact.push(c);
+    }
+    let conditions = compact
+        .split_whitespace()
+        .filter(|term| !term.eq_ignore_ascii_case("and") && *term != "&&")
+        .map(FilterCondition::parse)
+        .collect::<Option<Vec<_>>>()?;
+    (!conditions.is_empty()).then_some(FilterExpr { conditions })
+}
+
+/// Per-chip match flags by slot id, parallel to each slot's chips
+pub fn match_flags(
+    expr: &FilterExpr,
+    data: &MinerData,
+    all_analysis: &[Vec<ChipAnalysis>],
+) -> HashMap<i32, Vec<bool>> {
+    data.slots
+        .iter()
+        .enumerate()
+        .map(|(slot_idx, slot)| {
+            let flags = slot
+                .chips
+                .iter()
+                .enumerate()
+                .map(|(chip_idx, chip)| {
+                    let analysis = all_analysis
+                        .get(slot_idx)
+                        .and_then(|a| a.get(chip_idx))
+                        .copied()
+                        .unwrap_or_default();
+                    expr.matches(chip, &analysis)
+                })
+                .collect();
+            (slot.id, flags)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filter_expr() {
+        let expr = parse_filter_expr("temp > 90 and errors>=50  health<70").unwrap();
+        assert_eq!(
+            expr.conditions,
+            vec![
+                FilterCondition {
+                    field: FilterField::Temp,
+                    op: CompareOp::Gt,
+                    value: 90.0,
+                },
+                FilterCondition {
+                    field: FilterField::Errors,
+                    op: CompareOp::Ge,
+                    value: 50.0,
+                },
+                FilterCondition {
+                    field: FilterField::Health,
+                    op: CompareOp::Lt,
+                    value: 70.0,
+                },
+            ]
+        );
+        assert!(parse_filter_expr("").is_none());
+        assert!(parse_filter_expr("voltage>300").is_none());
+        assert!(parse_filter_expr("temp>hot").is_none());
+        assert!(parse_filter_expr("temp 90").is_none());
+
+        let hot = Chip {
+            temp: 95,
+            errors: 60,
+            ..Chip::default()
+        };
+        let analysis = ChipAnalysis {
+            health_score: 50.0,
+            ..ChipAnalysis::default()
+        };
+        assert!(expr.matches(&hot, &analysis));
+        let cool = Chip { temp: 80, ..hot };
+        assert!(!expr.matches(&cool, &analysis));
+        assert!(
+            parse_filter_expr("temp=80")
+                .unwrap()
+                .matches(&cool, &analysis)
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! IR photo drawn on a canvas: click a spot to pair it with the selected chip
+//!
+//! Calibration points are numbered on the photo, and once the photo is aligned the
+//! outline of the slot's chip grid is projected onto it.
+
+use iced::{
+    Color, Element, Length, Point, Rectangle, Renderer, Theme,
+    alignment::Vertical,
+    mouse,
+    widget::{
+        canvas::{self, Action, Event, Frame, Path, Stroke},
+        text::Alignment,
+    },
+};
+
+use crate::Message;
+use crate::ir::{IrAlignment, IrImage};
+use crate::theme;
+
+/// Width the photo is scaled to in the IR panel
+const IR_VIEW_WIDTH: f32 = 360.0;
+const MARKER_RADIUS: f32 = 7.0;
+const PENDING_ARM: f32 = 8.0;
+
+/// The IR photo with the calibration points, the clicked spot waiting for a chip,
+/// and the projected outline of the aligned grid (`grid_corners`, photo pixels)
+pub fn ir_photo<'a>(
+    image: &'a IrImage,
+    alignment: &'a IrAlignment,
+    pending: Option<(f64, f64)>,
+    grid_corners: Option<[(f64, f64); 4]>,
+) -> Element<'a, Message> {
+    let scale = IR_VIEW_WIDTH / image.width.max(1) as f32;
+    #[allow(clippy::cast_precision_loss)] // photo dimensions fit in f32
+    let height = image.height as f32 * scale;
+    canvas::Canvas::new(IrCanvas {
+        image,
+        alignment,
+        pending,
+        grid_corners,
+        scale,
+    })
+    .width(Length::Fixed(IR_VIEW_WIDTH))
+    .height(Length::Fixed(height))
+    .into()
+}
+
+struct IrCanvas<'a> {
+    image: &'a IrImage,
+    alignment: &'a IrAlignment,
+    pending: Option<(f64, f64)>,
+    grid_corners: Option<[(f64, f64); 4]>,
+    /// Canvas pixels per photo pixel
+    scale: f32,
+}
+
+impl IrCanvas<'_> {
+    #[allow(clippy::cast_possible_truncation)] // canvas coordinates fit in f32
+    fn to_canvas(&self, (x, y): (f64, f64)) -> Point {
+        Point::new(x as f32 * self.scale, y as f32 * self.scale)
+    }
+}
+
+impl canvas::Program<Message> for IrCanvas<'_> {
+    type State = ();
+
+    fn update(
+        &self,
+        _state: &mut (),
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<Action<Message>> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let p = cursor.position_in(bounds)?;
+                Some(
+                    Action::publish(Message::IrPhotoClicked(
+                        f64::from(p.x / self.scale),
+                        f64::from(p.y / self.scale),
+                    ))
+                    .and_capture(),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        frame.draw_image(Rectangle::with_size(bounds.size()), &self.image.handle);
+
+        if let Some(corners) = self.grid_corners {
+            let outline = Path::new(|p| {
+                p.move_to(self.to_canvas(corners[0]));
+                for &corner in &corners[1..] {
+                    p.line_to(self.to_canvas(corner));
+                }
+                p.close();
+            });
+            frame.stroke(
+                &outline,
+                Stroke::default()
+                    .with_color(theme::BRAND_ORANGE)
+                    .with_width(2.0),
+            );
+        }
+
+        for (n, point) in self.alignment.points.iter().enumerate() {
+            let center = self.to_canvas(point.pixel);
+            frame.fill(&Path::circle(center, MARKER_RADIUS), theme::BRAND_ORANGE);
+            frame.fill_text(canvas::Text {
+                content: (n + 1).to_string(),
+                position: center,
+                color: Color::BLACK,
+                size: 11.0.into(),
+                align_x: Alignment::Center,
+                align_y: Vertical::Center,
+                ..Default::default()
+            });
+        }
+
+        if let Some(pixel) = self.pending {
+            let Point { x, y } = self.to_canvas(pixel);
+            let cross = Path::new(|p| {
+                p.move_to(Point::new(x - PENDING_ARM, y));
+                p.line_to(Point::new(x + PENDING_ARM, y));
+                p.move_to(Point::new(x, y - PENDING_ARM));
+                p.line_to(Point::new(x, y + PENDING_ARM));
+            });
+            frame.stroke(
+                &cross,
+                Stroke::default().with_color(Color::WHITE).with_width(2.0),
+            );
+            frame.stroke(
+                &Path::circle(Point::new(x, y), PENDING_ARM),
+                Stroke::default().with_color(Color::WHITE).with_width(1.0),
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
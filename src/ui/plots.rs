@@ -0,0 +1,431 @@
+//! Small canvas plots for the stats panel
+
+use iced::{
+    Color, Element, Length, Point, Rectangle, Renderer, Size, Theme,
+    alignment::Vertical,
+    mouse,
+    widget::{
+        canvas::{self, Action, Event, Frame, Path, Stroke},
+        text::Alignment,
+    },
+};
+
+use crate::Message;
+use crate::theme;
+
+use super::chip_canvas::draw_tooltip;
+
+const SCATTER_HEIGHT: f32 = 140.0;
+// Plot area inset: room for the y label on the left and the x label below
+const SCATTER_MARGIN_LEFT: f32 = 14.0;
+const SCATTER_MARGIN_BOTTOM: f32 = 14.0;
+const SCATTER_MARGIN: f32 = 6.0;
+const SCATTER_DOT_RADIUS: f32 = 3.0;
+// A dot counts as hovered within this many pixels of the cursor
+const SCATTER_HOVER_RADIUS: f32 = 6.0;
+const AXIS_LABEL_SIZE: f32 = 10.0;
+
+// Slot header sparkline
+const SPARKLINE_WIDTH: f32 = 60.0;
+const SPARKLINE_HEIGHT: f32 = 10.0;
+
+// Gradient legend: bar above the threshold labels
+const LEGEND_BAR_HEIGHT: f32 = 12.0;
+const LEGEND_HEIGHT: f32 = LEGEND_BAR_HEIGHT + 14.0;
+
+/// Scatter plot of points in 0–1 on both axes, with a least-squares trend line
+///
+/// `colors` and `labels` are parallel to the values; a point's label shows on hover.
+pub fn scatter_plot<'a>(
+    x_values: &[f32],
+    y_values: &[f32],
+    colors: Vec<Color>,
+    labels: Vec<String>,
+    x_label: &str,
+    y_label: &str,
+) -> Element<'a, Message> {
+    let points: Vec<Point> = x_values
+        .iter()
+        .zip(y_values)
+        .map(|(&x, &y)| Point::new(x.clamp(0.0, 1.0), y.clamp(0.0, 1.0)))
+        .collect();
+    canvas::Canvas::new(ScatterPlot {
+        trend: linear_fit(x_values, y_values),
+        points,
+        colors,
+        labels,
+        x_label: x_label.to_string(),
+        y_label: y_label.to_string(),
+    })
+    .width(Length::Fill)
+    .height(SCATTER_HEIGHT)
+    .into()
+}
+
+/// Compact line chart of `values` scaled to their min..max; a flat series is
+/// drawn along the middle
+pub fn sparkline<'a>(values: &[f32], color: Color) -> Element<'a, Message> {
+    canvas::Canvas::new(Sparkline {
+        values: values.to_vec(),
+        color,
+    })
+    .width(SPARKLINE_WIDTH)
+    .height(SPARKLINE_HEIGHT)
+    .into()
+}
+
+/// Scale values to 0–1 by the largest one; all zeros stay zero
+#[allow(clippy::cast_precision_loss)] // counts fit in f32
+pub fn normalize(values: impl IntoIterator<Item = i64>) -> Vec<f32> {
+    let values: Vec<i64> = values.into_iter().collect();
+    let max = values.iter().copied().max().unwrap_or(0).max(1) as f32;
+    values.iter().map(|&v| v.max(0) as f32 / max).collect()
+}
+
+/// Least-squares (slope, intercept); None with fewer than two distinct x values
+#[allow(clippy::cast_precision_loss)] // point counts fit in f32
+pub fn linear_fit(xs: &[f32], ys: &[f32]) -> Option<(f32, f32)> {
+    let n = xs.len().min(ys.len());
+    if n < 2 {
+        return None;
+    }
+    let (xs, ys) = (&xs[..n], &ys[..n]);
+    let mean_x = xs.iter().sum::<f32>() / n as f32;
+    let mean_y = ys.iter().sum::<f32>() / n as f32;
+    let (cov, var) = xs.iter().zip(ys).fold((0.0, 0.0), |(cov, var), (&x, &y)| {
+        (
+            cov + (x - mean_x) * (y - mean_y),
+            var + (x - mean_x).powi(2),
+        )
+    });
+    if var <= f32::EPSILON {
+        return None;
+    }
+    let slope = cov / var;
+    Some((slope, mean_y - slope * mean_x))
+}
+
+/// Continuous color legend from `min_val` to `max_val`, one 1px slice per column,
+/// with the stop values written under the bar
+pub fn gradient_legend<'a>(
+    stops: [(f32, Color); 4],
+    min_val: f32,
+    max_val: f32,
+    unit: &'static str,
+    width: f32,
+) -> Element<'a, Message> {
+    canvas::Canvas::new(GradientLegend {
+        stops,
+        min_val,
+        max_val,
+        unit,
+    })
+    .width(width)
+    .height(LEGEND_HEIGHT)
+    .into()
+}
+
+/// Legend annotation: whole numbers without decimals, others with one
+fn legend_value(value: f32) -> String {
+    if (value - value.round()).abs() < 0.05 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+struct GradientLegend {
+    stops: [(f32, Color); 4],
+    min_val: f32,
+    max_val: f32,
+    unit: &'static str,
+}
+
+impl canvas::Program<Message> for GradientLegend {
+    type State = ();
+
+    #[allow(clippy::cast_precision_loss)] // pixel columns fit in f32
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let width = bounds.width.max(1.0);
+        let span = self.max_val - self.min_val;
+        let columns = width.ceil() as usize;
+        for x in 0..columns {
+            let x = x as f32;
+            let value = self.min_val + span * (x + 0.5) / width;
+            frame.fill_rectangle(
+                Point::new(x, 0.0),
+                Size::new(1.0, LEGEND_BAR_HEIGHT),
+                theme::legend_color(&self.stops, value),
+            );
+        }
+
+        let last = self.stops.len() - 1;
+        for (i, &(value, _)) in self.stops.iter().enumerate() {
+            let x = if span > 0.0 {
+                (value - self.min_val) / span * width
+            } else {
+                0.0
+            };
+            let (content, align_x) = match i {
+                0 => (legend_value(value), Alignment::Left),
+                i if i == last => (
+                    format!("{}{}", legend_value(value), self.unit),
+                    Alignment::Right,
+                ),
+                _ => (legend_value(value), Alignment::Center),
+            };
+            frame.fill_text(canvas::Text {
+                content,
+                position: Point::new(x.clamp(0.0, width), LEGEND_BAR_HEIGHT + 2.0),
+                color: theme::plot_axis_color(),
+                size: AXIS_LABEL_SIZE.into(),
+                align_x,
+                align_y: Vertical::Top,
+                ..Default::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+struct Sparkline {
+    values: Vec<f32>,
+    color: Color,
+}
+
+impl canvas::Program<Message> for Sparkline {
+    type State = ();
+
+    #[allow(clippy::cast_precision_loss)] // poll counts fit in f32
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let min = self.values.iter().copied().fold(f32::MAX, f32::min);
+        let max = self.values.iter().copied().fold(f32::MIN, f32::max);
+        let (width, height) = (bounds.width, bounds.height - 1.0);
+        let step = width / (self.values.len().max(2) - 1) as f32;
+        let point = |i: usize, v: f32| {
+            let y = if max - min > f32::EPSILON {
+                (max - v) / (max - min) * height
+            } else {
+                height / 2.0
+            };
+            Point::new(i as f32 * step, y + 0.5)
+        };
+        let line = Path::new(|p| {
+            for (i, &v) in self.values.iter().enumerate() {
+                if i == 0 {
+                    p.move_to(point(i, v));
+                } else {
+                    p.line_to(point(i, v));
+                }
+            }
+        });
+        frame.stroke(
+            &line,
+            Stroke::default().with_color(self.color).with_width(1.0),
+        );
+        vec![frame.into_geometry()]
+    }
+}
+
+struct ScatterPlot {
+    /// Normalized (x, y) per point
+    points: Vec<Point>,
+    colors: Vec<Color>,
+    labels: Vec<String>,
+    trend: Option<(f32, f32)>,
+    x_label: String,
+    y_label: String,
+}
+
+impl ScatterPlot {
+    /// Plot area inside the axis labels
+    fn area(size: Size) -> Rectangle {
+        Rectangle {
+            x: SCATTER_MARGIN_LEFT,
+            y: SCATTER_MARGIN,
+            width: (size.width - SCATTER_MARGIN_LEFT - SCATTER_MARGIN).max(1.0),
+            height: (size.height - SCATTER_MARGIN - SCATTER_MARGIN_BOTTOM).max(1.0),
+        }
+    }
+
+    /// Canvas position of a normalized point; y grows upward
+    fn to_canvas(area: Rectangle, p: Point) -> Point {
+        Point::new(
+            area.x + p.x * area.width,
+            area.y + (1.0 - p.y) * area.height,
+        )
+    }
+
+    /// Nearest point within SCATTER_HOVER_RADIUS of `cursor`
+    fn hit_test(&self, size: Size, cursor: Point) -> Option<usize> {
+        let area = Self::area(size);
+        self.points
+            .iter()
+            .map(|&p| Self::to_canvas(area, p).distance(cursor))
+            .enumerate()
+            .filter(|&(_, d)| d <= SCATTER_HOVER_RADIUS)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(idx, _)| idx)
+    }
+}
+
+#[derive(Default)]
+struct ScatterState {
+    hovered: Option<usize>,
+}
+
+impl canvas::Program<Message> for ScatterPlot {
+    type State = ScatterState;
+
+    fn update(
+        &self,
+        state: &mut ScatterState,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<Action<Message>> {
+        let Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) = event
+        else {
+            return None;
+        };
+        let hovered = cursor
+            .position_in(bounds)
+            .and_then(|p| self.hit_test(bounds.size(), p));
+        if hovered == state.hovered {
+            return None;
+        }
+        state.hovered = hovered;
+        Some(Action::request_redraw())
+    }
+
+    fn draw(
+        &self,
+        state: &ScatterState,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let area = Self::area(bounds.size());
+        let axis = Stroke::default()
+            .with_color(theme::plot_axis_color())
+            .with_width(1.0);
+        let origin = Point::new(area.x, area.y + area.height);
+        frame.stroke(&Path::line(origin, Point::new(area.x, area.y)), axis);
+        frame.stroke(
+            &Path::line(origin, Point::new(area.x + area.width, origin.y)),
+            axis,
+        );
+        let label = |content: &str, position, align_x| canvas::Text {
+            content: content.to_string(),
+            position,
+            color: theme::plot_axis_color(),
+            size: AXIS_LABEL_SIZE.into(),
+            align_x,
+            align_y: Vertical::Top,
+            ..Default::default()
+        };
+        frame.fill_text(label(
+            &self.x_label,
+            Point::new(area.x + area.width, origin.y + 2.0),
+            Alignment::Right,
+        ));
+        frame.fill_text(label(
+            &self.y_label,
+            Point::new(area.x + 4.0, area.y),
+            Alignment::Left,
+        ));
+
+        for (idx, &p) in self.points.iter().enumerate() {
+            let color = self.colors.get(idx).copied().unwrap_or(Color::WHITE);
+            frame.fill(
+                &Path::circle(Self::to_canvas(area, p), SCATTER_DOT_RADIUS),
+                color,
+            );
+        }
+
+        if let Some((slope, intercept)) = self.trend {
+            let at = |x: f32| {
+                Self::to_canvas(area, Point::new(x, (slope * x + intercept).clamp(0.0, 1.0)))
+            };
+            frame.stroke(
+                &Path::line(at(0.0), at(1.0)),
+                Stroke::default()
+                    .with_color(theme::BRAND_ORANGE)
+                    .with_width(1.5),
+            );
+        }
+
+        if let Some(idx) = state.hovered
+            && let (Some(&p), Some(text)) = (self.points.get(idx), self.labels.get(idx))
+        {
+            let center = Self::to_canvas(area, p);
+            let dot = Point::new(center.x - SCATTER_DOT_RADIUS, center.y - SCATTER_DOT_RADIUS);
+            draw_tooltip(
+                &mut frame,
+                text,
+                dot,
+                SCATTER_DOT_RADIUS * 2.0,
+                bounds.size(),
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &ScatterState,
+        _bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if state.hovered.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_fit() {
+        let (slope, intercept) = linear_fit(&[0.0, 0.5, 1.0], &[1.0, 0.75, 0.5]).unwrap();
+        assert!((slope + 0.5).abs() < 1e-6);
+        assert!((intercept - 1.0).abs() < 1e-6);
+        // All x equal: no line
+        assert_eq!(linear_fit(&[0.3, 0.3], &[0.1, 0.9]), None);
+        assert_eq!(linear_fit(&[0.3], &[0.1]), None);
+
+        assert_eq!(normalize([0, 5, 10]), [0.0, 0.5, 1.0]);
+        assert_eq!(normalize([0, 0]), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_legend_value() {
+        assert_eq!(legend_value(64.0), "64");
+        assert_eq!(legend_value(6.000_000_5), "6");
+        assert_eq!(legend_value(1.2), "1.2");
+    }
+}
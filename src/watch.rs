@@ -0,0 +1,158 @@
+//! Watch window: chips tracked across polls, alerting when their temperature drifts from a baseline
+
+use std::collections::VecDeque;
+
+use crate::analysis::ChipAnalysis;
+use crate::models::MinerData;
+
+/// Poll values kept per watched chip (sparkline length)
+pub const WATCH_HISTORY_LEN: usize = 20;
+
+/// Default temperature drift from the baseline (°C) that raises an alert
+pub const DEFAULT_ALERT_DELTA: i32 = 5;
+
+/// A chip in the watch window, identified by slot id and chip id
+#[derive(Debug, Clone)]
+pub struct WatchedChip {
+    pub slot_id: i32,
+    pub chip_id: i32,
+    /// Temperature when the chip was added to the watch
+    pub baseline_temp: i32,
+    /// Temperatures of the last WATCH_HISTORY_LEN polls, oldest first
+    pub history: VecDeque<i32>,
+    pub nonce: i64,
+    pub gradient: f32,
+    /// Over the alert delta as of the last poll; used to notify only once per excursion
+    pub alerting: bool,
+}
+
+impl WatchedChip {
+    /// Start watching a chip, using its current temperature as the baseline
+    pub fn new(
+        data: &MinerData,
+        all_analysis: &[Vec<ChipAnalysis>],
+        slot_id: i32,
+        chip_id: i32,
+    ) -> Option<Self> {
+        let (temp, nonce, gradient) = chip_values(data, all_analysis, slot_id, chip_id)?;
+        Some(Self {
+            slot_id,
+            chip_id,
+            baseline_temp: temp,
+            history: VecDeque::from([temp]),
+            nonce,
+            gradient,
+            alerting: false,
+        })
+    }
+
+    /// Latest polled temperature
+    pub fn temp(&self) -> i32 {
+        self.history.back().copied().unwrap_or(self.baseline_temp)
+    }
+
+    /// Change from the baseline temperature (°C)
+    pub fn drift(&self) -> i32 {
+        self.temp() - self.baseline_temp
+    }
+
+    /// Record the chip's values from a new poll
+    ///
+    /// Returns true if the chip just crossed `alert_delta`; a chip that stays
+    /// over the delta doesn't alert again until it comes back within it.
+    pub fn record(
+        &mut self,
+        data: &MinerData,
+        all_analysis: &[Vec<ChipAnalysis>],
+        alert_delta: i32,
+    ) -> bool {
+        let Some((temp, nonce, gradient)) =
+            chip_values(data, all_analysis, self.slot_id, self.chip_id)
+        else {
+            return false;
+        };
+        if self.history.len() == WATCH_HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(temp);
+        self.nonce = nonce;
+        self.gradient = gradient;
+
+        let was_alerting = self.alerting;
+        self.alerting = self.drift().abs() > alert_delta;
+        self.alerting && !was_alerting
+    }
+}
+
+/// (temp, nonce, gradient) of a chip, matched by slot id and chip id
+fn chip_values(
+    data: &MinerData,
+    all_analysis: &[Vec<ChipAnalysis>],
+    slot_id: i32,
+    chip_id: i32,
+) -> Option<(i32, i64, f32)> {
+    let slot_idx = data.slots.iter().position(|s| s.id == slot_id)?;
+    let slot = &data.slots[slot_idx];
+    let chip_idx = slot.chips.iter().position(|c| c.id == chip_id)?;
+    let chip = &slot.chips[chip_idx];
+    let gradient = all_analysis
+        .get(slot_idx)
+        .and_then(|a| a.get(chip_idx))
+        .map_or(0.0, |a| a.gradient);
+    Some((chip.temp, chip.nonce, gradient))
+}
+
+/// Show a desktop notification; failures (no notification daemon) are ignored
+pub async fn notify(summary: String, body: String) {
+    let _ = tokio::task::spawn_blocking(move || {
+        notify_rust::Notification::new()
+            .summary(&summary)
+            .body(&body)
+            .show()
+    })
+    .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Chip, Slot};
+
+    fn data_with_temp(temp: i32) -> MinerData {
+        MinerData {
+            slots: vec![Slot {
+                id: 1,
+                chips: vec![Chip {
+                    id: 7,
+                    temp,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_watch_alerts_once_per_excursion() {
+        let mut chip = WatchedChip::new(&data_with_temp(70), &[], 1, 7).unwrap();
+        assert!(!chip.record(&data_with_temp(74), &[], 5));
+        assert!(chip.record(&data_with_temp(76), &[], 5));
+        assert_eq!(chip.drift(), 6);
+        // Still hot: no repeat alert
+        assert!(!chip.record(&data_with_temp(78), &[], 5));
+        // Back within the delta, then over again (cooling counts too)
+        assert!(!chip.record(&data_with_temp(72), &[], 5));
+        assert!(chip.record(&data_with_temp(64), &[], 5));
+    }
+
+    #[test]
+    fn test_watch_history_is_capped() {
+        let mut chip = WatchedChip::new(&data_with_temp(70), &[], 1, 7).unwrap();
+        for t in 0..30 {
+            chip.record(&data_with_temp(70 + t % 3), &[], 5);
+        }
+        assert_eq!(chip.history.len(), WATCH_HISTORY_LEN);
+        assert!(WatchedChip::new(&data_with_temp(70), &[], 2, 7).is_none());
+    }
+}